@@ -0,0 +1,69 @@
+//! Compares collecting built-in locale messages inline with collecting them boxed.
+//!
+//! Run with `cargo bench --bench error_collection`.
+
+use cjtoolkit_structured_validator::base::number_rules::NumberMandatoryLocale;
+use cjtoolkit_structured_validator::base::string_rules::StringLengthLocale;
+use cjtoolkit_structured_validator::common::locale::{LocaleMessage, ValidateErrorCollector};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 1_000_000;
+
+fn measure(name: &str, push: impl Fn(&mut ValidateErrorCollector)) {
+    let mut allocations = 0;
+    let started = Instant::now();
+    for _ in 0..ITERATIONS {
+        let mut messages = ValidateErrorCollector(Vec::with_capacity(1));
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        push(&mut messages);
+        allocations += ALLOCATIONS.load(Ordering::Relaxed) - before;
+        black_box(messages);
+    }
+    let elapsed = started.elapsed();
+    println!(
+        "{name:<32} {:>5.2} allocations/error {:>8.1} ns/error",
+        allocations as f64 / ITERATIONS as f64,
+        elapsed.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    measure("min length, boxed", |messages| {
+        let locale: Box<dyn LocaleMessage> = Box::new(StringLengthLocale::MinLength(5));
+        messages.push((format!("Must be at least {} characters", 5), locale));
+    });
+    measure("min length, inline", |messages| {
+        messages.push((
+            format!("Must be at least {} characters", 5),
+            StringLengthLocale::MinLength(5),
+        ));
+    });
+    measure("mandatory, boxed", |messages| {
+        let locale: Box<dyn LocaleMessage> = Box::new(NumberMandatoryLocale);
+        messages.push(("Cannot be empty".to_string(), locale));
+    });
+    measure("mandatory, inline", |messages| {
+        messages.push(("Cannot be empty", NumberMandatoryLocale));
+    });
+}
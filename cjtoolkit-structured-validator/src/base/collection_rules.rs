@@ -0,0 +1,140 @@
+//! This module contains the `CollectionRules` struct, which is used to define rules for
+//! validating the number of items of a collection, e.g. an array of a JSON payload.
+
+use crate::common::constraint::Constraint;
+use crate::common::locale::{LocaleData, LocaleMessage, LocaleValue, ValidateErrorCollector};
+use crate::common::locale_keys;
+use crate::common::observer::rule_result;
+use crate::common::rule_violation::RuleViolation;
+use std::sync::Arc;
+
+/// An enumeration of the collection specific validation failures.
+#[derive(Clone)]
+pub enum CollectionLocale {
+    /// The collection was not provided.
+    /// # Key
+    /// `validate-cannot-be-empty`
+    Mandatory,
+    /// Minimum number of items constraint.
+    /// # Key
+    /// `validate-min-items`
+    MinItems(usize),
+    /// Maximum number of items constraint.
+    /// # Key
+    /// `validate-max-items`
+    MaxItems(usize),
+}
+
+impl LocaleMessage for CollectionLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::Mandatory => ld::new(locale_keys::VALIDATE_CANNOT_BE_EMPTY),
+            Self::MinItems(min) => ld::new_with_vec(
+                locale_keys::VALIDATE_MIN_ITEMS,
+                vec![("min".to_string(), lv::from(*min))],
+            ),
+            Self::MaxItems(max) => ld::new_with_vec(
+                locale_keys::VALIDATE_MAX_ITEMS,
+                vec![("max".to_string(), lv::from(*max))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::Mandatory => RuleViolation::Mandatory,
+            Self::MinItems(min) => RuleViolation::MinItems { min: *min },
+            Self::MaxItems(max) => RuleViolation::MaxItems { max: *max },
+        }
+    }
+}
+
+/// A structure representing rules for validating the number of items of a collection.
+///
+/// # Fields
+/// * `is_mandatory` - Whether the collection must be provided. An empty collection is provided.
+/// * `min_items` - An optional minimum number of items.
+/// * `max_items` - An optional maximum number of items.
+///
+/// # Defaults
+/// The collection is mandatory, without limits on its number of items.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectionRules {
+    pub is_mandatory: bool,
+    pub min_items: Option<usize>,
+    pub max_items: Option<usize>,
+}
+
+impl Default for CollectionRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            min_items: None,
+            max_items: None,
+        }
+    }
+}
+
+impl CollectionRules {
+    /// Checks the number of items `len` of a collection, `None` if the collection was not
+    /// provided, and collects the errors in `messages`.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::base::collection_rules::CollectionRules;
+    /// use cjtoolkit_structured_validator::common::locale::ValidateErrorCollector;
+    ///
+    /// let rules = CollectionRules {
+    ///     min_items: Some(1),
+    ///     max_items: Some(3),
+    ///     ..CollectionRules::default()
+    /// };
+    /// let mut errors = ValidateErrorCollector::new();
+    /// rules.check(&mut errors, Some(2));
+    /// assert!(errors.is_empty());
+    /// rules.check(&mut errors, Some(4));
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    pub fn check(&self, messages: &mut ValidateErrorCollector, len: Option<usize>) {
+        let Some(len) = len else {
+            if self.is_mandatory {
+                rule_result(locale_keys::VALIDATE_CANNOT_BE_EMPTY, false);
+                messages.push(("Cannot be empty", Box::new(CollectionLocale::Mandatory)));
+            }
+            return;
+        };
+        if let Some(min_items) = self.min_items {
+            rule_result(locale_keys::VALIDATE_MIN_ITEMS, len >= min_items);
+            if len < min_items {
+                messages.push_default(Box::new(CollectionLocale::MinItems(min_items)));
+            }
+        }
+        if let Some(max_items) = self.max_items {
+            rule_result(locale_keys::VALIDATE_MAX_ITEMS, len <= max_items);
+            if len > max_items {
+                messages.push_default(Box::new(CollectionLocale::MaxItems(max_items)));
+            }
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.is_mandatory {
+            constraints.push(Constraint::from_locale(&CollectionLocale::Mandatory));
+        }
+        if let Some(min_items) = self.min_items {
+            constraints.push(Constraint::from_locale(&CollectionLocale::MinItems(
+                min_items,
+            )));
+        }
+        if let Some(max_items) = self.max_items {
+            constraints.push(Constraint::from_locale(&CollectionLocale::MaxItems(
+                max_items,
+            )));
+        }
+        constraints
+    }
+}
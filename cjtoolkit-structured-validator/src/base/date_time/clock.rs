@@ -0,0 +1,41 @@
+//! This module contains a trait for supplying the current time to the date and time rules.
+
+use std::time::SystemTime;
+
+/// A source of the current time.
+///
+/// The rules depending on the current time take a `Clock`, so tests and replays can supply a
+/// fixed reference time with `FixedClock` instead of the `SystemClock`.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::base::date_time::clock::{Clock, FixedClock};
+/// use std::time::{Duration, SystemTime};
+///
+/// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+/// assert_eq!(FixedClock(now).now(), now);
+/// ```
+pub trait Clock: Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// A clock returning the time of the system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock always returning the same time.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub SystemTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
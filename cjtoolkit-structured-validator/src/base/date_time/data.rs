@@ -1,3 +1,4 @@
+use crate::base::calendar::civil_from_days;
 use std::fmt::Display;
 
 /// `DateTimeKind` is an enumeration that represents different kinds of date and time representations.
@@ -14,7 +15,7 @@ use std::fmt::Display;
 /// # Default
 ///
 /// The `DateTime` variant is the default variant of this enum, as specified by the `#[default]` attribute.
-#[derive(Default, Clone)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum DateTimeKind {
     Date,
     #[default]
@@ -44,7 +45,23 @@ pub enum DateTimeKind {
 /// * This struct implements the `Default` trait, providing a convenient way to create an instance
 ///   with default values.
 /// * It also implements the `Clone` trait, allowing the struct to be copied efficiently.
-#[derive(Default, Clone)]
+/// * The values are compared by `timestamp_seconds_days` and `subsec_nano` only, so only values
+///   of the same `kind` should be compared.
+///
+/// # Example
+///
+/// ```rust
+/// use cjtoolkit_structured_validator::base::date_time::data::{DateTimeData, DateTimeKind};
+///
+/// let start = DateTimeData::from_timestamp(1_700_000_000, 0);
+/// let end = DateTimeData::from_timestamp(1_700_000_000, 500_000_000);
+///
+/// assert_eq!(start.to_string(), "2023-11-14T22:13:20+00:00");
+/// assert_eq!(end.to_string(), "2023-11-14T22:13:20.500+00:00");
+/// assert!(start.is_before(&end));
+/// assert!(start.same_kind(&DateTimeData::from_parts(DateTimeKind::DateTime, "now", 0, 0)));
+/// ```
+#[derive(Debug, Default, Clone)]
 pub struct DateTimeData {
     pub kind: DateTimeKind,
     pub date_formatted: String,
@@ -52,6 +69,118 @@ pub struct DateTimeData {
     pub subsec_nano: u32,
 }
 
+impl DateTimeData {
+    /// Creates the data of a date and time from its fields.
+    ///
+    /// `timestamp_seconds_days` is the number of seconds since the UNIX epoch for `DateTime` and
+    /// `DateTimeNaive`, the number of days since the common era, `0001-01-01` being day `1`, for
+    /// `Date`, and the number of seconds since midnight for `Time`.
+    pub fn from_parts(
+        kind: DateTimeKind,
+        date_formatted: impl Into<String>,
+        timestamp_seconds_days: i64,
+        subsec_nano: u32,
+    ) -> Self {
+        Self {
+            kind,
+            date_formatted: date_formatted.into(),
+            timestamp_seconds_days,
+            subsec_nano,
+        }
+    }
+
+    /// Creates the data of the date and time `seconds` and `subsec_nano` after the UNIX epoch,
+    /// formatted as RFC 3339 in UTC.
+    pub fn from_timestamp(seconds: i64, subsec_nano: u32) -> Self {
+        Self::from_parts(
+            DateTimeKind::DateTime,
+            format_rfc3339(seconds, subsec_nano),
+            seconds,
+            subsec_nano,
+        )
+    }
+
+    /// Returns `true` if `self` is strictly before `other`.
+    pub fn is_before(&self, other: &Self) -> bool {
+        self < other
+    }
+
+    /// Returns `true` if `self` is strictly after `other`.
+    pub fn is_after(&self, other: &Self) -> bool {
+        self > other
+    }
+
+    /// Returns `true` if `self` and `other` are of the same kind, so can be compared.
+    pub fn same_kind(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+
+    /// Returns the calendar date of a `Date`, `None` for the other kinds.
+    pub fn calendar_date(&self) -> Option<CalendarDate> {
+        (self.kind == DateTimeKind::Date)
+            .then(|| CalendarDate::from_days_from_ce(self.timestamp_seconds_days))
+    }
+}
+
+/// A date of the proleptic Gregorian calendar.
+///
+/// # Fields
+///
+/// * `year` - The year, e.g. `2025`.
+/// * `month` - The month, from `1` for January to `12`.
+/// * `day` - The day of the month, from `1`.
+/// * `weekday` - The ISO 8601 day of the week, from `1` for Monday to `7` for Sunday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarDate {
+    pub year: i64,
+    pub month: u8,
+    pub day: u8,
+    pub weekday: u8,
+}
+
+impl CalendarDate {
+    /// Returns the date `days` since the common era, `0001-01-01`, a Monday, being day `1`.
+    pub fn from_days_from_ce(days: i64) -> Self {
+        let (year, month, day) = civil_from_days(days - 719_163);
+        Self {
+            year,
+            month,
+            day,
+            weekday: ((days - 1).rem_euclid(7) + 1) as u8,
+        }
+    }
+
+    /// Returns `true` for Saturday and Sunday.
+    pub fn is_weekend(&self) -> bool {
+        self.weekday >= 6
+    }
+}
+
+/// Formats a UNIX timestamp as RFC 3339 in UTC, with the fraction of seconds in milli, micro or
+/// nano seconds, omitted when zero.
+fn format_rfc3339(seconds: i64, subsec_nano: u32) -> String {
+    let days = seconds.div_euclid(86_400);
+    let second_of_day = seconds.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+
+    let fraction = match subsec_nano {
+        0 => String::new(),
+        nano if nano % 1_000_000 == 0 => format!(".{:03}", nano / 1_000_000),
+        nano if nano % 1_000 == 0 => format!(".{:06}", nano / 1_000),
+        nano => format!(".{:09}", nano),
+    };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}+00:00",
+        year,
+        month,
+        day,
+        second_of_day / 3600,
+        second_of_day / 60 % 60,
+        second_of_day % 60,
+        fraction
+    )
+}
+
 impl Display for DateTimeData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.date_formatted)
@@ -65,10 +194,18 @@ impl PartialEq for DateTimeData {
     }
 }
 
+impl Eq for DateTimeData {}
+
 impl PartialOrd for DateTimeData {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTimeData {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         (self.timestamp_seconds_days, self.subsec_nano)
-            .partial_cmp(&(other.timestamp_seconds_days, other.subsec_nano))
+            .cmp(&(other.timestamp_seconds_days, other.subsec_nano))
     }
 }
 
@@ -219,6 +356,192 @@ mod humantime_impl {
     }
 }
 
+#[cfg(feature = "time")]
+mod time_impl {
+    use super::*;
+    use time::format_description::BorrowedFormatItem;
+    use time::format_description::well_known::Rfc3339;
+    use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+    /// The Julian day of `0000-12-31`, so `0001-01-01` is day `1` of the common era, like chrono.
+    const JULIAN_DAY_OF_CE: i64 = 1_721_425;
+
+    fn days_from_ce(date: &Date) -> i64 {
+        date.to_julian_day() as i64 - JULIAN_DAY_OF_CE
+    }
+
+    fn seconds_from_midnight(time: &Time) -> i64 {
+        let (hour, minute, second) = time.as_hms();
+        hour as i64 * 3600 + minute as i64 * 60 + second as i64
+    }
+
+    impl AsDateTimeData for OffsetDateTime {
+        fn as_date_time_data(&self) -> DateTimeData {
+            DateTimeData {
+                kind: DateTimeKind::DateTime,
+                date_formatted: self.format(&Rfc3339).unwrap_or_else(|_| self.to_string()),
+                timestamp_seconds_days: self.unix_timestamp(),
+                subsec_nano: self.nanosecond(),
+            }
+        }
+    }
+
+    impl AsDateTimeData for Date {
+        fn as_date_time_data(&self) -> DateTimeData {
+            DateTimeData {
+                kind: DateTimeKind::Date,
+                date_formatted: self.to_string(),
+                timestamp_seconds_days: days_from_ce(self),
+                subsec_nano: 0,
+            }
+        }
+    }
+
+    impl AsDateTimeData for PrimitiveDateTime {
+        fn as_date_time_data(&self) -> DateTimeData {
+            let as_utc = self.assume_utc();
+            DateTimeData {
+                kind: DateTimeKind::DateTimeNaive,
+                date_formatted: self.to_string(),
+                timestamp_seconds_days: as_utc.unix_timestamp(),
+                subsec_nano: as_utc.nanosecond(),
+            }
+        }
+    }
+
+    impl AsDateTimeData for Time {
+        fn as_date_time_data(&self) -> DateTimeData {
+            DateTimeData {
+                kind: DateTimeKind::Time,
+                date_formatted: self.to_string(),
+                timestamp_seconds_days: seconds_from_midnight(self),
+                subsec_nano: self.nanosecond(),
+            }
+        }
+    }
+
+    /// Formats the data of `subject` with `format`, falling back to its default format when it
+    /// does not apply, e.g. a time component in the format of a date.
+    fn with_format<T: AsDateTimeData>(
+        subject: &T,
+        format: Option<&[BorrowedFormatItem<'_>]>,
+        apply: impl FnOnce(&T, &[BorrowedFormatItem<'_>]) -> Result<String, time::error::Format>,
+    ) -> DateTimeData {
+        let mut data = subject.as_date_time_data();
+        if let Some(Ok(formatted)) = format.map(|format| apply(subject, format)) {
+            data.date_formatted = formatted;
+        }
+        data
+    }
+
+    impl AsDateTimeData for (Option<&[BorrowedFormatItem<'_>]>, &Date) {
+        fn as_date_time_data(&self) -> DateTimeData {
+            with_format(self.1, self.0, |date, format| date.format(format))
+        }
+    }
+
+    impl AsDateTimeData for (Option<&[BorrowedFormatItem<'_>]>, &PrimitiveDateTime) {
+        fn as_date_time_data(&self) -> DateTimeData {
+            with_format(self.1, self.0, |date_time, format| date_time.format(format))
+        }
+    }
+
+    impl AsDateTimeData for (Option<&[BorrowedFormatItem<'_>]>, &Time) {
+        fn as_date_time_data(&self) -> DateTimeData {
+            with_format(self.1, self.0, |time, format| time.format(format))
+        }
+    }
+}
+
+#[cfg(feature = "jiff")]
+mod jiff_impl {
+    use super::*;
+    use jiff::Zoned;
+    use jiff::civil::{Date, Time};
+    use jiff::fmt::strtime;
+
+    /// The days from `0001-01-01` to the UNIX epoch, so `0001-01-01` is day `1` of the common
+    /// era, like chrono.
+    const DAYS_FROM_CE_TO_EPOCH: i64 = 719_163;
+
+    /// Days since the UNIX epoch of a civil date, see
+    /// https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    fn days_from_epoch(date: &Date) -> i64 {
+        let (month, day) = (date.month() as i64, date.day() as i64);
+        let year = date.year() as i64 - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn seconds_from_midnight(time: &Time) -> i64 {
+        time.hour() as i64 * 3600 + time.minute() as i64 * 60 + time.second() as i64
+    }
+
+    impl AsDateTimeData for Zoned {
+        fn as_date_time_data(&self) -> DateTimeData {
+            let timestamp = self.timestamp();
+            let (seconds, nano) = (timestamp.as_second(), timestamp.subsec_nanosecond());
+            // jiff signs the fraction like the seconds, e.g. -1.5s is -1s and -500_000_000ns.
+            let (seconds, nano) = if nano < 0 {
+                (seconds - 1, nano + 1_000_000_000)
+            } else {
+                (seconds, nano)
+            };
+            DateTimeData {
+                kind: DateTimeKind::DateTime,
+                date_formatted: self.to_string(),
+                timestamp_seconds_days: seconds,
+                subsec_nano: nano as u32,
+            }
+        }
+    }
+
+    impl AsDateTimeData for Date {
+        fn as_date_time_data(&self) -> DateTimeData {
+            DateTimeData {
+                kind: DateTimeKind::Date,
+                date_formatted: self.to_string(),
+                timestamp_seconds_days: days_from_epoch(self) + DAYS_FROM_CE_TO_EPOCH,
+                subsec_nano: 0,
+            }
+        }
+    }
+
+    impl AsDateTimeData for (Option<&str>, &Date) {
+        fn as_date_time_data(&self) -> DateTimeData {
+            let mut data = self.1.as_date_time_data();
+            if let Some(Ok(formatted)) = self.0.map(|format| strtime::format(format, *self.1)) {
+                data.date_formatted = formatted;
+            }
+            data
+        }
+    }
+
+    impl AsDateTimeData for Time {
+        fn as_date_time_data(&self) -> DateTimeData {
+            DateTimeData {
+                kind: DateTimeKind::Time,
+                date_formatted: self.to_string(),
+                timestamp_seconds_days: seconds_from_midnight(self),
+                subsec_nano: self.subsec_nanosecond() as u32,
+            }
+        }
+    }
+
+    impl AsDateTimeData for (Option<&str>, &Time) {
+        fn as_date_time_data(&self) -> DateTimeData {
+            let mut data = self.1.as_date_time_data();
+            if let Some(Ok(formatted)) = self.0.map(|format| strtime::format(format, *self.1)) {
+                data.date_formatted = formatted;
+            }
+            data
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,4 +563,75 @@ mod tests {
 
         assert!(a < b);
     }
+
+    #[test]
+    fn test_from_timestamp() {
+        assert_eq!(
+            DateTimeData::from_timestamp(0, 0).to_string(),
+            "1970-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            DateTimeData::from_timestamp(-1, 1_000).to_string(),
+            "1969-12-31T23:59:59.000001+00:00"
+        );
+        assert_eq!(
+            DateTimeData::from_timestamp(951_825_600, 7).to_string(),
+            "2000-02-29T12:00:00.000000007+00:00"
+        );
+    }
+
+    #[test]
+    fn test_calendar_date() {
+        let date = DateTimeData::from_parts(DateTimeKind::Date, "2024-03-01", 738_946, 0);
+        let calendar_date = CalendarDate {
+            year: 2024,
+            month: 3,
+            day: 1,
+            weekday: 5,
+        };
+        assert_eq!(date.calendar_date(), Some(calendar_date));
+        assert_eq!(CalendarDate::from_days_from_ce(1).weekday, 1);
+        assert!(CalendarDate::from_days_from_ce(738_948).is_weekend());
+        assert_eq!(DateTimeData::from_timestamp(0, 0).calendar_date(), None);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_matches_chrono_scale() {
+        use time::macros::{date, datetime};
+
+        let date = date!(2024 - 03 - 01).as_date_time_data();
+        assert_eq!(date.kind, DateTimeKind::Date);
+        assert_eq!(date.to_string(), "2024-03-01");
+        assert_eq!(date.timestamp_seconds_days, 738_946);
+
+        let date_time = datetime!(2023-11-14 22:13:20.5 UTC).as_date_time_data();
+        assert_eq!(
+            date_time,
+            DateTimeData::from_timestamp(1_700_000_000, 500_000_000)
+        );
+        assert_eq!(date_time.to_string(), "2023-11-14T22:13:20.5Z");
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_jiff_matches_chrono_scale() {
+        use jiff::Timestamp;
+        use jiff::civil::date;
+        use jiff::tz::TimeZone;
+
+        let data = date(2024, 3, 1).as_date_time_data();
+        assert_eq!(data.kind, DateTimeKind::Date);
+        assert_eq!(data.timestamp_seconds_days, 738_946);
+        assert_eq!(date(1, 1, 1).as_date_time_data().timestamp_seconds_days, 1);
+
+        let zoned = Timestamp::new(-2, 500_000_000)
+            .expect("Expected valid timestamp")
+            .to_zoned(TimeZone::UTC);
+        assert_eq!(zoned.timestamp().subsec_nanosecond(), -500_000_000);
+        assert_eq!(
+            zoned.as_date_time_data(),
+            DateTimeData::from_timestamp(-2, 500_000_000)
+        );
+    }
 }
@@ -1,4 +1,5 @@
 //! Date and time-related functionality.
 
+pub mod clock;
 pub mod data;
 pub mod rules;
@@ -1,5 +1,10 @@
-use crate::base::date_time::data::{DateTimeData, DateTimeKind};
-use crate::common::locale::{LocaleData, LocaleMessage, LocaleValue, ValidateErrorCollector};
+use crate::base::date_time::data::{CalendarDate, DateTimeData, DateTimeKind};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+};
+use crate::common::locale_keys;
+use crate::common::observer::rule_result;
 use std::sync::Arc;
 
 /// `DateTimeMandatoryLocale` is a struct that enforces the usage of a specific locale
@@ -13,11 +18,16 @@ use std::sync::Arc;
 /// While this struct itself may not expose direct methods, its purpose could be to act
 /// as a marker or to be used as part of a larger system for locale management related to
 /// parsing or formatting dates and times.
+#[derive(Clone)]
 pub struct DateTimeMandatoryLocale;
 
 impl LocaleMessage for DateTimeMandatoryLocale {
     fn get_locale_data(&self) -> Arc<LocaleData> {
-        LocaleData::new("validate-cannot-be-empty")
+        LocaleData::new(locale_keys::VALIDATE_CANNOT_BE_EMPTY)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Mandatory
     }
 }
 
@@ -51,13 +61,23 @@ impl DateTimeMandatoryRules {
     /// - If `is_mandatory` is `true` and `subject` is `None`, a validation error with a localized
     ///   "Cannot be empty" message is added to the `messages` collector.
     pub fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<&DateTimeData>) {
-        if self.is_mandatory && subject.is_none() {
-            messages.push((
-                "Cannot be empty".to_string(),
-                Box::new(DateTimeMandatoryLocale),
-            ));
+        if !self.is_mandatory {
+            return;
+        }
+        rule_result(locale_keys::VALIDATE_CANNOT_BE_EMPTY, subject.is_some());
+        if subject.is_none() {
+            messages.push(("Cannot be empty", DateTimeMandatoryLocale));
         }
     }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.is_mandatory {
+            constraints.push(Constraint::from_locale(&DateTimeMandatoryLocale));
+        }
+        constraints
+    }
 }
 
 /// An enumeration representing the localization of a range constraint for a date-time value.
@@ -78,6 +98,7 @@ impl DateTimeMandatoryRules {
 /// - This enum is designed to provide a flexible way to specify date-time range constraints,
 ///   which could be used in validation, filtering, or UI-based date pickers.
 /// - Ensure the associated `DateTimeData` objects are valid and comply with the required format.
+#[derive(Clone)]
 pub enum DateTimeRangeLocale {
     /// A variant representing the minimum value constraint, encapsulated in a `DateTimeData`.
     /// # Key
@@ -95,46 +116,49 @@ pub enum DateTimeRangeLocale {
     MaxValue(DateTimeData),
 }
 
+fn min_key(kind: &DateTimeKind) -> &'static str {
+    match kind {
+        DateTimeKind::Date => locale_keys::VALIDATE_DATE_MIN,
+        DateTimeKind::DateTime => locale_keys::VALIDATE_DATE_TIME_MIN,
+        DateTimeKind::DateTimeNaive => locale_keys::VALIDATE_DATE_TIME_NAIVE_MIN,
+        DateTimeKind::Time => locale_keys::VALIDATE_TIME_MIN,
+    }
+}
+
+fn max_key(kind: &DateTimeKind) -> &'static str {
+    match kind {
+        DateTimeKind::Date => locale_keys::VALIDATE_DATE_MAX,
+        DateTimeKind::DateTime => locale_keys::VALIDATE_DATE_TIME_MAX,
+        DateTimeKind::DateTimeNaive => locale_keys::VALIDATE_DATE_TIME_NAIVE_MAX,
+        DateTimeKind::Time => locale_keys::VALIDATE_TIME_MAX,
+    }
+}
+
 impl LocaleMessage for DateTimeRangeLocale {
     fn get_locale_data(&self) -> Arc<LocaleData> {
         use LocaleData as ld;
         use LocaleValue as lv;
         match self {
-            DateTimeRangeLocale::MinValue(min) => match min.kind {
-                DateTimeKind::Date => ld::new_with_vec(
-                    "validate-date-min",
-                    vec![("min".to_string(), lv::from(min.date_formatted.clone()))],
-                ),
-                DateTimeKind::DateTime => ld::new_with_vec(
-                    "validate-date-time-min",
-                    vec![("min".to_string(), lv::from(min.date_formatted.clone()))],
-                ),
-                DateTimeKind::DateTimeNaive => ld::new_with_vec(
-                    "validate-date-time-naive-min",
-                    vec![("min".to_string(), lv::from(min.date_formatted.clone()))],
-                ),
-                DateTimeKind::Time => ld::new_with_vec(
-                    "validate-time-min",
-                    vec![("min".to_string(), lv::from(min.date_formatted.clone()))],
-                ),
+            DateTimeRangeLocale::MinValue(min) => ld::new_with_vec(
+                min_key(&min.kind),
+                vec![("min".to_string(), lv::from(min.date_formatted.clone()))],
+            ),
+            DateTimeRangeLocale::MaxValue(max) => ld::new_with_vec(
+                max_key(&max.kind),
+                vec![("max".to_string(), lv::from(max.date_formatted.clone()))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            DateTimeRangeLocale::MinValue(min) => RuleViolation::Range {
+                min: Some(LocaleValue::from(min.date_formatted.clone())),
+                max: None,
             },
-            DateTimeRangeLocale::MaxValue(max) => match max.kind {
-                DateTimeKind::Date => ld::new_with_vec(
-                    "validate-date-max",
-                    vec![("max".to_string(), lv::from(max.date_formatted.clone()))],
-                ),
-                DateTimeKind::DateTime => ld::new_with_vec(
-                    "validate-date-time-max",
-                    vec![("max".to_string(), lv::from(max.date_formatted.clone()))],
-                ),
-                DateTimeKind::DateTimeNaive => ld::new_with_vec(
-                    "validate-date-time-naive-max",
-                    vec![("max".to_string(), lv::from(max.date_formatted.clone()))],
-                ),
-                DateTimeKind::Time => ld::new_with_vec(
-                    "validate-time-max",
-                    vec![("max".to_string(), lv::from(max.date_formatted.clone()))],
-                ),
+            DateTimeRangeLocale::MaxValue(max) => RuleViolation::Range {
+                min: None,
+                max: Some(LocaleValue::from(max.date_formatted.clone())),
             },
         }
     }
@@ -175,22 +199,24 @@ impl DateTimeRangeRules {
     /// - If `subject` does not meet the `min` or `max` constraints, a corresponding error message is pushed to the
     ///   `messages` collection.
     pub fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<&DateTimeData>) {
-        let default = DateTimeData::default();
-        let is_some = subject.is_some();
-        let subject = subject.unwrap_or(&default);
+        let Some(subject) = subject else {
+            return;
+        };
         if let Some(min) = &self.min {
-            if is_some && subject < min {
+            rule_result(min_key(&min.kind), subject >= min);
+            if subject < min {
                 messages.push((
                     format!("Must be after '{}'", &subject.date_formatted),
-                    Box::new(DateTimeRangeLocale::MinValue(min.clone())),
+                    DateTimeRangeLocale::MinValue(min.clone()),
                 ))
             }
         }
         if let Some(max) = &self.max {
-            if is_some && subject > max {
+            rule_result(max_key(&max.kind), subject <= max);
+            if subject > max {
                 messages.push((
                     format!("Must be before '{}'", &subject.date_formatted),
-                    Box::new(DateTimeRangeLocale::MaxValue(max.clone())),
+                    DateTimeRangeLocale::MaxValue(max.clone()),
                 ))
             }
         }
@@ -202,14 +228,15 @@ impl DateTimeRangeRules {
         messages: &mut ValidateErrorCollector,
         subject: Option<&DateTimeData>,
     ) {
-        let default = DateTimeData::default();
-        let is_some = subject.is_some();
-        let subject = subject.unwrap_or(&default);
+        let Some(subject) = subject else {
+            return;
+        };
         if let Some(min) = &self.min {
-            if is_some && subject < min {
+            rule_result(min_key(&min.kind), subject >= min);
+            if subject < min {
                 messages.push((
                     format!("Must be after '{}'", &subject.date_formatted),
-                    Box::new(DateTimeRangeLocale::MinValue(min.clone())),
+                    DateTimeRangeLocale::MinValue(min.clone()),
                 ))
             }
         }
@@ -221,14 +248,305 @@ impl DateTimeRangeRules {
                 // add day
                 max.timestamp_seconds_days += 24 * 60 * 60;
             }
-            if is_some && subject > &max {
+            rule_result(max_key(&max.kind), subject <= &max);
+            if subject > &max {
                 messages.push((
                     format!("Must be before '{}'", &subject.date_formatted),
-                    Box::new(DateTimeRangeLocale::MaxValue(max.clone())),
+                    DateTimeRangeLocale::MaxValue(max.clone()),
                 ))
             }
         }
     }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if let Some(min) = &self.min {
+            constraints.push(Constraint::from_locale(&DateTimeRangeLocale::MinValue(
+                min.clone(),
+            )));
+        }
+        if let Some(max) = &self.max {
+            constraints.push(Constraint::from_locale(&DateTimeRangeLocale::MaxValue(
+                max.clone(),
+            )));
+        }
+        constraints
+    }
+}
+
+/// A provider of the days that are not business days, besides the weekends.
+///
+/// The trait is implemented for closures taking a `&CalendarDate`, and for slices and vectors of
+/// `(year, month, day)` tuples.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::base::date_time::data::CalendarDate;
+/// use cjtoolkit_structured_validator::base::date_time::rules::HolidayProvider;
+///
+/// let christmas = |date: &CalendarDate| date.month == 12 && date.day == 25;
+/// let date = CalendarDate { year: 2025, month: 12, day: 25, weekday: 4 };
+/// assert!(christmas.is_holiday(&date));
+/// assert!(vec![(2025, 12, 25)].is_holiday(&date));
+/// ```
+pub trait HolidayProvider: Send + Sync {
+    /// Returns `true` if `date` is a holiday.
+    fn is_holiday(&self, date: &CalendarDate) -> bool;
+}
+
+impl<F> HolidayProvider for F
+where
+    F: Fn(&CalendarDate) -> bool + Send + Sync,
+{
+    fn is_holiday(&self, date: &CalendarDate) -> bool {
+        self(date)
+    }
+}
+
+impl HolidayProvider for [(i64, u8, u8)] {
+    fn is_holiday(&self, date: &CalendarDate) -> bool {
+        self.contains(&(date.year, date.month, date.day))
+    }
+}
+
+impl HolidayProvider for Vec<(i64, u8, u8)> {
+    fn is_holiday(&self, date: &CalendarDate) -> bool {
+        self.as_slice().is_holiday(date)
+    }
+}
+
+/// A locale message for a date that is not a business day.
+///
+/// # Key
+/// `validate-date-not-business-day`
+#[derive(Clone)]
+pub struct BusinessDayLocale;
+
+impl LocaleMessage for BusinessDayLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new(locale_keys::VALIDATE_DATE_NOT_BUSINESS_DAY)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Custom(locale_keys::VALIDATE_DATE_NOT_BUSINESS_DAY.to_string())
+    }
+}
+
+/// Rules requiring a date to be a business day.
+///
+/// # Fields
+///
+/// * `exclude_weekends` - A boolean flag indicating whether Saturdays and Sundays are excluded.
+/// * `holidays` - The providers of the other excluded days.
+///
+/// # Default
+///
+/// Weekends are excluded, without holidays.
+#[derive(Clone)]
+pub struct BusinessDayRules {
+    pub exclude_weekends: bool,
+    pub holidays: Vec<Arc<dyn HolidayProvider>>,
+}
+
+impl Default for BusinessDayRules {
+    fn default() -> Self {
+        Self {
+            exclude_weekends: true,
+            holidays: Vec::new(),
+        }
+    }
+}
+
+impl BusinessDayRules {
+    /// Returns `true` if `date` is a business day.
+    pub fn is_business_day(&self, date: &CalendarDate) -> bool {
+        if self.exclude_weekends && date.is_weekend() {
+            return false;
+        }
+        !self.holidays.iter().any(|h| h.is_holiday(date))
+    }
+
+    /// Checks that the `subject` date is a business day, other kinds of date and time are
+    /// ignored.
+    pub fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<&DateTimeData>) {
+        let Some(date) = subject.and_then(|s| s.calendar_date()) else {
+            return;
+        };
+        let is_business_day = self.is_business_day(&date);
+        rule_result(locale_keys::VALIDATE_DATE_NOT_BUSINESS_DAY, is_business_day);
+        if !is_business_day {
+            messages.push(("Must be a business day", Box::new(BusinessDayLocale)));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        vec![Constraint::from_locale(&BusinessDayLocale)]
+    }
+}
+
+/// A locale message for a time that does not fall on a slot of `granularity_minutes` minutes,
+/// counted from midnight.
+///
+/// # Key
+/// `validate-time-granularity`
+#[derive(Clone)]
+pub struct TimeGranularityLocale {
+    pub granularity_minutes: u32,
+}
+
+impl LocaleMessage for TimeGranularityLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_TIME_GRANULARITY,
+            vec![(
+                "granularity".to_string(),
+                LocaleValue::from(self.granularity_minutes as usize),
+            )],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Custom(locale_keys::VALIDATE_TIME_GRANULARITY.to_string())
+    }
+}
+
+/// Rules requiring a time to fall on a slot of `granularity_minutes` minutes counted from
+/// midnight, e.g. on :00, :15, :30 and :45 for a granularity of 15 minutes.
+///
+/// A granularity of zero allows any time.
+#[derive(Clone, Copy)]
+pub struct TimeGranularityRules {
+    pub granularity_minutes: u32,
+}
+
+impl TimeGranularityRules {
+    /// Returns `true` if the time `subject` falls on a slot, other kinds of date and time being
+    /// always accepted.
+    pub fn is_on_slot(&self, subject: &DateTimeData) -> bool {
+        if self.granularity_minutes == 0 || subject.kind != DateTimeKind::Time {
+            return true;
+        }
+        subject.subsec_nano == 0
+            && subject.timestamp_seconds_days % (i64::from(self.granularity_minutes) * 60) == 0
+    }
+
+    /// Checks that the `subject` time falls on a slot.
+    pub fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<&DateTimeData>) {
+        let Some(subject) = subject else {
+            return;
+        };
+        let is_on_slot = self.is_on_slot(subject);
+        rule_result(locale_keys::VALIDATE_TIME_GRANULARITY, is_on_slot);
+        if !is_on_slot {
+            messages.push((
+                format!("Must be on a {} minute interval", self.granularity_minutes),
+                Box::new(TimeGranularityLocale {
+                    granularity_minutes: self.granularity_minutes,
+                }),
+            ));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        vec![Constraint::from_locale(&TimeGranularityLocale {
+            granularity_minutes: self.granularity_minutes,
+        })]
+    }
+}
+
+/// The localization of a string that does not match any of the accepted formats of a date-time
+/// value.
+///
+/// # Fields
+///
+/// * `kind` - The kind of the date-time value that was expected.
+/// * `formats` - The accepted formats, passed as the `formats` argument joined with `, `.
+///
+/// # Key
+/// * `validate-date-unparseable`
+/// * `validate-date-time-unparseable`
+/// * `validate-date-time-naive-unparseable`
+/// * `validate-time-unparseable`
+#[derive(Clone)]
+pub struct DateTimeUnparseableLocale {
+    pub kind: DateTimeKind,
+    pub formats: Vec<String>,
+}
+
+fn unparseable_key(kind: &DateTimeKind) -> &'static str {
+    match kind {
+        DateTimeKind::Date => locale_keys::VALIDATE_DATE_UNPARSEABLE,
+        DateTimeKind::DateTime => locale_keys::VALIDATE_DATE_TIME_UNPARSEABLE,
+        DateTimeKind::DateTimeNaive => locale_keys::VALIDATE_DATE_TIME_NAIVE_UNPARSEABLE,
+        DateTimeKind::Time => locale_keys::VALIDATE_TIME_UNPARSEABLE,
+    }
+}
+
+impl LocaleMessage for DateTimeUnparseableLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            unparseable_key(&self.kind),
+            vec![(
+                "formats".to_string(),
+                LocaleValue::from(self.formats.join(", ")),
+            )],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::pattern(match self.kind {
+            DateTimeKind::Date => "date",
+            DateTimeKind::DateTime => "date-time",
+            DateTimeKind::DateTimeNaive => "date-time-naive",
+            DateTimeKind::Time => "time",
+        })
+    }
+}
+
+/// The formats accepted when parsing a date-time value from a string.
+///
+/// # Fields
+///
+/// * `kind` - The kind of the date-time value being parsed.
+/// * `formats` - The accepted formats, tried in order.
+pub struct DateTimeParseRules<'a> {
+    pub kind: DateTimeKind,
+    pub formats: &'a [&'a str],
+}
+
+impl<'a> DateTimeParseRules<'a> {
+    /// Parses `subject` with the first of the formats accepted by `parse`, returning the value
+    /// together with the format it was parsed with.
+    ///
+    /// A missing or blank `subject` parses to `None`, leaving it to the mandatory rule. A
+    /// `subject` matching none of the formats also parses to `None`, and a
+    /// `DateTimeUnparseableLocale` error is pushed to `messages`.
+    pub fn parse<T>(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: Option<&str>,
+        parse: impl Fn(&str, &str) -> Option<T>,
+    ) -> Option<(T, &'a str)> {
+        let subject = subject.map(str::trim).filter(|s| !s.is_empty())?;
+        let parsed = self
+            .formats
+            .iter()
+            .find_map(|format| parse(subject, format).map(|value| (value, *format)));
+        rule_result(unparseable_key(&self.kind), parsed.is_some());
+        if parsed.is_none() {
+            messages.push((
+                format!("Must be in the format '{}'", self.formats.join("' or '")),
+                Box::new(DateTimeUnparseableLocale {
+                    kind: self.kind,
+                    formats: self.formats.iter().map(|f| f.to_string()).collect(),
+                }),
+            ));
+        }
+        parsed
+    }
 }
 
 #[cfg(test)]
@@ -349,4 +667,68 @@ mod tests {
             assert_eq!(messages.len(), 0);
         }
     }
+
+    mod business_day_rule {
+        use super::*;
+
+        fn date(days: i64) -> Option<DateTimeData> {
+            Some(DateTimeData::from_parts(DateTimeKind::Date, "", days, 0))
+        }
+
+        #[test]
+        fn test_business_day() {
+            // 2024-03-01 is a Friday.
+            let friday = 738_946;
+            let rules = BusinessDayRules {
+                exclude_weekends: true,
+                holidays: vec![Arc::new(vec![(2024, 3, 4)])],
+            };
+            for (days, is_business_day) in [
+                (friday, true),
+                (friday + 1, false),
+                (friday + 2, false),
+                (friday + 3, false),
+                (friday + 4, true),
+            ] {
+                let mut messages = ValidateErrorCollector::new();
+                rules.check(&mut messages, date(days).as_ref());
+                assert_eq!(messages.is_empty(), is_business_day, "{}", days);
+            }
+        }
+    }
+
+    mod date_time_parse_rule {
+        use super::*;
+
+        fn parse_number(subject: &str, format: &str) -> Option<u32> {
+            subject.strip_prefix(format)?.parse().ok()
+        }
+
+        #[test]
+        fn test_parse() {
+            let rules = DateTimeParseRules {
+                kind: DateTimeKind::Date,
+                formats: &["a", "b"],
+            };
+
+            let mut messages = ValidateErrorCollector::new();
+            let parsed = rules.parse(&mut messages, Some(" b12 "), parse_number);
+            assert_eq!(parsed, Some((12, "b")));
+            assert_eq!(messages.len(), 0);
+
+            let mut messages = ValidateErrorCollector::new();
+            let parsed = rules.parse(&mut messages, Some("  "), parse_number);
+            assert_eq!(parsed, None);
+            assert_eq!(messages.len(), 0);
+
+            let mut messages = ValidateErrorCollector::new();
+            let parsed = rules.parse(&mut messages, Some("c12"), parse_number);
+            assert_eq!(parsed, None);
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages.0[0].0, "Must be in the format 'a' or 'b'");
+            let locale = messages.0[0].1.get_locale_data();
+            assert_eq!(locale.name, locale_keys::VALIDATE_DATE_UNPARSEABLE);
+            assert_eq!(locale.args.get("formats"), Some(&LocaleValue::from("a, b")));
+        }
+    }
 }
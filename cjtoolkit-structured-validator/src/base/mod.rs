@@ -1,4 +1,11 @@
-#[cfg(any(feature = "chrono"))]
-pub(crate) mod date_time;
+pub(crate) mod calendar;
+pub mod collection_rules;
+#[cfg(any(
+    feature = "chrono",
+    feature = "time",
+    feature = "jiff",
+    feature = "humantime"
+))]
+pub mod date_time;
 pub mod number_rules;
 pub mod string_rules;
@@ -1,8 +1,18 @@
-//! This module contains the `NumberMandatoryRules` and `NumberRangeRules` structs,
-//! which are used to define rules for validating numerical values.
+//! This module contains the `NumberMandatoryRules`, `NumberRangeRules` and `NumberValueRules`
+//! structs, which are used to define rules for validating numerical values.
+//!
+//! It also contains the `ExternalBoundsProvider` traits, fetching the bounds of a number at
+//! validation time.
 
-use crate::common::locale::{LocaleData, LocaleMessage, LocaleValue, ValidateErrorCollector};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+};
+use crate::common::locale_keys;
+use crate::common::observer::rule_result;
 use std::fmt::Display;
+use std::ops::Rem;
+use std::str::FromStr;
 use std::sync::Arc;
 
 /// `NumberMandatoryLocale` is a struct representing a type that may be used
@@ -11,11 +21,16 @@ use std::sync::Arc;
 ///
 /// # Possible key values:
 /// * `validate-cannot-be-empty`
+#[derive(Clone)]
 pub struct NumberMandatoryLocale;
 
 impl LocaleMessage for NumberMandatoryLocale {
     fn get_locale_data(&self) -> Arc<LocaleData> {
-        LocaleData::new("validate-cannot-be-empty")
+        LocaleData::new(locale_keys::VALIDATE_CANNOT_BE_EMPTY)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Mandatory
     }
 }
 
@@ -74,13 +89,23 @@ impl NumberMandatoryRules {
         messages: &mut ValidateErrorCollector,
         subject: Option<T>,
     ) {
-        if self.is_mandatory && subject.is_none() {
-            messages.push((
-                "Cannot be empty".to_string(),
-                Box::new(NumberMandatoryLocale),
-            ));
+        if !self.is_mandatory {
+            return;
+        }
+        rule_result(locale_keys::VALIDATE_CANNOT_BE_EMPTY, subject.is_some());
+        if subject.is_none() {
+            messages.push(("Cannot be empty", NumberMandatoryLocale));
         }
     }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.is_mandatory {
+            constraints.push(Constraint::from_locale(&NumberMandatoryLocale));
+        }
+        constraints
+    }
 }
 
 /// An enumeration representing a range of values with localization support.
@@ -98,6 +123,7 @@ impl NumberMandatoryRules {
 /// - `MinValue(T)`: Represents the minimum localized value for the range.
 /// - `MaxValue(T)`: Represents the maximum localized value for the range.
 ///
+#[derive(Clone)]
 pub enum NumberRangeLocale<T: Into<LocaleValue> + Send + Sync + Clone> {
     /// Represents the minimum localized value for the range.
     /// # Key
@@ -118,15 +144,28 @@ where
         use LocaleValue as lv;
         match self {
             Self::MinValue(min) => ld::new_with_vec(
-                "validate-number-min-value",
+                locale_keys::VALIDATE_NUMBER_MIN_VALUE,
                 vec![("min".to_string(), lv::from(min.clone()))],
             ),
             Self::MaxValue(max) => ld::new_with_vec(
-                "validate-number-max-value",
+                locale_keys::VALIDATE_NUMBER_MAX_VALUE,
                 vec![("max".to_string(), lv::from(max.clone()))],
             ),
         }
     }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::MinValue(min) => RuleViolation::Range {
+                min: Some(LocaleValue::from(min.clone())),
+                max: None,
+            },
+            Self::MaxValue(max) => RuleViolation::Range {
+                min: None,
+                max: Some(LocaleValue::from(max.clone())),
+            },
+        }
+    }
 }
 
 /// A struct that represents rules for defining a range of numeric values with optional minimum and maximum bounds.
@@ -202,25 +241,312 @@ where
     /// - The `ValidateErrorCollector` and `NumberRangeLocale` types are expected to support the operations shown above.
     ///
     pub fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<T>) {
-        let is_some = subject.is_some();
-        let subject = subject.unwrap_or_default();
+        let Some(subject) = subject else {
+            return;
+        };
         if let Some(min) = &self.min {
-            if is_some && subject < *min {
-                messages.push((
-                    format!("Must be at least {}", min),
-                    Box::new(NumberRangeLocale::MinValue(min.clone().into())),
+            rule_result(locale_keys::VALIDATE_NUMBER_MIN_VALUE, subject >= *min);
+            if subject < *min {
+                messages.push_default(NumberRangeLocale::<LocaleValue>::MinValue(
+                    min.clone().into(),
                 ));
             }
         }
         if let Some(max) = &self.max {
-            if is_some && subject > *max {
-                messages.push((
-                    format!("Must be at most {}", max),
-                    Box::new(NumberRangeLocale::MaxValue(max.clone().into())),
+            rule_result(locale_keys::VALIDATE_NUMBER_MAX_VALUE, subject <= *max);
+            if subject > *max {
+                messages.push_default(NumberRangeLocale::<LocaleValue>::MaxValue(
+                    max.clone().into(),
                 ));
             }
         }
     }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if let Some(min) = &self.min {
+            constraints.push(Constraint::from_locale(
+                &NumberRangeLocale::<LocaleValue>::MinValue(min.clone().into()),
+            ));
+        }
+        if let Some(max) = &self.max {
+            constraints.push(Constraint::from_locale(
+                &NumberRangeLocale::<LocaleValue>::MaxValue(max.clone().into()),
+            ));
+        }
+        constraints
+    }
+}
+
+/// An enumeration of the value constraints that can be applied to whole numbers.
+///
+/// # Variants
+/// - `ForbidZero`: The value must not be zero.
+/// - `MustBeEven`: The value must be divisible by two.
+/// - `MustBeOdd`: The value must not be divisible by two.
+/// - `NotAllowed(String)`: The value is not one of the allowed values, the `String` holds the
+///   allowed values joined by a comma.
+#[derive(Clone)]
+pub enum NumberValueLocale {
+    /// Value must not be zero.
+    /// # Key
+    /// `validate-number-forbid-zero`
+    ForbidZero,
+    /// Value must be even.
+    /// # Key
+    /// `validate-number-must-be-even`
+    MustBeEven,
+    /// Value must be odd.
+    /// # Key
+    /// `validate-number-must-be-odd`
+    MustBeOdd,
+    /// Value must be one of the allowed values.
+    /// # Key
+    /// `validate-number-not-allowed`
+    NotAllowed(String),
+}
+
+impl LocaleMessage for NumberValueLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::ForbidZero => ld::new(locale_keys::VALIDATE_NUMBER_FORBID_ZERO),
+            Self::MustBeEven => ld::new(locale_keys::VALIDATE_NUMBER_MUST_BE_EVEN),
+            Self::MustBeOdd => ld::new(locale_keys::VALIDATE_NUMBER_MUST_BE_ODD),
+            Self::NotAllowed(allowed) => ld::new_with_vec(
+                locale_keys::VALIDATE_NUMBER_NOT_ALLOWED,
+                vec![("allowed".to_string(), lv::from(allowed.clone()))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::ForbidZero => RuleViolation::ForbidZero,
+            Self::MustBeEven => RuleViolation::MustBeEven,
+            Self::MustBeOdd => RuleViolation::MustBeOdd,
+            Self::NotAllowed(allowed) => RuleViolation::NotAllowed {
+                allowed: allowed.clone(),
+            },
+        }
+    }
+}
+
+/// A struct that represents value rules for whole numbers, such as quantities or pagination
+/// parameters.
+///
+/// # Fields
+/// - `forbid_zero` (`bool`): When `true`, zero is rejected.
+/// - `must_be_even` (`bool`): When `true`, the value must be even.
+/// - `must_be_odd` (`bool`): When `true`, the value must be odd.
+/// - `allowed_values` (`Option<Vec<T>>`): When `Some`, the value must be one of the listed values.
+///
+/// # Defaults
+/// When derived using `Default`, no constraint is applied.
+#[derive(Default)]
+pub struct NumberValueRules<T>
+where
+    T: Copy + PartialEq + Display + From<u8> + Rem<Output = T>,
+{
+    pub forbid_zero: bool,
+    pub must_be_even: bool,
+    pub must_be_odd: bool,
+    pub allowed_values: Option<Vec<T>>,
+}
+
+impl<T> NumberValueRules<T>
+where
+    T: Copy + PartialEq + Display + From<u8> + Rem<Output = T>,
+{
+    /// Validates a given `subject` against the zero, parity and allowed values constraints.
+    ///
+    /// Nothing is checked when the `subject` is `None`, that is left to `NumberMandatoryRules`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cjtoolkit_structured_validator::common::locale::ValidateErrorCollector;
+    /// use cjtoolkit_structured_validator::base::number_rules::NumberValueRules;
+    /// let mut error_collector = ValidateErrorCollector::new();
+    /// let validator = NumberValueRules::<usize> {
+    ///     forbid_zero: true,
+    ///     must_be_even: true,
+    ///     must_be_odd: false,
+    ///     allowed_values: Some(vec![10, 20, 50]),
+    /// };
+    ///
+    /// validator.check(&mut error_collector, Some(15)); // Odd and not allowed, two errors are added.
+    /// assert_eq!(error_collector.len(), 2);
+    /// validator.check(&mut error_collector, Some(20)); // Valid value, no error.
+    /// assert_eq!(error_collector.len(), 2);
+    /// ```
+    pub fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<T>) {
+        let Some(subject) = subject else {
+            return;
+        };
+        let zero = T::from(0);
+        let two = T::from(2);
+        if self.forbid_zero {
+            let failed = subject == zero;
+            rule_result(locale_keys::VALIDATE_NUMBER_FORBID_ZERO, !failed);
+            if failed {
+                messages.push(("Must not be zero", NumberValueLocale::ForbidZero));
+            }
+        }
+        if self.must_be_even {
+            let failed = subject % two != zero;
+            rule_result(locale_keys::VALIDATE_NUMBER_MUST_BE_EVEN, !failed);
+            if failed {
+                messages.push(("Must be an even number", NumberValueLocale::MustBeEven));
+            }
+        }
+        if self.must_be_odd {
+            let failed = subject % two == zero;
+            rule_result(locale_keys::VALIDATE_NUMBER_MUST_BE_ODD, !failed);
+            if failed {
+                messages.push(("Must be an odd number", NumberValueLocale::MustBeOdd));
+            }
+        }
+        if let Some(allowed_values) = &self.allowed_values {
+            let passed = allowed_values.contains(&subject);
+            rule_result(locale_keys::VALIDATE_NUMBER_NOT_ALLOWED, passed);
+            if passed {
+                return;
+            }
+            let allowed = allowed_values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            messages.push((
+                format!("Must be one of {}", allowed),
+                NumberValueLocale::NotAllowed(allowed),
+            ));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.forbid_zero {
+            constraints.push(Constraint::from_locale(&NumberValueLocale::ForbidZero));
+        }
+        if self.must_be_even {
+            constraints.push(Constraint::from_locale(&NumberValueLocale::MustBeEven));
+        }
+        if self.must_be_odd {
+            constraints.push(Constraint::from_locale(&NumberValueLocale::MustBeOdd));
+        }
+        if let Some(allowed_values) = &self.allowed_values {
+            let allowed = allowed_values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            constraints.push(Constraint::from_locale(&NumberValueLocale::NotAllowed(
+                allowed,
+            )));
+        }
+        constraints
+    }
+}
+
+/// `NumberTypeLocale` is a struct representing a text that could not be read as a number,
+/// holding the name of the expected type, e.g. `integer` or `number`.
+///
+/// # Possible key values:
+/// * `validate-invalid-type`
+#[derive(Clone)]
+pub struct NumberTypeLocale(pub &'static str);
+
+impl LocaleMessage for NumberTypeLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_INVALID_TYPE,
+            vec![("expected".to_string(), LocaleValue::from(self.0))],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::InvalidType {
+            expected: self.0.to_string(),
+        }
+    }
+}
+
+/// The bounds of a number fetched at validation time, each replacing the bound of the rules
+/// when `Some`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ExternalBounds<T> {
+    pub min: Option<T>,
+    pub max: Option<T>,
+}
+
+/// A trait that defines a method to fetch the bounds of a number at validation time, e.g. the
+/// maximum order amount of the tier of a user.
+pub trait ExternalBoundsProvider<T> {
+    fn bounds(&self) -> ExternalBounds<T>;
+}
+
+/// This trait defines an asynchronous method to fetch the bounds of a number at validation
+/// time, e.g. from a database or a configuration service.
+pub trait ExternalBoundsProviderAsync<T> {
+    fn bounds_async(&self) -> impl Future<Output = ExternalBounds<T>>;
+}
+
+/// `NumberOverflowLocale` is a struct representing the result of an arithmetic operation on
+/// validated numbers, which is out of the range of the type.
+///
+/// # Possible key values:
+/// * `validate-number-overflow`
+#[derive(Clone)]
+pub struct NumberOverflowLocale;
+
+impl LocaleMessage for NumberOverflowLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new(locale_keys::VALIDATE_NUMBER_OVERFLOW)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Custom(locale_keys::VALIDATE_NUMBER_OVERFLOW.to_string())
+    }
+}
+
+/// Returns the result of an arithmetic operation of the checked variants of the number types,
+/// reporting `None` as an overflow.
+pub(crate) fn number_checked<N>(result: Option<N>) -> Result<N, ValidateErrorCollector> {
+    rule_result(locale_keys::VALIDATE_NUMBER_OVERFLOW, result.is_some());
+    result.ok_or_else(|| {
+        let mut messages = ValidateErrorCollector::new();
+        messages.push(("Result is out of range", Box::new(NumberOverflowLocale)));
+        messages
+    })
+}
+
+/// Reads a number of the type `expected` from the text `s`, for the `parse_str` variants of the
+/// number types. A missing or blank text is read as `None`, so the mandatory rule reports it.
+pub(crate) fn number_from_str<N: FromStr>(
+    s: Option<&str>,
+    expected: &'static str,
+) -> Result<Option<N>, ValidateErrorCollector> {
+    let Some(s) = s.map(str::trim).filter(|s| !s.is_empty()) else {
+        return Ok(None);
+    };
+    let number = s.parse::<N>().ok();
+    rule_result(locale_keys::VALIDATE_INVALID_TYPE, number.is_some());
+    match number {
+        Some(number) => Ok(Some(number)),
+        None => {
+            let mut messages = ValidateErrorCollector::new();
+            messages.push((
+                format!("Must be of type {}", expected),
+                Box::new(NumberTypeLocale(expected)),
+            ));
+            Err(messages)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -303,4 +629,63 @@ mod tests {
             assert_eq!(messages.0[0].0, "Must be at most 2");
         }
     }
+
+    mod number_value_rule {
+        use super::*;
+
+        #[test]
+        fn test_forbid_zero() {
+            let mut messages = ValidateErrorCollector::new();
+            let rules = NumberValueRules::<isize> {
+                forbid_zero: true,
+                ..Default::default()
+            };
+            rules.check(&mut messages, Some(0));
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages.0[0].0, "Must not be zero");
+        }
+
+        #[test]
+        fn test_parity() {
+            let mut messages = ValidateErrorCollector::new();
+            let rules = NumberValueRules::<isize> {
+                must_be_even: true,
+                ..Default::default()
+            };
+            rules.check(&mut messages, Some(-3));
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages.0[0].0, "Must be an even number");
+
+            let mut messages = ValidateErrorCollector::new();
+            let rules = NumberValueRules::<isize> {
+                must_be_odd: true,
+                ..Default::default()
+            };
+            rules.check(&mut messages, Some(-3));
+            assert_eq!(messages.len(), 0);
+        }
+
+        #[test]
+        fn test_allowed_values() {
+            let mut messages = ValidateErrorCollector::new();
+            let rules = NumberValueRules::<usize> {
+                allowed_values: Some(vec![10, 25]),
+                ..Default::default()
+            };
+            rules.check(&mut messages, Some(20));
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages.0[0].0, "Must be one of 10, 25");
+        }
+
+        #[test]
+        fn test_none_value() {
+            let mut messages = ValidateErrorCollector::new();
+            let rules = NumberValueRules::<usize> {
+                forbid_zero: true,
+                ..Default::default()
+            };
+            rules.check(&mut messages, None);
+            assert_eq!(messages.len(), 0);
+        }
+    }
 }
@@ -1,7 +1,13 @@
 //! This module contains structures and traits for defining rules for validating strings.
 
-use crate::common::locale::{LocaleData, LocaleMessage, LocaleValue, ValidateErrorCollector};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+};
+use crate::common::locale_keys;
+use crate::common::observer::rule_result;
 use crate::common::string_validator::StringValidator;
+use crate::common::tenant_policy::{TightenRules, tighter_max};
 use std::sync::Arc;
 
 /// A struct representing a mandatory locale for string processing.
@@ -16,11 +22,16 @@ use std::sync::Arc;
 /// - Providing stricter typing in functions or structs requiring locale-based string operations.
 /// # Key
 /// * `validate-cannot-be-empty`
+#[derive(Clone)]
 pub struct StringMandatoryLocale;
 
 impl LocaleMessage for StringMandatoryLocale {
     fn get_locale_data(&self) -> Arc<LocaleData> {
-        LocaleData::new("validate-cannot-be-empty")
+        LocaleData::new(locale_keys::VALIDATE_CANNOT_BE_EMPTY)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Mandatory
     }
 }
 
@@ -37,14 +48,18 @@ impl LocaleMessage for StringMandatoryLocale {
 ///   - When set to `true`, the associated string must be provided.
 ///   - When set to `false`, the associated string is optional.
 ///
+/// * `treat_whitespace_as_empty`
+///   - When set to `true`, a string made only of whitespace is treated as empty.
+///
 /// # Traits
 ///
 /// * The `Default` trait is implemented for this struct, allowing you to
-///   create a default instance where `is_mandatory` is set to `false`.
+///   create a default instance where both flags are set to `false`.
 ///
 #[derive(Default)]
 pub struct StringMandatoryRules {
     pub is_mandatory: bool,
+    pub treat_whitespace_as_empty: bool,
 }
 
 impl StringMandatoryRules {
@@ -57,6 +72,7 @@ impl StringMandatoryRules {
     /// # Behavior
     /// - If the `self.is_mandatory` field is `true` and the `subject` is empty, an error message with the text `"Cannot be empty"`
     ///   is pushed into the `messages` collector along with a locale identifier (`StringMandatoryLocale`).
+    /// - If the `self.treat_whitespace_as_empty` field is `true`, a blank `subject` is treated as empty.
     ///
     /// # Example
     /// ```
@@ -64,7 +80,7 @@ impl StringMandatoryRules {
     /// use cjtoolkit_structured_validator::common::string_validator::StrValidationExtension;
     /// use cjtoolkit_structured_validator::base::string_rules::StringMandatoryRules;
     /// let mut messages = ValidateErrorCollector::new();
-    /// let validator = StringMandatoryRules { is_mandatory: true };
+    /// let validator = StringMandatoryRules { is_mandatory: true, treat_whitespace_as_empty: false };
     /// let subject = "".as_string_validator();
     ///
     /// validator.check(&mut messages, &subject);
@@ -72,13 +88,28 @@ impl StringMandatoryRules {
     /// assert_eq!(messages.len(), 1); // If the subject is empty and is_mandatory is true, an error will be collected.
     /// ```
     pub fn check(&self, messages: &mut ValidateErrorCollector, subject: &StringValidator) {
-        if self.is_mandatory && subject.is_empty() {
-            messages.push((
-                "Cannot be empty".to_string(),
-                Box::new(StringMandatoryLocale),
-            ));
+        let is_empty = if self.treat_whitespace_as_empty {
+            subject.is_blank()
+        } else {
+            subject.is_empty()
+        };
+        if !self.is_mandatory {
+            return;
+        }
+        rule_result(locale_keys::VALIDATE_CANNOT_BE_EMPTY, !is_empty);
+        if is_empty {
+            messages.push(("Cannot be empty", StringMandatoryLocale));
         }
     }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.is_mandatory {
+            constraints.push(Constraint::from_locale(&StringMandatoryLocale));
+        }
+        constraints
+    }
 }
 
 /// An enumeration representing the constraints for string length,
@@ -94,6 +125,11 @@ impl StringMandatoryRules {
 ///   Specifies the maximum length that a string is allowed to have.
 ///   The `usize` represents the maximum number of characters allowed.
 ///
+/// - `MinBytes(usize)`, `MaxBytes(usize)`, `MinUtf16CodeUnits(usize)` and
+///   `MaxUtf16CodeUnits(usize)`
+///   The same constraints, for a length counted in bytes or UTF-16 code units.
+///
+#[derive(Clone)]
 pub enum StringLengthLocale {
     /// Minimum length constraint.
     /// # Key
@@ -103,6 +139,53 @@ pub enum StringLengthLocale {
     /// # Key
     /// `validate-max-length`
     MaxLength(usize),
+    /// Minimum length constraint, in bytes.
+    /// # Key
+    /// `validate-min-length-bytes`
+    MinBytes(usize),
+    /// Maximum length constraint, in bytes.
+    /// # Key
+    /// `validate-max-length-bytes`
+    MaxBytes(usize),
+    /// Minimum length constraint, in UTF-16 code units.
+    /// # Key
+    /// `validate-min-length-utf16`
+    MinUtf16CodeUnits(usize),
+    /// Maximum length constraint, in UTF-16 code units.
+    /// # Key
+    /// `validate-max-length-utf16`
+    MaxUtf16CodeUnits(usize),
+}
+
+impl StringLengthLocale {
+    /// Returns the minimum length constraint for a length counted in `unit`.
+    pub fn min_length(unit: LengthUnit, min_length: usize) -> Self {
+        match unit {
+            LengthUnit::Graphemes | LengthUnit::Chars => Self::MinLength(min_length),
+            LengthUnit::Bytes => Self::MinBytes(min_length),
+            LengthUnit::Utf16CodeUnits => Self::MinUtf16CodeUnits(min_length),
+        }
+    }
+
+    /// Returns the maximum length constraint for a length counted in `unit`.
+    pub fn max_length(unit: LengthUnit, max_length: usize) -> Self {
+        match unit {
+            LengthUnit::Graphemes | LengthUnit::Chars => Self::MaxLength(max_length),
+            LengthUnit::Bytes => Self::MaxBytes(max_length),
+            LengthUnit::Utf16CodeUnits => Self::MaxUtf16CodeUnits(max_length),
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Self::MinLength(_) => locale_keys::VALIDATE_MIN_LENGTH,
+            Self::MaxLength(_) => locale_keys::VALIDATE_MAX_LENGTH,
+            Self::MinBytes(_) => locale_keys::VALIDATE_MIN_LENGTH_BYTES,
+            Self::MaxBytes(_) => locale_keys::VALIDATE_MAX_LENGTH_BYTES,
+            Self::MinUtf16CodeUnits(_) => locale_keys::VALIDATE_MIN_LENGTH_UTF16,
+            Self::MaxUtf16CodeUnits(_) => locale_keys::VALIDATE_MAX_LENGTH_UTF16,
+        }
+    }
 }
 
 impl LocaleMessage for StringLengthLocale {
@@ -110,14 +193,27 @@ impl LocaleMessage for StringLengthLocale {
         use LocaleData as ld;
         use LocaleValue as lv;
         match self {
-            Self::MinLength(min_length) => ld::new_with_vec(
-                "validate-min-length",
-                vec![("min".to_string(), lv::from(*min_length))],
-            ),
-            Self::MaxLength(max_length) => ld::new_with_vec(
-                "validate-max-length",
-                vec![("max".to_string(), lv::from(*max_length))],
-            ),
+            Self::MinLength(min_length)
+            | Self::MinBytes(min_length)
+            | Self::MinUtf16CodeUnits(min_length) => {
+                ld::new_with_vec(self.key(), vec![("min".to_string(), lv::from(*min_length))])
+            }
+            Self::MaxLength(max_length)
+            | Self::MaxBytes(max_length)
+            | Self::MaxUtf16CodeUnits(max_length) => {
+                ld::new_with_vec(self.key(), vec![("max".to_string(), lv::from(*max_length))])
+            }
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::MinLength(min) | Self::MinBytes(min) | Self::MinUtf16CodeUnits(min) => {
+                RuleViolation::MinLength { min: *min }
+            }
+            Self::MaxLength(max) | Self::MaxBytes(max) | Self::MaxUtf16CodeUnits(max) => {
+                RuleViolation::MaxLength { max: *max }
+            }
         }
     }
 }
@@ -133,14 +229,45 @@ impl LocaleMessage for StringLengthLocale {
 ///   If set, the string must have at least this many characters to pass validation.
 /// * `max_length` - An optional maximum length constraint for the string.
 ///   If set, the string must not exceed this many characters to pass validation.
+/// * `unit` - The unit the length of the string is counted in.
 ///
 /// # Defaults
-/// When derived using `Default`, both `min_length` and `max_length` will be set to `None`.
+/// When derived using `Default`, both `min_length` and `max_length` will be set to `None`,
+/// and `unit` to `LengthUnit::Graphemes`.
 ///
 #[derive(Default)]
 pub struct StringLengthRules {
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
+    pub unit: LengthUnit,
+}
+
+/// The unit the length of a string is counted in.
+///
+/// # Variants
+/// - `Graphemes`: User-perceived characters, the default.
+/// - `Chars`: Unicode scalar values.
+/// - `Bytes`: Bytes of the UTF-8 encoding, for database column limits.
+/// - `Utf16CodeUnits`: UTF-16 code units, for parity with the JavaScript `maxlength` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LengthUnit {
+    #[default]
+    Graphemes,
+    Chars,
+    Bytes,
+    Utf16CodeUnits,
+}
+
+impl LengthUnit {
+    /// Returns the length of `subject` counted in this unit.
+    pub fn count(&self, subject: &StringValidator) -> usize {
+        match self {
+            Self::Graphemes => subject.count_graphemes(),
+            Self::Chars => subject.count_chars(),
+            Self::Bytes => subject.count_bytes(),
+            Self::Utf16CodeUnits => subject.count_utf16_code_units(),
+        }
+    }
 }
 
 impl StringLengthRules {
@@ -158,17 +285,18 @@ impl StringLengthRules {
     /// # Behavior
     ///
     /// 1. If a minimum length (`min_length`) is specified via `self` and the `subject` string's
-    ///    grapheme count is less than the minimum; an error message is added to the `messages` collector
-    ///    indicating that the string must be at least the specified number of characters.
+    ///    length, counted in `unit`, is less than the minimum; an error message is added to the `messages` collector
+    ///    indicating that the string must be at least the specified number of characters, or of
+    ///    bytes or UTF-16 code units with those units.
     /// 2. If a maximum length (`max_length`) is specified via `self` and the `subject` string's
-    ///    grapheme count exceeds the maximum, an error message is added to the `messages` collector
-    ///    indicating that the string must be at most the specified number of characters.
+    ///    length, counted in `unit`, exceeds the maximum, an error message is added to the `messages` collector
+    ///    indicating that the string must be at most the specified number of characters, or of
+    ///    bytes or UTF-16 code units with those units.
     ///
     /// # Notes
     ///
-    /// This function assumes the `count_graphemes` method is available on the `subject` to properly count
-    /// grapheme clusters, ensuring correctness when dealing with multibyte characters or special Unicode
-    /// characters.
+    /// The default unit counts grapheme clusters, ensuring correctness when dealing with multibyte
+    /// characters or special Unicode characters.
     ///
     /// # Examples
     ///
@@ -178,30 +306,45 @@ impl StringLengthRules {
     /// use cjtoolkit_structured_validator::base::string_rules::StringLengthRules;
     /// let mut messages = ValidateErrorCollector::new();
     /// let validator = "example".as_string_validator();
-    /// let criteria = StringLengthRules { min_length: Some(5), max_length: Some(10) };
+    /// let criteria = StringLengthRules { min_length: Some(5), max_length: Some(10), ..Default::default() };
     ///
     /// criteria.check(&mut messages, &validator);
     ///
     /// assert!(messages.is_empty()); // The string "example" satisfies the length constraints.
     /// ```
     pub fn check(&self, messages: &mut ValidateErrorCollector, subject: &StringValidator) {
+        let length = self.unit.count(subject);
         if let Some(min_length) = self.min_length {
-            if subject.count_graphemes() < min_length {
-                messages.push((
-                    format!("Must be at least {} characters", min_length),
-                    Box::new(StringLengthLocale::MinLength(min_length)),
-                ));
+            let locale = StringLengthLocale::min_length(self.unit, min_length);
+            rule_result(locale.key(), length >= min_length);
+            if length < min_length {
+                messages.push_default(locale);
             }
         }
         if let Some(max_length) = self.max_length {
-            if subject.count_graphemes() > max_length {
-                messages.push((
-                    format!("Must be at most {} characters", max_length),
-                    Box::new(StringLengthLocale::MaxLength(max_length)),
-                ));
+            let locale = StringLengthLocale::max_length(self.unit, max_length);
+            rule_result(locale.key(), length <= max_length);
+            if length > max_length {
+                messages.push_default(locale);
             }
         }
     }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if let Some(min_length) = self.min_length {
+            constraints.push(Constraint::from_locale(&StringLengthLocale::min_length(
+                self.unit, min_length,
+            )));
+        }
+        if let Some(max_length) = self.max_length {
+            constraints.push(Constraint::from_locale(&StringLengthLocale::max_length(
+                self.unit, max_length,
+            )));
+        }
+        constraints
+    }
 }
 
 /// An enumeration defining various string constraints or requirements based on the presence of
@@ -229,6 +372,7 @@ impl StringLengthRules {
 /// - `MustHaveDigit`
 ///   Enforces that the string must contain at least one numeric digit (0-9).
 ///
+#[derive(Clone)]
 pub enum StringSpecialCharLocale {
     /// Must have special characters.
     /// # Key
@@ -256,13 +400,25 @@ impl LocaleMessage for StringSpecialCharLocale {
     fn get_locale_data(&self) -> Arc<LocaleData> {
         use LocaleData as ld;
         match self {
-            Self::MustHaveSpecialChars => ld::new("validate-must-have-special-chars"),
+            Self::MustHaveSpecialChars => ld::new(locale_keys::VALIDATE_MUST_HAVE_SPECIAL_CHARS),
+            Self::MustHaveUppercaseAndLowercase => {
+                ld::new(locale_keys::VALIDATE_MUST_HAVE_UPPERCASE_AND_LOWERCASE)
+            }
+            Self::MustHaveUppercase => ld::new(locale_keys::VALIDATE_MUST_HAVE_UPPERCASE),
+            Self::MustHaveLowercase => ld::new(locale_keys::VALIDATE_MUST_HAVE_LOWERCASE),
+            Self::MustHaveDigit => ld::new(locale_keys::VALIDATE_MUST_HAVE_DIGIT),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::MustHaveSpecialChars => RuleViolation::pattern("special-chars"),
             Self::MustHaveUppercaseAndLowercase => {
-                ld::new("validate-must-have-uppercase-and-lowercase")
+                RuleViolation::pattern("uppercase-and-lowercase")
             }
-            Self::MustHaveUppercase => ld::new("validate-must-have-uppercase"),
-            Self::MustHaveLowercase => ld::new("validate-must-have-lowercase"),
-            Self::MustHaveDigit => ld::new("validate-must-have-digit"),
+            Self::MustHaveUppercase => RuleViolation::pattern("uppercase"),
+            Self::MustHaveLowercase => RuleViolation::pattern("lowercase"),
+            Self::MustHaveDigit => RuleViolation::pattern("digit"),
         }
     }
 }
@@ -358,46 +514,361 @@ impl StringSpecialCharRules {
     /// ```
     pub fn check(&self, messages: &mut ValidateErrorCollector, subject: &StringValidator) {
         if self.must_have_special_chars {
-            if !subject.has_special_chars() {
+            let passed = subject.has_special_chars();
+            rule_result(locale_keys::VALIDATE_MUST_HAVE_SPECIAL_CHARS, passed);
+            if !passed {
                 messages.push((
-                    "Must contain at least one special character".to_string(),
-                    Box::new(StringSpecialCharLocale::MustHaveSpecialChars),
+                    "Must contain at least one special character",
+                    StringSpecialCharLocale::MustHaveSpecialChars,
                 ));
             }
         }
         if self.must_have_uppercase && self.must_have_lowercase {
-            if !subject.has_ascii_uppercase_and_lowercase() {
+            let passed = subject.has_ascii_uppercase_and_lowercase();
+            rule_result(
+                locale_keys::VALIDATE_MUST_HAVE_UPPERCASE_AND_LOWERCASE,
+                passed,
+            );
+            if !passed {
                 messages.push((
-                    "Must contain at least one uppercase and lowercase letter".to_string(),
-                    Box::new(StringSpecialCharLocale::MustHaveUppercaseAndLowercase),
+                    "Must contain at least one uppercase and lowercase letter",
+                    StringSpecialCharLocale::MustHaveUppercaseAndLowercase,
                 ));
             }
         } else {
             if self.must_have_uppercase {
-                if !subject.has_ascii_uppercase() {
+                let passed = subject.has_ascii_uppercase();
+                rule_result(locale_keys::VALIDATE_MUST_HAVE_UPPERCASE, passed);
+                if !passed {
                     messages.push((
-                        "Must contain at least one uppercase letter".to_string(),
-                        Box::new(StringSpecialCharLocale::MustHaveUppercase),
+                        "Must contain at least one uppercase letter",
+                        StringSpecialCharLocale::MustHaveUppercase,
                     ));
                 }
             }
             if self.must_have_lowercase {
-                if !subject.has_ascii_lowercase() {
+                let passed = subject.has_ascii_lowercase();
+                rule_result(locale_keys::VALIDATE_MUST_HAVE_LOWERCASE, passed);
+                if !passed {
                     messages.push((
-                        "Must contain at least one lowercase letter".to_string(),
-                        Box::new(StringSpecialCharLocale::MustHaveLowercase),
+                        "Must contain at least one lowercase letter",
+                        StringSpecialCharLocale::MustHaveLowercase,
                     ));
                 }
             }
         }
         if self.must_have_digit {
-            if !subject.has_ascii_digit() {
+            let passed = subject.has_ascii_digit();
+            rule_result(locale_keys::VALIDATE_MUST_HAVE_DIGIT, passed);
+            if !passed {
                 messages.push((
-                    "Must contain at least one digit".to_string(),
-                    Box::new(StringSpecialCharLocale::MustHaveDigit),
+                    "Must contain at least one digit",
+                    StringSpecialCharLocale::MustHaveDigit,
+                ));
+            }
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.must_have_special_chars {
+            constraints.push(Constraint::from_locale(
+                &StringSpecialCharLocale::MustHaveSpecialChars,
+            ));
+        }
+        if self.must_have_uppercase && self.must_have_lowercase {
+            constraints.push(Constraint::from_locale(
+                &StringSpecialCharLocale::MustHaveUppercaseAndLowercase,
+            ));
+        } else {
+            if self.must_have_uppercase {
+                constraints.push(Constraint::from_locale(
+                    &StringSpecialCharLocale::MustHaveUppercase,
+                ));
+            }
+            if self.must_have_lowercase {
+                constraints.push(Constraint::from_locale(
+                    &StringSpecialCharLocale::MustHaveLowercase,
                 ));
             }
         }
+        if self.must_have_digit {
+            constraints.push(Constraint::from_locale(
+                &StringSpecialCharLocale::MustHaveDigit,
+            ));
+        }
+        constraints
+    }
+}
+
+/// An enumeration of the characters forbidden by `StringSafetyRules`.
+#[derive(Clone)]
+pub enum StringSafetyLocale {
+    /// Must not contain control characters.
+    /// # Key
+    /// `validate-forbid-control-chars`
+    ControlChars,
+    /// Must not contain zero-width characters.
+    /// # Key
+    /// `validate-forbid-zero-width`
+    ZeroWidth,
+    /// Must not contain bidirectional override characters.
+    /// # Key
+    /// `validate-forbid-bidi-overrides`
+    BidiOverrides,
+}
+
+impl LocaleMessage for StringSafetyLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        match self {
+            Self::ControlChars => ld::new(locale_keys::VALIDATE_FORBID_CONTROL_CHARS),
+            Self::ZeroWidth => ld::new(locale_keys::VALIDATE_FORBID_ZERO_WIDTH),
+            Self::BidiOverrides => ld::new(locale_keys::VALIDATE_FORBID_BIDI_OVERRIDES),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::ControlChars => RuleViolation::forbidden_chars("control"),
+            Self::ZeroWidth => RuleViolation::forbidden_chars("zero-width"),
+            Self::BidiOverrides => RuleViolation::forbidden_chars("bidi-override"),
+        }
+    }
+}
+
+/// A structure that defines rules for rejecting control and invisible characters, as a defense
+/// against "Trojan Source" and spoofing attacks.
+///
+/// # Fields
+/// - `forbid_control_chars`: Rejects control characters, such as null bytes or line feeds.
+/// - `forbid_zero_width`: Rejects zero-width characters, such as zero-width spaces and joiners.
+/// - `forbid_bidi_overrides`: Rejects bidirectional embedding, override and isolate characters.
+///
+/// # Defaults
+/// When derived using `Default`, every field is set to `false`.
+#[derive(Default)]
+pub struct StringSafetyRules {
+    pub forbid_control_chars: bool,
+    pub forbid_zero_width: bool,
+    pub forbid_bidi_overrides: bool,
+}
+
+impl StringSafetyRules {
+    /// Validates the `subject` against the forbidden characters, adding an error to `messages`
+    /// for each kind of forbidden character found.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cjtoolkit_structured_validator::common::locale::ValidateErrorCollector;
+    /// use cjtoolkit_structured_validator::common::string_validator::StrValidationExtension;
+    /// use cjtoolkit_structured_validator::base::string_rules::StringSafetyRules;
+    /// let mut errors = ValidateErrorCollector::new();
+    /// let rules = StringSafetyRules {
+    ///     forbid_control_chars: true,
+    ///     forbid_zero_width: true,
+    ///     forbid_bidi_overrides: true,
+    /// };
+    ///
+    /// rules.check(&mut errors, &"admin\u{202E}".as_string_validator());
+    ///
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    pub fn check(&self, messages: &mut ValidateErrorCollector, subject: &StringValidator) {
+        if self.forbid_control_chars {
+            let found = subject.has_control_chars();
+            rule_result(locale_keys::VALIDATE_FORBID_CONTROL_CHARS, !found);
+            if found {
+                messages.push((
+                    "Must not contain control characters",
+                    StringSafetyLocale::ControlChars,
+                ));
+            }
+        }
+        if self.forbid_zero_width {
+            let found = subject.has_zero_width_chars();
+            rule_result(locale_keys::VALIDATE_FORBID_ZERO_WIDTH, !found);
+            if found {
+                messages.push((
+                    "Must not contain invisible characters",
+                    StringSafetyLocale::ZeroWidth,
+                ));
+            }
+        }
+        if self.forbid_bidi_overrides {
+            let found = subject.has_bidi_overrides();
+            rule_result(locale_keys::VALIDATE_FORBID_BIDI_OVERRIDES, !found);
+            if found {
+                messages.push((
+                    "Must not contain text direction characters",
+                    StringSafetyLocale::BidiOverrides,
+                ));
+            }
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.forbid_control_chars {
+            constraints.push(Constraint::from_locale(&StringSafetyLocale::ControlChars));
+        }
+        if self.forbid_zero_width {
+            constraints.push(Constraint::from_locale(&StringSafetyLocale::ZeroWidth));
+        }
+        if self.forbid_bidi_overrides {
+            constraints.push(Constraint::from_locale(&StringSafetyLocale::BidiOverrides));
+        }
+        constraints
+    }
+}
+
+/// An enumeration of the spam heuristics of `SpamHeuristicsRules`.
+#[derive(Clone)]
+pub enum SpamHeuristicsLocale {
+    /// Must not contain more than `max` links.
+    /// # Key
+    /// `validate-too-many-links`
+    TooManyLinks(usize),
+    /// Must not have more than `max_percent` percent of uppercase letters.
+    /// # Key
+    /// `validate-too-much-uppercase`
+    TooMuchUppercase(usize),
+    /// Must not repeat a character more than `max` times in a row.
+    /// # Key
+    /// `validate-repeated-chars`
+    RepeatedChars(usize),
+}
+
+impl LocaleMessage for SpamHeuristicsLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::TooManyLinks(max) => ld::new_with_vec(
+                locale_keys::VALIDATE_TOO_MANY_LINKS,
+                vec![("max".to_string(), lv::from(*max))],
+            ),
+            Self::TooMuchUppercase(max_percent) => ld::new_with_vec(
+                locale_keys::VALIDATE_TOO_MUCH_UPPERCASE,
+                vec![("max_percent".to_string(), lv::from(*max_percent))],
+            ),
+            Self::RepeatedChars(max) => ld::new_with_vec(
+                locale_keys::VALIDATE_REPEATED_CHARS,
+                vec![("max".to_string(), lv::from(*max))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::TooManyLinks(_) => RuleViolation::pattern("links"),
+            Self::TooMuchUppercase(_) => RuleViolation::pattern("uppercase-ratio"),
+            Self::RepeatedChars(_) => RuleViolation::pattern("repeated-chars"),
+        }
+    }
+}
+
+/// A structure that defines heuristics rejecting spam in free text, e.g. forum posts or
+/// comments, as a first line of defense before any moderation.
+///
+/// # Fields
+/// - `max_links`: The maximum number of links, counting `http://`, `https://` and `www.`.
+/// - `max_uppercase_ratio`: The maximum ratio of uppercase letters among the letters, from
+///   `0.0` to `1.0`, e.g. `0.7`.
+/// - `max_repeated_chars`: The maximum number of times a character may be repeated in a row,
+///   e.g. `3` rejects `!!!!`.
+///
+/// # Defaults
+/// When derived using `Default`, every field is set to `None`, so no heuristic is applied.
+#[derive(Clone, Default)]
+pub struct SpamHeuristicsRules {
+    pub max_links: Option<usize>,
+    pub max_uppercase_ratio: Option<f64>,
+    pub max_repeated_chars: Option<usize>,
+}
+
+impl SpamHeuristicsRules {
+    /// Validates the `subject` against the heuristics, adding an error to `messages` for each
+    /// heuristic exceeded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cjtoolkit_structured_validator::common::locale::ValidateErrorCollector;
+    /// use cjtoolkit_structured_validator::common::string_validator::StrValidationExtension;
+    /// use cjtoolkit_structured_validator::base::string_rules::SpamHeuristicsRules;
+    /// let mut errors = ValidateErrorCollector::new();
+    /// let rules = SpamHeuristicsRules {
+    ///     max_links: Some(1),
+    ///     max_uppercase_ratio: Some(0.5),
+    ///     max_repeated_chars: Some(3),
+    /// };
+    ///
+    /// rules.check(&mut errors, &"BUY NOW!!!! WWW.A.EXAMPLE WWW.B.EXAMPLE".as_string_validator());
+    ///
+    /// assert_eq!(errors.len(), 3);
+    /// ```
+    pub fn check(&self, messages: &mut ValidateErrorCollector, subject: &StringValidator) {
+        if let Some(max) = self.max_links {
+            let is_within_max = subject.count_links() <= max;
+            rule_result(locale_keys::VALIDATE_TOO_MANY_LINKS, is_within_max);
+            if !is_within_max {
+                messages.push_default(Box::new(SpamHeuristicsLocale::TooManyLinks(max)));
+            }
+        }
+        if let Some(max_ratio) = self.max_uppercase_ratio {
+            let is_within_max = subject.uppercase_ratio() <= max_ratio;
+            rule_result(locale_keys::VALIDATE_TOO_MUCH_UPPERCASE, is_within_max);
+            if !is_within_max {
+                let max_percent = Self::percent(max_ratio);
+                messages.push_default(Box::new(SpamHeuristicsLocale::TooMuchUppercase(
+                    max_percent,
+                )));
+            }
+        }
+        if let Some(max) = self.max_repeated_chars {
+            let is_within_max = subject.longest_repeated_char_run() <= max;
+            rule_result(locale_keys::VALIDATE_REPEATED_CHARS, is_within_max);
+            if !is_within_max {
+                messages.push_default(Box::new(SpamHeuristicsLocale::RepeatedChars(max)));
+            }
+        }
+    }
+
+    fn percent(ratio: f64) -> usize {
+        (ratio.clamp(0.0, 1.0) * 100.0).round() as usize
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if let Some(max) = self.max_links {
+            constraints.push(Constraint::from_locale(
+                &SpamHeuristicsLocale::TooManyLinks(max),
+            ));
+        }
+        if let Some(max_ratio) = self.max_uppercase_ratio {
+            constraints.push(Constraint::from_locale(
+                &SpamHeuristicsLocale::TooMuchUppercase(Self::percent(max_ratio)),
+            ));
+        }
+        if let Some(max) = self.max_repeated_chars {
+            constraints.push(Constraint::from_locale(
+                &SpamHeuristicsLocale::RepeatedChars(max),
+            ));
+        }
+        constraints
+    }
+}
+
+impl TightenRules for SpamHeuristicsRules {
+    fn tighten(&self, other: &Self) -> Self {
+        Self {
+            max_links: tighter_max(self.max_links, other.max_links),
+            max_uppercase_ratio: tighter_max(self.max_uppercase_ratio, other.max_uppercase_ratio),
+            max_repeated_chars: tighter_max(self.max_repeated_chars, other.max_repeated_chars),
+        }
     }
 }
 
@@ -413,7 +884,10 @@ mod tests {
         fn test_string_mandatory_rule_check_empty_string() {
             let mut messages = ValidateErrorCollector::new();
             let subject = "".as_string_validator();
-            let rule = StringMandatoryRules { is_mandatory: true };
+            let rule = StringMandatoryRules {
+                is_mandatory: true,
+                treat_whitespace_as_empty: false,
+            };
             rule.check(&mut messages, &subject);
             assert_eq!(messages.len(), 1);
             assert_eq!(messages.0[0].0, "Cannot be empty");
@@ -423,10 +897,33 @@ mod tests {
         fn test_string_mandatory_rule_check_not_empty_string() {
             let mut messages = ValidateErrorCollector::new();
             let subject = "Hello".as_string_validator();
-            let rule = StringMandatoryRules { is_mandatory: true };
+            let rule = StringMandatoryRules {
+                is_mandatory: true,
+                treat_whitespace_as_empty: false,
+            };
             rule.check(&mut messages, &subject);
             assert_eq!(messages.len(), 0);
         }
+
+        #[test]
+        fn test_string_mandatory_rule_check_blank_string() {
+            let subject = "   ".as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            StringMandatoryRules {
+                is_mandatory: true,
+                treat_whitespace_as_empty: false,
+            }
+            .check(&mut messages, &subject);
+            assert_eq!(messages.len(), 0);
+
+            let mut messages = ValidateErrorCollector::new();
+            StringMandatoryRules {
+                is_mandatory: true,
+                treat_whitespace_as_empty: true,
+            }
+            .check(&mut messages, &subject);
+            assert_eq!(messages.len(), 1);
+        }
     }
 
     mod string_length_rule {
@@ -439,6 +936,7 @@ mod tests {
             let rule = StringLengthRules {
                 min_length: Some(5),
                 max_length: Some(10),
+                ..Default::default()
             };
             rule.check(&mut messages, &subject);
             assert_eq!(messages.len(), 1);
@@ -452,11 +950,54 @@ mod tests {
             let rule = StringLengthRules {
                 min_length: Some(2),
                 max_length: Some(4),
+                ..Default::default()
             };
             rule.check(&mut messages, &subject);
             assert_eq!(messages.len(), 1);
             assert_eq!(messages.0[0].0, "Must be at most 4 characters");
         }
+
+        #[test]
+        fn test_string_length_rule_messages_use_unit() {
+            let message = |unit: LengthUnit| {
+                let mut messages = ValidateErrorCollector::new();
+                let rule = StringLengthRules {
+                    min_length: Some(2),
+                    max_length: Some(4),
+                    unit,
+                };
+                rule.check(&mut messages, &"Hello".as_string_validator());
+                messages.0[0].0.to_string()
+            };
+            assert_eq!(message(LengthUnit::Chars), "Must be at most 4 characters");
+            assert_eq!(message(LengthUnit::Bytes), "Must be at most 4 bytes");
+            assert_eq!(
+                message(LengthUnit::Utf16CodeUnits),
+                "Must be at most 4 UTF-16 code units"
+            );
+            let violation = StringLengthLocale::max_length(LengthUnit::Bytes, 4).rule_violation();
+            assert_eq!(violation, RuleViolation::MaxLength { max: 4 });
+        }
+
+        #[test]
+        fn test_string_length_rule_check_units() {
+            // Two graphemes, three chars, seven bytes and four UTF-16 code units.
+            let subject = "e\u{301}\u{1F600}".as_string_validator();
+            let length = |unit: LengthUnit| {
+                let mut messages = ValidateErrorCollector::new();
+                let rule = StringLengthRules {
+                    min_length: None,
+                    max_length: Some(2),
+                    unit,
+                };
+                rule.check(&mut messages, &subject);
+                messages.len()
+            };
+            assert_eq!(length(LengthUnit::Graphemes), 0);
+            assert_eq!(length(LengthUnit::Chars), 1);
+            assert_eq!(length(LengthUnit::Bytes), 1);
+            assert_eq!(length(LengthUnit::Utf16CodeUnits), 1);
+        }
     }
 
     mod string_special_char_rule {
@@ -554,4 +1095,68 @@ mod tests {
             assert_eq!(messages.len(), 0);
         }
     }
+
+    mod string_safety_rule {
+        use super::*;
+
+        #[test]
+        fn test_string_safety_rule_check() {
+            let rule = StringSafetyRules {
+                forbid_control_chars: true,
+                forbid_zero_width: true,
+                forbid_bidi_overrides: true,
+            };
+            let count = |s: &str| {
+                let mut messages = ValidateErrorCollector::new();
+                rule.check(&mut messages, &s.as_string_validator());
+                messages.len()
+            };
+            assert_eq!(count("admin"), 0);
+            assert_eq!(count("ad\u{0}min"), 1);
+            assert_eq!(count("ad\u{200B}min"), 1);
+            assert_eq!(count("ad\u{2066}min"), 1);
+            assert_eq!(count("a\td\u{FEFF}m\u{202E}in"), 3);
+        }
+    }
+
+    mod spam_heuristics_rule {
+        use super::*;
+
+        #[test]
+        fn test_spam_heuristics_rule_check() {
+            let rule = SpamHeuristicsRules {
+                max_links: Some(1),
+                max_uppercase_ratio: Some(0.5),
+                max_repeated_chars: Some(3),
+            };
+            let keys = |s: &str| {
+                let mut messages = ValidateErrorCollector::new();
+                rule.check(&mut messages, &s.as_string_validator());
+                messages
+                    .0
+                    .iter()
+                    .map(|(_, locale)| locale.get_locale_data().name.clone())
+                    .collect::<Vec<_>>()
+            };
+            assert!(keys("See https://www.example.com, it is nice!!!").is_empty());
+            assert_eq!(
+                keys("http://a.example www.b.example"),
+                vec![locale_keys::VALIDATE_TOO_MANY_LINKS]
+            );
+            assert_eq!(
+                keys("HELLO you"),
+                vec![locale_keys::VALIDATE_TOO_MUCH_UPPERCASE]
+            );
+            assert_eq!(keys("Hiiii"), vec![locale_keys::VALIDATE_REPEATED_CHARS]);
+            assert!(keys("1234 !!!").is_empty());
+
+            let mut messages = ValidateErrorCollector::new();
+            rule.check(&mut messages, &"HELLO you".as_string_validator());
+            assert_eq!(messages.0[0].0, "Must not be more than 50% uppercase");
+            assert_eq!(
+                messages.violations(),
+                vec![RuleViolation::pattern("uppercase-ratio")]
+            );
+        }
+    }
 }
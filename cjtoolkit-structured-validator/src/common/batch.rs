@@ -0,0 +1,226 @@
+//! This module contains a validator for batches of records, e.g. the rows of a CSV import.
+//!
+//! `BatchValidator` validates each raw record with a closure, returning either the validated
+//! value or the `ValidationReport` of the record, and gathers the outcome of every row into a
+//! `BatchReport`. Validation can stop early once too many rows have failed.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::batch::BatchValidator;
+//! use cjtoolkit_structured_validator::common::validation_report::ValidationReport;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use cjtoolkit_structured_validator::types::numbers::unsigned::Unsigned;
+//!
+//! let rows = vec![("Alice Smith", 30), ("Bob", 42), ("Carol Jones", 300)];
+//! let report = BatchValidator::new(|(name, age): (&str, usize)| {
+//!     let mut report = ValidationReport::new();
+//!     let name = report.check("name", Name::parse(Some(name)));
+//!     let age = report.check("age", Unsigned::parse(Some(age)));
+//!     report.into_result()?;
+//!     Ok((name.expect("Expected valid name"), age.expect("Expected valid age")))
+//! })
+//! .validate(rows);
+//!
+//! assert_eq!(report.valid_count(), 1);
+//! assert_eq!(report.failed_count(), 2);
+//! assert_eq!(report.failed[0].0, 1);
+//! assert_eq!(report.failed[1].1.fields(), vec!["age"]);
+//! ```
+
+use crate::common::validation_report::ValidationReport;
+use std::collections::BTreeMap;
+
+/// A validator of batches of records.
+///
+/// # Fields
+/// - `validate_row`: The closure validating a raw record.
+/// - `max_failed_rows`: The number of failed rows after which the batch stops being read,
+///   `None` to read every row. A failed row is always recorded, so `0` stops at the first failed
+///   row, like `1`.
+pub struct BatchValidator<F> {
+    pub validate_row: F,
+    pub max_failed_rows: Option<usize>,
+}
+
+impl<F> BatchValidator<F> {
+    /// Creates a validator reading every row with `validate_row`.
+    pub fn new(validate_row: F) -> Self {
+        Self {
+            validate_row,
+            max_failed_rows: None,
+        }
+    }
+
+    /// Stops reading the batch once `max_failed_rows` rows have failed.
+    ///
+    /// The batch is only checked after a row fails, so `0` is clamped to `1`, stopping at the
+    /// first failed row.
+    pub fn abort_after(mut self, max_failed_rows: usize) -> Self {
+        self.max_failed_rows = Some(max_failed_rows.max(1));
+        self
+    }
+
+    /// Validates each record of `rows` in order, until the batch ends or too many rows failed.
+    pub fn validate<R, T, I>(&self, rows: I) -> BatchReport<T>
+    where
+        I: IntoIterator<Item = R>,
+        F: Fn(R) -> Result<T, ValidationReport>,
+    {
+        let mut report = BatchReport::default();
+        let mut rows = rows.into_iter().enumerate().peekable();
+        while let Some((index, row)) = rows.next() {
+            report.rows_read += 1;
+            match (self.validate_row)(row) {
+                Ok(value) => report.valid.push((index, value)),
+                Err(errors) => {
+                    report.failed.push((index, errors));
+                    if self
+                        .max_failed_rows
+                        .is_some_and(|max_failed_rows| report.failed.len() >= max_failed_rows)
+                        && rows.peek().is_some()
+                    {
+                        report.aborted = true;
+                        break;
+                    }
+                }
+            }
+        }
+        report
+    }
+}
+
+/// The outcome of validating a batch of records.
+///
+/// Rows are identified by their zero-based index in the batch.
+///
+/// # Fields
+/// - `valid`: The index and validated value of each valid row.
+/// - `failed`: The index and report of each failed row.
+/// - `rows_read`: The number of rows read from the batch.
+/// - `aborted`: `true` if reading stopped because too many rows failed, leaving rows unread.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchReport<T> {
+    pub valid: Vec<(usize, T)>,
+    pub failed: Vec<(usize, ValidationReport)>,
+    pub rows_read: usize,
+    pub aborted: bool,
+}
+
+impl<T> Default for BatchReport<T> {
+    fn default() -> Self {
+        Self {
+            valid: Vec::new(),
+            failed: Vec::new(),
+            rows_read: 0,
+            aborted: false,
+        }
+    }
+}
+
+impl<T> BatchReport<T> {
+    /// Returns `true` if every row read is valid and the batch was read to the end.
+    pub fn is_valid(&self) -> bool {
+        self.failed.is_empty() && !self.aborted
+    }
+
+    /// Returns the number of valid rows.
+    pub fn valid_count(&self) -> usize {
+        self.valid.len()
+    }
+
+    /// Returns the number of failed rows.
+    pub fn failed_count(&self) -> usize {
+        self.failed.len()
+    }
+
+    /// Returns the total number of errors, across every failed row.
+    pub fn error_count(&self) -> usize {
+        self.failed
+            .iter()
+            .map(|(_, report)| report.error_count())
+            .sum()
+    }
+
+    /// Returns the number of failed rows for each field.
+    pub fn failed_rows_by_field(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for (_, report) in &self.failed {
+            for field in report.fields() {
+                *counts.entry(field.to_string()).or_default() += 1;
+            }
+        }
+        counts
+    }
+
+    /// Returns the validated values, if every row read is valid and the batch was read to the
+    /// end, otherwise returns the report.
+    pub fn into_result(self) -> Result<Vec<T>, Self> {
+        if self.is_valid() {
+            Ok(self.valid.into_iter().map(|(_, value)| value).collect())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::Name;
+
+    fn validate_name(name: &str) -> Result<Name, ValidationReport> {
+        let mut report = ValidationReport::new();
+        let name = report.check("name", Name::parse(Some(name)));
+        report.into_result()?;
+        Ok(name.expect("Expected valid name"))
+    }
+
+    #[test]
+    fn test_batch_aborts_after_max_failed_rows() {
+        let rows = ["Alice Smith", "No", "Bob Jones", "Eve", "Al", "Carol Jones"];
+        let report = BatchValidator::new(validate_name)
+            .abort_after(2)
+            .validate(rows);
+
+        assert!(report.aborted);
+        assert_eq!(report.rows_read, 4);
+        assert_eq!(report.valid_count(), 2);
+        assert_eq!(
+            report.failed.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(report.failed_rows_by_field().get("name"), Some(&2));
+        assert!(report.into_result().is_err());
+    }
+
+    #[test]
+    fn test_batch_aborts_only_with_rows_left() {
+        let report = BatchValidator::new(validate_name)
+            .abort_after(0)
+            .validate(["Alice Smith", "Bob Jones"]);
+        assert!(!report.aborted);
+        assert!(report.is_valid());
+
+        let report = BatchValidator::new(validate_name)
+            .abort_after(1)
+            .validate(["Alice Smith", "No"]);
+        assert!(!report.aborted);
+        assert_eq!((report.rows_read, report.failed_count()), (2, 1));
+    }
+
+    #[test]
+    fn test_batch_abort_after_zero_stops_at_first_failure() {
+        let validator = BatchValidator::new(validate_name).abort_after(0);
+        assert_eq!(validator.max_failed_rows, Some(1));
+        let report = validator.validate(["Alice Smith", "No", "Bob Jones"]);
+        assert!(report.aborted);
+        assert_eq!((report.rows_read, report.failed_count()), (2, 1));
+    }
+
+    #[test]
+    fn test_batch_into_result() {
+        let report = BatchValidator::new(validate_name).validate(["Alice Smith", "Bob Jones"]);
+        let names = report.into_result().expect("Expected valid batch");
+        assert_eq!(names.len(), 2);
+    }
+}
@@ -0,0 +1,359 @@
+//! This module contains the `CachedAsyncRule` type, memoizing the results of expensive async
+//! checks, e.g. MX lookups or uniqueness checks against an external API.
+//!
+//! Results are keyed by the normalized input, trimmed and lowercased by default, and kept for a
+//! time to live, so retrying a form does not repeat the network calls for the same input. The
+//! rule itself checks the input as given, the normalization only being used for the key. The
+//! cache is pluggable with the `CacheBackend` trait, `MemoryCache` being an in-process backend.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::cached_rule::{AsyncRule, CachedAsyncRule, MemoryCache};
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::time::Duration;
+//!
+//! #[derive(Default)]
+//! struct MxLookup(AtomicUsize);
+//!
+//! impl AsyncRule for MxLookup {
+//!     type Output = bool;
+//!
+//!     async fn check_async(&self, input: &str) -> bool {
+//!         self.0.fetch_add(1, Ordering::Relaxed);
+//!         input.ends_with("@example.com")
+//!     }
+//! }
+//!
+//! # tokio_test(async {
+//! let rule = CachedAsyncRule::new(MxLookup::default(), MemoryCache::new(), Duration::from_secs(60));
+//! assert!(rule.check_async("user@example.com").await);
+//! assert!(rule.check_async(" User@Example.com ").await);
+//! assert_eq!(rule.rule.0.load(Ordering::Relaxed), 1);
+//! # });
+//! # fn tokio_test(f: impl std::future::Future<Output = ()>) {
+//! #     tokio::runtime::Runtime::new().expect("Expected runtime").block_on(f)
+//! # }
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A trait for an async check of an input, e.g. whether a username is taken.
+pub trait AsyncRule {
+    type Output: Clone;
+
+    fn check_async(&self, input: &str) -> impl Future<Output = Self::Output>;
+}
+
+/// A trait for an async check of an input with a request-scoped context `Ctx`, e.g. a database
+/// transaction or the authenticated user, so the rule needs no global state nor a new instance
+/// per request.
+///
+/// Every `AsyncRule` implements it, ignoring the context.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::cached_rule::AsyncRuleWithContext;
+///
+/// struct Transaction {
+///     reserved_slugs: Vec<String>,
+/// }
+///
+/// struct IsSlugReserved;
+///
+/// impl AsyncRuleWithContext<Transaction> for IsSlugReserved {
+///     type Output = bool;
+///
+///     async fn check_async_with_context(&self, input: &str, tx: &Transaction) -> bool {
+///         tx.reserved_slugs.iter().any(|slug| slug == input)
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().expect("Expected runtime").block_on(async {
+/// let tx = Transaction {
+///     reserved_slugs: vec!["about".to_string()],
+/// };
+/// assert!(IsSlugReserved.check_async_with_context("about", &tx).await);
+/// # });
+/// ```
+pub trait AsyncRuleWithContext<Ctx: ?Sized> {
+    type Output: Clone;
+
+    fn check_async_with_context(
+        &self,
+        input: &str,
+        ctx: &Ctx,
+    ) -> impl Future<Output = Self::Output>;
+}
+
+impl<R: AsyncRule, Ctx: ?Sized> AsyncRuleWithContext<Ctx> for R {
+    type Output = R::Output;
+
+    async fn check_async_with_context(&self, input: &str, _ctx: &Ctx) -> Self::Output {
+        self.check_async(input).await
+    }
+}
+
+/// A trait for the cache of a `CachedAsyncRule`, e.g. in process or in Redis.
+///
+/// # Required Methods
+/// - `get`: Returns the value of `key`, `None` if missing or expired.
+/// - `insert`: Stores the value of `key`, expiring after `ttl`.
+pub trait CacheBackend<V> {
+    fn get(&self, key: &str) -> Option<V>;
+
+    fn insert(&self, key: String, value: V, ttl: Duration);
+}
+
+/// An in-process `CacheBackend`, removing expired entries when they are read.
+///
+/// The cache holds at most `max_entries` entries. When a new entry does not fit, the expired
+/// entries are removed, then the entry expiring the soonest if it is still full. The entries are
+/// also ordered by expiry, so making room does not scan the cache. An entry whose expiry is too
+/// far in the future for `Instant` never expires.
+pub struct MemoryCache<V> {
+    entries: Mutex<Entries<V>>,
+    max_entries: usize,
+}
+
+/// The expiry of an entry, ordered from the soonest to never.
+type Expiry = (bool, Option<Instant>);
+
+fn expiry(expires_at: Option<Instant>) -> Expiry {
+    (expires_at.is_none(), expires_at)
+}
+
+struct Entries<V> {
+    values: HashMap<String, (V, Option<Instant>)>,
+    by_expiry: BTreeSet<(Expiry, String)>,
+}
+
+impl<V> Entries<V> {
+    fn remove(&mut self, key: &str) {
+        if let Some((key, (_, expires_at))) = self.values.remove_entry(key) {
+            self.by_expiry.remove(&(expiry(expires_at), key));
+        }
+    }
+
+    fn pop_soonest(&mut self) -> Option<Option<Instant>> {
+        let ((_, expires_at), key) = self.by_expiry.pop_first()?;
+        self.values.remove(&key);
+        Some(expires_at)
+    }
+
+    /// Removes the expired entries, then the entry expiring the soonest if there is still no
+    /// room for another entry.
+    fn make_room(&mut self, max_entries: usize, now: Instant) {
+        while let Some(((_, Some(at)), _)) = self.by_expiry.first()
+            && *at <= now
+        {
+            self.pop_soonest();
+        }
+        if self.values.len() >= max_entries {
+            self.pop_soonest();
+        }
+    }
+}
+
+impl<V> MemoryCache<V> {
+    /// The maximum number of entries of a cache created with `new`.
+    pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+    /// Creates an empty cache, holding at most `DEFAULT_MAX_ENTRIES` entries.
+    pub fn new() -> Self {
+        Self::with_max_entries(Self::DEFAULT_MAX_ENTRIES)
+    }
+
+    /// Creates an empty cache, holding at most `max_entries` entries.
+    pub fn with_max_entries(max_entries: usize) -> Self {
+        Self {
+            entries: Mutex::new(Entries {
+                values: HashMap::new(),
+                by_expiry: BTreeSet::new(),
+            }),
+            max_entries,
+        }
+    }
+
+    /// Returns the number of entries, including expired entries not yet removed.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values
+            .len()
+    }
+
+    /// Returns `true` if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<V> Default for MemoryCache<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Clone> CacheBackend<V> for MemoryCache<V> {
+    fn get(&self, key: &str) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.values.get(key) {
+            Some((value, expires_at)) if expires_at.is_none_or(|at| at > Instant::now()) => {
+                Some(value.clone())
+            }
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: String, value: V, ttl: Duration) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.remove(&key);
+        if entries.values.len() >= self.max_entries {
+            entries.make_room(self.max_entries, now);
+        }
+        let expires_at = now.checked_add(ttl);
+        entries.by_expiry.insert((expiry(expires_at), key.clone()));
+        entries.values.insert(key, (value, expires_at));
+    }
+}
+
+/// Returns the input trimmed and lowercased, the default normalization of `CachedAsyncRule`.
+pub fn normalize_lowercase(input: &str) -> String {
+    input.trim().to_lowercase()
+}
+
+/// An async rule whose results are cached by normalized input.
+///
+/// # Fields
+/// - `rule`: The rule checking inputs missing from the cache.
+/// - `cache`: The cache of the results.
+/// - `ttl`: How long a result is kept.
+/// - `normalize`: The normalization of the input for the cache key, the rule checking the input
+///   as given.
+pub struct CachedAsyncRule<R, C> {
+    pub rule: R,
+    pub cache: C,
+    pub ttl: Duration,
+    pub normalize: fn(&str) -> String,
+}
+
+impl<R, C> CachedAsyncRule<R, C> {
+    /// Creates the cached rule, normalizing inputs with `normalize_lowercase`.
+    pub fn new(rule: R, cache: C, ttl: Duration) -> Self {
+        Self {
+            rule,
+            cache,
+            ttl,
+            normalize: normalize_lowercase,
+        }
+    }
+
+    /// Returns the cached result for the normalized `input`, or else checks `input` with `check`,
+    /// e.g. calling a method of `rule`, and caches the result.
+    pub async fn get_or_check<V, F, Fut>(&self, input: &str, check: F) -> V
+    where
+        V: Clone,
+        C: CacheBackend<V>,
+        F: FnOnce(String) -> Fut,
+        Fut: Future<Output = V>,
+    {
+        let key = (self.normalize)(input);
+        if let Some(value) = self.cache.get(&key) {
+            return value;
+        }
+        let value = check(input.to_string()).await;
+        self.cache.insert(key, value.clone(), self.ttl);
+        value
+    }
+}
+
+impl<R, C> AsyncRule for CachedAsyncRule<R, C>
+where
+    R: AsyncRule,
+    C: CacheBackend<R::Output>,
+{
+    type Output = R::Output;
+
+    async fn check_async(&self, input: &str) -> Self::Output {
+        self.get_or_check(
+            input,
+            |input| async move { self.rule.check_async(&input).await },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct CountingRule(AtomicUsize);
+
+    impl AsyncRule for CountingRule {
+        type Output = usize;
+
+        async fn check_async(&self, input: &str) -> usize {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            input.len()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_results_are_checked_again() {
+        let rule =
+            CachedAsyncRule::new(CountingRule::default(), MemoryCache::new(), Duration::ZERO);
+        assert_eq!(rule.check_async(" abc ").await, 5);
+        assert_eq!(rule.check_async("abc").await, 3);
+        assert_eq!(rule.rule.0.load(Ordering::Relaxed), 2);
+        assert!(rule.cache.len() <= 1);
+    }
+
+    #[tokio::test]
+    async fn test_rule_checks_the_original_input() {
+        let rule = CachedAsyncRule::new(
+            CountingRule::default(),
+            MemoryCache::new(),
+            Duration::from_secs(60),
+        );
+        assert_eq!(rule.check_async(" Abc ").await, 5);
+        assert_eq!(rule.check_async("abc").await, 5);
+        assert_eq!(rule.rule.0.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_memory_cache_max_entries() {
+        let cache = MemoryCache::with_max_entries(2);
+        cache.insert("a".to_string(), 1, Duration::from_secs(60));
+        cache.insert("b".to_string(), 2, Duration::from_secs(30));
+        cache.insert("a".to_string(), 3, Duration::from_secs(60));
+        assert_eq!(cache.len(), 2);
+        cache.insert("c".to_string(), 4, Duration::from_secs(60));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("a"), Some(3));
+        assert_eq!(cache.get("b"), None);
+        cache.insert("d".to_string(), 5, Duration::ZERO);
+        cache.insert("e".to_string(), 6, Duration::from_secs(60));
+        assert_eq!((cache.get("a"), cache.get("c")), (None, Some(4)));
+        assert_eq!(cache.get("e"), Some(6));
+    }
+
+    #[test]
+    fn test_memory_cache_ttl_overflow() {
+        let cache = MemoryCache::new();
+        cache.insert("a".to_string(), 1, Duration::MAX);
+        assert_eq!(cache.get("a"), Some(1));
+    }
+}
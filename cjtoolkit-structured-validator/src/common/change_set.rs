@@ -0,0 +1,157 @@
+//! This module contains a field-level diff of validated values, e.g. for the audit log of a
+//! profile update.
+//!
+//! `ChangeSet` compares the canonical string of the old and new value of each field, and keeps
+//! the fields that changed. The values of sensitive fields, e.g. passwords, are redacted, so only
+//! the fact that they changed is recorded. An empty string is treated as no value.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::change_set::{ChangeSet, REDACTED};
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use cjtoolkit_structured_validator::types::password::Password;
+//!
+//! let old_name = Name::parse(Some("Alice Smith")).expect("Expected valid name");
+//! let new_name = Name::parse(Some("Alice Jones")).expect("Expected valid name");
+//! let old_password = Password::parse(Some("Pa55word!")).expect("Expected valid password");
+//! let new_password = Password::parse(Some("N3wPassword!")).expect("Expected valid password");
+//!
+//! let changes = ChangeSet::new()
+//!     .compare("name", &old_name, &new_name)
+//!     .compare_sensitive("password", &old_password, &new_password);
+//!
+//! assert_eq!(changes.fields(), vec!["name", "password"]);
+//! assert_eq!(changes.0[0].new.as_deref(), Some("Alice Jones"));
+//! assert_eq!(changes.0[1].new.as_deref(), Some(REDACTED));
+//! ```
+
+/// The placeholder of the values of sensitive fields.
+pub const REDACTED: &str = "[redacted]";
+
+/// The change of a field.
+///
+/// # Fields
+/// - `field`: The name of the field.
+/// - `old`: The canonical string of the old value, `None` if it had no value.
+/// - `new`: The canonical string of the new value, `None` if it has no value.
+/// - `redacted`: `true` if the values were replaced by `REDACTED`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+    pub redacted: bool,
+}
+
+/// The fields that changed between two versions of a record, in the order they were compared.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChangeSet(pub Vec<FieldChange>);
+
+impl ChangeSet {
+    /// Creates an empty change set.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Compares the `old` and `new` value of `field`, recording it if they differ.
+    pub fn compare<T>(self, field: &str, old: &T, new: &T) -> Self
+    where
+        for<'a> &'a T: Into<String>,
+    {
+        self.compare_str(field, &old.into(), &new.into(), false)
+    }
+
+    /// Compares the `old` and `new` value of the sensitive `field`, recording it with redacted
+    /// values if they differ.
+    pub fn compare_sensitive<T>(self, field: &str, old: &T, new: &T) -> Self
+    where
+        for<'a> &'a T: Into<String>,
+    {
+        self.compare_str(field, &old.into(), &new.into(), true)
+    }
+
+    /// Compares two snapshots of a record, as lists of field names and canonical strings,
+    /// recording the fields that differ, or that are only in one of them. The values of the
+    /// fields in `sensitive` are redacted.
+    pub fn between<K: AsRef<str>, V: AsRef<str>>(
+        old: &[(K, V)],
+        new: &[(K, V)],
+        sensitive: &[&str],
+    ) -> Self {
+        let find = |snapshot: &[(K, V)], field: &str| {
+            snapshot
+                .iter()
+                .find(|(name, _)| name.as_ref() == field)
+                .map(|(_, value)| value.as_ref().to_string())
+                .unwrap_or_default()
+        };
+        let mut fields: Vec<&str> = Vec::new();
+        for (field, _) in old.iter().chain(new) {
+            if !fields.contains(&field.as_ref()) {
+                fields.push(field.as_ref());
+            }
+        }
+        fields.into_iter().fold(Self::new(), |changes, field| {
+            changes.compare_str(
+                field,
+                &find(old, field),
+                &find(new, field),
+                sensitive.contains(&field),
+            )
+        })
+    }
+
+    fn compare_str(mut self, field: &str, old: &str, new: &str, redacted: bool) -> Self {
+        if old == new {
+            return self;
+        }
+        let value =
+            |s: &str| (!s.is_empty()).then(|| if redacted { REDACTED } else { s }.to_string());
+        self.0.push(FieldChange {
+            field: field.to_string(),
+            old: value(old),
+            new: value(new),
+            redacted,
+        });
+        self
+    }
+
+    /// Returns `true` if no field changed.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the names of the fields that changed.
+    pub fn fields(&self) -> Vec<&str> {
+        self.0.iter().map(|change| change.field.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_between_snapshots() {
+        let old = [("name", "Alice Smith"), ("token", "abc"), ("bio", "")];
+        let new = [
+            ("name", "Alice Smith"),
+            ("token", "def"),
+            ("email", "a@b.com"),
+        ];
+        let changes = ChangeSet::between(&old, &new, &["token"]);
+
+        assert_eq!(changes.fields(), vec!["token", "email"]);
+        assert_eq!(
+            changes.0[0],
+            FieldChange {
+                field: "token".to_string(),
+                old: Some(REDACTED.to_string()),
+                new: Some(REDACTED.to_string()),
+                redacted: true,
+            }
+        );
+        assert_eq!(changes.0[1].old, None);
+        assert!(ChangeSet::between(&old, &old, &[]).is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+//! This module contains the `CheckBudget` type, limiting the number of expensive async checks,
+//! e.g. uniqueness checks against a database, that a request may run.
+//!
+//! A budget is passed to the `_with_budget` check methods, and the external implementations of
+//! the checks consult and decrement it. When the budget is exhausted, the check is skipped and
+//! a `validate-check-skipped` warning is returned with the value instead of an error, so
+//! validation degrades gracefully under load.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::check_budget::CheckBudget;
+//!
+//! let budget = CheckBudget::new(2);
+//! assert!(budget.try_consume(1));
+//! assert!(!budget.try_consume(2));
+//! assert_eq!(budget.remaining(), 1);
+//! ```
+
+use crate::common::locale::{LocaleData, LocaleMessage, RuleViolation, ValidateErrorStore};
+use crate::common::locale_keys;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A budget of units shared by the async checks of a request, safe to share between tasks.
+#[derive(Debug)]
+pub struct CheckBudget {
+    remaining: AtomicUsize,
+}
+
+impl CheckBudget {
+    /// Creates a budget of `units`.
+    pub fn new(units: usize) -> Self {
+        Self {
+            remaining: AtomicUsize::new(units),
+        }
+    }
+
+    /// Creates a budget that is never exhausted in practice.
+    pub fn unlimited() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    /// Returns the number of units left.
+    pub fn remaining(&self) -> usize {
+        self.remaining.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if no units are left.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Takes `units` from the budget, returns `false` and takes nothing if fewer are left.
+    pub fn try_consume(&self, units: usize) -> bool {
+        self.remaining
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |remaining| {
+                remaining.checked_sub(units)
+            })
+            .is_ok()
+    }
+}
+
+/// The value of a check run with a budget, and the warnings of the checks that were skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckOutcome<T> {
+    pub value: T,
+    pub warnings: ValidateErrorStore,
+}
+
+impl<T> CheckOutcome<T> {
+    /// Returns `true` if a check was skipped, i.e. the value is not fully validated.
+    pub fn is_degraded(&self) -> bool {
+        !self.warnings.0.is_empty()
+    }
+
+    /// Returns the value, discarding the warnings.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+/// A struct representing the locale for the warning of a check skipped because the budget
+/// was exhausted.
+///
+/// # Key
+/// `validate-check-skipped`
+pub struct CheckSkippedLocale;
+
+impl LocaleMessage for CheckSkippedLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new(locale_keys::VALIDATE_CHECK_SKIPPED)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Unverified {
+            name: "budget".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_try_consume_does_not_overdraw() {
+        let budget = CheckBudget::new(3);
+        assert!(budget.try_consume(2));
+        assert!(!budget.try_consume(2));
+        assert!(budget.try_consume(1));
+        assert!(budget.is_exhausted());
+        assert!(!budget.try_consume(1));
+    }
+
+    #[test]
+    fn test_budget_is_shared_between_threads() {
+        let budget = CheckBudget::new(10);
+        let consumed: usize = thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| (0..5).filter(|_| budget.try_consume(1)).count()))
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().expect("Expected thread to finish"))
+                .sum()
+        });
+        assert_eq!(consumed, 10);
+        assert!(budget.is_exhausted());
+    }
+}
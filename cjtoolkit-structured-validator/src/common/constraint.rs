@@ -0,0 +1,94 @@
+//! This module contains a structured description of the constraints enforced by rules.
+//!
+//! Every rules struct has a `describe` method listing its active constraints, each with the
+//! locale key and arguments of the message reported when it fails. So documentation generators
+//! and front-ends can render the constraints of the live rules with the same translations as the
+//! errors, e.g. "Must be at least 8 characters".
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::locale::{LocaleValue, RuleViolation};
+//! use cjtoolkit_structured_validator::common::locale_keys;
+//! use cjtoolkit_structured_validator::types::password::PasswordRules;
+//!
+//! let constraints = PasswordRules::default().describe();
+//! let min_length = constraints
+//!     .iter()
+//!     .find(|constraint| constraint.locale_key == locale_keys::VALIDATE_MIN_LENGTH)
+//!     .expect("Expected min length constraint");
+//!
+//! assert_eq!(min_length.kind, RuleViolation::MinLength { min: 8 });
+//! assert_eq!(min_length.param("min"), Some(&LocaleValue::Uint(8)));
+//! ```
+
+use crate::common::locale::{LocaleMessage, LocaleValue, RuleViolation};
+
+/// A constraint enforced by rules.
+///
+/// # Fields
+/// - `kind`: The rule, as the `RuleViolation` reported when it fails.
+/// - `locale_key`: The locale key of the message reported when it fails.
+/// - `params`: The arguments of the message, sorted by name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint {
+    pub kind: RuleViolation,
+    pub locale_key: String,
+    pub params: Vec<(String, LocaleValue)>,
+}
+
+impl Constraint {
+    /// Creates the constraint reporting `message` when it fails.
+    pub fn from_locale(message: &dyn LocaleMessage) -> Self {
+        let data = message.get_locale_data();
+        let mut params: Vec<(String, LocaleValue)> = data
+            .args
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        params.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Self {
+            kind: message.rule_violation(),
+            locale_key: data.name.clone(),
+            params,
+        }
+    }
+
+    /// Returns the argument named `name`, if any.
+    pub fn param(&self, name: &str) -> Option<&LocaleValue> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::locale_keys;
+    use crate::types::numbers::integer::IntegerRules;
+    use crate::types::username::UsernameRules;
+
+    #[test]
+    fn test_constraints_match_locale_keys() {
+        let mut constraints = IntegerRules {
+            must_be_even: true,
+            allowed_values: Some(vec![2, 4]),
+            ..IntegerRules::default()
+        }
+        .describe();
+        constraints.extend(UsernameRules::default().describe());
+
+        for constraint in &constraints {
+            assert!(locale_keys::all_keys().contains(&constraint.locale_key.as_str()));
+            let params: Vec<&str> = constraint.params.iter().map(|(n, _)| n.as_str()).collect();
+            assert_eq!(params, locale_keys::key_args(&constraint.locale_key));
+        }
+        assert_eq!(
+            constraints[4].kind,
+            RuleViolation::NotAllowed {
+                allowed: "2, 4".to_string()
+            }
+        );
+    }
+}
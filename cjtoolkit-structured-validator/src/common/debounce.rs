@@ -0,0 +1,136 @@
+//! This module contains the `DebouncedAsyncCheck` type, debouncing async checks called on every
+//! keystroke, e.g. whether a username is taken, so the service is only called once the input
+//! stops changing.
+//!
+//! Each call waits for the delay, and is superseded if another call starts meanwhile, returning
+//! `None` without calling the service. A call whose result arrives after a later call started
+//! also returns `None`, so a stale result never replaces the result of the latest input.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::debounce::DebouncedAsyncCheck;
+//! use cjtoolkit_structured_validator::types::username::IsUsernameTakenAsync;
+//! use std::time::Duration;
+//!
+//! struct UsernameService;
+//!
+//! impl IsUsernameTakenAsync for UsernameService {
+//!     async fn is_username_taken_async(&self, username: &str) -> bool {
+//!         username == "alice"
+//!     }
+//! }
+//!
+//! # tokio::runtime::Runtime::new().expect("Expected runtime").block_on(async {
+//! let check = DebouncedAsyncCheck::new(UsernameService, Duration::from_millis(10));
+//! let (first, latest) = tokio::join!(
+//!     check.is_username_taken_async("alic"),
+//!     check.is_username_taken_async("alice"),
+//! );
+//! assert_eq!(first, None);
+//! assert_eq!(latest, Some(true));
+//! # });
+//! ```
+
+use crate::common::cached_rule::AsyncRule;
+use crate::types::username::IsUsernameTakenAsync;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Debounces the async checks of `service`, see the module documentation.
+///
+/// # Fields
+/// - `service`: The service checking the inputs, e.g. an `IsUsernameTakenAsync`.
+/// - `delay`: How long the input must stay unchanged before the service is called.
+pub struct DebouncedAsyncCheck<S> {
+    pub service: S,
+    pub delay: Duration,
+    latest_call: AtomicU64,
+}
+
+impl<S> DebouncedAsyncCheck<S> {
+    /// Creates the debounced check of `service`, waiting for `delay`.
+    pub fn new(service: S, delay: Duration) -> Self {
+        Self {
+            service,
+            delay,
+            latest_call: AtomicU64::new(0),
+        }
+    }
+
+    /// Runs `check` on the service after the delay, returning its result, or `None` if another
+    /// call started before the delay elapsed or before the result arrived.
+    pub async fn debounce<'a, V, F, Fut>(&'a self, check: F) -> Option<V>
+    where
+        F: FnOnce(&'a S) -> Fut,
+        Fut: Future<Output = V> + 'a,
+    {
+        let call = self.latest_call.fetch_add(1, Ordering::AcqRel) + 1;
+        let is_latest = || self.latest_call.load(Ordering::Acquire) == call;
+        tokio::time::sleep(self.delay).await;
+        if !is_latest() {
+            return None;
+        }
+        let value = check(&self.service).await;
+        is_latest().then_some(value)
+    }
+}
+
+impl<S: IsUsernameTakenAsync> DebouncedAsyncCheck<S> {
+    /// Returns whether `username` is taken, `None` if superseded by a later call.
+    pub async fn is_username_taken_async(&self, username: &str) -> Option<bool> {
+        self.debounce(|service| service.is_username_taken_async(username))
+            .await
+    }
+}
+
+impl<R: AsyncRule> AsyncRule for DebouncedAsyncCheck<R> {
+    type Output = Option<R::Output>;
+
+    async fn check_async(&self, input: &str) -> Self::Output {
+        self.debounce(|rule| rule.check_async(input)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[derive(Default)]
+    struct CountingRule(AtomicUsize);
+
+    impl AsyncRule for CountingRule {
+        type Output = usize;
+
+        async fn check_async(&self, input: &str) -> usize {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            input.len()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_only_latest_call_is_checked() {
+        let check = DebouncedAsyncCheck::new(CountingRule::default(), Duration::from_millis(5));
+        let (a, ab, abc) = tokio::join!(
+            check.check_async("a"),
+            check.check_async("ab"),
+            check.check_async("abc"),
+        );
+        assert_eq!((a, ab, abc), (None, None, Some(3)));
+        assert_eq!(check.service.0.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stale_result_is_suppressed() {
+        let check = DebouncedAsyncCheck::new(CountingRule::default(), Duration::from_millis(5));
+        let stale = check.check_async("stale");
+        let latest = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            check.check_async("latest!").await
+        };
+        let (stale, latest) = tokio::join!(stale, latest);
+        assert_eq!((stale, latest), (None, Some(7)));
+        assert_eq!(check.service.0.load(Ordering::Relaxed), 2);
+    }
+}
@@ -0,0 +1,41 @@
+//! This module contains helpers for the `test-fixtures` feature.
+//!
+//! With the feature enabled, the value types provide `valid_sample` and `sample_matching`, and
+//! their rules provide `arbitrary_valid_input`, so tests can build valid values without
+//! unwrapping `parse`. The samples are deterministic, the same rules always give the same value.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::name::{Name, NameRules};
+//!
+//! let name = Name::valid_sample();
+//! assert!(Name::parse(Some(name.as_str())).is_ok());
+//!
+//! let rules = NameRules {
+//!     min_length: Some(30),
+//!     max_length: Some(40),
+//!     ..NameRules::default()
+//! };
+//! assert_eq!(rules.arbitrary_valid_input().len(), 30);
+//! ```
+
+/// Returns `seed` repeated or truncated to fit between `min` and `max` characters.
+///
+/// The `seed` is expected to be ASCII, so that its length is the same in every `LengthUnit`.
+pub fn sample_string(seed: &str, min: Option<usize>, max: Option<usize>) -> String {
+    let length = seed.len().max(min.unwrap_or_default());
+    let length = max.map_or(length, |max| length.min(max));
+    seed.chars().cycle().take(length).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_string() {
+        assert_eq!(sample_string("abc", None, None), "abc");
+        assert_eq!(sample_string("abc", Some(7), None), "abcabca");
+        assert_eq!(sample_string("abc", None, Some(2)), "ab");
+    }
+}
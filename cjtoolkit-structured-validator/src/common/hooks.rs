@@ -0,0 +1,237 @@
+//! This module contains `RuleHooks`, functions run before and after the rules of a type, e.g.
+//! to normalize the input before it is checked, or to write an audit log of the outcome.
+//!
+//! The hooks of an input type can be attached to a validator with `WithHooks::with_hooks`, or
+//! registered globally with `set_global_hooks`, for every hooked validation of that input type.
+//! The hooks run in order, the global hooks around the attached hooks: the global pre hooks, the
+//! attached pre hooks, the rules, the attached post hooks and the global post hooks.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::hooks::{RuleHooks, WithHooks};
+//! use cjtoolkit_structured_validator::common::validator::Validator;
+//! use cjtoolkit_structured_validator::types::numbers::integer::{Integer, IntegerRules};
+//! use std::sync::{Arc, Mutex};
+//!
+//! let audit = Arc::new(Mutex::new(Vec::new()));
+//! let log = audit.clone();
+//! let rules = IntegerRules {
+//!     max: Some(100),
+//!     ..IntegerRules::default()
+//! }
+//! .with_hooks(
+//!     RuleHooks::new()
+//!         .pre(|input: Option<isize>| input.map(|n| n.abs()))
+//!         .post(move |outcome| {
+//!             log.lock().expect("Expected lock").push(outcome.errors.0.len());
+//!         }),
+//! );
+//!
+//! assert_eq!(rules.validate(Some(-42)).map(|n| n.as_isize()).ok(), Some(42));
+//! assert!(rules.validate(Some(-420)).is_err());
+//! assert_eq!(*audit.lock().expect("Expected lock"), vec![0, 1]);
+//! ```
+
+use crate::common::locale::ValidateErrorStore;
+use crate::common::validator::Validator;
+use std::any::{Any, TypeId, type_name};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// The outcome of a hooked validation, passed to the post hooks.
+///
+/// # Fields
+/// - `type_name`: The name of the value type, e.g. `Integer`.
+/// - `errors`: The errors of the validation, empty if the input is valid.
+pub struct HookOutcome<'a> {
+    pub type_name: &'static str,
+    pub errors: &'a ValidateErrorStore,
+}
+
+impl HookOutcome<'_> {
+    /// Returns `true` if the input is valid.
+    pub fn is_valid(&self) -> bool {
+        self.errors.0.is_empty()
+    }
+}
+
+type PreHook<I> = Arc<dyn Fn(I) -> I + Send + Sync>;
+type PostHook = Arc<dyn Fn(&HookOutcome) + Send + Sync>;
+
+/// The functions run before and after the rules of an input of type `I`, see the module
+/// documentation.
+pub struct RuleHooks<I> {
+    pre: Vec<PreHook<I>>,
+    post: Vec<PostHook>,
+}
+
+impl<I> Clone for RuleHooks<I> {
+    fn clone(&self) -> Self {
+        Self {
+            pre: self.pre.clone(),
+            post: self.post.clone(),
+        }
+    }
+}
+
+impl<I> Default for RuleHooks<I> {
+    fn default() -> Self {
+        Self {
+            pre: Vec::new(),
+            post: Vec::new(),
+        }
+    }
+}
+
+impl<I: 'static> RuleHooks<I> {
+    /// Creates hooks without functions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a function run before the rules, returning the input to check, e.g. trimmed.
+    pub fn pre(mut self, hook: impl Fn(I) -> I + Send + Sync + 'static) -> Self {
+        self.pre.push(Arc::new(hook));
+        self
+    }
+
+    /// Adds a function run after the rules, with their outcome.
+    pub fn post(mut self, hook: impl Fn(&HookOutcome) + Send + Sync + 'static) -> Self {
+        self.post.push(Arc::new(hook));
+        self
+    }
+
+    /// Runs `parse` on `input`, with the global hooks of `I` around these hooks.
+    pub fn run<O, E>(&self, input: I, parse: impl FnOnce(I) -> Result<O, E>) -> Result<O, E>
+    where
+        for<'a> &'a E: Into<ValidateErrorStore>,
+    {
+        let global = global_hooks::<I>();
+        let input = global
+            .iter()
+            .flat_map(|hooks| hooks.pre.iter())
+            .chain(self.pre.iter())
+            .fold(input, |input, hook| hook(input));
+        let result = parse(input);
+        let post: Vec<&PostHook> = self
+            .post
+            .iter()
+            .chain(global.iter().flat_map(|hooks| hooks.post.iter()))
+            .collect();
+        if !post.is_empty() {
+            let errors = result.as_ref().err().map(Into::into).unwrap_or_default();
+            let outcome = HookOutcome {
+                type_name: type_name::<O>().rsplit("::").next().unwrap_or_default(),
+                errors: &errors,
+            };
+            for hook in post {
+                hook(&outcome);
+            }
+        }
+        result
+    }
+}
+
+static GLOBAL: RwLock<Option<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>> = RwLock::new(None);
+
+/// Registers `hooks` for every hooked validation of an input of type `I`, replacing the previous
+/// global hooks of `I`.
+pub fn set_global_hooks<I: 'static>(hooks: RuleHooks<I>) {
+    let mut global = GLOBAL.write().unwrap_or_else(|e| e.into_inner());
+    global
+        .get_or_insert_with(HashMap::new)
+        .insert(TypeId::of::<I>(), Arc::new(hooks));
+}
+
+/// Removes the global hooks of `I`.
+pub fn clear_global_hooks<I: 'static>() {
+    let mut global = GLOBAL.write().unwrap_or_else(|e| e.into_inner());
+    if let Some(global) = global.as_mut() {
+        global.remove(&TypeId::of::<I>());
+    }
+}
+
+fn global_hooks<I: 'static>() -> Option<Arc<RuleHooks<I>>> {
+    let global = GLOBAL.read().unwrap_or_else(|e| e.into_inner());
+    let hooks = global.as_ref()?.get(&TypeId::of::<I>())?.clone();
+    hooks.downcast::<RuleHooks<I>>().ok()
+}
+
+/// A validator with hooks, see `WithHooks::with_hooks`.
+///
+/// # Fields
+/// - `rules`: The validator, e.g. `IntegerRules`.
+/// - `hooks`: The hooks run around it.
+#[derive(Clone)]
+pub struct Hooked<R, I> {
+    pub rules: R,
+    pub hooks: RuleHooks<I>,
+}
+
+impl<R, I, O, E> Validator<I, O, E> for Hooked<R, I>
+where
+    R: Validator<I, O, E>,
+    I: 'static,
+    for<'a> &'a E: Into<ValidateErrorStore>,
+{
+    fn validate(&self, input: I) -> Result<O, E> {
+        self.hooks.run(input, |input| self.rules.validate(input))
+    }
+}
+
+/// A trait attaching hooks to a validator, implemented for every type.
+pub trait WithHooks: Sized {
+    fn with_hooks<I>(self, hooks: RuleHooks<I>) -> Hooked<Self, I> {
+        Hooked { rules: self, hooks }
+    }
+}
+
+impl<T> WithHooks for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::numbers::integer::IntegerRules;
+    use std::sync::Mutex;
+
+    struct Doubled(Option<isize>);
+
+    #[test]
+    fn test_global_hooks_run_around_attached_hooks() {
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let (global_pre, global_post, pre, post) =
+            (order.clone(), order.clone(), order.clone(), order.clone());
+        set_global_hooks(
+            RuleHooks::new()
+                .pre(move |input: Doubled| {
+                    global_pre.lock().expect("Expected lock").push("global pre");
+                    Doubled(input.0.map(|n| n * 2))
+                })
+                .post(move |_| {
+                    global_post
+                        .lock()
+                        .expect("Expected lock")
+                        .push("global post")
+                }),
+        );
+        let rules = (|input: Doubled| IntegerRules::default().validate(input.0)).with_hooks(
+            RuleHooks::new()
+                .pre(move |input: Doubled| {
+                    pre.lock().expect("Expected lock").push("pre");
+                    Doubled(input.0.map(|n| n + 1))
+                })
+                .post(move |outcome| {
+                    assert_eq!(outcome.type_name, "Integer");
+                    post.lock().expect("Expected lock").push("post");
+                }),
+        );
+        let result = rules.validate(Doubled(Some(5)));
+        clear_global_hooks::<Doubled>();
+
+        assert_eq!(result.map(|n| n.as_isize()).ok(), Some(11));
+        assert_eq!(
+            *order.lock().expect("Expected lock"),
+            vec!["global pre", "pre", "post", "global post"]
+        );
+    }
+}
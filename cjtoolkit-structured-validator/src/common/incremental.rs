@@ -0,0 +1,181 @@
+//! This module contains the `IncrementalValidator` type, validating a form on every keystroke
+//! without re-running the checks whose inputs did not change, e.g. in a Dioxus or Leptos form.
+//!
+//! Each rule of the validator declares its dependency, the part of the form it checks, e.g.
+//! the username field. The dependency is hashed into a tag, and the errors of the last check
+//! are kept with the tag, so a rule is only checked again when its tag changes.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::incremental::IncrementalValidator;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use cjtoolkit_structured_validator::types::password::Password;
+//!
+//! struct Signup {
+//!     name: String,
+//!     password: String,
+//! }
+//!
+//! let mut validator = IncrementalValidator::new()
+//!     .rule("name", |form: &Signup| form.name.clone(), |name| {
+//!         Name::parse(Some(name))
+//!     })
+//!     .rule("password", |form: &Signup| form.password.clone(), |password| {
+//!         Password::parse(Some(password))
+//!     });
+//!
+//! let mut form = Signup {
+//!     name: "Al".to_string(),
+//!     password: "Pa55word!".to_string(),
+//! };
+//! assert_eq!(validator.validate(&form).fields(), vec!["name"]);
+//! assert_eq!(validator.last_checked(), vec!["name", "password"]);
+//!
+//! form.name.push_str("ice Smith");
+//! assert!(validator.validate(&form).is_valid());
+//! assert_eq!(validator.last_checked(), vec!["name"]);
+//! ```
+
+use crate::common::locale::ValidateErrorStore;
+use crate::common::validation_report::ValidationReport;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+type TaggedCheck<T> = Box<dyn Fn(&T, Option<u64>) -> Option<(u64, ValidateErrorStore)>>;
+
+struct IncrementalRule<T> {
+    field: String,
+    check: TaggedCheck<T>,
+    last: Option<(u64, ValidateErrorStore)>,
+}
+
+/// Validates the fields of a form of type `T`, only checking the rules whose dependency
+/// changed since the last validation, see the module documentation.
+pub struct IncrementalValidator<T> {
+    rules: Vec<IncrementalRule<T>>,
+    last_checked: Vec<usize>,
+}
+
+fn dependency_tag<D: Hash>(dependency: &D) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    dependency.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<T> IncrementalValidator<T> {
+    /// Creates a validator without rules.
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            last_checked: Vec::new(),
+        }
+    }
+
+    /// Adds a rule for `field`, checking the dependency returned by `depends_on` with `check`,
+    /// e.g. the parse of a value type.
+    pub fn rule<D, V, E>(
+        mut self,
+        field: &str,
+        depends_on: impl Fn(&T) -> D + 'static,
+        check: impl Fn(&D) -> Result<V, E> + 'static,
+    ) -> Self
+    where
+        D: Hash + 'static,
+        for<'a> &'a E: Into<ValidateErrorStore>,
+    {
+        self.rules.push(IncrementalRule {
+            field: field.to_string(),
+            check: Box::new(move |input, last_tag| {
+                let dependency = depends_on(input);
+                let tag = dependency_tag(&dependency);
+                if last_tag == Some(tag) {
+                    return None;
+                }
+                let errors = check(&dependency)
+                    .as_ref()
+                    .err()
+                    .map(Into::into)
+                    .unwrap_or_default();
+                Some((tag, errors))
+            }),
+            last: None,
+        });
+        self
+    }
+
+    /// Validates `input`, checking the rules whose dependency changed, or which were never
+    /// checked, and reusing the errors of the last check for the others.
+    pub fn validate(&mut self, input: &T) -> ValidationReport {
+        self.last_checked.clear();
+        let mut report = ValidationReport::new();
+        for (i, rule) in self.rules.iter_mut().enumerate() {
+            let last_tag = rule.last.as_ref().map(|(tag, _)| *tag);
+            if let Some(checked) = (rule.check)(input, last_tag) {
+                rule.last = Some(checked);
+                self.last_checked.push(i);
+            }
+            if let Some((_, errors)) = &rule.last
+                && !errors.0.is_empty()
+            {
+                report.0.push((rule.field.clone(), errors.clone()));
+            }
+        }
+        report
+    }
+
+    /// Returns the fields of the rules checked by the last validation.
+    pub fn last_checked(&self) -> Vec<&str> {
+        self.last_checked
+            .iter()
+            .map(|i| self.rules[*i].field.as_str())
+            .collect()
+    }
+
+    /// Forgets the errors of the last checks, so every rule is checked by the next validation.
+    pub fn reset(&mut self) {
+        for rule in &mut self.rules {
+            rule.last = None;
+        }
+    }
+}
+
+impl<T> Default for IncrementalValidator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::username::Username;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_unchanged_rules_are_not_checked_again() {
+        let checks = Rc::new(Cell::new(0));
+        let counted = checks.clone();
+        let mut validator = IncrementalValidator::new().rule(
+            "username",
+            |form: &(String, String)| form.0.clone(),
+            move |username| {
+                counted.set(counted.get() + 1);
+                Username::parse(Some(username))
+            },
+        );
+
+        let mut form = ("bob".to_string(), "ignored".to_string());
+        assert_eq!(validator.validate(&form).fields(), vec!["username"]);
+        form.1.push('!');
+        assert_eq!(validator.validate(&form).fields(), vec!["username"]);
+        assert!(validator.last_checked().is_empty());
+        assert_eq!(checks.get(), 1);
+
+        validator.reset();
+        let _ = validator.validate(&form);
+        form.0.push_str("by_smith");
+        assert!(validator.validate(&form).is_valid());
+        assert_eq!(checks.get(), 3);
+    }
+}
@@ -0,0 +1,65 @@
+//! This module contains a small interpolation engine, filling the `{name}` placeholders of a
+//! message template with the arguments of `LocaleData`, e.g. `Must be at least {min} characters`.
+//!
+//! The default English messages of the base rules are built from the templates of
+//! `locale_keys::default_message`, so they use the same arguments as the translations. The
+//! `MessageOverrides` and the gettext translator use it too.
+//!
+//! `{{` and `}}` are a literal brace, and a placeholder without an argument is kept as is.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::interpolate::interpolate;
+//! use cjtoolkit_structured_validator::common::locale::{LocaleData, LocaleValue};
+//!
+//! let data = LocaleData::new_with_vec(
+//!     "validate-min-length",
+//!     vec![("min".to_string(), LocaleValue::from(5usize))],
+//! );
+//! assert_eq!(
+//!     interpolate("Must be at least {min} characters", &data),
+//!     "Must be at least 5 characters"
+//! );
+//! assert_eq!(interpolate("{{min}} is {min}, not {max}", &data), "{min} is 5, not {max}");
+//! ```
+
+use crate::common::locale::{LocaleData, LocaleValue};
+
+/// Returns `template` with its placeholders filled with the arguments of `data`.
+pub fn interpolate(template: &str, data: &LocaleData) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(i) = rest.find(['{', '}']) {
+        message.push_str(&rest[..i]);
+        let brace = &rest[i..i + 1];
+        rest = &rest[i + 1..];
+        if let Some(escaped) = rest.strip_prefix(brace) {
+            message.push_str(brace);
+            rest = escaped;
+            continue;
+        }
+        let placeholder = (brace == "{")
+            .then(|| rest.find('}'))
+            .flatten()
+            .map(|end| (&rest[..end], end));
+        match placeholder.and_then(|(name, end)| Some((data.args.get(name)?, end))) {
+            Some((value, end)) => {
+                message.push_str(&format_value(value));
+                rest = &rest[end + 1..];
+            }
+            None => message.push_str(brace),
+        }
+    }
+    message.push_str(rest);
+    message
+}
+
+/// Returns `value` as it is written in a message, e.g. `5` for `LocaleValue::Uint(5)`.
+pub fn format_value(value: &LocaleValue) -> String {
+    match value {
+        LocaleValue::String(s) => s.clone(),
+        LocaleValue::Uint(n) => n.to_string(),
+        LocaleValue::Int(n) => n.to_string(),
+        LocaleValue::Float(n) => n.to_string(),
+    }
+}
@@ -0,0 +1,83 @@
+//! This module contains the `KeyNamespace` type, prefixing the locale keys of the errors, e.g.
+//! `myapp.validate-min-length`, so the keys of the crate do not collide with the keys of other
+//! crates translated with the same Fluent bundle.
+//!
+//! The namespace is applied by `ValidateErrorCollector::push`, so every rule uses it. It can be
+//! registered globally with `set_global_key_namespace`, for the duration of a call with
+//! `with_key_namespace`, which takes precedence, or for the validations of a `TenantPolicy` with
+//! `TenantPolicy::with_key_namespace`. `MessageOverrides` and the observers still use the keys
+//! without namespace.
+//!
+//! Fluent identifiers cannot contain a dot, use `KeyNamespace::with_separator("-")` to get e.g.
+//! `myapp-validate-min-length` instead.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::key_namespace::{KeyNamespace, with_key_namespace};
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! with_key_namespace(KeyNamespace::new("myapp"), || {
+//!     let error = Name::parse(Some("No")).expect_err("Expected name to be too short");
+//!     assert_eq!(error.0.0[0].1.get_locale_data().name, "myapp.validate-min-length");
+//! });
+//! ```
+
+use crate::common::scoped_registry::{LocalStack, ScopedRegistry};
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// The namespace prefixed to the locale keys, with its separator, `.` by default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyNamespace {
+    pub namespace: String,
+    pub separator: String,
+}
+
+impl KeyNamespace {
+    /// Creates the namespace `namespace`, separated from the keys by a dot.
+    pub fn new(namespace: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            separator: ".".to_string(),
+        }
+    }
+
+    /// Returns the namespace separated from the keys by `separator`.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Returns `key` in the namespace, e.g. `myapp.validate-min-length`.
+    pub fn apply(&self, key: &str) -> String {
+        format!("{}{}{}", self.namespace, self.separator, key)
+    }
+}
+
+thread_local! {
+    static LOCAL: LocalStack<KeyNamespace> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) static REGISTRY: ScopedRegistry<KeyNamespace> = ScopedRegistry::new(&LOCAL);
+
+/// Registers `namespace` for every validation, replacing the previous global namespace.
+pub fn set_global_key_namespace(namespace: KeyNamespace) {
+    REGISTRY.set_global(Arc::new(namespace));
+}
+
+/// Removes the global namespace.
+pub fn clear_global_key_namespace() {
+    REGISTRY.clear_global();
+}
+
+/// Registers `namespace` for the validations run by `f` on the current thread, then returns the
+/// result of `f`. It takes precedence over the global namespace.
+pub fn with_key_namespace<R>(namespace: KeyNamespace, f: impl FnOnce() -> R) -> R {
+    REGISTRY.with(Arc::new(namespace), f)
+}
+
+/// Returns the namespace in force, the innermost registered with `with_key_namespace` first,
+/// or `None` if there is none.
+pub(crate) fn current_namespace() -> Option<Arc<KeyNamespace>> {
+    REGISTRY.current()
+}
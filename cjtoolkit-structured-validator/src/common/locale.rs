@@ -1,7 +1,12 @@
 //! This module contains structures and traits for working with locales and localization.
 
-pub use crate::common::validation_collector::{ValidateErrorCollector, ValidateErrorStore};
-use std::collections::HashMap;
+use crate::common::locale_keys;
+pub use crate::common::rule_violation::RuleViolation;
+use crate::common::status_hint::StatusHint;
+pub use crate::common::validation_collector::{
+    AnyLocaleMessage, KeyedMessage, StaticLocaleMessage, ValidateErrorCollector, ValidateErrorStore,
+};
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 
 /// Represents various types of values associated with a locale.
@@ -15,7 +20,7 @@ use std::sync::Arc;
 /// - `Int(isize)`: Stores a signed integer value.
 /// - `Float(f64)`: Stores a floating-point number value.
 ///
-/// The `Clone` trait is implemented for `LocaleValue`, allowing instances of this enum to be duplicated.
+/// The `Clone`, `Debug` and `PartialEq` traits are implemented for `LocaleValue`.
 ///
 /// Example:
 /// ```
@@ -30,7 +35,7 @@ use std::sync::Arc;
 ///     _ => println!("Not a string"),
 /// }
 /// ```
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum LocaleValue {
     String(String),
     Uint(usize),
@@ -38,6 +43,27 @@ pub enum LocaleValue {
     Float(f64),
 }
 
+impl LocaleValue {
+    /// Returns the kind of the value, e.g. `LocaleValueKind::Uint` for `LocaleValue::Uint(42)`.
+    pub fn kind(&self) -> LocaleValueKind {
+        match self {
+            Self::String(_) => LocaleValueKind::String,
+            Self::Uint(_) => LocaleValueKind::Uint,
+            Self::Int(_) => LocaleValueKind::Int,
+            Self::Float(_) => LocaleValueKind::Float,
+        }
+    }
+}
+
+/// The kind of a `LocaleValue`, without the value, e.g. to describe the arguments of a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocaleValueKind {
+    String,
+    Uint,
+    Int,
+    Float,
+}
+
 impl From<String> for LocaleValue {
     fn from(s: String) -> Self {
         Self::String(s)
@@ -56,6 +82,21 @@ impl From<usize> for LocaleValue {
     }
 }
 
+impl From<u32> for LocaleValue {
+    fn from(s: u32) -> Self {
+        Self::from(s as u64)
+    }
+}
+
+/// Values above `usize::MAX`, on 32-bit targets, are kept as a `String` instead of truncated.
+impl From<u64> for LocaleValue {
+    fn from(s: u64) -> Self {
+        usize::try_from(s)
+            .map(Self::Uint)
+            .unwrap_or_else(|_| Self::String(s.to_string()))
+    }
+}
+
 impl From<isize> for LocaleValue {
     fn from(s: isize) -> Self {
         Self::Int(s)
@@ -143,6 +184,15 @@ impl LocaleData {
 ///
 /// - `get_locale_data`: Retrieves locale-specific information encapsulated in a `LocaleData` object.
 ///
+/// # Provided Methods
+///
+/// - `rule_violation`: Returns the typed rule violated, defaults to `RuleViolation::Custom` holding
+///   the locale key.
+/// - `status_hint`: Returns the HTTP status suggested by the error, defaults to the hint of its
+///   `rule_violation`.
+/// - `priority`: Returns the priority of the error, the most relevant errors having the highest
+///   priority, defaults to the priority of its `rule_violation`.
+///
 /// # Example
 ///
 /// ```rust
@@ -167,6 +217,18 @@ impl LocaleData {
 /// to locale information is necessary.
 pub trait LocaleMessage: Send + Sync {
     fn get_locale_data(&self) -> Arc<LocaleData>;
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Custom(self.get_locale_data().name.clone())
+    }
+
+    fn status_hint(&self) -> StatusHint {
+        self.rule_violation().status_hint()
+    }
+
+    fn priority(&self) -> u8 {
+        self.rule_violation().priority()
+    }
 }
 
 impl LocaleMessage for Arc<LocaleData> {
@@ -174,3 +236,154 @@ impl LocaleMessage for Arc<LocaleData> {
         Arc::clone(self)
     }
 }
+
+/// The result of comparing an i18n catalog against the locale keys of the crate.
+///
+/// # Fields
+/// - `missing`: The keys of the crate not in the catalog.
+/// - `extra`: The keys of the catalog not in the crate, expected for application keys.
+/// - `unknown_args`: The arguments used by a message of the catalog, but not passed by the
+///   crate, as pairs of key and argument name.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CatalogReport {
+    pub missing: Vec<&'static str>,
+    pub extra: Vec<String>,
+    pub unknown_args: Vec<(String, String)>,
+}
+
+impl CatalogReport {
+    /// Returns `true` if no key of the crate is missing and every argument is known.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.unknown_args.is_empty()
+    }
+}
+
+/// Compares the keys known by an i18n backend, each with the names of the arguments used by its
+/// message, against `locale_keys::all_keys()`.
+///
+/// Intended to be called from an integration test of the application.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::locale::catalog_check;
+/// use cjtoolkit_structured_validator::common::locale_keys;
+///
+/// let mut catalog: Vec<(&str, Vec<&str>)> = locale_keys::all_keys()
+///     .iter()
+///     .map(|key| (*key, locale_keys::key_args(key).to_vec()))
+///     .collect();
+/// catalog.retain(|(key, _)| *key != locale_keys::VALIDATE_POSTCODE);
+/// catalog.push(("validate-min-length", vec!["minimum"]));
+///
+/// let report = catalog_check(catalog);
+/// assert_eq!(report.missing, vec![locale_keys::VALIDATE_POSTCODE]);
+/// assert_eq!(
+///     report.unknown_args,
+///     vec![("validate-min-length".to_string(), "minimum".to_string())]
+/// );
+/// assert!(!report.is_complete());
+/// ```
+pub fn catalog_check<I, K, A, N>(catalog: I) -> CatalogReport
+where
+    I: IntoIterator<Item = (K, A)>,
+    K: AsRef<str>,
+    A: IntoIterator<Item = N>,
+    N: AsRef<str>,
+{
+    let mut known: BTreeSet<String> = BTreeSet::new();
+    let mut report = CatalogReport::default();
+    for (key, args) in catalog {
+        let key = key.as_ref();
+        let crate_key = locale_keys::all_keys().contains(&key);
+        for arg in args {
+            let arg = arg.as_ref();
+            if crate_key && !locale_keys::key_args(key).contains(&arg) {
+                report.unknown_args.push((key.to_string(), arg.to_string()));
+            }
+        }
+        if !crate_key {
+            report.extra.push(key.to_string());
+        }
+        known.insert(key.to_string());
+    }
+    report.missing = locale_keys::all_keys()
+        .iter()
+        .copied()
+        .filter(|key| !known.contains(*key))
+        .collect();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn full_catalog() -> Vec<(String, Vec<String>)> {
+        locale_keys::all_keys()
+            .iter()
+            .map(|key| {
+                let args = locale_keys::key_args(key).iter().map(|arg| arg.to_string());
+                (key.to_string(), args.collect())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_catalog_check_complete() {
+        let report = catalog_check(full_catalog());
+        assert_eq!(report, CatalogReport::default());
+        assert!(report.is_complete());
+    }
+
+    #[test]
+    fn test_catalog_check_missing_keys() {
+        let mut catalog = full_catalog();
+        catalog.retain(|(key, _)| {
+            key != locale_keys::VALIDATE_MIN_LENGTH && key != locale_keys::VALIDATE_EMAIL_INVALID
+        });
+        let report = catalog_check(catalog);
+        assert_eq!(
+            report.missing,
+            vec![
+                locale_keys::VALIDATE_MIN_LENGTH,
+                locale_keys::VALIDATE_EMAIL_INVALID
+            ]
+        );
+        assert!(!report.is_complete());
+    }
+
+    #[test]
+    fn test_catalog_check_extra_keys() {
+        let mut catalog = full_catalog();
+        catalog.push(("app-greeting".to_string(), vec!["name".to_string()]));
+        let report = catalog_check(catalog);
+        assert_eq!(report.extra, vec!["app-greeting"]);
+        assert!(report.unknown_args.is_empty());
+        assert!(report.is_complete());
+    }
+
+    #[test]
+    fn test_catalog_check_argument_mismatch() {
+        let catalog = vec![
+            (locale_keys::VALIDATE_MAX_LENGTH, vec!["max", "maximum"]),
+            (locale_keys::VALIDATE_CANNOT_BE_EMPTY, vec!["field"]),
+            (locale_keys::VALIDATE_MIN_LENGTH, vec![]),
+        ];
+        let report = catalog_check(catalog);
+        assert_eq!(
+            report.unknown_args,
+            vec![
+                (
+                    locale_keys::VALIDATE_MAX_LENGTH.to_string(),
+                    "maximum".to_string()
+                ),
+                (
+                    locale_keys::VALIDATE_CANNOT_BE_EMPTY.to_string(),
+                    "field".to_string()
+                ),
+            ]
+        );
+        assert!(report.extra.is_empty());
+        assert!(!report.is_complete());
+    }
+}
@@ -0,0 +1,751 @@
+//! This module contains the locale keys of every message reported by the crate.
+//!
+//! Use the constants instead of string literals when writing catalogs or matching on
+//! `LocaleData::name`, `all_keys` to check a catalog is complete, and `key_arg_types` to check
+//! the placeholders of its messages.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::locale_keys::{self, VALIDATE_MIN_LENGTH};
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! let error = Name::parse(Some("No")).expect_err("Expected name to be too short");
+//! let key = error.0.0[0].1.get_locale_data().name.clone();
+//! assert_eq!(key, VALIDATE_MIN_LENGTH);
+//! assert!(locale_keys::all_keys().contains(&key.as_str()));
+//! ```
+
+use crate::common::locale::LocaleValueKind;
+
+/// The value is mandatory but empty.
+pub const VALIDATE_CANNOT_BE_EMPTY: &str = "validate-cannot-be-empty";
+
+/// The string is too short, with argument `min`.
+pub const VALIDATE_MIN_LENGTH: &str = "validate-min-length";
+
+/// The string is too long, with argument `max`.
+pub const VALIDATE_MAX_LENGTH: &str = "validate-max-length";
+
+/// The string is too short in bytes, with argument `min`.
+pub const VALIDATE_MIN_LENGTH_BYTES: &str = "validate-min-length-bytes";
+
+/// The string is too long in bytes, with argument `max`.
+pub const VALIDATE_MAX_LENGTH_BYTES: &str = "validate-max-length-bytes";
+
+/// The string is too short in UTF-16 code units, with argument `min`.
+pub const VALIDATE_MIN_LENGTH_UTF16: &str = "validate-min-length-utf16";
+
+/// The string is too long in UTF-16 code units, with argument `max`.
+pub const VALIDATE_MAX_LENGTH_UTF16: &str = "validate-max-length-utf16";
+
+/// The string has no special characters.
+pub const VALIDATE_MUST_HAVE_SPECIAL_CHARS: &str = "validate-must-have-special-chars";
+
+/// The string has no uppercase or no lowercase letters.
+pub const VALIDATE_MUST_HAVE_UPPERCASE_AND_LOWERCASE: &str =
+    "validate-must-have-uppercase-and-lowercase";
+
+/// The string has no uppercase letters.
+pub const VALIDATE_MUST_HAVE_UPPERCASE: &str = "validate-must-have-uppercase";
+
+/// The string has no lowercase letters.
+pub const VALIDATE_MUST_HAVE_LOWERCASE: &str = "validate-must-have-lowercase";
+
+/// The string has no digits.
+pub const VALIDATE_MUST_HAVE_DIGIT: &str = "validate-must-have-digit";
+
+/// The string contains control characters.
+pub const VALIDATE_FORBID_CONTROL_CHARS: &str = "validate-forbid-control-chars";
+
+/// The string contains zero-width characters.
+pub const VALIDATE_FORBID_ZERO_WIDTH: &str = "validate-forbid-zero-width";
+
+/// The string contains bidirectional override characters.
+pub const VALIDATE_FORBID_BIDI_OVERRIDES: &str = "validate-forbid-bidi-overrides";
+
+/// The string contains too many links, with argument `max`.
+pub const VALIDATE_TOO_MANY_LINKS: &str = "validate-too-many-links";
+
+/// The string has too many uppercase letters, with argument `max_percent`.
+pub const VALIDATE_TOO_MUCH_UPPERCASE: &str = "validate-too-much-uppercase";
+
+/// The string repeats a character too many times in a row, with argument `max`.
+pub const VALIDATE_REPEATED_CHARS: &str = "validate-repeated-chars";
+
+/// The number is too small, with argument `min`.
+pub const VALIDATE_NUMBER_MIN_VALUE: &str = "validate-number-min-value";
+
+/// The number is too large, with argument `max`.
+pub const VALIDATE_NUMBER_MAX_VALUE: &str = "validate-number-max-value";
+
+/// The number is zero.
+pub const VALIDATE_NUMBER_FORBID_ZERO: &str = "validate-number-forbid-zero";
+
+/// The number is odd.
+pub const VALIDATE_NUMBER_MUST_BE_EVEN: &str = "validate-number-must-be-even";
+
+/// The number is even.
+pub const VALIDATE_NUMBER_MUST_BE_ODD: &str = "validate-number-must-be-odd";
+
+/// The number is not allowed, with argument `allowed`.
+pub const VALIDATE_NUMBER_NOT_ALLOWED: &str = "validate-number-not-allowed";
+
+/// The result of an arithmetic operation on numbers is out of the range of the type.
+pub const VALIDATE_NUMBER_OVERFLOW: &str = "validate-number-overflow";
+
+/// The star rating is out of bounds, with arguments `min` and `max`.
+pub const VALIDATE_STAR_RATING_RANGE: &str = "validate-star-rating-range";
+
+/// The Net Promoter Score is out of bounds, with arguments `min` and `max`.
+pub const VALIDATE_NPS_RANGE: &str = "validate-nps-range";
+
+/// The answer to a Likert scale question is out of bounds, with arguments `min` and `max`.
+pub const VALIDATE_LIKERT_RANGE: &str = "validate-likert-range";
+
+/// The date is too early, with argument `min`.
+pub const VALIDATE_DATE_MIN: &str = "validate-date-min";
+
+/// The date is too late, with argument `max`.
+pub const VALIDATE_DATE_MAX: &str = "validate-date-max";
+
+/// The date time is too early, with argument `min`.
+pub const VALIDATE_DATE_TIME_MIN: &str = "validate-date-time-min";
+
+/// The date time is too late, with argument `max`.
+pub const VALIDATE_DATE_TIME_MAX: &str = "validate-date-time-max";
+
+/// The naive date time is too early, with argument `min`.
+pub const VALIDATE_DATE_TIME_NAIVE_MIN: &str = "validate-date-time-naive-min";
+
+/// The naive date time is too late, with argument `max`.
+pub const VALIDATE_DATE_TIME_NAIVE_MAX: &str = "validate-date-time-naive-max";
+
+/// The time is too early, with argument `min`.
+pub const VALIDATE_TIME_MIN: &str = "validate-time-min";
+
+/// The time is too late, with argument `max`.
+pub const VALIDATE_TIME_MAX: &str = "validate-time-max";
+
+/// The date is not a business day.
+pub const VALIDATE_DATE_NOT_BUSINESS_DAY: &str = "validate-date-not-business-day";
+
+/// The date does not match any of the accepted formats, with argument `formats`.
+pub const VALIDATE_DATE_UNPARSEABLE: &str = "validate-date-unparseable";
+
+/// The date and time does not match any of the accepted formats, with argument `formats`.
+pub const VALIDATE_DATE_TIME_UNPARSEABLE: &str = "validate-date-time-unparseable";
+
+/// The naive date and time does not match any of the accepted formats, with argument `formats`.
+pub const VALIDATE_DATE_TIME_NAIVE_UNPARSEABLE: &str = "validate-date-time-naive-unparseable";
+
+/// The time does not match any of the accepted formats, with argument `formats`.
+pub const VALIDATE_TIME_UNPARSEABLE: &str = "validate-time-unparseable";
+
+/// The collection has too few items, with argument `min`.
+pub const VALIDATE_MIN_ITEMS: &str = "validate-min-items";
+
+/// The collection has too many items, with argument `max`.
+pub const VALIDATE_MAX_ITEMS: &str = "validate-max-items";
+
+/// The value has the wrong type, with argument `expected`.
+pub const VALIDATE_INVALID_TYPE: &str = "validate-invalid-type";
+
+/// The consent checkbox must be checked, e.g. to accept the terms of service.
+pub const VALIDATE_CONSENT_REQUIRED: &str = "validate-consent-required";
+
+/// The email address is not valid.
+pub const VALIDATE_EMAIL_INVALID: &str = "validate-email-invalid";
+
+/// The email address does not match its confirmation.
+pub const VALIDATE_EMAIL_DOES_NOT_MATCH: &str = "validate-email-does-not-match";
+
+/// The email address uses plus addressing, e.g. `user+tag@example.com`.
+pub const VALIDATE_EMAIL_PLUS_ADDRESSING: &str = "validate-email-plus-addressing";
+
+/// The domain of the email address has no top-level domain.
+pub const VALIDATE_EMAIL_MISSING_TLD: &str = "validate-email-missing-tld";
+
+/// The email address contains non-ASCII characters, forbidden by the rules.
+pub const VALIDATE_EMAIL_UNICODE: &str = "validate-email-unicode";
+
+/// The email address is listed more than once.
+pub const VALIDATE_EMAIL_DUPLICATE: &str = "validate-email-duplicate";
+
+/// The URL is not valid.
+pub const VALIDATE_INVALID_URL: &str = "validate-invalid-url";
+
+/// The URL does not use the `https` scheme.
+pub const VALIDATE_URL_NOT_HTTPS: &str = "validate-url-not-https";
+
+/// The URL contains a username or a password.
+pub const VALIDATE_URL_CREDENTIALS: &str = "validate-url-credentials";
+
+/// The host of the URL is `localhost`, or a loopback, private or link-local IP address.
+pub const VALIDATE_URL_PRIVATE_HOST: &str = "validate-url-private-host";
+
+/// The port of the URL is not allowed, with argument `allowed`.
+pub const VALIDATE_URL_PORT_NOT_ALLOWED: &str = "validate-url-port-not-allowed";
+
+/// The username is already taken.
+pub const VALIDATE_USERNAME_TAKEN: &str = "validate-username-taken";
+
+/// The username is confusable with an existing username, with argument `username`.
+pub const VALIDATE_USERNAME_CONFUSABLE: &str = "validate-username-confusable";
+
+/// The value already exists, with arguments `entity`, e.g. `user`, and `field`, e.g. `email`.
+pub const VALIDATE_ALREADY_EXISTS: &str = "validate-already-exists";
+
+/// A check was skipped because the check budget was exhausted, returned as a warning.
+pub const VALIDATE_CHECK_SKIPPED: &str = "validate-check-skipped";
+
+/// Tags or attributes were stripped from the HTML by the sanitization, returned as a warning,
+/// with argument `stripped`, e.g. `<script>, onclick`.
+pub const VALIDATE_HTML_STRIPPED: &str = "validate-html-stripped";
+
+/// The sanitized HTML has more tags than allowed, with argument `max`.
+pub const VALIDATE_HTML_MAX_TAGS: &str = "validate-html-max-tags";
+
+/// The SMS body is split in too many segments, with arguments `max` and `segments`.
+pub const VALIDATE_SMS_MAX_SEGMENTS: &str = "validate-sms-max-segments";
+
+/// The template has an unbalanced or empty placeholder.
+pub const VALIDATE_TEMPLATE_UNBALANCED: &str = "validate-template-unbalanced";
+
+/// The template uses a placeholder which is not allowed, with arguments `placeholder` and
+/// `allowed`.
+pub const VALIDATE_TEMPLATE_UNKNOWN_PLACEHOLDER: &str = "validate-template-unknown-placeholder";
+
+/// The file name contains a path separator.
+pub const VALIDATE_FILENAME_PATH_SEPARATOR: &str = "validate-filename-path-separator";
+
+/// The file name is reserved, e.g. `CON` on Windows, with argument `name`.
+pub const VALIDATE_FILENAME_RESERVED: &str = "validate-filename-reserved";
+
+/// The extension of the file name is not allowed, with argument `allowed`.
+pub const VALIDATE_FILENAME_EXTENSION_NOT_ALLOWED: &str = "validate-filename-extension-not-allowed";
+
+/// The file name contains a character which Windows does not allow, `<>:"|?*`.
+pub const VALIDATE_FILENAME_INVALID_CHAR: &str = "validate-filename-invalid-char";
+
+/// The file name ends with a dot or a space.
+pub const VALIDATE_FILENAME_TRAILING_DOT_OR_SPACE: &str = "validate-filename-trailing-dot-or-space";
+
+/// The path is absolute instead of relative.
+pub const VALIDATE_PATH_ABSOLUTE: &str = "validate-path-absolute";
+
+/// The path has a `..` component.
+pub const VALIDATE_PATH_TRAVERSAL: &str = "validate-path-traversal";
+
+/// The MIME type is not `type/subtype`.
+pub const VALIDATE_MIME_TYPE_INVALID: &str = "validate-mime-type-invalid";
+
+/// The MIME type is not allowed, with argument `allowed`.
+pub const VALIDATE_MIME_TYPE_NOT_ALLOWED: &str = "validate-mime-type-not-allowed";
+
+/// An entry of the `Accept-Language` header is not a language range with an optional quality.
+pub const VALIDATE_ACCEPT_LANGUAGE_INVALID: &str = "validate-accept-language-invalid";
+
+/// The `Accept-Language` header has too many entries, with argument `max`.
+pub const VALIDATE_ACCEPT_LANGUAGE_MAX_ENTRIES: &str = "validate-accept-language-max-entries";
+
+/// The password does not match its confirmation.
+pub const VALIDATE_PASSWORD_DOES_NOT_MATCH: &str = "validate-password-does-not-match";
+
+/// The postcode is not valid.
+pub const VALIDATE_POSTCODE: &str = "validate-postcode";
+
+/// The national ID has the wrong format, with argument `scheme`.
+pub const VALIDATE_NATIONAL_ID_INVALID_FORMAT: &str = "validate-national-id-invalid-format";
+
+/// The national ID has invalid check digits, with argument `scheme`.
+pub const VALIDATE_NATIONAL_ID_INVALID_CHECKSUM: &str = "validate-national-id-invalid-checksum";
+
+/// The handle is not valid.
+pub const VALIDATE_HANDLE_INVALID: &str = "validate-handle-invalid";
+
+/// The Twitter handle is not valid.
+pub const VALIDATE_HANDLE_TWITTER_INVALID: &str = "validate-handle-twitter-invalid";
+
+/// The Instagram handle is not valid.
+pub const VALIDATE_HANDLE_INSTAGRAM_INVALID: &str = "validate-handle-instagram-invalid";
+
+/// The GitHub handle is not valid.
+pub const VALIDATE_HANDLE_GITHUB_INVALID: &str = "validate-handle-github-invalid";
+
+/// The sort field is not allowed, with argument `field`.
+pub const VALIDATE_SORT_FIELD_NOT_ALLOWED: &str = "validate-sort-field-not-allowed";
+
+/// The sort expression has an empty field.
+pub const VALIDATE_SORT_EMPTY_FIELD: &str = "validate-sort-empty-field";
+
+/// The sort expression has a field more than once, with argument `field`.
+pub const VALIDATE_SORT_DUPLICATE_FIELD: &str = "validate-sort-duplicate-field";
+
+/// The field is not one of the fields of the form, with argument `field`.
+pub const VALIDATE_UNKNOWN_FIELD: &str = "validate-unknown-field";
+
+/// The MAC address is not valid.
+pub const VALIDATE_MAC_ADDRESS_INVALID: &str = "validate-mac-address-invalid";
+
+/// The hostname is too long, with argument `max`.
+pub const VALIDATE_HOSTNAME_TOO_LONG: &str = "validate-hostname-too-long";
+
+/// A label of the hostname is not valid, with argument `label`.
+pub const VALIDATE_HOSTNAME_INVALID_LABEL: &str = "validate-hostname-invalid-label";
+
+/// The hostname is not fully qualified.
+pub const VALIDATE_HOSTNAME_NOT_FQDN: &str = "validate-hostname-not-fqdn";
+
+/// The domain is not valid.
+pub const VALIDATE_DOMAIN_INVALID: &str = "validate-domain-invalid";
+
+/// The domain is not registrable.
+pub const VALIDATE_DOMAIN_NOT_REGISTRABLE: &str = "validate-domain-not-registrable";
+
+/// A label of the host mixes characters of different scripts, e.g. Latin and Cyrillic.
+pub const VALIDATE_HOST_MIXED_SCRIPT: &str = "validate-host-mixed-script";
+
+/// The rule cannot be verified because a crate feature is disabled, with argument `feature`.
+pub const VALIDATE_RULE_UNVERIFIABLE: &str = "validate-rule-unverifiable";
+
+/// The cron expression does not have the expected number of fields, with argument `max`.
+pub const VALIDATE_CRON_FIELD_COUNT: &str = "validate-cron-field-count";
+
+/// A field of the cron expression is not valid, with argument `field`.
+pub const VALIDATE_CRON_INVALID_FIELD: &str = "validate-cron-invalid-field";
+
+/// The cron expression never runs.
+pub const VALIDATE_CRON_NEVER_RUNS: &str = "validate-cron-never-runs";
+
+/// The cron expression runs too often, with argument `min` in seconds.
+pub const VALIDATE_CRON_MIN_INTERVAL: &str = "validate-cron-min-interval";
+
+/// The date falls on a day of the week which is not allowed, with argument `allowed`.
+pub const VALIDATE_DATE_WEEKDAY_NOT_ALLOWED: &str = "validate-date-weekday-not-allowed";
+
+/// The date falls in a month which is not allowed, with argument `allowed`.
+pub const VALIDATE_DATE_MONTH_NOT_ALLOWED: &str = "validate-date-month-not-allowed";
+
+/// The time does not fall on a slot, with argument `granularity`.
+pub const VALIDATE_TIME_GRANULARITY: &str = "validate-time-granularity";
+
+/// The string is not a human-readable duration.
+pub const VALIDATE_DURATION_UNPARSEABLE: &str = "validate-duration-unparseable";
+
+/// The duration is too short, with argument `min`.
+pub const VALIDATE_DURATION_MIN: &str = "validate-duration-min";
+
+/// The duration is too long, with argument `max`.
+pub const VALIDATE_DURATION_MAX: &str = "validate-duration-max";
+
+/// The string is not a period in the expected format, with argument `format`.
+pub const VALIDATE_PERIOD_UNPARSEABLE: &str = "validate-period-unparseable";
+
+/// The period is too early, with argument `min`.
+pub const VALIDATE_PERIOD_MIN: &str = "validate-period-min";
+
+/// The period is too late, with argument `max`.
+pub const VALIDATE_PERIOD_MAX: &str = "validate-period-max";
+
+/// The naive date-time does not map to exactly one date-time in the time zone, with argument
+/// `reason`, either `nonexistent` or `ambiguous`.
+pub const VALIDATE_DATETIME_INVALID_LOCAL_TIME: &str = "validate-datetime-invalid-local-time";
+
+/// The person is too young, with arguments `min` and `age`.
+pub const VALIDATE_AGE_MIN: &str = "validate-age-min";
+
+/// The person is too old, with arguments `max` and `age`.
+pub const VALIDATE_AGE_MAX: &str = "validate-age-max";
+
+/// Returns every locale key of the crate, regardless of the enabled features.
+pub fn all_keys() -> &'static [&'static str] {
+    &[
+        VALIDATE_CANNOT_BE_EMPTY,
+        VALIDATE_MIN_LENGTH,
+        VALIDATE_MAX_LENGTH,
+        VALIDATE_MIN_LENGTH_BYTES,
+        VALIDATE_MAX_LENGTH_BYTES,
+        VALIDATE_MIN_LENGTH_UTF16,
+        VALIDATE_MAX_LENGTH_UTF16,
+        VALIDATE_MUST_HAVE_SPECIAL_CHARS,
+        VALIDATE_MUST_HAVE_UPPERCASE_AND_LOWERCASE,
+        VALIDATE_MUST_HAVE_UPPERCASE,
+        VALIDATE_MUST_HAVE_LOWERCASE,
+        VALIDATE_MUST_HAVE_DIGIT,
+        VALIDATE_FORBID_CONTROL_CHARS,
+        VALIDATE_FORBID_ZERO_WIDTH,
+        VALIDATE_FORBID_BIDI_OVERRIDES,
+        VALIDATE_TOO_MANY_LINKS,
+        VALIDATE_TOO_MUCH_UPPERCASE,
+        VALIDATE_REPEATED_CHARS,
+        VALIDATE_NUMBER_MIN_VALUE,
+        VALIDATE_NUMBER_MAX_VALUE,
+        VALIDATE_NUMBER_FORBID_ZERO,
+        VALIDATE_NUMBER_MUST_BE_EVEN,
+        VALIDATE_NUMBER_MUST_BE_ODD,
+        VALIDATE_NUMBER_NOT_ALLOWED,
+        VALIDATE_NUMBER_OVERFLOW,
+        VALIDATE_STAR_RATING_RANGE,
+        VALIDATE_NPS_RANGE,
+        VALIDATE_LIKERT_RANGE,
+        VALIDATE_DATE_MIN,
+        VALIDATE_DATE_MAX,
+        VALIDATE_DATE_TIME_MIN,
+        VALIDATE_DATE_TIME_MAX,
+        VALIDATE_DATE_TIME_NAIVE_MIN,
+        VALIDATE_DATE_TIME_NAIVE_MAX,
+        VALIDATE_TIME_MIN,
+        VALIDATE_TIME_MAX,
+        VALIDATE_DATE_NOT_BUSINESS_DAY,
+        VALIDATE_DATE_UNPARSEABLE,
+        VALIDATE_DATE_TIME_UNPARSEABLE,
+        VALIDATE_DATE_TIME_NAIVE_UNPARSEABLE,
+        VALIDATE_TIME_UNPARSEABLE,
+        VALIDATE_MIN_ITEMS,
+        VALIDATE_MAX_ITEMS,
+        VALIDATE_INVALID_TYPE,
+        VALIDATE_CONSENT_REQUIRED,
+        VALIDATE_EMAIL_INVALID,
+        VALIDATE_EMAIL_DOES_NOT_MATCH,
+        VALIDATE_EMAIL_PLUS_ADDRESSING,
+        VALIDATE_EMAIL_MISSING_TLD,
+        VALIDATE_EMAIL_UNICODE,
+        VALIDATE_EMAIL_DUPLICATE,
+        VALIDATE_INVALID_URL,
+        VALIDATE_URL_NOT_HTTPS,
+        VALIDATE_URL_CREDENTIALS,
+        VALIDATE_URL_PRIVATE_HOST,
+        VALIDATE_URL_PORT_NOT_ALLOWED,
+        VALIDATE_USERNAME_TAKEN,
+        VALIDATE_USERNAME_CONFUSABLE,
+        VALIDATE_ALREADY_EXISTS,
+        VALIDATE_CHECK_SKIPPED,
+        VALIDATE_HTML_STRIPPED,
+        VALIDATE_HTML_MAX_TAGS,
+        VALIDATE_SMS_MAX_SEGMENTS,
+        VALIDATE_TEMPLATE_UNBALANCED,
+        VALIDATE_TEMPLATE_UNKNOWN_PLACEHOLDER,
+        VALIDATE_FILENAME_PATH_SEPARATOR,
+        VALIDATE_FILENAME_RESERVED,
+        VALIDATE_FILENAME_EXTENSION_NOT_ALLOWED,
+        VALIDATE_FILENAME_INVALID_CHAR,
+        VALIDATE_FILENAME_TRAILING_DOT_OR_SPACE,
+        VALIDATE_PATH_ABSOLUTE,
+        VALIDATE_PATH_TRAVERSAL,
+        VALIDATE_MIME_TYPE_INVALID,
+        VALIDATE_MIME_TYPE_NOT_ALLOWED,
+        VALIDATE_ACCEPT_LANGUAGE_INVALID,
+        VALIDATE_ACCEPT_LANGUAGE_MAX_ENTRIES,
+        VALIDATE_PASSWORD_DOES_NOT_MATCH,
+        VALIDATE_POSTCODE,
+        VALIDATE_NATIONAL_ID_INVALID_FORMAT,
+        VALIDATE_NATIONAL_ID_INVALID_CHECKSUM,
+        VALIDATE_HANDLE_INVALID,
+        VALIDATE_HANDLE_TWITTER_INVALID,
+        VALIDATE_HANDLE_INSTAGRAM_INVALID,
+        VALIDATE_HANDLE_GITHUB_INVALID,
+        VALIDATE_SORT_FIELD_NOT_ALLOWED,
+        VALIDATE_SORT_EMPTY_FIELD,
+        VALIDATE_SORT_DUPLICATE_FIELD,
+        VALIDATE_UNKNOWN_FIELD,
+        VALIDATE_MAC_ADDRESS_INVALID,
+        VALIDATE_HOSTNAME_TOO_LONG,
+        VALIDATE_HOSTNAME_INVALID_LABEL,
+        VALIDATE_HOSTNAME_NOT_FQDN,
+        VALIDATE_DOMAIN_INVALID,
+        VALIDATE_DOMAIN_NOT_REGISTRABLE,
+        VALIDATE_HOST_MIXED_SCRIPT,
+        VALIDATE_RULE_UNVERIFIABLE,
+        VALIDATE_CRON_FIELD_COUNT,
+        VALIDATE_CRON_INVALID_FIELD,
+        VALIDATE_CRON_NEVER_RUNS,
+        VALIDATE_CRON_MIN_INTERVAL,
+        VALIDATE_AGE_MIN,
+        VALIDATE_AGE_MAX,
+        VALIDATE_DATE_WEEKDAY_NOT_ALLOWED,
+        VALIDATE_DATE_MONTH_NOT_ALLOWED,
+        VALIDATE_TIME_GRANULARITY,
+        VALIDATE_DURATION_UNPARSEABLE,
+        VALIDATE_DURATION_MIN,
+        VALIDATE_DURATION_MAX,
+        VALIDATE_PERIOD_UNPARSEABLE,
+        VALIDATE_PERIOD_MIN,
+        VALIDATE_PERIOD_MAX,
+        VALIDATE_DATETIME_INVALID_LOCAL_TIME,
+    ]
+}
+
+/// Returns the names of the arguments passed with the messages of `key`, empty for keys without
+/// arguments or unknown keys.
+pub fn key_args(key: &str) -> &'static [&'static str] {
+    match key {
+        VALIDATE_MIN_LENGTH
+        | VALIDATE_MIN_LENGTH_BYTES
+        | VALIDATE_MIN_LENGTH_UTF16
+        | VALIDATE_NUMBER_MIN_VALUE
+        | VALIDATE_DATE_MIN
+        | VALIDATE_DATE_TIME_MIN
+        | VALIDATE_DATE_TIME_NAIVE_MIN
+        | VALIDATE_TIME_MIN
+        | VALIDATE_MIN_ITEMS
+        | VALIDATE_CRON_MIN_INTERVAL => &["min"],
+        VALIDATE_MAX_LENGTH
+        | VALIDATE_MAX_LENGTH_BYTES
+        | VALIDATE_MAX_LENGTH_UTF16
+        | VALIDATE_NUMBER_MAX_VALUE
+        | VALIDATE_DATE_MAX
+        | VALIDATE_DATE_TIME_MAX
+        | VALIDATE_DATE_TIME_NAIVE_MAX
+        | VALIDATE_TIME_MAX
+        | VALIDATE_MAX_ITEMS
+        | VALIDATE_HOSTNAME_TOO_LONG
+        | VALIDATE_CRON_FIELD_COUNT
+        | VALIDATE_TOO_MANY_LINKS
+        | VALIDATE_REPEATED_CHARS
+        | VALIDATE_ACCEPT_LANGUAGE_MAX_ENTRIES => &["max"],
+        VALIDATE_TOO_MUCH_UPPERCASE => &["max_percent"],
+        VALIDATE_NUMBER_NOT_ALLOWED
+        | VALIDATE_DATE_WEEKDAY_NOT_ALLOWED
+        | VALIDATE_DATE_MONTH_NOT_ALLOWED
+        | VALIDATE_URL_PORT_NOT_ALLOWED
+        | VALIDATE_FILENAME_EXTENSION_NOT_ALLOWED
+        | VALIDATE_MIME_TYPE_NOT_ALLOWED => &["allowed"],
+        VALIDATE_TIME_GRANULARITY => &["granularity"],
+        VALIDATE_DURATION_MIN | VALIDATE_PERIOD_MIN => &["min"],
+        VALIDATE_DURATION_MAX | VALIDATE_PERIOD_MAX => &["max"],
+        VALIDATE_PERIOD_UNPARSEABLE => &["format"],
+        VALIDATE_DATETIME_INVALID_LOCAL_TIME => &["reason"],
+        VALIDATE_STAR_RATING_RANGE | VALIDATE_NPS_RANGE | VALIDATE_LIKERT_RANGE => &["min", "max"],
+        VALIDATE_AGE_MIN => &["min", "age"],
+        VALIDATE_AGE_MAX => &["max", "age"],
+        VALIDATE_DATE_UNPARSEABLE
+        | VALIDATE_DATE_TIME_UNPARSEABLE
+        | VALIDATE_DATE_TIME_NAIVE_UNPARSEABLE
+        | VALIDATE_TIME_UNPARSEABLE => &["formats"],
+        VALIDATE_INVALID_TYPE => &["expected"],
+        VALIDATE_NATIONAL_ID_INVALID_FORMAT | VALIDATE_NATIONAL_ID_INVALID_CHECKSUM => &["scheme"],
+        VALIDATE_SORT_FIELD_NOT_ALLOWED
+        | VALIDATE_SORT_DUPLICATE_FIELD
+        | VALIDATE_UNKNOWN_FIELD
+        | VALIDATE_CRON_INVALID_FIELD => &["field"],
+        VALIDATE_HOSTNAME_INVALID_LABEL => &["label"],
+        VALIDATE_RULE_UNVERIFIABLE => &["feature"],
+        VALIDATE_USERNAME_CONFUSABLE => &["username"],
+        VALIDATE_ALREADY_EXISTS => &["entity", "field"],
+        VALIDATE_HTML_STRIPPED => &["stripped"],
+        VALIDATE_HTML_MAX_TAGS => &["max"],
+        VALIDATE_SMS_MAX_SEGMENTS => &["max", "segments"],
+        VALIDATE_TEMPLATE_UNKNOWN_PLACEHOLDER => &["placeholder", "allowed"],
+        VALIDATE_FILENAME_RESERVED => &["name"],
+        _ => &[],
+    }
+}
+
+/// An argument passed with a locale key, with the kinds of value it may hold.
+///
+/// # Fields
+/// - `name`: The name of the argument, e.g. `min`.
+/// - `kinds`: The kinds of `LocaleValue` passed, more than one for the keys shared by several
+///   types, e.g. `validate-number-min-value`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyArg {
+    pub name: &'static str,
+    pub kinds: &'static [LocaleValueKind],
+}
+
+macro_rules! arg {
+    ($name:literal, $kinds:ident) => {
+        KeyArg {
+            name: $name,
+            kinds: $kinds,
+        }
+    };
+}
+
+const STRING: &[LocaleValueKind] = &[LocaleValueKind::String];
+const UINT: &[LocaleValueKind] = &[LocaleValueKind::Uint];
+const INT: &[LocaleValueKind] = &[LocaleValueKind::Int];
+/// `U64` values above `usize::MAX`, on 32-bit targets, are passed as a `String`.
+const NUMBER: &[LocaleValueKind] = &[
+    LocaleValueKind::Int,
+    LocaleValueKind::Uint,
+    LocaleValueKind::Float,
+    LocaleValueKind::String,
+];
+
+/// Returns the arguments passed with `key`, with their kinds, in the order of `key_args`, e.g.
+/// `min: Uint` for `validate-min-length`.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::locale::LocaleValueKind;
+/// use cjtoolkit_structured_validator::common::locale_keys::{self, KeyArg};
+///
+/// assert_eq!(
+///     locale_keys::key_arg_types(locale_keys::VALIDATE_MIN_LENGTH),
+///     &[KeyArg { name: "min", kinds: &[LocaleValueKind::Uint] }]
+/// );
+/// ```
+pub fn key_arg_types(key: &str) -> &'static [KeyArg] {
+    match key {
+        VALIDATE_MIN_LENGTH
+        | VALIDATE_MIN_LENGTH_BYTES
+        | VALIDATE_MIN_LENGTH_UTF16
+        | VALIDATE_MIN_ITEMS
+        | VALIDATE_CRON_MIN_INTERVAL => &[arg!("min", UINT)],
+        VALIDATE_MAX_LENGTH
+        | VALIDATE_MAX_LENGTH_BYTES
+        | VALIDATE_MAX_LENGTH_UTF16
+        | VALIDATE_MAX_ITEMS
+        | VALIDATE_HOSTNAME_TOO_LONG
+        | VALIDATE_CRON_FIELD_COUNT
+        | VALIDATE_TOO_MANY_LINKS
+        | VALIDATE_REPEATED_CHARS
+        | VALIDATE_ACCEPT_LANGUAGE_MAX_ENTRIES => &[arg!("max", UINT)],
+        VALIDATE_TOO_MUCH_UPPERCASE => &[arg!("max_percent", UINT)],
+        VALIDATE_NUMBER_MIN_VALUE => &[arg!("min", NUMBER)],
+        VALIDATE_NUMBER_MAX_VALUE => &[arg!("max", NUMBER)],
+        VALIDATE_DATE_MIN
+        | VALIDATE_DATE_TIME_MIN
+        | VALIDATE_DATE_TIME_NAIVE_MIN
+        | VALIDATE_TIME_MIN
+        | VALIDATE_DURATION_MIN
+        | VALIDATE_PERIOD_MIN => &[arg!("min", STRING)],
+        VALIDATE_DATE_MAX
+        | VALIDATE_DATE_TIME_MAX
+        | VALIDATE_DATE_TIME_NAIVE_MAX
+        | VALIDATE_TIME_MAX
+        | VALIDATE_DURATION_MAX
+        | VALIDATE_PERIOD_MAX => &[arg!("max", STRING)],
+        VALIDATE_NUMBER_NOT_ALLOWED
+        | VALIDATE_DATE_WEEKDAY_NOT_ALLOWED
+        | VALIDATE_DATE_MONTH_NOT_ALLOWED
+        | VALIDATE_URL_PORT_NOT_ALLOWED
+        | VALIDATE_FILENAME_EXTENSION_NOT_ALLOWED
+        | VALIDATE_MIME_TYPE_NOT_ALLOWED => &[arg!("allowed", STRING)],
+        VALIDATE_TIME_GRANULARITY => &[arg!("granularity", UINT)],
+        VALIDATE_PERIOD_UNPARSEABLE => &[arg!("format", STRING)],
+        VALIDATE_DATETIME_INVALID_LOCAL_TIME => &[arg!("reason", STRING)],
+        VALIDATE_STAR_RATING_RANGE | VALIDATE_NPS_RANGE | VALIDATE_LIKERT_RANGE => {
+            &[arg!("min", INT), arg!("max", INT)]
+        }
+        VALIDATE_AGE_MIN => &[arg!("min", UINT), arg!("age", INT)],
+        VALIDATE_AGE_MAX => &[arg!("max", UINT), arg!("age", INT)],
+        VALIDATE_DATE_UNPARSEABLE
+        | VALIDATE_DATE_TIME_UNPARSEABLE
+        | VALIDATE_DATE_TIME_NAIVE_UNPARSEABLE
+        | VALIDATE_TIME_UNPARSEABLE => &[arg!("formats", STRING)],
+        VALIDATE_INVALID_TYPE => &[arg!("expected", STRING)],
+        VALIDATE_NATIONAL_ID_INVALID_FORMAT | VALIDATE_NATIONAL_ID_INVALID_CHECKSUM => {
+            &[arg!("scheme", STRING)]
+        }
+        VALIDATE_SORT_FIELD_NOT_ALLOWED
+        | VALIDATE_SORT_DUPLICATE_FIELD
+        | VALIDATE_UNKNOWN_FIELD
+        | VALIDATE_CRON_INVALID_FIELD => &[arg!("field", STRING)],
+        VALIDATE_HOSTNAME_INVALID_LABEL => &[arg!("label", STRING)],
+        VALIDATE_RULE_UNVERIFIABLE => &[arg!("feature", STRING)],
+        VALIDATE_USERNAME_CONFUSABLE => &[arg!("username", STRING)],
+        VALIDATE_ALREADY_EXISTS => &[arg!("entity", STRING), arg!("field", STRING)],
+        VALIDATE_HTML_STRIPPED => &[arg!("stripped", STRING)],
+        VALIDATE_HTML_MAX_TAGS => &[arg!("max", UINT)],
+        VALIDATE_SMS_MAX_SEGMENTS => &[arg!("max", UINT), arg!("segments", UINT)],
+        VALIDATE_TEMPLATE_UNKNOWN_PLACEHOLDER => {
+            &[arg!("placeholder", STRING), arg!("allowed", STRING)]
+        }
+        VALIDATE_FILENAME_RESERVED => &[arg!("name", STRING)],
+        _ => &[],
+    }
+}
+
+/// Returns the template of the default English message of `key`, with its arguments as `{name}`
+/// placeholders, see `interpolate`, or `None` if the message of the key is not built from a
+/// template.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::locale_keys;
+///
+/// assert_eq!(
+///     locale_keys::default_message(locale_keys::VALIDATE_MIN_LENGTH),
+///     Some("Must be at least {min} characters")
+/// );
+/// ```
+pub fn default_message(key: &str) -> Option<&'static str> {
+    match key {
+        VALIDATE_MIN_LENGTH => Some("Must be at least {min} characters"),
+        VALIDATE_MAX_LENGTH => Some("Must be at most {max} characters"),
+        VALIDATE_MIN_LENGTH_BYTES => Some("Must be at least {min} bytes"),
+        VALIDATE_MAX_LENGTH_BYTES => Some("Must be at most {max} bytes"),
+        VALIDATE_MIN_LENGTH_UTF16 => Some("Must be at least {min} UTF-16 code units"),
+        VALIDATE_MAX_LENGTH_UTF16 => Some("Must be at most {max} UTF-16 code units"),
+        VALIDATE_MIN_ITEMS => Some("Must have at least {min} items"),
+        VALIDATE_MAX_ITEMS => Some("Must have at most {max} items"),
+        VALIDATE_NUMBER_MIN_VALUE => Some("Must be at least {min}"),
+        VALIDATE_NUMBER_MAX_VALUE => Some("Must be at most {max}"),
+        VALIDATE_TOO_MANY_LINKS => Some("Must not contain more than {max} links"),
+        VALIDATE_TOO_MUCH_UPPERCASE => Some("Must not be more than {max_percent}% uppercase"),
+        VALIDATE_REPEATED_CHARS => Some("Must not repeat a character more than {max} times"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::string_rules::StringLengthLocale;
+    use crate::common::locale::LocaleMessage;
+    use crate::types::numbers::float::{Float, FloatRules};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_all_keys_are_unique() {
+        let keys: HashSet<&str> = all_keys().iter().copied().collect();
+        assert_eq!(keys.len(), all_keys().len());
+    }
+
+    #[test]
+    fn test_default_messages_use_key_args() {
+        for key in all_keys() {
+            let Some(template) = default_message(key) else {
+                continue;
+            };
+            for name in key_args(key) {
+                assert!(template.contains(&format!("{{{}}}", name)), "{}", key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_key_arg_types_match_key_args() {
+        for key in all_keys() {
+            let names: Vec<&str> = key_arg_types(key).iter().map(|a| a.name).collect();
+            assert_eq!(names, key_args(key), "{}", key);
+        }
+    }
+
+    #[test]
+    fn test_key_arg_types_match_emitted_values() {
+        let mut emitted = vec![StringLengthLocale::MinLength(3).get_locale_data()];
+        let error = Float::parse_custom(
+            Some(0.5),
+            FloatRules {
+                min: Some(1.0),
+                ..FloatRules::default()
+            },
+        )
+        .expect_err("Expected float to be too small");
+        emitted.push(error.0.0[0].1.get_locale_data());
+
+        for data in emitted {
+            let types = key_arg_types(&data.name);
+            for (name, value) in &data.args {
+                let arg = types
+                    .iter()
+                    .find(|a| a.name == name)
+                    .expect("Expected argument to be described");
+                assert!(arg.kinds.contains(&value.kind()), "{}", data.name);
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! This module contains `parse_localized`, translating the messages of a validation when it
+//! fails, for applications which know the locale of the user when validating, e.g. from the
+//! `Accept-Language` header of the request, and want the errors ready to display.
+//!
+//! The translator is called with the locale data of each error, its key and arguments, and its
+//! original English message, to fall back on when the key has no translation. The errors keep
+//! their locale data, so they can still be translated again later.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::locale::LocaleData;
+//! use cjtoolkit_structured_validator::common::locale_keys;
+//! use cjtoolkit_structured_validator::common::localized::parse_localized;
+//! use cjtoolkit_structured_validator::types::name::NameRules;
+//!
+//! let french = |data: &LocaleData, original: &str| match data.name.as_str() {
+//!     locale_keys::VALIDATE_CANNOT_BE_EMPTY => "Ne peut pas être vide".to_string(),
+//!     _ => original.to_string(),
+//! };
+//!
+//! let error = parse_localized(Some(""), NameRules::default(), &french)
+//!     .expect_err("Expected name to be empty");
+//! assert_eq!(error.0.as_original_message_vec()[0], "Ne peut pas être vide");
+//! assert!(parse_localized(Some("Alice Smith"), NameRules::default(), &french).is_ok());
+//! ```
+
+use crate::common::locale::ValidateErrorStore;
+use crate::common::translator::Translator;
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+
+/// Validates `s` with `rules`, e.g. `NameRules`, and translates the messages of the error with
+/// `translator`, e.g. a closure, see the module documentation.
+///
+/// # Errors
+/// Returns the error of the rules, with the translated messages.
+pub fn parse_localized<I, O, E, R, T>(s: I, rules: R, translator: &T) -> Result<O, E>
+where
+    R: Validator<I, O, E>,
+    E: ValidationCheck,
+    for<'a> &'a E: Into<ValidateErrorStore>,
+    T: Translator + ?Sized,
+{
+    rules.validate(s).map_err(|error| {
+        E::validate_new(
+            (&error)
+                .into()
+                .translated(|data, original| translator.translate(data, original)),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::locale::{LocaleData, LocaleValue};
+    use crate::common::locale_keys;
+    use crate::types::numbers::integer::IntegerRules;
+
+    #[test]
+    fn test_translator_receives_args_and_original() {
+        let rules = IntegerRules {
+            min: Some(10),
+            ..IntegerRules::default()
+        };
+        let translator = |data: &LocaleData, original: &str| match data.args.get("min") {
+            Some(LocaleValue::Int(min)) => format!("{} ({} >= {})", data.name, original, min),
+            _ => original.to_string(),
+        };
+        let error = parse_localized(Some(5), rules, &translator)
+            .expect_err("Expected integer to be too small");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec![format!(
+                "{} (Must be at least 10 >= 10)",
+                locale_keys::VALIDATE_NUMBER_MIN_VALUE
+            )]
+        );
+        assert_eq!(
+            error.0.0[0].1.get_locale_data().name,
+            locale_keys::VALIDATE_NUMBER_MIN_VALUE
+        );
+    }
+}
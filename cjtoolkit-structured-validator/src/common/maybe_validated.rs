@@ -0,0 +1,219 @@
+//! This module contains the `MaybeValidated` type, making the result of parsing an optional field
+//! explicit about whether a value was provided.
+//!
+//! The parsed types flag a value that was not provided, e.g. `Float(0.0, true)` or
+//! `DateValue(None)`, which is easy to mistake for a provided value. Wrapping them in
+//! `MaybeValidated` with the `OptionalValue` trait moves the flag into the type, so an absent
+//! value has to be handled before the value can be used.
+//!
+//! The non-mandatory parses of every type return a `MaybeValidated`: `parse_optional`, with the
+//! rules of the type made optional by `OptionalRules`, and `FormMap::optional_field` for forms.
+//! The `parse_custom` methods still return the flagged values, whatever `is_mandatory`, so
+//! existing code and the conversions built on them keep working, e.g. with `into_option`.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::maybe_validated::MaybeValidated;
+//! use cjtoolkit_structured_validator::types::numbers::float::{Float, FloatRules};
+//!
+//! let rules = FloatRules {
+//!     is_mandatory: false,
+//!     ..FloatRules::default()
+//! };
+//! let parse = |s| Float::parse_custom(s, rules.clone());
+//!
+//! let absent = MaybeValidated::parse(None, parse).expect("Expected absent float to be valid");
+//! assert!(absent.is_absent());
+//!
+//! let present = MaybeValidated::parse(Some(1.5), parse).expect("Expected float to be valid");
+//! assert_eq!(present.map(|f| f.as_f64()).into_option(), Some(1.5));
+//! ```
+//!
+//! Every type implementing `OptionalValue` is parsed as an optional field straight into a
+//! `MaybeValidated` with `parse_optional`, the field being optional whatever `is_mandatory`.
+//!
+//! ```
+//! use cjtoolkit_structured_validator::common::maybe_validated::OptionalValue;
+//! use cjtoolkit_structured_validator::types::name::{Name, NameRules};
+//!
+//! let absent = Name::parse_optional(None, NameRules::default()).expect("Expected absent name");
+//! assert!(absent.is_absent());
+//! let name = Name::parse_optional(Some("Alice"), NameRules::default());
+//! assert!(name.is_ok_and(|name| name.is_present()));
+//! assert!(Name::parse_optional(Some("Al"), NameRules::default()).is_err());
+//! ```
+
+use crate::common::validator::Validator;
+
+/// A value parsed by a type flagging whether it was provided, such as `Float` or `DateValue`.
+pub trait OptionalValue: Sized {
+    /// Returns `true` if the value was not provided.
+    fn is_absent(&self) -> bool;
+
+    /// Converts the value into a `MaybeValidated`, `Absent` if it was not provided.
+    fn into_maybe_validated(self) -> MaybeValidated<Self> {
+        if self.is_absent() {
+            MaybeValidated::Absent
+        } else {
+            MaybeValidated::Present(self)
+        }
+    }
+
+    /// Parses `input` as an optional field with `rules`, e.g. `NameRules`, made optional with
+    /// `OptionalRules::optional`, `Absent` if the value was not provided.
+    ///
+    /// # Errors
+    /// Returns the error of `rules` if the value was provided and is not valid.
+    fn parse_optional<I, E>(
+        input: I,
+        rules: impl Validator<I, Self, E> + OptionalRules,
+    ) -> Result<MaybeValidated<Self>, E> {
+        rules
+            .optional()
+            .validate(input)
+            .map(Self::into_maybe_validated)
+    }
+}
+
+/// The rules of a type implementing `OptionalValue`, which can be made optional.
+///
+/// It is implemented by the rules of every type, and by the pairs of rules and scheme or pattern,
+/// e.g. `(PostcodeRules, Regex)`.
+pub trait OptionalRules: Sized {
+    /// Returns the rules with the field not mandatory.
+    fn optional(self) -> Self;
+}
+
+impl<R: OptionalRules, S> OptionalRules for (R, S) {
+    fn optional(self) -> Self {
+        (self.0.optional(), self.1)
+    }
+}
+
+/// The result of parsing an optional field.
+///
+/// # Variants
+/// - `Absent`: The field was not provided.
+/// - `Present(T)`: The field was provided, and is valid.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MaybeValidated<T> {
+    #[default]
+    Absent,
+    Present(T),
+}
+
+impl<T: OptionalValue> MaybeValidated<T> {
+    /// Parses `input` with `parse`, `Absent` if the parsed value was not provided.
+    ///
+    /// # Errors
+    /// Returns the error of `parse`, e.g. if the field is mandatory and not provided.
+    pub fn parse<I, E>(input: I, parse: impl FnOnce(I) -> Result<T, E>) -> Result<Self, E> {
+        parse(input).map(T::into_maybe_validated)
+    }
+}
+
+impl<T> MaybeValidated<T> {
+    /// Returns `true` if the field was not provided.
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Self::Absent)
+    }
+
+    /// Returns `true` if the field was provided.
+    pub fn is_present(&self) -> bool {
+        matches!(self, Self::Present(_))
+    }
+
+    /// Converts from `&MaybeValidated<T>` to `MaybeValidated<&T>`.
+    pub fn as_ref(&self) -> MaybeValidated<&T> {
+        match self {
+            Self::Absent => MaybeValidated::Absent,
+            Self::Present(value) => MaybeValidated::Present(value),
+        }
+    }
+
+    /// Maps the value of the field, if present.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> MaybeValidated<U> {
+        match self {
+            Self::Absent => MaybeValidated::Absent,
+            Self::Present(value) => MaybeValidated::Present(f(value)),
+        }
+    }
+
+    /// Converts the field into an `Option`, `None` if it was not provided.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::Absent => None,
+            Self::Present(value) => Some(value),
+        }
+    }
+
+    /// Returns the value of the field if present, otherwise `default`.
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Self::Absent => default,
+            Self::Present(value) => value,
+        }
+    }
+}
+
+impl<T> From<Option<T>> for MaybeValidated<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            None => Self::Absent,
+            Some(value) => Self::Present(value),
+        }
+    }
+}
+
+impl<T> From<MaybeValidated<T>> for Option<T> {
+    fn from(value: MaybeValidated<T>) -> Self {
+        value.into_option()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::name_alias::{FirstName, FirstNameRules};
+    use crate::types::name::{Name, NameRules};
+    use crate::types::numbers::float::{Float, FloatRules};
+
+    #[test]
+    fn test_parse_string_type() {
+        let rules = NameRules {
+            is_mandatory: false,
+            ..NameRules::default()
+        };
+        let parse = |s| Name::parse_custom(s, rules.clone());
+        assert_eq!(
+            MaybeValidated::parse(None, parse),
+            Ok(MaybeValidated::Absent)
+        );
+        let name = MaybeValidated::parse(Some("Alice"), parse).expect("Expected valid name");
+        assert_eq!(
+            name.as_ref().map(|n| n.as_str()),
+            MaybeValidated::Present("Alice")
+        );
+        assert!(MaybeValidated::parse(Some("Alice"), Name::parse).is_ok());
+        assert!(MaybeValidated::parse(None, Name::parse).is_err());
+    }
+
+    #[test]
+    fn test_parse_optional() {
+        assert_eq!(
+            Name::parse_optional(None, NameRules::default()),
+            Ok(MaybeValidated::Absent)
+        );
+        let name =
+            Name::parse_optional(Some("Alice"), NameRules::default()).expect("Expected valid name");
+        assert_eq!(
+            name.as_ref().map(Name::as_str),
+            MaybeValidated::Present("Alice")
+        );
+        assert!(Name::parse_optional(Some("No"), NameRules::default()).is_err());
+
+        assert!(Float::parse_optional(None, FloatRules::default()).is_ok_and(|f| f.is_absent()));
+        assert!(FirstName::parse_optional(None, FirstNameRules::default()).is_ok());
+        assert!(FirstName::parse_optional(Some("Al"), FirstNameRules::default()).is_ok());
+    }
+}
@@ -0,0 +1,225 @@
+//! This module contains the `MessageValidator` type, validating the messages of a queue, e.g.
+//! Kafka, and classifying them for an ingestion pipeline.
+//!
+//! A message is decoded, then validated with `ValidateMessage`, and the outcome is one of:
+//! - `Accept`: The message is valid.
+//! - `Retry`: Every error is transient, e.g. a check skipped because the `CheckBudget` was
+//!   exhausted, so the message may be valid later.
+//! - `DeadLetter`: The message cannot be decoded, or has an error that retrying will not fix.
+//!
+//! Which violations are transient is configurable with `MessageValidator::with_retry_policy`.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::message::{
+//!     MessageOutcome, MessageValidator, ValidateMessage,
+//! };
+//! use cjtoolkit_structured_validator::common::validation_report::ValidationReport;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! struct Signup {
+//!     name: Name,
+//! }
+//!
+//! impl ValidateMessage for Signup {
+//!     type Input = String;
+//!
+//!     fn validate_message(input: String) -> Result<Self, ValidationReport> {
+//!         let mut report = ValidationReport::new();
+//!         let name = report.check("name", Name::parse(Some(&input)));
+//!         report.into_result()?;
+//!         Ok(Self {
+//!             name: name.expect("Expected name to be valid"),
+//!         })
+//!     }
+//! }
+//!
+//! let validator = MessageValidator::<Signup>::new(|payload: &[u8]| {
+//!     String::from_utf8(payload.to_vec()).map_err(|e| e.to_string())
+//! });
+//! assert!(matches!(validator.validate(b"Valid Name"), MessageOutcome::Accept(_)));
+//! assert!(matches!(validator.validate(b"No"), MessageOutcome::DeadLetter(_)));
+//! assert!(matches!(validator.validate(&[0xff]), MessageOutcome::DeadLetter(_)));
+//! ```
+
+use crate::common::locale::RuleViolation;
+use crate::common::validation_report::ValidationReport;
+
+/// A trait for messages which are validated after being decoded.
+///
+/// # Associated Types
+/// - `Input`: The raw, decoded message.
+pub trait ValidateMessage: Sized {
+    type Input;
+
+    fn validate_message(input: Self::Input) -> Result<Self, ValidationReport>;
+}
+
+/// Why a message is sent to the dead letter queue.
+///
+/// # Variants
+/// - `Malformed`: The message cannot be decoded, with the error of the decoder.
+/// - `Invalid`: The message has an error that retrying will not fix, with every error.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeadLetterReason {
+    Malformed(String),
+    Invalid(ValidationReport),
+}
+
+/// The outcome of the validation of a message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageOutcome<T> {
+    Accept(T),
+    Retry(ValidationReport),
+    DeadLetter(DeadLetterReason),
+}
+
+impl<T> MessageOutcome<T> {
+    /// Returns `true` if the message is valid.
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Self::Accept(_))
+    }
+
+    /// Returns the valid message, `None` if it is retried or sent to the dead letter queue.
+    pub fn accepted(self) -> Option<T> {
+        match self {
+            Self::Accept(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+type Decoder<I> = Box<dyn Fn(&[u8]) -> Result<I, String> + Send + Sync>;
+
+/// Validates the payloads of messages as `T`, see the module documentation.
+pub struct MessageValidator<T: ValidateMessage> {
+    decode: Decoder<T::Input>,
+    is_transient: fn(&RuleViolation) -> bool,
+}
+
+/// Returns `true` for the violations of the checks skipped when the `CheckBudget` was
+/// exhausted, the default retry policy of `MessageValidator`.
+pub fn is_transient(violation: &RuleViolation) -> bool {
+    matches!(violation, RuleViolation::Unverified { name } if name == "budget")
+}
+
+impl<T: ValidateMessage> MessageValidator<T> {
+    /// Creates a validator decoding the payloads with `decode`, with the default retry policy,
+    /// `is_transient`.
+    pub fn new(decode: impl Fn(&[u8]) -> Result<T::Input, String> + Send + Sync + 'static) -> Self {
+        Self {
+            decode: Box::new(decode),
+            is_transient,
+        }
+    }
+
+    /// Sets the retry policy, returning `true` for the violations which may disappear when the
+    /// message is retried.
+    pub fn with_retry_policy(mut self, is_transient: fn(&RuleViolation) -> bool) -> Self {
+        self.is_transient = is_transient;
+        self
+    }
+
+    /// Decodes and validates `payload`, then classifies the outcome.
+    pub fn validate(&self, payload: &[u8]) -> MessageOutcome<T> {
+        let input = match (self.decode)(payload) {
+            Ok(input) => input,
+            Err(e) => return MessageOutcome::DeadLetter(DeadLetterReason::Malformed(e)),
+        };
+        match T::validate_message(input) {
+            Ok(value) => MessageOutcome::Accept(value),
+            Err(report) => self.classify(report),
+        }
+    }
+
+    /// Classifies the errors of `report`, retried only when every violation is transient.
+    pub fn classify(&self, report: ValidationReport) -> MessageOutcome<T> {
+        let all_transient = report
+            .0
+            .iter()
+            .flat_map(|(_, store)| store.violations())
+            .all(|violation| (self.is_transient)(&violation));
+        if all_transient && !report.is_valid() {
+            MessageOutcome::Retry(report)
+        } else {
+            MessageOutcome::DeadLetter(DeadLetterReason::Invalid(report))
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T: ValidateMessage> MessageValidator<T>
+where
+    T::Input: serde::de::DeserializeOwned,
+{
+    /// Creates a validator decoding the payloads as JSON.
+    pub fn json() -> Self {
+        Self::new(|payload| serde_json::from_slice(payload).map_err(|e| e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_budget::CheckSkippedLocale;
+    use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+    use crate::types::name::Name;
+
+    struct Signup;
+
+    impl ValidateMessage for Signup {
+        type Input = (String, bool);
+
+        fn validate_message((name, skipped): (String, bool)) -> Result<Self, ValidationReport> {
+            let mut report = ValidationReport::new();
+            let _ = report.check("name", Name::parse(Some(&name)));
+            if skipped {
+                let mut messages = ValidateErrorCollector::new();
+                messages.push(("Could not be checked", Box::new(CheckSkippedLocale)));
+                let store: ValidateErrorStore = messages.into();
+                report.0.push(("username".to_string(), store));
+            }
+            report.into_result()?;
+            Ok(Self)
+        }
+    }
+
+    fn validator() -> MessageValidator<Signup> {
+        MessageValidator::new(|payload| {
+            let payload = std::str::from_utf8(payload).map_err(|e| e.to_string())?;
+            Ok(match payload.strip_suffix('?') {
+                Some(name) => (name.to_string(), true),
+                None => (payload.to_string(), false),
+            })
+        })
+    }
+
+    #[test]
+    fn test_classify() {
+        let validator = validator();
+        assert!(validator.validate(b"Valid Name").is_accepted());
+        assert!(matches!(
+            validator.validate(b"Valid Name?"),
+            MessageOutcome::Retry(_)
+        ));
+        assert!(matches!(
+            validator.validate(b"No?"),
+            MessageOutcome::DeadLetter(DeadLetterReason::Invalid(_))
+        ));
+        assert!(matches!(
+            validator.validate(&[0xff]),
+            MessageOutcome::DeadLetter(DeadLetterReason::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn test_with_retry_policy() {
+        let validator = validator().with_retry_policy(|violation| {
+            is_transient(violation) || matches!(violation, RuleViolation::MinLength { .. })
+        });
+        assert!(matches!(
+            validator.validate(b"No?"),
+            MessageOutcome::Retry(_)
+        ));
+    }
+}
@@ -0,0 +1,151 @@
+//! This module contains a registry overriding the English messages of the crate, e.g. to
+//! replace "Cannot be empty" with "This field is required", without an i18n stack.
+//!
+//! Overrides are keyed by locale key, and consulted by `ValidateErrorCollector::push`, so every
+//! rule uses them. A message may use the arguments of its key as placeholders, e.g.
+//! `{min}` for `validate-min-length`, see `locale_keys::key_args`. The overrides can be
+//! registered globally with `set_global_message_overrides`, or for the duration of a call with
+//! `with_message_overrides`, which take precedence.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::locale_keys;
+//! use cjtoolkit_structured_validator::common::message_overrides::{
+//!     MessageOverrides, with_message_overrides,
+//! };
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! let overrides = MessageOverrides::new()
+//!     .with(locale_keys::VALIDATE_CANNOT_BE_EMPTY, "This field is required")
+//!     .with(locale_keys::VALIDATE_MIN_LENGTH, "Use {min} characters or more");
+//! with_message_overrides(overrides, || {
+//!     let error = Name::parse(Some("")).expect_err("Expected name to be empty");
+//!     assert_eq!(error.0.as_original_message_vec()[0], "This field is required");
+//!     let error = Name::parse(Some("No")).expect_err("Expected name to be too short");
+//!     assert_eq!(error.0.as_original_message_vec(), vec!["Use 5 characters or more"]);
+//! });
+//! ```
+
+use crate::common::interpolate::interpolate;
+use crate::common::locale::{LocaleData, LocaleMessage};
+use crate::common::scoped_registry::{LocalStack, ScopedRegistry};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The messages replacing the English messages of the crate, by locale key.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MessageOverrides(pub HashMap<String, String>);
+
+impl MessageOverrides {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the message of `key`, with the arguments of the key as `{name}` placeholders.
+    pub fn with(mut self, key: &str, message: impl Into<String>) -> Self {
+        self.0.insert(key.to_string(), message.into());
+        self
+    }
+
+    /// Returns the message overriding `locale_message`, with its placeholders filled, or `None`
+    /// if its key is not overridden.
+    pub fn message_for(&self, locale_message: &dyn LocaleMessage) -> Option<String> {
+        self.message_for_data(&locale_message.get_locale_data())
+    }
+
+    fn message_for_data(&self, data: &LocaleData) -> Option<String> {
+        let template = self.0.get(&data.name)?;
+        Some(interpolate(template, data))
+    }
+}
+
+thread_local! {
+    static LOCAL: LocalStack<MessageOverrides> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) static REGISTRY: ScopedRegistry<MessageOverrides> = ScopedRegistry::new(&LOCAL);
+
+/// Registers `overrides` for every validation, replacing the previous global overrides.
+pub fn set_global_message_overrides(overrides: MessageOverrides) {
+    REGISTRY.set_global(Arc::new(overrides));
+}
+
+/// Removes the global overrides.
+pub fn clear_global_message_overrides() {
+    REGISTRY.clear_global();
+}
+
+/// Registers `overrides` for the validations run by `f` on the current thread, then returns the
+/// result of `f`. They take precedence over the global overrides.
+pub fn with_message_overrides<R>(overrides: MessageOverrides, f: impl FnOnce() -> R) -> R {
+    REGISTRY.with(Arc::new(overrides), f)
+}
+
+/// Returns the message overriding `locale_message` in the registered overrides, the innermost
+/// first, or `None` if its key is not overridden. The placeholders are filled from `data` if
+/// given, e.g. the locale data with formatted bounds, otherwise from `locale_message`.
+pub(crate) fn override_for(
+    locale_message: &dyn LocaleMessage,
+    data: Option<&LocaleData>,
+) -> Option<String> {
+    let registered = REGISTRY.all();
+    if registered.is_empty() {
+        return None;
+    }
+    let fetched;
+    let data = match data {
+        Some(data) => data,
+        None => {
+            fetched = locale_message.get_locale_data();
+            &fetched
+        }
+    };
+    registered
+        .iter()
+        .rev()
+        .find_map(|overrides| overrides.message_for_data(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::locale_keys;
+    use crate::types::numbers::integer::{Integer, IntegerRules};
+
+    #[test]
+    fn test_innermost_overrides_take_precedence() {
+        let outer = MessageOverrides::new()
+            .with(locale_keys::VALIDATE_CANNOT_BE_EMPTY, "Required")
+            .with(locale_keys::VALIDATE_NUMBER_MAX_VALUE, "Outer {max}");
+        let inner = MessageOverrides::new().with(
+            locale_keys::VALIDATE_NUMBER_MAX_VALUE,
+            "At most {max}, not {unknown}",
+        );
+        let rules = IntegerRules {
+            max: Some(10),
+            ..IntegerRules::default()
+        };
+        let default = Integer::parse_custom(Some(20), rules.clone())
+            .expect_err("Expected integer to be too large")
+            .0
+            .as_original_message_vec();
+        with_message_overrides(outer, || {
+            with_message_overrides(inner, || {
+                let error = Integer::parse_custom(Some(20), rules.clone())
+                    .expect_err("Expected integer to be too large");
+                assert_eq!(
+                    error.0.as_original_message_vec(),
+                    vec!["At most 10, not {unknown}"]
+                );
+                let error = Integer::parse_custom(None, rules.clone())
+                    .expect_err("Expected integer to be missing");
+                assert_eq!(error.0.as_original_message_vec(), vec!["Required"]);
+            });
+        });
+        let error =
+            Integer::parse_custom(Some(20), rules).expect_err("Expected integer to be too large");
+        assert_eq!(error.0.as_original_message_vec(), default);
+    }
+}
@@ -1,5 +1,40 @@
+pub mod batch;
+pub mod cached_rule;
+pub mod change_set;
+pub mod check_budget;
+pub mod constraint;
+#[cfg(feature = "tokio-util")]
+pub mod debounce;
+#[cfg(feature = "test-fixtures")]
+pub mod fixtures;
 pub mod flag_error;
+pub mod hooks;
+pub mod incremental;
+pub mod interpolate;
+pub mod key_namespace;
 pub mod locale;
+pub mod locale_keys;
+pub mod localized;
+pub mod maybe_validated;
+pub mod message;
+pub mod message_overrides;
+pub mod observer;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod partial;
+pub mod rejected_input;
+pub mod rule_outcomes;
+pub mod rule_violation;
+pub mod schema;
+pub(crate) mod scoped_registry;
+pub mod status_hint;
 pub mod string_validator;
+pub mod tenant_policy;
+pub mod translator;
+pub mod unique;
 pub mod validation_check;
 pub mod validation_collector;
+pub mod validation_report;
+pub mod validator;
+pub mod value_formatter;
+pub mod wizard;
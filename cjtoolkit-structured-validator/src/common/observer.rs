@@ -0,0 +1,167 @@
+//! This module contains a hook for observing validations, e.g. to export metrics.
+//!
+//! An observer can be registered globally with `set_global_observer`, or for the duration of a
+//! call with `with_observer`. Both are notified when registered, and nothing is measured when
+//! no observer is registered.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::observer::{ValidationObserver, with_observer};
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use std::sync::Arc;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! #[derive(Default)]
+//! struct MinLengthFailures(AtomicUsize);
+//!
+//! impl ValidationObserver for MinLengthFailures {
+//!     fn on_rule_result(&self, rule_key: &str, passed: bool) {
+//!         if rule_key == "validate-min-length" && !passed {
+//!             self.0.fetch_add(1, Ordering::Relaxed);
+//!         }
+//!     }
+//! }
+//!
+//! let observer = Arc::new(MinLengthFailures::default());
+//! with_observer(observer.clone(), || {
+//!     let _ = Name::parse(Some("No"));
+//! });
+//! assert_eq!(observer.0.load(Ordering::Relaxed), 1);
+//! ```
+
+use crate::common::locale::ValidateErrorStore;
+use crate::common::scoped_registry::{LocalStack, ScopedRegistry};
+use std::any::type_name;
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A trait for observing the outcome of validations.
+///
+/// Both methods do nothing by default, so implementors only override what they need.
+///
+/// # Provided Methods
+/// - `on_rule_result`: Called after a rule is checked, with the locale key of the rule, e.g.
+///   `validate-min-length`, and whether the value passed it.
+/// - `on_parse_complete`: Called after a value is parsed, with the name of the value type, the
+///   number of errors and the time spent.
+pub trait ValidationObserver: Send + Sync {
+    fn on_rule_result(&self, rule_key: &str, passed: bool) {
+        let _ = (rule_key, passed);
+    }
+
+    fn on_parse_complete(&self, type_name: &str, error_count: usize, duration: Duration) {
+        let _ = (type_name, error_count, duration);
+    }
+}
+
+thread_local! {
+    static LOCAL: LocalStack<dyn ValidationObserver> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) static REGISTRY: ScopedRegistry<dyn ValidationObserver> = ScopedRegistry::new(&LOCAL);
+
+/// Registers `observer` for every validation, replacing the previous global observer.
+pub fn set_global_observer(observer: Arc<dyn ValidationObserver>) {
+    REGISTRY.set_global(observer);
+}
+
+/// Removes the global observer.
+pub fn clear_global_observer() {
+    REGISTRY.clear_global();
+}
+
+/// Registers `observer` for the validations run by `f` on the current thread, then returns the
+/// result of `f`.
+pub fn with_observer<R>(observer: Arc<dyn ValidationObserver>, f: impl FnOnce() -> R) -> R {
+    REGISTRY.with(observer, f)
+}
+
+fn notify(f: impl Fn(&dyn ValidationObserver)) {
+    for observer in REGISTRY.all() {
+        f(observer.as_ref());
+    }
+}
+
+/// Reports the result of the rule identified by `rule_key` to the registered observers.
+///
+/// The built-in rules report themselves, custom rules may call this to be observed as well.
+pub fn rule_result(rule_key: &str, passed: bool) {
+    notify(|observer| observer.on_rule_result(rule_key, passed));
+}
+
+/// Runs the parse `f` of the value type `T`, reporting its outcome to the registered observers.
+pub(crate) fn observe_parse<T, E>(f: impl FnOnce() -> Result<T, E>) -> Result<T, E>
+where
+    for<'a> &'a E: Into<ValidateErrorStore>,
+{
+    if !REGISTRY.is_set() {
+        return f();
+    }
+    let started = Instant::now();
+    let result = f();
+    let duration = started.elapsed();
+    let error_count = match &result {
+        Ok(_) => 0,
+        Err(e) => {
+            let store: ValidateErrorStore = e.into();
+            store.0.len()
+        }
+    };
+    notify(|observer| observer.on_parse_complete(type_name::<T>(), error_count, duration));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::Name;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder {
+        rules: Mutex<Vec<(String, bool)>>,
+        parses: Mutex<Vec<(String, usize)>>,
+    }
+
+    impl ValidationObserver for Recorder {
+        fn on_rule_result(&self, rule_key: &str, passed: bool) {
+            self.rules
+                .lock()
+                .expect("Expected lock")
+                .push((rule_key.to_string(), passed));
+        }
+
+        fn on_parse_complete(&self, type_name: &str, error_count: usize, _duration: Duration) {
+            self.parses
+                .lock()
+                .expect("Expected lock")
+                .push((type_name.to_string(), error_count));
+        }
+    }
+
+    #[test]
+    fn test_with_observer() {
+        let recorder = Arc::new(Recorder::default());
+        with_observer(recorder.clone(), || {
+            let _ = Name::parse(Some("No"));
+        });
+        let _ = Name::parse(Some("Not observed"));
+
+        assert_eq!(
+            *recorder.rules.lock().expect("Expected lock"),
+            vec![
+                ("validate-cannot-be-empty".to_string(), true),
+                ("validate-min-length".to_string(), false),
+                ("validate-max-length".to_string(), true),
+            ]
+        );
+        assert_eq!(
+            *recorder.parses.lock().expect("Expected lock"),
+            vec![(
+                "cjtoolkit_structured_validator::types::name::Name".to_string(),
+                1
+            )]
+        );
+    }
+}
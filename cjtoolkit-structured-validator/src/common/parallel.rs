@@ -0,0 +1,216 @@
+//! This module contains helpers for validating independent fields or rows in parallel, on the
+//! `rayon` thread pool.
+//!
+//! `FieldsValidator` runs the parse of each field of a record concurrently, while
+//! `validate_rows` validates many records concurrently, e.g. the rows of a CSV import. Both
+//! aggregate the errors into a `ValidationReport`, keeping the order of the fields and rows.
+//!
+//! The hooks registered on the calling thread with the `with_*` functions, e.g. `with_observer`
+//! or `with_message_overrides`, are reinstalled on the worker threads for the duration of each
+//! parse, so the fields and rows are validated as they would be sequentially.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::parallel::validate_rows;
+//! use cjtoolkit_structured_validator::common::validation_report::ValidationReport;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use cjtoolkit_structured_validator::types::numbers::unsigned::Unsigned;
+//!
+//! let rows = vec![("Alice Smith", 30), ("Bob", 42), ("Carol Jones", 300)];
+//! let failures = validate_rows(&rows, |(name, age)| {
+//!     let mut report = ValidationReport::new();
+//!     let _ = report.check("name", Name::parse(Some(name)));
+//!     let _ = report.check("age", Unsigned::parse(Some(*age)));
+//!     report
+//! });
+//!
+//! assert_eq!(failures.len(), 2);
+//! assert_eq!(failures[0].0, 1);
+//! assert_eq!(failures[0].1.fields(), vec!["name"]);
+//! assert_eq!(failures[1].0, 2);
+//! assert_eq!(failures[1].1.fields(), vec!["age"]);
+//! ```
+
+use crate::common::key_namespace::{self, KeyNamespace};
+use crate::common::locale::ValidateErrorStore;
+use crate::common::message_overrides::{self, MessageOverrides};
+use crate::common::observer::{self, ValidationObserver};
+use crate::common::rejected_input::{self, RegisteredLogger};
+use crate::common::validation_report::ValidationReport;
+use crate::common::value_formatter::{self, ValueFormatter};
+use rayon::prelude::*;
+use std::sync::Arc;
+
+/// The values registered with the `with_*` functions on a thread, e.g. `with_observer`,
+/// captured to run work on another thread with the same hooks.
+struct ScopedContext {
+    key_namespaces: Vec<Arc<KeyNamespace>>,
+    message_overrides: Vec<Arc<MessageOverrides>>,
+    observers: Vec<Arc<dyn ValidationObserver>>,
+    rejected_input_loggers: Vec<Arc<RegisteredLogger>>,
+    value_formatters: Vec<Arc<dyn ValueFormatter>>,
+}
+
+impl ScopedContext {
+    /// Captures the scoped values of the current thread.
+    fn capture() -> Self {
+        Self {
+            key_namespaces: key_namespace::REGISTRY.locals(),
+            message_overrides: message_overrides::REGISTRY.locals(),
+            observers: observer::REGISTRY.locals(),
+            rejected_input_loggers: rejected_input::REGISTRY.locals(),
+            value_formatters: value_formatter::REGISTRY.locals(),
+        }
+    }
+
+    /// Runs `f` on the current thread with the captured scoped values in place of its own.
+    fn run<R>(&self, f: impl FnOnce() -> R) -> R {
+        key_namespace::REGISTRY.with_locals(self.key_namespaces.clone(), || {
+            message_overrides::REGISTRY.with_locals(self.message_overrides.clone(), || {
+                observer::REGISTRY.with_locals(self.observers.clone(), || {
+                    rejected_input::REGISTRY
+                        .with_locals(self.rejected_input_loggers.clone(), || {
+                            value_formatter::REGISTRY.with_locals(self.value_formatters.clone(), f)
+                        })
+                })
+            })
+        })
+    }
+}
+
+type FieldParse<'a> = Box<dyn FnOnce() -> Option<ValidateErrorStore> + Send + 'a>;
+
+/// A builder running the parse of independent fields concurrently.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::parallel::FieldsValidator;
+/// use cjtoolkit_structured_validator::types::description::Description;
+/// use cjtoolkit_structured_validator::types::name::Name;
+///
+/// let report = FieldsValidator::new()
+///     .field("name", || Name::parse(Some("Jo")))
+///     .field("description", || Description::parse(Some("A short description")))
+///     .validate();
+///
+/// assert_eq!(report.fields(), vec!["name"]);
+/// ```
+#[derive(Default)]
+pub struct FieldsValidator<'a>(Vec<(String, FieldParse<'a>)>);
+
+impl<'a> FieldsValidator<'a> {
+    /// Creates a validator without fields.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds the field `field`, validated by `parse`.
+    pub fn field<T, E>(
+        mut self,
+        field: &str,
+        parse: impl FnOnce() -> Result<T, E> + Send + 'a,
+    ) -> Self
+    where
+        for<'b> &'b E: Into<ValidateErrorStore>,
+    {
+        self.0.push((
+            field.to_string(),
+            Box::new(move || parse().err().map(|e| (&e).into())),
+        ));
+        self
+    }
+
+    /// Runs the parse of every field concurrently, and returns their errors in the order the
+    /// fields were added.
+    pub fn validate(self) -> ValidationReport {
+        let context = ScopedContext::capture();
+        ValidationReport(
+            self.0
+                .into_par_iter()
+                .filter_map(|(field, parse)| context.run(parse).map(|store| (field, store)))
+                .collect(),
+        )
+    }
+}
+
+/// Validates each row with `validate_row` concurrently, and returns the index and report of
+/// every row with errors, in the order of the rows.
+pub fn validate_rows<R, F>(rows: &[R], validate_row: F) -> Vec<(usize, ValidationReport)>
+where
+    R: Sync,
+    F: Fn(&R) -> ValidationReport + Sync,
+{
+    let context = ScopedContext::capture();
+    rows.par_iter()
+        .enumerate()
+        .filter_map(|(index, row)| {
+            let report = context.run(|| validate_row(row));
+            (!report.is_valid()).then_some((index, report))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::locale_keys;
+    use crate::common::message_overrides::{MessageOverrides, with_message_overrides};
+    use crate::common::observer::{ValidationObserver, with_observer};
+    use crate::types::name::Name;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn test_validate_rows_keeps_order() {
+        let rows: Vec<String> = (0..1000)
+            .map(|i| {
+                if i % 100 == 0 {
+                    "No".to_string()
+                } else {
+                    format!("Name {i}")
+                }
+            })
+            .collect();
+        let failures = validate_rows(&rows, |name| {
+            let mut report = ValidationReport::new();
+            let _ = report.check("name", Name::parse(Some(name)));
+            report
+        });
+
+        let indexes: Vec<usize> = failures.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indexes, (0..1000).step_by(100).collect::<Vec<_>>());
+        assert!(failures.iter().all(|(_, report)| report.error_count() == 1));
+    }
+
+    #[derive(Default)]
+    struct ParseCount(AtomicUsize);
+
+    impl ValidationObserver for ParseCount {
+        fn on_parse_complete(&self, _type_name: &str, _error_count: usize, _duration: Duration) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_scoped_hooks_reach_the_workers() {
+        let rows: Vec<String> = (0..100).map(|i| format!("Name {i}")).collect();
+        let count = Arc::new(ParseCount::default());
+        let overrides = MessageOverrides::new().with(locale_keys::VALIDATE_MIN_LENGTH, "Too short");
+        let report = with_observer(count.clone(), || {
+            with_message_overrides(overrides, || {
+                validate_rows(&rows, |name| {
+                    let mut report = ValidationReport::new();
+                    let _ = report.check("name", Name::parse(Some(name)));
+                    report
+                });
+                FieldsValidator::new()
+                    .field("name", || Name::parse(Some("No")))
+                    .validate()
+            })
+        });
+
+        assert_eq!(count.0.load(Ordering::Relaxed), 101);
+        assert_eq!(report.0[0].1.as_original_message_vec(), vec!["Too short"]);
+    }
+}
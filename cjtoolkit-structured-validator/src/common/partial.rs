@@ -0,0 +1,143 @@
+//! This module contains the `Partial` type, for validating only the fields provided by a request,
+//! e.g. an HTTP `PATCH`.
+//!
+//! A field that is absent from the request is skipped entirely, rather than parsed as `None`, so
+//! it neither triggers the mandatory rule nor clears the existing value. A field that is present
+//! but empty, e.g. `null`, is still parsed as `None`, with the same rules as a full update.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::partial::Partial;
+//! use cjtoolkit_structured_validator::types::description::Description;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! struct Profile {
+//!     name: Name,
+//!     description: Description,
+//! }
+//!
+//! let mut profile = Profile {
+//!     name: Name::parse(Some("Alice Smith")).expect("Expected valid name"),
+//!     description: Description::parse(Some("Likes tea")).expect("Expected valid description"),
+//! };
+//!
+//! // The request only provides the description.
+//! let name = Partial::parse(None, Name::parse).expect("Expected absent name to be skipped");
+//! let description = Partial::parse(Some(Some("Likes coffee")), Description::parse)
+//!     .expect("Expected valid description");
+//!
+//! name.merge_into(&mut profile.name);
+//! description.merge_into(&mut profile.description);
+//! assert_eq!(profile.name.as_str(), "Alice Smith");
+//! assert_eq!(profile.description.as_str(), "Likes coffee");
+//! ```
+
+/// A value that may be absent from a partial update.
+///
+/// # Variants
+/// - `Absent`: The field was not provided, and keeps its current value.
+/// - `Present(T)`: The field was provided, and replaces the current value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Partial<T> {
+    #[default]
+    Absent,
+    Present(T),
+}
+
+impl<T> Partial<T> {
+    /// Parses the `input` of a field with `parse`, if the field is present.
+    ///
+    /// The outer `Option` of `input` is the presence of the field, the inner one its value, so
+    /// `Some(None)` is a field provided without value, e.g. `null`, and is parsed as `None`.
+    ///
+    /// # Errors
+    /// Returns the error of `parse`, if the field is present and not valid.
+    pub fn parse<I, E>(
+        input: Option<Option<I>>,
+        parse: impl FnOnce(Option<I>) -> Result<T, E>,
+    ) -> Result<Self, E> {
+        match input {
+            None => Ok(Self::Absent),
+            Some(input) => parse(input).map(Self::Present),
+        }
+    }
+
+    /// Returns `true` if the field was not provided.
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Self::Absent)
+    }
+
+    /// Returns `true` if the field was provided.
+    pub fn is_present(&self) -> bool {
+        matches!(self, Self::Present(_))
+    }
+
+    /// Converts from `&Partial<T>` to `Partial<&T>`.
+    pub fn as_ref(&self) -> Partial<&T> {
+        match self {
+            Self::Absent => Partial::Absent,
+            Self::Present(value) => Partial::Present(value),
+        }
+    }
+
+    /// Maps the value of the field, if present.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Partial<U> {
+        match self {
+            Self::Absent => Partial::Absent,
+            Self::Present(value) => Partial::Present(f(value)),
+        }
+    }
+
+    /// Converts the field into an `Option`, `None` if it was not provided.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::Absent => None,
+            Self::Present(value) => Some(value),
+        }
+    }
+
+    /// Returns the value of the field if present, otherwise the `current` value.
+    pub fn unwrap_or(self, current: T) -> T {
+        match self {
+            Self::Absent => current,
+            Self::Present(value) => value,
+        }
+    }
+
+    /// Replaces `target` with the value of the field, if present.
+    pub fn merge_into(self, target: &mut T) {
+        if let Self::Present(value) = self {
+            *target = value;
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Partial<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            None => Self::Absent,
+            Some(value) => Self::Present(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::Name;
+
+    #[test]
+    fn test_absent_is_distinct_from_none() {
+        let absent = Partial::parse(None, Name::parse);
+        assert_eq!(absent, Ok(Partial::Absent));
+
+        let null = Partial::parse(Some(None), Name::parse);
+        assert!(null.is_err());
+
+        let mut current = Name::parse(Some("Alice Smith")).expect("Expected valid name");
+        Partial::parse(Some(Some("Bob Jones")), Name::parse)
+            .expect("Expected valid name")
+            .merge_into(&mut current);
+        assert_eq!(current.as_str(), "Bob Jones");
+    }
+}
@@ -0,0 +1,275 @@
+//! This module contains a hook for logging rejected inputs, e.g. to analyze the common mistakes
+//! of users, without logging personal data.
+//!
+//! A `RejectedInputLogger` receives the name of the value type, the locale keys of the errors and
+//! a preview of the input, redacted according to the `RedactionRules` it was registered with.
+//! The redaction can be configured per value type, passwords being hidden by default. Loggers
+//! are registered like observers, globally with `set_global_rejected_input_logger`, or for the
+//! duration of a call with `with_rejected_input_logger`. Only the types parsed from strings are
+//! logged.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::rejected_input::{
+//!     Redaction, RedactionRules, RejectedInputLogger, with_rejected_input_logger,
+//! };
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use std::sync::{Arc, Mutex};
+//!
+//! #[derive(Default)]
+//! struct Log(Mutex<Vec<String>>);
+//!
+//! impl RejectedInputLogger for Log {
+//!     fn on_rejected_input(&self, type_name: &str, locale_keys: &[String], preview: &str) {
+//!         let line = format!("{} {:?} {}", type_name, locale_keys, preview);
+//!         self.0.lock().expect("Expected lock").push(line);
+//!     }
+//! }
+//!
+//! let log = Arc::new(Log::default());
+//! let rules = RedactionRules::new(Redaction::Shape);
+//! with_rejected_input_logger(log.clone(), rules, || {
+//!     let _ = Name::parse(Some("Jo"));
+//! });
+//! assert_eq!(
+//!     log.0.lock().expect("Expected lock")[0],
+//!     "cjtoolkit_structured_validator::types::name::Name [\"validate-min-length\"] Aa"
+//! );
+//! ```
+
+use crate::common::locale::ValidateErrorStore;
+use crate::common::observer::observe_parse;
+use crate::common::scoped_registry::{LocalStack, ScopedRegistry};
+use std::any::type_name;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A trait for logging the inputs rejected by validation.
+///
+/// # Required Method
+/// - `on_rejected_input`: Called after an input is rejected, with the name of the value type,
+///   the locale keys of the errors, e.g. `validate-min-length`, and the redacted input.
+pub trait RejectedInputLogger: Send + Sync {
+    fn on_rejected_input(&self, type_name: &str, locale_keys: &[String], preview: &str);
+}
+
+/// How a rejected input is redacted before it is logged.
+///
+/// # Variants
+/// - `Hidden`: The input is replaced by `[redacted]`.
+/// - `Length`: The input is replaced by its length in chars, e.g. `[12 chars]`.
+/// - `Shape`: Each letter is replaced by `A` or `a` and each digit by `9`, other chars being
+///   kept, e.g. `Aaaa-99`, so formatting mistakes show without the content.
+/// - `Prefix`: The first chars are kept and the rest replaced by `…`.
+/// - `Plain`: The input is logged as is, for inputs that are never personal data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Redaction {
+    #[default]
+    Hidden,
+    Length,
+    Shape,
+    Prefix(usize),
+    Plain,
+}
+
+impl Redaction {
+    /// Returns `input` redacted.
+    pub fn apply(&self, input: &str) -> String {
+        match self {
+            Self::Hidden => "[redacted]".to_string(),
+            Self::Length => format!("[{} chars]", input.chars().count()),
+            Self::Shape => input
+                .chars()
+                .map(|c| match c {
+                    c if c.is_uppercase() => 'A',
+                    c if c.is_alphabetic() => 'a',
+                    c if c.is_numeric() => '9',
+                    c => c,
+                })
+                .collect(),
+            Self::Prefix(len) => {
+                let mut preview: String = input.chars().take(*len).collect();
+                if input.chars().count() > *len {
+                    preview.push('…');
+                }
+                preview
+            }
+            Self::Plain => input.to_string(),
+        }
+    }
+}
+
+/// The redaction of the rejected inputs, per value type.
+///
+/// # Fields
+/// - `default`: The redaction of the types without an override.
+/// - `per_type`: The redaction of each type, by the name returned by `std::any::type_name`.
+///
+/// # Default
+/// Inputs are hidden, and so are passwords whatever the default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedactionRules {
+    pub default: Redaction,
+    pub per_type: HashMap<String, Redaction>,
+}
+
+impl RedactionRules {
+    /// Creates rules redacting inputs with `default`, except passwords, which are hidden.
+    pub fn new(default: Redaction) -> Self {
+        Self {
+            default,
+            per_type: HashMap::new(),
+        }
+        .with_type::<crate::types::password::Password>(Redaction::Hidden)
+    }
+
+    /// Sets the redaction of the value type `T`.
+    pub fn with_type<T>(mut self, redaction: Redaction) -> Self {
+        self.per_type
+            .insert(type_name::<T>().to_string(), redaction);
+        self
+    }
+
+    /// Returns the redaction of the value type named `type_name`.
+    pub fn redaction_for(&self, type_name: &str) -> Redaction {
+        self.per_type
+            .get(type_name)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+impl Default for RedactionRules {
+    fn default() -> Self {
+        Self::new(Redaction::default())
+    }
+}
+
+pub(crate) type RegisteredLogger = (Arc<dyn RejectedInputLogger>, RedactionRules);
+
+thread_local! {
+    static LOCAL: LocalStack<RegisteredLogger> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) static REGISTRY: ScopedRegistry<RegisteredLogger> = ScopedRegistry::new(&LOCAL);
+
+/// Registers `logger` for every validation, replacing the previous global logger.
+pub fn set_global_rejected_input_logger(
+    logger: Arc<dyn RejectedInputLogger>,
+    rules: RedactionRules,
+) {
+    REGISTRY.set_global(Arc::new((logger, rules)));
+}
+
+/// Removes the global logger.
+pub fn clear_global_rejected_input_logger() {
+    REGISTRY.clear_global();
+}
+
+/// Registers `logger` for the validations run by `f` on the current thread, then returns the
+/// result of `f`.
+pub fn with_rejected_input_logger<R>(
+    logger: Arc<dyn RejectedInputLogger>,
+    rules: RedactionRules,
+    f: impl FnOnce() -> R,
+) -> R {
+    REGISTRY.with(Arc::new((logger, rules)), f)
+}
+
+/// Reports the input rejected with `errors` as the value type `T` to the registered loggers.
+///
+/// The built-in string types report themselves, custom types may call this to be logged as well.
+pub fn log_rejected_input<T>(input: &str, errors: &ValidateErrorStore) {
+    if !REGISTRY.is_set() {
+        return;
+    }
+    let type_name = type_name::<T>();
+    let locale_keys: Vec<String> = errors
+        .0
+        .iter()
+        .map(|e| e.1.get_locale_data().name.clone())
+        .collect();
+    for registered in REGISTRY.all() {
+        let (logger, rules) = registered.as_ref();
+        let preview = rules.redaction_for(type_name).apply(input);
+        logger.on_rejected_input(type_name, &locale_keys, &preview);
+    }
+}
+
+/// Runs the parse `f` of the value type `T` like `observe_parse`, logging `input` if rejected.
+pub(crate) fn observe_parse_input<T, E>(
+    input: Option<&str>,
+    f: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E>
+where
+    for<'a> &'a E: Into<ValidateErrorStore>,
+{
+    let result = observe_parse(f);
+    if let (Err(e), true) = (&result, REGISTRY.is_set()) {
+        log_rejected_input::<T>(input.unwrap_or_default(), &e.into());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::Name;
+    use crate::types::password::{Password, PasswordRules};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<(String, Vec<String>, String)>>);
+
+    impl RejectedInputLogger for Recorder {
+        fn on_rejected_input(&self, type_name: &str, locale_keys: &[String], preview: &str) {
+            self.0.lock().expect("Expected lock").push((
+                type_name.to_string(),
+                locale_keys.to_vec(),
+                preview.to_string(),
+            ));
+        }
+    }
+
+    #[test]
+    fn test_redaction() {
+        assert_eq!(Redaction::Hidden.apply("Jo"), "[redacted]");
+        assert_eq!(Redaction::Length.apply("Jöe"), "[3 chars]");
+        assert_eq!(Redaction::Shape.apply("Jo-12 x@"), "Aa-99 a@");
+        assert_eq!(Redaction::Prefix(2).apply("Joe"), "Jo…");
+        assert_eq!(Redaction::Prefix(3).apply("Joe"), "Joe");
+        assert_eq!(Redaction::Plain.apply("Joe"), "Joe");
+    }
+
+    #[test]
+    fn test_with_rejected_input_logger() {
+        let recorder = Arc::new(Recorder::default());
+        let rules = RedactionRules::new(Redaction::Plain).with_type::<Name>(Redaction::Length);
+        with_rejected_input_logger(recorder.clone(), rules, || {
+            let _ = Name::parse(Some("Jo"));
+            let _ = Name::parse(Some("Valid Name"));
+            let _ = Password::parse_custom(
+                Some("secret"),
+                PasswordRules {
+                    min_length: Some(8),
+                    ..PasswordRules::default()
+                },
+            );
+        });
+        let _ = Name::parse(Some("No"));
+
+        let logged = recorder.0.lock().expect("Expected lock");
+        assert_eq!(logged.len(), 2);
+        assert_eq!(
+            logged[0],
+            (
+                type_name::<Name>().to_string(),
+                vec!["validate-min-length".to_string()],
+                "[2 chars]".to_string()
+            )
+        );
+        assert_eq!(logged[1].0, type_name::<Password>());
+        assert_eq!(logged[1].2, "[redacted]");
+    }
+}
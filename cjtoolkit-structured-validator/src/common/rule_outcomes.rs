@@ -0,0 +1,85 @@
+//! This module contains the `RuleOutcomes` type, reporting which rules a value passed as well as
+//! its errors, e.g. for a checklist of the password requirements.
+//!
+//! The outcomes are recorded from the `rule_result` reports of the rules, see the `observer`
+//! module, so custom rules calling `rule_result` are recorded as well.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::password::{Password, PasswordRules};
+//!
+//! let outcomes = Password::evaluate(Some("longpassword"), PasswordRules::default());
+//! assert_eq!(outcomes.passed("validate-min-length"), Some(true));
+//! assert_eq!(outcomes.passed("validate-must-have-digit"), Some(false));
+//! assert!(!outcomes.is_valid());
+//! ```
+
+use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::observer::{ValidationObserver, with_observer};
+use std::sync::{Arc, Mutex};
+
+/// The outcome of a rule, identified by its locale key, e.g. `validate-min-length`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleOutcome {
+    pub key: String,
+    pub passed: bool,
+}
+
+/// The outcome of every rule checked, in the order they were checked, and the errors of the
+/// rules which failed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuleOutcomes {
+    pub outcomes: Vec<RuleOutcome>,
+    pub errors: ValidateErrorStore,
+}
+
+#[derive(Default)]
+struct OutcomeRecorder(Mutex<Vec<RuleOutcome>>);
+
+impl ValidationObserver for OutcomeRecorder {
+    fn on_rule_result(&self, rule_key: &str, passed: bool) {
+        self.0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(RuleOutcome {
+                key: rule_key.to_string(),
+                passed,
+            });
+    }
+}
+
+impl RuleOutcomes {
+    /// Runs the checks of `f`, recording the outcome of every rule reported with `rule_result`
+    /// and the errors collected.
+    pub fn record(f: impl FnOnce(&mut ValidateErrorCollector)) -> Self {
+        let recorder = Arc::new(OutcomeRecorder::default());
+        let mut messages = ValidateErrorCollector::new();
+        with_observer(recorder.clone(), || f(&mut messages));
+        let outcomes = std::mem::take(&mut *recorder.0.lock().unwrap_or_else(|e| e.into_inner()));
+        Self {
+            outcomes,
+            errors: messages.into(),
+        }
+    }
+
+    /// Returns `true` if every rule passed.
+    pub fn is_valid(&self) -> bool {
+        self.outcomes.iter().all(|outcome| outcome.passed)
+    }
+
+    /// Returns whether the rule identified by `key` passed, `None` if it was not checked.
+    pub fn passed(&self, key: &str) -> Option<bool> {
+        self.outcomes
+            .iter()
+            .find(|outcome| outcome.key == key)
+            .map(|outcome| outcome.passed)
+    }
+
+    /// Returns the number of rules which passed.
+    pub fn passed_count(&self) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.passed)
+            .count()
+    }
+}
@@ -0,0 +1,204 @@
+//! This module contains a typed representation of the rule violated by a validation error.
+
+use crate::common::locale::LocaleValue;
+use crate::common::status_hint::StatusHint;
+
+/// The rule violated by a validation error.
+///
+/// Every built-in `LocaleMessage` reports its violation through
+/// `LocaleMessage::rule_violation`, so programmatic consumers can match on the violations of an
+/// error store rather than comparing locale keys.
+///
+/// # Variants
+/// - `Mandatory`: The value is empty, or was not provided.
+/// - `MinLength { min }`: The value is shorter than `min`.
+/// - `MaxLength { max }`: The value is longer than `max`.
+/// - `Pattern { name }`: The value does not have the expected format, `name` identifies the
+///   format, e.g. `email`, `url`, `uppercase` or `digit`.
+/// - `ForbiddenChars { name }`: The value contains forbidden characters, `name` identifies
+///   them, e.g. `control`, `zero-width` or `bidi-override`.
+/// - `Checksum { name }`: The check digits of the value are not valid, `name` identifies the
+///   scheme.
+/// - `Range { min, max }`: The value is out of range, only the violated bound is set.
+/// - `ForbidZero`: The value must not be zero.
+/// - `MustBeEven`: The value must be even.
+/// - `MustBeOdd`: The value must be odd.
+/// - `NotAllowed { allowed }`: The value is not one of the allowed values.
+/// - `FieldNotAllowed { field }`: The field is not one of the allowed fields.
+/// - `DoesNotMatch`: The value does not match its confirmation.
+/// - `Taken`: The value is already taken.
+/// - `Confusable { with }`: The value looks like the existing value `with`.
+/// - `MinItems { min }`: The collection has fewer than `min` items.
+/// - `MaxItems { max }`: The collection has more than `max` items.
+/// - `InvalidType { expected }`: The value is not of the `expected` type, e.g. `string`.
+/// - `InvalidLocalTime { reason }`: The local date and time does not exist or is ambiguous in
+///   the time zone, `reason` is `nonexistent` or `ambiguous`.
+/// - `Unverified { name }`: The rule could not be checked, so the value is not accepted, `name`
+///   identifies why, e.g. the disabled feature `psl`, or `budget` for a check skipped because
+///   the `CheckBudget` was exhausted.
+/// - `Sanitized { removed }`: The value was accepted after `removed` was stripped from it, e.g.
+///   unsupported HTML tags, reported as a warning.
+/// - `Custom(String)`: Any other violation, holding the locale key of the message.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::rule_violation::RuleViolation;
+/// use cjtoolkit_structured_validator::types::name::Name;
+///
+/// let error = Name::parse(Some("No")).expect_err("Expected name to be too short");
+/// assert_eq!(error.0.violations(), vec![RuleViolation::MinLength { min: 5 }]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleViolation {
+    Mandatory,
+    MinLength {
+        min: usize,
+    },
+    MaxLength {
+        max: usize,
+    },
+    Pattern {
+        name: String,
+    },
+    ForbiddenChars {
+        name: String,
+    },
+    Checksum {
+        name: String,
+    },
+    Range {
+        min: Option<LocaleValue>,
+        max: Option<LocaleValue>,
+    },
+    ForbidZero,
+    MustBeEven,
+    MustBeOdd,
+    NotAllowed {
+        allowed: String,
+    },
+    FieldNotAllowed {
+        field: String,
+    },
+    DoesNotMatch,
+    Taken,
+    Confusable {
+        with: String,
+    },
+    MinItems {
+        min: usize,
+    },
+    MaxItems {
+        max: usize,
+    },
+    InvalidType {
+        expected: String,
+    },
+    InvalidLocalTime {
+        reason: String,
+    },
+    Unverified {
+        name: String,
+    },
+    Sanitized {
+        removed: String,
+    },
+    Custom(String),
+}
+
+impl RuleViolation {
+    /// Returns the HTTP status suggested by the violation, `Conflict` for `Taken` and
+    /// `Confusable`, `BadRequest` for `FieldNotAllowed` and `InvalidType`, otherwise
+    /// `UnprocessableEntity`.
+    pub fn status_hint(&self) -> StatusHint {
+        match self {
+            Self::Taken | Self::Confusable { .. } => StatusHint::Conflict,
+            Self::FieldNotAllowed { .. } | Self::InvalidType { .. } => StatusHint::BadRequest,
+            _ => StatusHint::UnprocessableEntity,
+        }
+    }
+
+    /// Returns the priority of the violation, the most relevant violations having the highest
+    /// priority, so they can be shown first:
+    /// - `4`: The value is missing or unusable, `Mandatory`, `InvalidType` and `FieldNotAllowed`.
+    /// - `3`: The value has the wrong size or characters, e.g. `MinLength`, `Range` or
+    ///   `ForbiddenChars`.
+    /// - `2`: The value has the wrong format, e.g. `Pattern`, `Checksum` or `NotAllowed`.
+    /// - `1`: Any other violation, e.g. `Taken`, `Unverified`, `Sanitized` or `Custom`.
+    pub fn priority(&self) -> u8 {
+        match self {
+            Self::Mandatory | Self::InvalidType { .. } | Self::FieldNotAllowed { .. } => 4,
+            Self::MinLength { .. }
+            | Self::MaxLength { .. }
+            | Self::MinItems { .. }
+            | Self::MaxItems { .. }
+            | Self::Range { .. }
+            | Self::ForbidZero
+            | Self::ForbiddenChars { .. } => 3,
+            Self::Pattern { .. }
+            | Self::Checksum { .. }
+            | Self::MustBeEven
+            | Self::MustBeOdd
+            | Self::NotAllowed { .. }
+            | Self::InvalidLocalTime { .. } => 2,
+            Self::DoesNotMatch
+            | Self::Taken
+            | Self::Confusable { .. }
+            | Self::Unverified { .. }
+            | Self::Sanitized { .. }
+            | Self::Custom(_) => 1,
+        }
+    }
+
+    pub(crate) fn pattern(name: &str) -> Self {
+        Self::Pattern {
+            name: name.to_string(),
+        }
+    }
+
+    pub(crate) fn forbidden_chars(name: &str) -> Self {
+        Self::ForbiddenChars {
+            name: name.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::locale::{LocaleData, ValidateErrorCollector, ValidateErrorStore};
+    use crate::types::numbers::integer::{Integer, IntegerRules};
+
+    #[test]
+    fn test_violations_survive_round_trip() {
+        let error = Integer::parse_custom(
+            Some(20),
+            IntegerRules {
+                max: Some(10),
+                ..IntegerRules::default()
+            },
+        )
+        .expect_err("Expected integer to be too large");
+        let expected = vec![RuleViolation::Range {
+            min: None,
+            max: Some(LocaleValue::Int(10)),
+        }];
+        assert_eq!(error.0.violations(), expected);
+
+        let collector = error.0.as_validate_error_collector();
+        let store: ValidateErrorStore = collector.into();
+        assert_eq!(store.violations(), expected);
+    }
+
+    #[test]
+    fn test_custom_violation() {
+        let mut collector = ValidateErrorCollector::new();
+        collector.push((
+            "Custom".to_string(),
+            Box::new(LocaleData::new("validate-custom")),
+        ));
+        assert_eq!(
+            collector.violations(),
+            vec![RuleViolation::Custom("validate-custom".to_string())]
+        );
+    }
+}
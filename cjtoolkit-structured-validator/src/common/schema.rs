@@ -0,0 +1,300 @@
+//! This module contains the `schema!` macro, declaring the fields of a form and their rules
+//! inline, and the `SchemaField` trait of the value types it accepts.
+//!
+//! `schema!` declares a module with:
+//! - `Input`: The raw values of the fields, `None` when missing.
+//! - `Rules`: The rules of each field, the defaults of the type with the rules of the
+//!   declaration applied.
+//! - `Validated`: The validated fields.
+//! - `Error`: The error of each field, `None` when the field is valid, converted into a
+//!   `ValidationReport` with `From`.
+//!
+//! A field is declared with its type, e.g. `username: Username`, for the default rules, or with
+//! some of the fields of its rules, e.g. `age: Integer { min: 18 }`. The values are converted
+//! with `Into`, so `18` is accepted for an `Option<isize>`.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::validation_report::ValidationReport;
+//! use cjtoolkit_structured_validator::schema;
+//! use cjtoolkit_structured_validator::types::numbers::integer::Integer;
+//! use cjtoolkit_structured_validator::types::username::Username;
+//!
+//! schema! {
+//!     /// The signup form.
+//!     pub mod signup {
+//!         username: Username,
+//!         age: Integer { min: 18 },
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let input = signup::Input {
+//!         username: Some("alice".to_string()),
+//!         age: Some(30),
+//!     };
+//!     let validated = input.validate().expect("Expected signup to be valid");
+//!     assert_eq!(validated.username.as_str(), "alice");
+//!
+//!     let input = signup::Input {
+//!         username: Some("alice".to_string()),
+//!         age: Some(16),
+//!     };
+//!     let error = input.validate().expect_err("Expected age to be too small");
+//!     assert!(error.username.is_none());
+//!     assert_eq!(ValidationReport::from(error).fields(), vec!["age"]);
+//! }
+//! ```
+
+use std::fmt::Debug;
+
+/// A trait for the value types which can be fields of a `schema!`.
+///
+/// # Associated Types
+/// - `Input`: The raw, owned value of the field, e.g. `String` for the string types.
+/// - `Rules`: The rules of the type.
+/// - `Error`: The error of the type.
+pub trait SchemaField: Clone + Sized {
+    type Input: Clone;
+    type Rules: Default + Clone;
+    type Error: Debug;
+
+    fn parse_field(input: Option<&Self::Input>, rules: Self::Rules) -> Result<Self, Self::Error>;
+}
+
+macro_rules! schema_field {
+    (str $type:ty, $rules:ty, $error:ty) => {
+        impl SchemaField for $type {
+            type Input = String;
+            type Rules = $rules;
+            type Error = $error;
+
+            fn parse_field(input: Option<&String>, rules: $rules) -> Result<Self, $error> {
+                <$type>::parse_custom(input.map(String::as_str), rules)
+            }
+        }
+    };
+    (copy $type:ty, $rules:ty, $error:ty, $input:ty) => {
+        impl SchemaField for $type {
+            type Input = $input;
+            type Rules = $rules;
+            type Error = $error;
+
+            fn parse_field(input: Option<&$input>, rules: $rules) -> Result<Self, $error> {
+                <$type>::parse_custom(input.copied(), rules)
+            }
+        }
+    };
+}
+
+use crate::types::cron::{Cron, CronError, CronRules};
+use crate::types::description::{Description, DescriptionError, DescriptionRules};
+use crate::types::name::{Name, NameError, NameRules};
+use crate::types::net::domain::{Domain, DomainError, DomainRules};
+use crate::types::net::hostname::{Hostname, HostnameError, HostnameRules};
+use crate::types::net::mac_address::{MacAddress, MacAddressError, MacAddressRules};
+use crate::types::numbers::float::{Float, FloatError, FloatRules};
+use crate::types::numbers::integer::{Integer, IntegerError, IntegerRules};
+use crate::types::numbers::unsigned::{
+    NonZeroUnsigned, U32, U32Error, U32Rules, U64, U64Error, U64Rules, Unsigned, UnsignedError,
+    UnsignedRules,
+};
+use crate::types::password::{Password, PasswordError, PasswordRules};
+use crate::types::social::{Handle, HandleError, HandleRules};
+use crate::types::sorting::{SortError, SortExpr, SortRules};
+use crate::types::username::{Username, UsernameError, UsernameRules};
+
+schema_field!(str Name, NameRules, NameError);
+schema_field!(str Description, DescriptionRules, DescriptionError);
+schema_field!(str Username, UsernameRules, UsernameError);
+schema_field!(str Password, PasswordRules, PasswordError);
+schema_field!(str Handle, HandleRules, HandleError);
+schema_field!(str SortExpr, SortRules, SortError);
+schema_field!(str Cron, CronRules, CronError);
+schema_field!(str Domain, DomainRules, DomainError);
+schema_field!(str Hostname, HostnameRules, HostnameError);
+schema_field!(str MacAddress, MacAddressRules, MacAddressError);
+schema_field!(copy Integer, IntegerRules, IntegerError, isize);
+schema_field!(copy Unsigned, UnsignedRules, UnsignedError, usize);
+schema_field!(copy Float, FloatRules, FloatError, f64);
+schema_field!(copy U64, U64Rules, U64Error, u64);
+schema_field!(copy U32, U32Rules, U32Error, u32);
+schema_field!(copy NonZeroUnsigned, U64Rules, U64Error, u64);
+
+#[cfg(feature = "email")]
+schema_field!(str crate::types::email::Email, crate::types::email::EmailRules, crate::types::email::EmailError);
+
+#[cfg(feature = "url")]
+schema_field!(str crate::types::url::Url, crate::types::url::UrlRules, crate::types::url::UrlError);
+
+#[cfg(feature = "chrono")]
+schema_field!(
+    copy crate::types::times_chrono::date::DateValue,
+    crate::types::times_chrono::date::DateRules,
+    crate::types::times_chrono::date::DateError,
+    chrono::NaiveDate
+);
+
+/// Declares a module validating the fields of a form, see the `schema` module.
+///
+/// The field types must implement `SchemaField`. The schema is declared at the module level,
+/// not in a function, as the types of the declaring module are brought into scope of the
+/// declared module. `Input` does not implement `Debug`, and the `Debug` of
+/// `Validated` omits the values, as they may hold e.g. a `Password`.
+#[macro_export]
+macro_rules! schema {
+    (
+        $(#[$meta:meta])*
+        $vis:vis mod $module:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident : $type:ty $({ $($rule:ident : $value:expr),* $(,)? })?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::common::schema::SchemaField;
+
+            /// The raw values of the fields, `None` when missing.
+            #[derive(Clone, Default)]
+            pub struct Input {
+                $(pub $field: Option<<$type as SchemaField>::Input>,)*
+            }
+
+            /// The rules of each field.
+            #[derive(Clone)]
+            pub struct Rules {
+                $(pub $field: <$type as SchemaField>::Rules,)*
+            }
+
+            impl Default for Rules {
+                fn default() -> Self {
+                    Self {
+                        $($field: {
+                            #[allow(unused_mut)]
+                            let mut rules = <<$type as SchemaField>::Rules>::default();
+                            $($(rules.$rule = ($value).into();)*)?
+                            rules
+                        },)*
+                    }
+                }
+            }
+
+            /// The validated fields.
+            #[derive(Clone)]
+            pub struct Validated {
+                $($(#[$field_meta])* pub $field: $type,)*
+            }
+
+            impl std::fmt::Debug for Validated {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.debug_struct("Validated").finish_non_exhaustive()
+                }
+            }
+
+            /// The error of each field, `None` when the field is valid.
+            #[derive(Debug)]
+            pub struct Error {
+                $(pub $field: Option<<$type as SchemaField>::Error>,)*
+            }
+
+            impl Input {
+                /// Validates the fields with the rules of the declaration.
+                ///
+                /// # Errors
+                /// Returns the error of each field if any field is invalid.
+                pub fn validate(&self) -> Result<Validated, Error> {
+                    self.validate_with(Rules::default())
+                }
+
+                /// Validates the fields with `rules`.
+                ///
+                /// # Errors
+                /// Returns the error of each field if any field is invalid.
+                pub fn validate_with(&self, rules: Rules) -> Result<Validated, Error> {
+                    $(let $field = <$type as SchemaField>::parse_field(
+                        self.$field.as_ref(),
+                        rules.$field,
+                    );)*
+                    match ($($field,)*) {
+                        ($(Ok($field),)*) => Ok(Validated { $($field,)* }),
+                        ($($field,)*) => Err(Error { $($field: $field.err(),)* }),
+                    }
+                }
+            }
+
+            impl From<&Error> for $crate::common::validation_report::ValidationReport {
+                fn from(error: &Error) -> Self {
+                    let mut report = Self::new();
+                    $(if let Some(e) = &error.$field {
+                        report.0.push((stringify!($field).to_string(), e.into()));
+                    })*
+                    report
+                }
+            }
+
+            impl From<Error> for $crate::common::validation_report::ValidationReport {
+                fn from(error: Error) -> Self {
+                    Self::from(&error)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::validation_report::ValidationReport;
+    use crate::types::name::name_alias::Title;
+    use crate::types::numbers::float::Float;
+    use crate::types::username::Username;
+
+    crate::schema! {
+        mod article {
+            title: Title,
+            author: Username { min_length: 8 },
+            /// The price, free by default.
+            price: Float { is_mandatory: false, min: 0.0 },
+        }
+    }
+
+    #[test]
+    fn test_schema_rules() {
+        let input = article::Input {
+            title: Some("A valid title".to_string()),
+            author: Some("alice".to_string()),
+            price: Some(-1.0),
+        };
+        let error = input
+            .validate()
+            .expect_err("Expected article to be invalid");
+        assert!(error.title.is_none());
+        let report = ValidationReport::from(&error);
+        assert_eq!(report.fields(), vec!["author", "price"]);
+
+        let mut rules = article::Rules::default();
+        rules.author.min_length = Some(3);
+        rules.price.min = None;
+        let validated = input
+            .validate_with(rules)
+            .expect("Expected article to be valid");
+        assert_eq!(validated.title.as_str(), "A valid title");
+        assert_eq!(validated.author.as_str(), "alice");
+        assert_eq!(validated.price.as_f64(), -1.0);
+    }
+
+    #[test]
+    fn test_schema_missing_fields() {
+        let error = article::Input::default()
+            .validate()
+            .expect_err("Expected mandatory fields to be missing");
+        assert!(error.price.is_none());
+        assert_eq!(
+            ValidationReport::from(error).fields(),
+            vec!["title", "author"]
+        );
+    }
+}
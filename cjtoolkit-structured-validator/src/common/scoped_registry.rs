@@ -0,0 +1,174 @@
+//! This module contains `ScopedRegistry`, the registry behind the hooks of the crate, e.g. the
+//! observers, the message overrides or the key namespace.
+//!
+//! A value can be registered globally, or for the duration of a call on the current thread,
+//! the innermost call taking precedence. Looking up an empty registry costs an atomic load and a
+//! thread-local access, so the hooks cost next to nothing when unused.
+//!
+//! The scoped values do not follow the work sent to other threads, the helpers of
+//! `common::parallel` capture them and reinstall them on the worker threads.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread::LocalKey;
+
+/// The thread-local stack of the values registered for the duration of a call.
+pub(crate) type LocalStack<T> = RefCell<Vec<Arc<T>>>;
+
+/// A registry of a global value and a thread-local stack of scoped values.
+///
+/// Generic statics cannot be thread-local, so the stack is declared by the user of the registry
+/// with `thread_local!` and passed to `ScopedRegistry::new`.
+pub(crate) struct ScopedRegistry<T: ?Sized + 'static> {
+    has_global: AtomicBool,
+    global: RwLock<Option<Arc<T>>>,
+    local: &'static LocalKey<LocalStack<T>>,
+}
+
+impl<T: ?Sized + 'static> ScopedRegistry<T> {
+    /// Creates an empty registry, with the scoped values stored in `local`.
+    pub(crate) const fn new(local: &'static LocalKey<LocalStack<T>>) -> Self {
+        Self {
+            has_global: AtomicBool::new(false),
+            global: RwLock::new(None),
+            local,
+        }
+    }
+
+    /// Registers `value` for every thread, replacing the previous global value.
+    pub(crate) fn set_global(&self, value: Arc<T>) {
+        let mut global = self.global.write().unwrap_or_else(|e| e.into_inner());
+        *global = Some(value);
+        self.has_global.store(true, Ordering::Release);
+    }
+
+    /// Removes the global value.
+    pub(crate) fn clear_global(&self) {
+        let mut global = self.global.write().unwrap_or_else(|e| e.into_inner());
+        *global = None;
+        self.has_global.store(false, Ordering::Release);
+    }
+
+    /// Registers `value` for the duration of `f` on the current thread, then returns the result
+    /// of `f`. The value is removed even if `f` panics.
+    pub(crate) fn with<R>(&self, value: Arc<T>, f: impl FnOnce() -> R) -> R {
+        self.local.with(|local| local.borrow_mut().push(value));
+        let _guard = LocalGuard(self.local);
+        f()
+    }
+
+    /// Returns `true` if a value is registered, globally or on the current thread.
+    pub(crate) fn is_set(&self) -> bool {
+        self.has_global.load(Ordering::Acquire)
+            || self.local.with(|local| !local.borrow().is_empty())
+    }
+
+    /// Returns the value in force, the innermost scoped value first, or `None` if there is none.
+    pub(crate) fn current(&self) -> Option<Arc<T>> {
+        let local = self.local.with(|local| local.borrow().last().cloned());
+        if local.is_some() || !self.has_global.load(Ordering::Acquire) {
+            return local;
+        }
+        self.global
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Returns every registered value, the global value first, then the scoped values from the
+    /// outermost to the innermost.
+    pub(crate) fn all(&self) -> Vec<Arc<T>> {
+        if !self.is_set() {
+            return Vec::new();
+        }
+        let mut all: Vec<Arc<T>> = self
+            .global
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned()
+            .collect();
+        self.local
+            .with(|local| all.extend(local.borrow().iter().cloned()));
+        all
+    }
+
+    /// Returns the scoped values of the current thread, from the outermost to the innermost.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn locals(&self) -> Vec<Arc<T>> {
+        self.local.with(|local| local.borrow().clone())
+    }
+
+    /// Replaces the scoped values of the current thread with `locals` for the duration of `f`,
+    /// then restores them. Replacing instead of pushing keeps the values from being registered
+    /// twice when `f` runs on the thread they were captured on.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn with_locals<R>(&self, locals: Vec<Arc<T>>, f: impl FnOnce() -> R) -> R {
+        let previous = self.local.with(|local| local.replace(locals));
+        let _guard = RestoreGuard(self.local, Some(previous));
+        f()
+    }
+}
+
+struct LocalGuard<T: ?Sized + 'static>(&'static LocalKey<LocalStack<T>>);
+
+impl<T: ?Sized + 'static> Drop for LocalGuard<T> {
+    fn drop(&mut self) {
+        self.0.with(|local| local.borrow_mut().pop());
+    }
+}
+
+#[cfg(feature = "rayon")]
+struct RestoreGuard<T: ?Sized + 'static>(&'static LocalKey<LocalStack<T>>, Option<Vec<Arc<T>>>);
+
+#[cfg(feature = "rayon")]
+impl<T: ?Sized + 'static> Drop for RestoreGuard<T> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.1.take() {
+            self.0.with(|local| local.replace(previous));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        static LOCAL: LocalStack<str> = const { RefCell::new(Vec::new()) };
+    }
+
+    static REGISTRY: ScopedRegistry<str> = ScopedRegistry::new(&LOCAL);
+
+    #[test]
+    fn test_scoped_registry() {
+        assert!(!REGISTRY.is_set());
+        assert_eq!(REGISTRY.current(), None);
+        REGISTRY.with(Arc::from("outer"), || {
+            REGISTRY.with(Arc::from("inner"), || {
+                assert_eq!(REGISTRY.current().as_deref(), Some("inner"));
+                let all = REGISTRY.all();
+                assert_eq!(
+                    all.iter().map(|value| &**value).collect::<Vec<_>>(),
+                    vec!["outer", "inner"]
+                );
+            });
+            assert_eq!(REGISTRY.current().as_deref(), Some("outer"));
+        });
+        let result = std::panic::catch_unwind(|| REGISTRY.with(Arc::from("panics"), || panic!()));
+        assert!(result.is_err());
+        assert!(!REGISTRY.is_set());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_with_locals_replaces_the_scoped_values() {
+        REGISTRY.with(Arc::from("outer"), || {
+            let locals = REGISTRY.locals();
+            REGISTRY.with_locals(locals, || assert_eq!(REGISTRY.all().len(), 1));
+            REGISTRY.with_locals(Vec::new(), || assert!(!REGISTRY.is_set()));
+            assert_eq!(REGISTRY.current().as_deref(), Some("outer"));
+        });
+    }
+}
@@ -0,0 +1,88 @@
+//! This module contains the HTTP status suggested by validation errors.
+//!
+//! Every `LocaleMessage` hints the HTTP status of the response rejecting it, defaulting to the
+//! hint of its `RuleViolation`, so web integrations can tell a conflict, e.g. a username already
+//! taken, from a malformed request. `ValidationReport::suggested_status` combines the hints of a
+//! report, the malformed request winning over the invalid values, themselves winning over the
+//! conflicts, since a conflict only matters once the rest of the request is valid.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::status_hint::StatusHint;
+//! use cjtoolkit_structured_validator::common::validation_report::ValidationReport;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! let mut report = ValidationReport::new();
+//! let _ = report.check("name", Name::parse(Some("No")));
+//!
+//! assert_eq!(report.suggested_status(), Some(StatusHint::UnprocessableEntity));
+//! assert_eq!(StatusHint::UnprocessableEntity.status_code(), 422);
+//! assert_eq!(ValidationReport::new().suggested_status(), None);
+//! ```
+
+/// The HTTP status suggested by a validation error, ordered by precedence.
+///
+/// # Variants
+/// - `Conflict`: The value conflicts with the current state, e.g. it is already taken, `409`.
+/// - `UnprocessableEntity`: The value is well-formed, but does not satisfy the rules, `422`.
+/// - `BadRequest`: The request itself is malformed, e.g. an unknown field or a value of the
+///   wrong type, `400`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StatusHint {
+    Conflict,
+    UnprocessableEntity,
+    BadRequest,
+}
+
+impl StatusHint {
+    /// Returns the HTTP status code of the hint.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::Conflict => 409,
+            Self::UnprocessableEntity => 422,
+            Self::BadRequest => 400,
+        }
+    }
+
+    /// Returns the hint of highest precedence among `hints`, `None` if there are none.
+    pub fn combine(hints: impl IntoIterator<Item = StatusHint>) -> Option<StatusHint> {
+        hints.into_iter().max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::locale::{
+        LocaleMessage, RuleViolation, ValidateErrorCollector, ValidateErrorStore,
+    };
+    use crate::common::validation_report::ValidationReport;
+    use crate::types::form::FormMap;
+    use crate::types::name::Name;
+    use crate::types::username::UsernameTakenLocale;
+
+    fn username_taken() -> ValidateErrorStore {
+        let mut messages = ValidateErrorCollector::new();
+        messages.push(("Username is taken", Box::new(UsernameTakenLocale)));
+        messages.into()
+    }
+
+    #[test]
+    fn test_suggested_status() {
+        assert_eq!(UsernameTakenLocale.status_hint(), StatusHint::Conflict);
+        assert_eq!(
+            RuleViolation::MinLength { min: 5 }.status_hint(),
+            StatusHint::UnprocessableEntity
+        );
+
+        let mut report = ValidationReport::new();
+        report.0.push(("username".to_string(), username_taken()));
+        assert_eq!(report.suggested_status(), Some(StatusHint::Conflict));
+
+        let mut form = FormMap::parse("token=abc").deny_unknown_fields();
+        let _ = form.field("name", Name::parse);
+        let mut report = form.into_report();
+        report.0.push(("username".to_string(), username_taken()));
+        assert_eq!(report.suggested_status(), Some(StatusHint::BadRequest));
+    }
+}
@@ -41,6 +41,22 @@ impl<'a> StringValidator<'a> {
         self.1
     }
 
+    /// Returns the number of Unicode scalar values (`char`) in the string.
+    pub fn count_chars(&self) -> usize {
+        self.0.chars().count()
+    }
+
+    /// Returns the number of bytes of the UTF-8 encoded string.
+    pub fn count_bytes(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the number of UTF-16 code units in the string, matching the length of a
+    /// JavaScript string.
+    pub fn count_utf16_code_units(&self) -> usize {
+        self.0.encode_utf16().count()
+    }
+
     /// Checks whether the current object is empty.
     ///
     /// # Returns
@@ -51,6 +67,11 @@ impl<'a> StringValidator<'a> {
         self.1 == 0
     }
 
+    /// Returns `true` if the string is empty or contains only whitespace.
+    pub fn is_blank(&self) -> bool {
+        self.0.trim().is_empty()
+    }
+
     /// Checks if the string contains any special character from a predefined set.
     ///
     /// # Returns
@@ -182,6 +203,31 @@ impl<'a> StringValidator<'a> {
         self.0.chars().any(|c| c.is_ascii_digit())
     }
 
+    /// Checks if the string contains any control character, such as a null byte, a tab or a
+    /// line feed.
+    pub fn has_control_chars(&self) -> bool {
+        self.0.chars().any(|c| c.is_control())
+    }
+
+    /// Checks if the string contains any zero-width character, such as a zero-width space,
+    /// joiner or non-joiner, a word joiner or a byte order mark.
+    pub fn has_zero_width_chars(&self) -> bool {
+        self.0.chars().any(|c| {
+            matches!(
+                c,
+                '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'
+            )
+        })
+    }
+
+    /// Checks if the string contains any bidirectional embedding, override or isolate
+    /// character, as used by "Trojan Source" attacks.
+    pub fn has_bidi_overrides(&self) -> bool {
+        self.0
+            .chars()
+            .any(|c| matches!(c, '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'))
+    }
+
     /// Counts the number of ASCII digit characters in the string.
     ///
     /// This function iterates through the characters of the string, filters out
@@ -224,6 +270,42 @@ impl<'a> StringValidator<'a> {
     pub fn count_ascii_alphanumeric(&self) -> usize {
         self.0.chars().filter(|c| c.is_ascii_alphanumeric()).count()
     }
+
+    /// Counts the links in the string, i.e. the occurrences of `http://`, `https://`, and of
+    /// `www.` not following either, ignoring case.
+    pub fn count_links(&self) -> usize {
+        let lowercase = self.0.to_ascii_lowercase();
+        let schemes = lowercase.matches("http://").count() + lowercase.matches("https://").count();
+        let hosts = lowercase
+            .match_indices("www.")
+            .filter(|(index, _)| !lowercase[..*index].ends_with("://"))
+            .count();
+        schemes + hosts
+    }
+
+    /// Returns the ratio of uppercase letters among the letters of the string, from `0.0` to
+    /// `1.0`, `0.0` if the string has no letters.
+    pub fn uppercase_ratio(&self) -> f64 {
+        let letters = self.0.chars().filter(|c| c.is_alphabetic()).count();
+        if letters == 0 {
+            return 0.0;
+        }
+        let uppercase = self.0.chars().filter(|c| c.is_uppercase()).count();
+        uppercase as f64 / letters as f64
+    }
+
+    /// Returns the length of the longest run of the same character, e.g. `4` for `Hi!!!!`.
+    pub fn longest_repeated_char_run(&self) -> usize {
+        let mut longest = 0;
+        let mut run = 0;
+        let mut previous = None;
+        for c in self.0.chars() {
+            run = if previous == Some(c) { run + 1 } else { 1 };
+            longest = longest.max(run);
+            previous = Some(c);
+        }
+        longest
+    }
 }
 
 trait StrSealed {}
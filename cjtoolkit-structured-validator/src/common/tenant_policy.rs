@@ -0,0 +1,189 @@
+//! This module contains the `TenantPolicy` type, holding the rules of each tenant of a
+//! multi-tenant application.
+//!
+//! A policy has the base rules, and the rules of the tenants which differ, e.g. a stricter
+//! password policy or a different maximum length. The `MergeStrategy` decides whether the rules
+//! of a tenant replace the base rules, or can only make them stricter.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::tenant_policy::{MergeStrategy, TenantPolicy};
+//! use cjtoolkit_structured_validator::types::name::{Name, NameRules};
+//!
+//! let policy = TenantPolicy::new(NameRules::default(), MergeStrategy::TightenOnly).with_override(
+//!     "acme",
+//!     NameRules {
+//!         max_length: Some(10),
+//!         ..NameRules::default()
+//!     },
+//! );
+//!
+//! let long_name = Some("Bartholomew");
+//! assert!(policy.parse_with_policy::<_, Name, _>("other", long_name).is_ok());
+//! assert!(policy.parse_with_policy::<_, Name, _>("acme", long_name).is_err());
+//! ```
+
+use crate::common::key_namespace::{KeyNamespace, with_key_namespace};
+use crate::common::validator::Validator;
+use std::collections::HashMap;
+
+/// How the rules of a tenant are merged with the base rules.
+///
+/// # Variants
+/// - `Override`: The rules of the tenant replace the base rules.
+/// - `TightenOnly`: The rules of the tenant are merged with the base rules, keeping the
+///   stricter of each rule, so a tenant cannot loosen the base rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    #[default]
+    Override,
+    TightenOnly,
+}
+
+/// A trait for rules which can be merged, keeping the stricter of each rule.
+///
+/// Implemented by the rules of the string and number types.
+pub trait TightenRules {
+    fn tighten(&self, other: &Self) -> Self;
+}
+
+/// The rules of each tenant, falling back to the base rules for tenants without their own.
+///
+/// # Fields
+/// - `base`: The rules of every tenant without its own rules.
+/// - `overrides`: The rules of each tenant, by tenant ID.
+/// - `strategy`: How the rules of a tenant are merged with the base rules.
+/// - `key_namespace`: The namespace of the locale keys of the errors, see `KeyNamespace`.
+#[derive(Clone)]
+pub struct TenantPolicy<R> {
+    pub base: R,
+    pub overrides: HashMap<String, R>,
+    pub strategy: MergeStrategy,
+    pub key_namespace: Option<KeyNamespace>,
+}
+
+impl<R: Clone + TightenRules> TenantPolicy<R> {
+    /// Creates a policy with the `base` rules, and no tenant with its own rules.
+    pub fn new(base: R, strategy: MergeStrategy) -> Self {
+        Self {
+            base,
+            overrides: HashMap::new(),
+            strategy,
+            key_namespace: None,
+        }
+    }
+
+    /// Sets the rules of the tenant `tenant_id`.
+    pub fn with_override(mut self, tenant_id: impl Into<String>, rules: R) -> Self {
+        self.overrides.insert(tenant_id.into(), rules);
+        self
+    }
+
+    /// Sets the namespace of the locale keys of the errors of the policy.
+    pub fn with_key_namespace(mut self, namespace: KeyNamespace) -> Self {
+        self.key_namespace = Some(namespace);
+        self
+    }
+
+    /// Returns the rules in force for the tenant `tenant_id`.
+    pub fn rules_for(&self, tenant_id: &str) -> R {
+        match (self.overrides.get(tenant_id), self.strategy) {
+            (None, _) => self.base.clone(),
+            (Some(rules), MergeStrategy::Override) => rules.clone(),
+            (Some(rules), MergeStrategy::TightenOnly) => self.base.tighten(rules),
+        }
+    }
+
+    /// Validates `input` with the rules in force for the tenant `tenant_id`.
+    ///
+    /// # Errors
+    /// Returns the error of the rules if `input` does not satisfy them.
+    pub fn parse_with_policy<I, O, E>(&self, tenant_id: &str, input: I) -> Result<O, E>
+    where
+        R: Validator<I, O, E>,
+    {
+        let rules = self.rules_for(tenant_id);
+        match &self.key_namespace {
+            Some(namespace) => with_key_namespace(namespace.clone(), || rules.validate(input)),
+            None => rules.validate(input),
+        }
+    }
+}
+
+/// Returns the stricter of two lower bounds, the highest.
+pub(crate) fn tighter_min<T: PartialOrd + Copy>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if b > a { b } else { a }),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Returns the stricter of two upper bounds, the lowest.
+pub(crate) fn tighter_max<T: PartialOrd + Copy>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if b < a { b } else { a }),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Returns the stricter of two lists of allowed values, the values in both.
+pub(crate) fn tighter_allowed<T: PartialEq + Clone>(
+    a: &Option<Vec<T>>,
+    b: &Option<Vec<T>>,
+) -> Option<Vec<T>> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.iter().filter(|v| b.contains(v)).cloned().collect()),
+        (a, b) => a.clone().or_else(|| b.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::numbers::integer::{Integer, IntegerRules};
+
+    fn policy(strategy: MergeStrategy) -> TenantPolicy<IntegerRules> {
+        TenantPolicy::new(IntegerRules::default(), strategy).with_override(
+            "acme",
+            IntegerRules {
+                min: Some(10),
+                max: Some(1000),
+                ..IntegerRules::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_override() {
+        let policy = policy(MergeStrategy::Override);
+        assert!(
+            policy
+                .parse_with_policy::<_, Integer, _>("acme", Some(500))
+                .is_ok()
+        );
+        assert!(
+            policy
+                .parse_with_policy::<_, Integer, _>("other", Some(500))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_key_namespace() {
+        let policy = policy(MergeStrategy::Override)
+            .with_key_namespace(KeyNamespace::new("acme").with_separator("-"));
+        let error = policy
+            .parse_with_policy::<_, Integer, _>("acme", Some(5))
+            .expect_err("Expected integer to be too small");
+        assert_eq!(
+            error.0.0[0].1.get_locale_data().name,
+            "acme-validate-number-min-value"
+        );
+    }
+
+    #[test]
+    fn test_tighten_only() {
+        let rules = policy(MergeStrategy::TightenOnly).rules_for("acme");
+        assert_eq!((rules.min, rules.max), (Some(10), Some(255)));
+    }
+}
@@ -0,0 +1,76 @@
+//! This module contains the `Translator` trait, the one trait an i18n backend implements to
+//! translate the errors of the crate, e.g. with a Fluent bundle or a `poem::i18n::Locale`.
+//!
+//! `TranslateStore` then translates the messages of a `ValidateErrorStore`, or of the error of a
+//! `Result`, with any translator. Closures taking the locale data and the original message are
+//! translators too.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::locale::LocaleData;
+//! use cjtoolkit_structured_validator::common::locale_keys;
+//! use cjtoolkit_structured_validator::common::translator::{TranslateStore, Translator};
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! struct French;
+//!
+//! impl Translator for French {
+//!     fn translate(&self, data: &LocaleData, original: &str) -> String {
+//!         match data.name.as_str() {
+//!             locale_keys::VALIDATE_CANNOT_BE_EMPTY => "Ne peut pas être vide".to_string(),
+//!             _ => original.to_string(),
+//!         }
+//!     }
+//! }
+//!
+//! let result = Name::parse(Some(""));
+//! assert_eq!(result.as_translated_messages(&French)[0], "Ne peut pas être vide");
+//! assert!(Name::parse(Some("Alice Smith")).as_translated_messages(&French).is_empty());
+//! ```
+
+use crate::common::locale::{LocaleData, ValidateErrorStore};
+use crate::common::validation_collector::AsValidateErrorStore;
+use std::sync::Arc;
+
+/// A trait for translating an error from its locale data, its key and arguments, falling back
+/// on its original English message when the key has no translation.
+pub trait Translator {
+    fn translate(&self, data: &LocaleData, original: &str) -> String;
+}
+
+impl<F> Translator for F
+where
+    F: Fn(&LocaleData, &str) -> String,
+{
+    fn translate(&self, data: &LocaleData, original: &str) -> String {
+        self(data, original)
+    }
+}
+
+/// A trait for translating the messages of the errors of a `ValidateErrorStore`, implemented for
+/// the store and for `Result`, with no messages when the result is `Ok`.
+pub trait TranslateStore {
+    fn as_translated_messages<T: Translator + ?Sized>(&self, translator: &T) -> Vec<String>;
+
+    fn as_translated_messages_arc<T: Translator + ?Sized>(&self, translator: &T) -> Arc<[String]> {
+        self.as_translated_messages(translator).into()
+    }
+}
+
+impl TranslateStore for ValidateErrorStore {
+    fn as_translated_messages<T: Translator + ?Sized>(&self, translator: &T) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|e| translator.translate(&e.1.get_locale_data(), &e.0))
+            .collect()
+    }
+}
+
+impl<V, E> TranslateStore for Result<V, E>
+where
+    for<'a> &'a E: Into<ValidateErrorStore>,
+{
+    fn as_translated_messages<T: Translator + ?Sized>(&self, translator: &T) -> Vec<String> {
+        self.as_validate_store().as_translated_messages(translator)
+    }
+}
@@ -0,0 +1,183 @@
+//! This module contains the `UniqueWithin` type, checking a value is unique within a database
+//! transaction, e.g. the email of a user or the slug of a post, with one error for every type.
+//!
+//! The existence check is a closure returning a future over the borrowed transaction, so it
+//! sees the rows inserted by the transaction, and the error is `validate-already-exists`, with
+//! the `entity` and the `field` as arguments, suggesting `409 Conflict`.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::unique::UniqueWithin;
+//! use cjtoolkit_structured_validator::types::username::Username;
+//! use std::sync::Mutex;
+//!
+//! struct Transaction {
+//!     usernames: Mutex<Vec<String>>,
+//! }
+//!
+//! impl Transaction {
+//!     async fn username_exists(&self, username: &str) -> bool {
+//!         self.usernames.lock().expect("Expected lock").iter().any(|u| u == username)
+//!     }
+//! }
+//!
+//! # tokio::runtime::Runtime::new().expect("Expected runtime").block_on(async {
+//! let tx = Transaction {
+//!     usernames: Mutex::new(vec!["alice".to_string()]),
+//! };
+//! let unique = UniqueWithin::<Transaction>::new("user", "username");
+//!
+//! let username = Username::parse(Some("alice")).expect("Expected valid username");
+//! let error = unique
+//!     .check(&tx, username.as_str(), |tx, username| tx.username_exists(username))
+//!     .await
+//!     .expect_err("Expected username to exist");
+//! assert_eq!(error.0.as_original_message_vec(), vec!["A user with this username already exists"]);
+//!
+//! let username = Username::parse(Some("bobby")).expect("Expected valid username");
+//! assert!(unique.check(&tx, username.as_str(), |tx, u| tx.username_exists(u)).await.is_ok());
+//! # });
+//! ```
+
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::observer::rule_result;
+use crate::common::validation_check::ValidationCheck;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// The locale of a value which already exists.
+///
+/// # Key
+/// `validate-already-exists`
+pub struct AlreadyExistsLocale {
+    pub entity: String,
+    pub field: String,
+}
+
+impl LocaleMessage for AlreadyExistsLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_ALREADY_EXISTS,
+            vec![
+                (
+                    "entity".to_string(),
+                    LocaleValue::from(self.entity.as_str()),
+                ),
+                ("field".to_string(), LocaleValue::from(self.field.as_str())),
+            ],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Taken
+    }
+}
+
+/// Represents an error that occurs when a value is not unique.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Uniqueness Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Uniqueness Validation Error")]
+pub struct UniqueError(pub ValidateErrorStore);
+
+impl ValidationCheck for UniqueError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&UniqueError> for ValidateErrorStore {
+    fn from(error: &UniqueError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// Checks a `field` of an `entity` is unique within a transaction of type `Tx`, see the module
+/// documentation.
+///
+/// # Fields
+/// - `entity`: The name of the entity, e.g. `user`.
+/// - `field`: The name of the field, e.g. `email`.
+pub struct UniqueWithin<Tx: ?Sized> {
+    pub entity: String,
+    pub field: String,
+    transaction: PhantomData<fn(&Tx)>,
+}
+
+impl<Tx: ?Sized> Clone for UniqueWithin<Tx> {
+    fn clone(&self) -> Self {
+        Self::new(&self.entity, &self.field)
+    }
+}
+
+impl<Tx: ?Sized> UniqueWithin<Tx> {
+    /// Creates the check of the `field` of `entity`.
+    pub fn new(entity: &str, field: &str) -> Self {
+        Self {
+            entity: entity.to_string(),
+            field: field.to_string(),
+            transaction: PhantomData,
+        }
+    }
+
+    /// Checks `value` does not exist in `tx`, calling `exists` with the transaction and the
+    /// value.
+    ///
+    /// # Errors
+    /// Returns a `UniqueError` with a `validate-already-exists` error if `exists` resolves to
+    /// `true`.
+    pub async fn check<'a, F, Fut>(
+        &self,
+        tx: &'a Tx,
+        value: &'a str,
+        exists: F,
+    ) -> Result<(), UniqueError>
+    where
+        F: FnOnce(&'a Tx, &'a str) -> Fut,
+        Fut: Future<Output = bool> + 'a,
+    {
+        let exists = exists(tx, value).await;
+        rule_result(locale_keys::VALIDATE_ALREADY_EXISTS, !exists);
+        let mut messages = ValidateErrorCollector::new();
+        if exists {
+            messages.push((
+                format!("A {} with this {} already exists", self.entity, self.field),
+                Box::new(AlreadyExistsLocale {
+                    entity: self.entity.clone(),
+                    field: self.field.clone(),
+                }),
+            ));
+        }
+        UniqueError::validate_check(messages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::status_hint::StatusHint;
+
+    #[tokio::test]
+    async fn test_conflict_error() {
+        let unique = UniqueWithin::<[&str]>::new("post", "slug");
+        let slugs: &[&str] = &["hello-world"];
+        let error = unique
+            .check(slugs, "hello-world", |slugs, slug| async move {
+                slugs.contains(&slug)
+            })
+            .await
+            .expect_err("Expected slug to exist");
+        assert_eq!(error.0.status_hint(), Some(StatusHint::Conflict));
+        let data = error.0.0[0].1.get_locale_data();
+        assert_eq!(data.args.get("field"), Some(&LocaleValue::from("slug")));
+    }
+}
@@ -1,6 +1,29 @@
 //! This module contains structures and traits for performing validation checks.
+//!
+//! `ValidationCheckWithContext` lets an error type also capture the `ValidationContext` in force,
+//! the name of the rule preset or policy and a hash of its rules, to debug which configuration
+//! rejected a request in multi-tenant deployments. `ContextError` adds it to the built-in errors.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::validation_check::{
+//!     ValidationContext, WithValidationContext,
+//! };
+//! use cjtoolkit_structured_validator::types::name::{Name, NameRules};
+//!
+//! let rules = NameRules::default();
+//! let context = ValidationContext::new("tenant-a/strict").with_constraints(&rules.describe());
+//!
+//! let error = Name::parse_custom(Some("No"), rules)
+//!     .with_validation_context(context.clone())
+//!     .expect_err("Expected the name to be too short");
+//! assert_eq!(error.context.as_ref().map(|c| c.policy.as_str()), Some("tenant-a/strict"));
+//! assert_eq!(error.context, Some(context));
+//! ```
 
+use crate::common::constraint::Constraint;
 use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use std::fmt::{Display, Formatter};
 
 /// A trait for performing validation checks and handling validation-related errors.
 ///
@@ -41,6 +64,11 @@ use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
 /// The default implementation checks if the provided `messages` is empty. If it is empty, it returns an `Ok(())`.
 /// Otherwise, it converts the messages into a `ValidateErrorStore` and creates a new validation error instance using `validate_new`.
 ///
+/// ## `validate_check_dedup`
+///
+/// Like `validate_check`, removing the duplicate errors first, see
+/// `ValidateErrorCollector::dedup`, e.g. when a custom rule layers base rules that report the
+/// same error.
 ///
 pub trait ValidationCheck: Sized {
     fn validate_new(messages: ValidateErrorStore) -> Self;
@@ -52,6 +80,138 @@ pub trait ValidationCheck: Sized {
             Err(Self::validate_new(messages.into()))
         }
     }
+
+    fn validate_check_dedup(messages: ValidateErrorCollector) -> Result<(), Self> {
+        if messages.is_empty() {
+            Ok(())
+        } else {
+            Err(Self::validate_new(messages.into_store_dedup()))
+        }
+    }
+}
+
+/// The rule preset or policy in force when a value was validated.
+///
+/// # Fields
+/// - `policy`: The name of the preset or policy, e.g. `tenant-a/strict`.
+/// - `rules_hash`: The hex encoded hash of the constraints of the rules, `None` if not set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationContext {
+    pub policy: String,
+    pub rules_hash: Option<String>,
+}
+
+impl ValidationContext {
+    /// Creates the context of the `policy`, without a hash of the rules.
+    pub fn new(policy: impl Into<String>) -> Self {
+        Self {
+            policy: policy.into(),
+            rules_hash: None,
+        }
+    }
+
+    /// Sets the hash of the rules from their constraints, as listed by their `describe`
+    /// method, so two configurations of the same policy can be told apart.
+    pub fn with_constraints(mut self, constraints: &[Constraint]) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        for constraint in constraints {
+            hasher.update(format!("{:?}", constraint).as_bytes());
+        }
+        self.rules_hash = Some(hasher.finalize().to_hex().to_string());
+        self
+    }
+}
+
+impl Display for ValidationContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.rules_hash {
+            Some(rules_hash) => write!(f, "{} ({})", self.policy, rules_hash),
+            None => f.write_str(&self.policy),
+        }
+    }
+}
+
+/// A `ValidationCheck` whose errors also capture the `ValidationContext` in force.
+///
+/// # Required Methods
+/// - `validate_new_with_context`: Creates the error from the messages and the context.
+/// - `context`: Returns the context captured by the error, if any.
+///
+/// # Provided Methods
+/// - `validate_check_with_context`: Like `validate_check`, capturing the `context`.
+pub trait ValidationCheckWithContext: ValidationCheck {
+    fn validate_new_with_context(messages: ValidateErrorStore, context: ValidationContext) -> Self;
+
+    fn context(&self) -> Option<&ValidationContext>;
+
+    fn validate_check_with_context(
+        messages: ValidateErrorCollector,
+        context: ValidationContext,
+    ) -> Result<(), Self> {
+        if messages.is_empty() {
+            Ok(())
+        } else {
+            Err(Self::validate_new_with_context(messages.into(), context))
+        }
+    }
+}
+
+/// An error with the `ValidationContext` it was reported in, e.g. `ContextError<NameError>`.
+///
+/// # Fields
+/// - `error`: The error.
+/// - `context`: The context, `None` if the error was created without one.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ContextError<E> {
+    pub error: E,
+    pub context: Option<ValidationContext>,
+}
+
+impl<E: Display> Display for ContextError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.context {
+            Some(context) => write!(f, "{} [{}]", self.error, context),
+            None => self.error.fmt(f),
+        }
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for ContextError<E> {}
+
+impl<E: ValidationCheck> ValidationCheck for ContextError<E> {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self {
+            error: E::validate_new(messages),
+            context: None,
+        }
+    }
+}
+
+impl<E: ValidationCheck> ValidationCheckWithContext for ContextError<E> {
+    fn validate_new_with_context(messages: ValidateErrorStore, context: ValidationContext) -> Self {
+        Self {
+            error: E::validate_new(messages),
+            context: Some(context),
+        }
+    }
+
+    fn context(&self) -> Option<&ValidationContext> {
+        self.context.as_ref()
+    }
+}
+
+/// Attaches a `ValidationContext` to the error of a parse result.
+pub trait WithValidationContext<T, E> {
+    fn with_validation_context(self, context: ValidationContext) -> Result<T, ContextError<E>>;
+}
+
+impl<T, E> WithValidationContext<T, E> for Result<T, E> {
+    fn with_validation_context(self, context: ValidationContext) -> Result<T, ContextError<E>> {
+        self.map_err(|error| ContextError {
+            error,
+            context: Some(context),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -79,4 +239,50 @@ mod tests {
         let messages = ValidateErrorCollector::new();
         assert!(TestValidationCheck::validate_check(messages).is_ok());
     }
+
+    #[test]
+    fn test_validate_check_dedup() {
+        use crate::types::name::NameError;
+        let mut messages = ValidateErrorCollector::new();
+        messages.push((
+            "Cannot be empty".to_string(),
+            Box::new(StringMandatoryLocale),
+        ));
+        messages.push((
+            "Cannot be empty".to_string(),
+            Box::new(StringMandatoryLocale),
+        ));
+        let error = NameError::validate_check_dedup(messages).expect_err("Expected error");
+        assert_eq!(error.0.as_original_message_vec(), vec!["Cannot be empty"]);
+        assert!(NameError::validate_check_dedup(ValidateErrorCollector::new()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_check_with_context() {
+        let mut messages = ValidateErrorCollector::new();
+        messages.push(("error".to_string(), Box::new(StringMandatoryLocale)));
+        let context = ValidationContext::new("strict");
+        let error = ContextError::<TestValidationCheck>::validate_check_with_context(
+            messages,
+            context.clone(),
+        )
+        .expect_err("Expected error");
+        assert_eq!(error.context(), Some(&context));
+    }
+
+    #[test]
+    fn test_rules_hash_tells_configurations_apart() {
+        use crate::types::name::NameRules;
+        let strict = NameRules {
+            max_length: Some(10),
+            ..NameRules::default()
+        };
+        let hash = |rules: &NameRules| {
+            ValidationContext::new("names")
+                .with_constraints(&rules.describe())
+                .rules_hash
+        };
+        assert_eq!(hash(&NameRules::default()), hash(&NameRules::default()));
+        assert_ne!(hash(&NameRules::default()), hash(&strict));
+    }
 }
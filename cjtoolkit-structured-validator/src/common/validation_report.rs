@@ -0,0 +1,269 @@
+//! This module contains a report of the validation errors of a record, by field.
+//!
+//! The fields of nested records are keyed by their path, e.g. `items[2].name`, see `FieldPath`,
+//! which can be rendered as a JSON Pointer, `/items/2/name`, or as a dotted path,
+//! `items.2.name`, to map the errors back to the controls of a form.
+
+use crate::common::locale::{KeyedMessage, ValidateErrorStore};
+use crate::common::status_hint::StatusHint;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// A segment of a `FieldPath`, either the name of a field or the index of an item.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+/// The path of a field in a nested record, e.g. `items[2].name`.
+///
+/// It is displayed, and parsed, with the names of the fields separated by dots and the indexes
+/// of the items in brackets, the format of the fields of a `ValidationReport`.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::validation_report::FieldPath;
+///
+/// let path = FieldPath::new().field("items").index(2).field("name");
+/// assert_eq!(path.to_string(), "items[2].name");
+/// assert_eq!(path.to_json_pointer(), "/items/2/name");
+/// assert_eq!(path.to_dotted(), "items.2.name");
+/// assert_eq!(FieldPath::parse("items[2].name"), path);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FieldPath(pub Vec<PathSegment>);
+
+impl FieldPath {
+    /// Creates an empty path, the record itself.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Returns the path with the field `name` appended.
+    pub fn field(mut self, name: &str) -> Self {
+        self.0.push(PathSegment::Field(name.to_string()));
+        self
+    }
+
+    /// Returns the path with the item `index` appended.
+    pub fn index(mut self, index: usize) -> Self {
+        self.0.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Returns the path with the segments of `path` appended.
+    pub fn join(mut self, path: &FieldPath) -> Self {
+        self.0.extend(path.0.iter().cloned());
+        self
+    }
+
+    /// Parses a path displayed by `FieldPath`, e.g. `items[2].name`. The text in brackets which
+    /// is not an index is kept as the name of a field.
+    pub fn parse(path: &str) -> Self {
+        let mut segments = Vec::new();
+        let mut rest = path;
+        while !rest.is_empty() {
+            if let Some(bracketed) = rest.strip_prefix('[') {
+                let end = bracketed.find(']').unwrap_or(bracketed.len());
+                let inner = &bracketed[..end];
+                segments.push(match inner.parse() {
+                    Ok(index) => PathSegment::Index(index),
+                    Err(_) => PathSegment::Field(inner.to_string()),
+                });
+                rest = bracketed.get(end + 1..).unwrap_or_default();
+            } else {
+                let rest_field = rest.strip_prefix('.').unwrap_or(rest);
+                let end = rest_field.find(['.', '[']).unwrap_or(rest_field.len());
+                segments.push(PathSegment::Field(rest_field[..end].to_string()));
+                rest = &rest_field[end..];
+            }
+        }
+        Self(segments)
+    }
+
+    /// Returns the path as a JSON Pointer, e.g. `/items/2/name`, empty for the record itself.
+    pub fn to_json_pointer(&self) -> String {
+        self.0
+            .iter()
+            .map(|segment| match segment {
+                PathSegment::Field(name) => {
+                    format!("/{}", name.replace('~', "~0").replace('/', "~1"))
+                }
+                PathSegment::Index(index) => format!("/{}", index),
+            })
+            .collect()
+    }
+
+    /// Returns the path with every segment separated by dots, e.g. `items.2.name`.
+    pub fn to_dotted(&self) -> String {
+        self.0
+            .iter()
+            .map(|segment| match segment {
+                PathSegment::Field(name) => name.clone(),
+                PathSegment::Index(index) => index.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+}
+
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => f.write_str(name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for FieldPath {
+    fn from(path: &str) -> Self {
+        Self::parse(path)
+    }
+}
+
+/// The validation errors of a record, as a list of field names and error stores.
+///
+/// Fields without errors are not kept.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport(pub Vec<(String, ValidateErrorStore)>);
+
+impl ValidationReport {
+    /// Creates an empty report.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Records the error of `result` under `field`, then returns `result` unchanged.
+    pub fn check<T, E>(&mut self, field: &str, result: Result<T, E>) -> Result<T, E>
+    where
+        for<'a> &'a E: Into<ValidateErrorStore>,
+    {
+        if let Err(e) = &result {
+            self.0.push((field.to_string(), e.into()));
+        }
+        result
+    }
+
+    /// Returns `true` if no field has errors.
+    pub fn is_valid(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the names of the fields with errors.
+    pub fn fields(&self) -> Vec<&str> {
+        self.0.iter().map(|(field, _)| field.as_str()).collect()
+    }
+
+    /// Returns the total number of errors, across every field.
+    pub fn error_count(&self) -> usize {
+        self.0.iter().map(|(_, store)| store.0.len()).sum()
+    }
+
+    /// Returns the HTTP status suggested by the errors of every field, `None` if no field has
+    /// errors.
+    pub fn suggested_status(&self) -> Option<StatusHint> {
+        StatusHint::combine(self.0.iter().filter_map(|(_, store)| store.status_hint()))
+    }
+
+    /// Records `errors` under the field at `path`.
+    pub fn push_path(&mut self, path: &FieldPath, errors: ValidateErrorStore) {
+        self.0.push((path.to_string(), errors));
+    }
+
+    /// Records the errors of `report`, the report of a nested record, under the field at
+    /// `prefix`, e.g. `name` becoming `items[2].name`, at any depth.
+    pub fn nest(&mut self, prefix: &FieldPath, report: ValidationReport) {
+        for (field, errors) in report.0 {
+            self.push_path(&prefix.clone().join(&FieldPath::parse(&field)), errors);
+        }
+    }
+
+    /// Returns the paths of the fields with errors.
+    pub fn paths(&self) -> Vec<FieldPath> {
+        self.0
+            .iter()
+            .map(|(field, _)| FieldPath::parse(field))
+            .collect()
+    }
+
+    /// Returns the errors keyed by the JSON Pointer of their field, e.g. `/items/2/name`.
+    pub fn by_json_pointer(&self) -> Vec<(String, &ValidateErrorStore)> {
+        self.0
+            .iter()
+            .map(|(field, errors)| (FieldPath::parse(field).to_json_pointer(), errors))
+            .collect()
+    }
+
+    /// Returns the errors keyed by the dotted path of their field, e.g. `items.2.name`.
+    pub fn by_dotted_path(&self) -> Vec<(String, &ValidateErrorStore)> {
+        self.0
+            .iter()
+            .map(|(field, errors)| (FieldPath::parse(field).to_dotted(), errors))
+            .collect()
+    }
+
+    /// Returns the errors of each field as `KeyedMessage`s, the errors of a field recorded twice
+    /// being merged.
+    pub fn as_message_map(&self) -> BTreeMap<String, Vec<KeyedMessage>> {
+        let mut messages: BTreeMap<String, Vec<KeyedMessage>> = BTreeMap::new();
+        for (field, errors) in &self.0 {
+            messages
+                .entry(field.clone())
+                .or_default()
+                .extend(errors.as_keyed_messages());
+        }
+        messages
+    }
+
+    /// Returns `Ok(())` if no field has errors, otherwise `Err(self)`.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_valid() { Ok(()) } else { Err(self) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::Name;
+
+    #[test]
+    fn test_nested_paths() {
+        let mut item = ValidationReport::new();
+        let _ = item.check("name", Name::parse(Some("")));
+        let mut order = ValidationReport::new();
+        order.nest(&FieldPath::new().field("items").index(2), item);
+        let mut report = ValidationReport::new();
+        report.nest(&FieldPath::parse("orders[0]"), order);
+
+        assert_eq!(report.fields(), vec!["orders[0].items[2].name"]);
+        let pointers: Vec<String> = report
+            .by_json_pointer()
+            .into_iter()
+            .map(|(pointer, _)| pointer)
+            .collect();
+        assert_eq!(pointers, vec!["/orders/0/items/2/name"]);
+        assert_eq!(
+            report.by_dotted_path()[0].0,
+            "orders.0.items.2.name".to_string()
+        );
+        assert_eq!(FieldPath::parse("a/b~c").to_json_pointer(), "/a~1b~0c");
+    }
+
+    #[test]
+    fn test_message_map_merges_fields() {
+        let mut report = ValidationReport::new();
+        let _ = report.check("name", Name::parse(Some("")));
+        let _ = report.check("name", Name::parse(Some("No")));
+        let messages = report.as_message_map();
+        assert_eq!(messages.len(), 1);
+        let keys: Vec<&str> = messages["name"].iter().map(|m| m.key.as_str()).collect();
+        assert!(keys.contains(&crate::common::locale_keys::VALIDATE_MIN_LENGTH));
+        assert_eq!(messages["name"].len(), 2);
+    }
+}
@@ -0,0 +1,55 @@
+//! This module contains a trait for treating validators uniformly.
+
+/// A trait for validating an input into an output, or an error.
+///
+/// The rules of every type implement this trait by parsing the input with a copy of
+/// themselves, e.g. `NameRules: Validator<Option<&str>, Name, NameError>`, so generic code
+/// can hold validators of different kinds, including as `Box<dyn Validator<...>>`. The types
+/// validated against a scheme or a pattern, `NationalId` and `Postcode`, implement it for the
+/// pair of the rules and the scheme or pattern.
+///
+/// The trait is also implemented for functions and closures taking the input and returning
+/// a `Result`, e.g. `Name::parse`.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::validator::Validator;
+/// use cjtoolkit_structured_validator::types::name::{Name, NameError, NameRules};
+///
+/// let validators: Vec<Box<dyn for<'a> Validator<Option<&'a str>, Name, NameError>>> = vec![
+///     Box::new(NameRules::default()),
+///     Box::new(Name::parse),
+/// ];
+///
+/// for validator in &validators {
+///     assert!(validator.validate(Some("ValidName")).is_ok());
+///     assert!(validator.validate(Some("No")).is_err());
+/// }
+/// ```
+pub trait Validator<Input, Output, Error> {
+    fn validate(&self, input: Input) -> Result<Output, Error>;
+}
+
+impl<Input, Output, Error, F> Validator<Input, Output, Error> for F
+where
+    F: Fn(Input) -> Result<Output, Error>,
+{
+    fn validate(&self, input: Input) -> Result<Output, Error> {
+        self(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate_all<V: Validator<u8, u8, ()>>(validator: &V, inputs: &[u8]) -> Vec<Result<u8, ()>> {
+        inputs.iter().map(|i| validator.validate(*i)).collect()
+    }
+
+    #[test]
+    fn test_closure_validator() {
+        let validator = |i: u8| if i > 1 { Ok(i) } else { Err(()) };
+        assert_eq!(validate_all(&validator, &[1, 2]), vec![Err(()), Ok(2)]);
+    }
+}
@@ -0,0 +1,237 @@
+//! This module contains the `ValueFormatter` trait, formatting the bounds passed as locale
+//! arguments in the language of the user, e.g. `1 000,5` or `30.06.2025`.
+//!
+//! By default, numbers are passed as `LocaleValue` numbers and dates in their English format.
+//! A formatter registered globally with `set_global_value_formatter`, or for the duration of a
+//! call with `with_value_formatter`, e.g. around `parse_custom_with_format`, replaces the `min`
+//! and `max` arguments of the range errors with the formatted strings when the errors are
+//! collected. The rule violations keep the values unformatted, for the machines.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::locale::LocaleValue;
+//! use cjtoolkit_structured_validator::common::value_formatter::{NumberFormat, with_value_formatter};
+//! use cjtoolkit_structured_validator::types::numbers::float::{Float, FloatRules};
+//! use std::sync::Arc;
+//!
+//! let rules = FloatRules {
+//!     max: Some(1000.5),
+//!     ..FloatRules::default()
+//! };
+//! let error = with_value_formatter(Arc::new(NumberFormat::new(',', Some(' '))), || {
+//!     Float::parse_custom(Some(2000.0), rules)
+//! })
+//! .expect_err("Expected float to be too large");
+//! let data = error.0.0[0].1.get_locale_data();
+//! assert_eq!(data.args["max"], LocaleValue::from("1 000,5"));
+//! ```
+
+#[cfg(any(
+    feature = "chrono",
+    feature = "time",
+    feature = "jiff",
+    feature = "humantime"
+))]
+use crate::base::date_time::data::DateTimeData;
+use crate::common::locale::LocaleValue;
+use crate::common::scoped_registry::{LocalStack, ScopedRegistry};
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// A trait for formatting the bounds passed as locale arguments.
+///
+/// Both methods return `None` by default, keeping the value as is, so implementors only
+/// override what they need.
+///
+/// # Provided Methods
+/// - `format_number`: Formats a numeric bound, e.g. the `max` of `validate-number-max-value`.
+/// - `format_date_time`: Formats a date or time bound, e.g. the `min` of `validate-date-min`.
+pub trait ValueFormatter: Send + Sync {
+    fn format_number(&self, value: &LocaleValue) -> Option<String> {
+        let _ = value;
+        None
+    }
+
+    #[cfg(any(
+        feature = "chrono",
+        feature = "time",
+        feature = "jiff",
+        feature = "humantime"
+    ))]
+    fn format_date_time(&self, value: &DateTimeData) -> Option<String> {
+        let _ = value;
+        None
+    }
+}
+
+/// A `ValueFormatter` for numbers, with the separators of a language.
+///
+/// # Fields
+/// - `decimal_separator`: The separator of the fraction, e.g. `,` in French.
+/// - `group_separator`: The separator of the groups of thousands, e.g. ` ` in French, `None`
+///   to not group them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal_separator: char,
+    pub group_separator: Option<char>,
+}
+
+impl NumberFormat {
+    /// Creates a format with `decimal_separator` and `group_separator`.
+    pub fn new(decimal_separator: char, group_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            group_separator,
+        }
+    }
+
+    fn format_digits(&self, formatted: &str) -> String {
+        let (sign, unsigned) = match formatted.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", formatted),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+        let mut result = sign.to_string();
+        for (i, digit) in integer.chars().enumerate() {
+            let is_group_start = i > 0 && (integer.len() - i) % 3 == 0;
+            if let Some(separator) = self.group_separator.filter(|_| is_group_start) {
+                result.push(separator);
+            }
+            result.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            result.push(self.decimal_separator);
+            result.push_str(fraction);
+        }
+        result
+    }
+}
+
+impl ValueFormatter for NumberFormat {
+    fn format_number(&self, value: &LocaleValue) -> Option<String> {
+        match value {
+            LocaleValue::Uint(n) => Some(self.format_digits(&n.to_string())),
+            LocaleValue::Int(n) => Some(self.format_digits(&n.to_string())),
+            LocaleValue::Float(n) if n.is_finite() => Some(self.format_digits(&n.to_string())),
+            LocaleValue::String(s) if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) => {
+                Some(self.format_digits(s))
+            }
+            _ => None,
+        }
+    }
+}
+
+thread_local! {
+    static LOCAL: LocalStack<dyn ValueFormatter> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) static REGISTRY: ScopedRegistry<dyn ValueFormatter> = ScopedRegistry::new(&LOCAL);
+
+/// Registers `formatter` for every validation, replacing the previous global formatter.
+pub fn set_global_value_formatter(formatter: Arc<dyn ValueFormatter>) {
+    REGISTRY.set_global(formatter);
+}
+
+/// Removes the global formatter.
+pub fn clear_global_value_formatter() {
+    REGISTRY.clear_global();
+}
+
+/// Registers `formatter` for the validations run by `f` on the current thread, then returns the
+/// result of `f`. It takes precedence over the global formatter.
+pub fn with_value_formatter<R>(formatter: Arc<dyn ValueFormatter>, f: impl FnOnce() -> R) -> R {
+    REGISTRY.with(formatter, f)
+}
+
+/// Returns the innermost registered formatter, `None` if no formatter is registered.
+pub(crate) fn current_formatter() -> Option<Arc<dyn ValueFormatter>> {
+    REGISTRY.current()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::locale::RuleViolation;
+    use crate::types::numbers::integer::{Integer, IntegerRules};
+
+    #[test]
+    fn test_number_format() {
+        let format = NumberFormat::new(',', Some('.'));
+        let formatted = |value: LocaleValue| format.format_number(&value);
+        assert_eq!(
+            formatted(LocaleValue::Uint(1_234_567)),
+            Some("1.234.567".into())
+        );
+        assert_eq!(formatted(LocaleValue::Int(-1_000)), Some("-1.000".into()));
+        assert_eq!(formatted(LocaleValue::Int(100)), Some("100".into()));
+        assert_eq!(
+            formatted(LocaleValue::Float(-12345.25)),
+            Some("-12.345,25".into())
+        );
+        assert_eq!(formatted(LocaleValue::Float(f64::NAN)), None);
+        assert_eq!(
+            NumberFormat::new('.', None).format_number(&LocaleValue::Uint(10_000)),
+            Some("10000".into())
+        );
+    }
+
+    #[test]
+    fn test_with_value_formatter_keeps_violation_values() {
+        let rules = IntegerRules {
+            min: Some(-5000),
+            ..IntegerRules::default()
+        };
+        let formatter = Arc::new(NumberFormat::new(',', Some(' ')));
+        let error = with_value_formatter(formatter, || {
+            Integer::parse_custom(Some(-6000), rules.clone())
+        })
+        .expect_err("Expected integer to be too small");
+        let data = error.0.0[0].1.get_locale_data();
+        assert_eq!(data.args["min"], LocaleValue::from("-5 000"));
+        assert_eq!(
+            error.0.violations(),
+            vec![RuleViolation::Range {
+                min: Some(LocaleValue::Int(-5000)),
+                max: None,
+            }]
+        );
+
+        let error = Integer::parse_custom(Some(-6000), rules)
+            .expect_err("Expected integer to be too small");
+        assert_eq!(
+            error.0.0[0].1.get_locale_data().args["min"],
+            LocaleValue::Int(-5000)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_date_time_formatter() {
+        use crate::types::times_chrono::date::{DateRules, DateValue};
+        use chrono::NaiveDate;
+
+        struct GermanDates;
+
+        impl ValueFormatter for GermanDates {
+            fn format_date_time(&self, value: &DateTimeData) -> Option<String> {
+                NaiveDate::from_num_days_from_ce_opt(value.timestamp_seconds_days as i32)
+                    .map(|date| date.format("%d.%m.%Y").to_string())
+            }
+        }
+
+        let min = NaiveDate::from_ymd_opt(2025, 6, 30).expect("Expected valid date");
+        let rules = DateRules {
+            min: Some(min),
+            ..DateRules::default()
+        };
+        let error = with_value_formatter(Arc::new(GermanDates), || {
+            DateValue::parse_custom_with_format(min.pred_opt(), rules, Some("%Y-%m-%d"))
+        })
+        .expect_err("Expected date to be too early");
+        let data = error.0.0[0].1.get_locale_data();
+        assert_eq!(data.args["min"], LocaleValue::from("30.06.2025"));
+    }
+}
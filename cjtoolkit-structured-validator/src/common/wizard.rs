@@ -0,0 +1,296 @@
+//! This module contains a validator for forms spanning several steps, e.g. a multi-page signup.
+//!
+//! A `Wizard` is an ordered list of `WizardStep`s, each owning a set of fields and validating
+//! them with a closure on a `FormMap`. A `WizardState` carries the values of the steps submitted
+//! so far forward, so a step can check its fields against those of an earlier step, and
+//! revalidates every step once the last one is submitted.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::wizard::{StepOutcome, Wizard, WizardStep};
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use cjtoolkit_structured_validator::types::password::Password;
+//! use cjtoolkit_structured_validator::types::username::Username;
+//!
+//! let wizard = Wizard::new()
+//!     .step(WizardStep::new("account", ["username", "password"], |form| {
+//!         let _ = form.field("username", Username::parse);
+//!         let _ = form.field("password", Password::parse);
+//!     }))
+//!     .step(WizardStep::new("profile", ["name"], |form| {
+//!         let _ = form.field("name", Name::parse);
+//!     }));
+//!
+//! let mut state = wizard.start();
+//! let outcome = state.submit_step(0, [("username", "alice_smith"), ("password", "Pa55word!")]);
+//! assert_eq!(outcome, Ok(StepOutcome::Next(1)));
+//!
+//! let error = state.submit_step(1, [("name", "Al")]).expect_err("Expected name to be too short");
+//! assert_eq!(error.report().map(|report| report.fields()), Some(vec!["name"]));
+//!
+//! let outcome = state.submit_step(1, [("name", "Alice Smith")]);
+//! assert_eq!(outcome, Ok(StepOutcome::Complete));
+//! assert_eq!(state.get("username"), Some("alice_smith"));
+//! ```
+
+use crate::common::validation_report::ValidationReport;
+use crate::types::form::FormMap;
+use thiserror::Error;
+
+type StepValidate = Box<dyn Fn(&mut FormMap) + Send + Sync>;
+
+/// A step of a `Wizard`.
+///
+/// # Fields
+/// - `name`: The name of the step.
+/// - `fields`: The names of the fields submitted with the step, other inputs are ignored.
+pub struct WizardStep {
+    pub name: String,
+    pub fields: Vec<String>,
+    validate: StepValidate,
+}
+
+impl WizardStep {
+    /// Creates a step owning `fields`, validated by `validate`.
+    ///
+    /// The `FormMap` given to `validate` holds the values of the step along with those of the
+    /// steps submitted before it.
+    pub fn new<F: Into<String>>(
+        name: &str,
+        fields: impl IntoIterator<Item = F>,
+        validate: impl Fn(&mut FormMap) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            fields: fields.into_iter().map(Into::into).collect(),
+            validate: Box::new(validate),
+        }
+    }
+
+    fn validate(&self, values: &[(String, String)]) -> ValidationReport {
+        let mut form = FormMap::new(values.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+        (self.validate)(&mut form);
+        form.into_report()
+    }
+}
+
+/// An ordered list of steps.
+#[derive(Default)]
+pub struct Wizard {
+    pub steps: Vec<WizardStep>,
+}
+
+impl Wizard {
+    /// Creates a wizard without steps.
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Adds `step` after the existing steps.
+    pub fn step(mut self, step: WizardStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Starts filling the wizard from its first step.
+    pub fn start(&self) -> WizardState<'_> {
+        self.resume(Vec::new(), 0)
+    }
+
+    /// Resumes filling the wizard with the `values` submitted so far, at `current_step`, e.g.
+    /// after storing them in a session between requests.
+    pub fn resume(&self, values: Vec<(String, String)>, current_step: usize) -> WizardState<'_> {
+        WizardState {
+            wizard: self,
+            values,
+            current_step,
+        }
+    }
+}
+
+/// The outcome of a valid step.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepOutcome {
+    /// The step is valid, and the wizard continues at the given step.
+    Next(usize),
+    /// The last step is valid, and so are all the steps.
+    Complete,
+}
+
+/// Represents an error that occurs when submitting a step.
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum WizardError {
+    /// The wizard has no step with this index.
+    #[error("Unknown wizard step {0}")]
+    UnknownStep(usize),
+    /// The step comes after the current step of the wizard.
+    #[error("Wizard step {0} not reached")]
+    StepNotReached(usize),
+    /// The step, or one of the steps when revalidating them all, is not valid.
+    #[error("Wizard Validation Error")]
+    Invalid(ValidationReport),
+}
+
+impl WizardError {
+    /// Returns the report of the invalid fields, if the error is a validation error.
+    pub fn report(&self) -> Option<&ValidationReport> {
+        match self {
+            Self::Invalid(report) => Some(report),
+            _ => None,
+        }
+    }
+}
+
+/// The progress of filling a `Wizard`.
+///
+/// # Fields
+/// - `values`: The values of the valid steps submitted so far.
+/// - `current_step`: The index of the first step not submitted yet.
+pub struct WizardState<'w> {
+    wizard: &'w Wizard,
+    pub values: Vec<(String, String)>,
+    pub current_step: usize,
+}
+
+impl WizardState<'_> {
+    /// Returns the value of the field named `name`, `None` if it was not submitted.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns `true` once every step has been submitted.
+    pub fn is_complete(&self) -> bool {
+        self.current_step >= self.wizard.steps.len()
+    }
+
+    /// Validates the `inputs` of the step `n`, which may be the current step or an earlier one.
+    ///
+    /// If the step is valid, its values replace those submitted before and the wizard advances.
+    /// Once the last step is valid, every step is validated again with all the values.
+    ///
+    /// # Errors
+    /// Returns a `WizardError` if the step does not exist or was not reached, or if the step is
+    /// not valid, in which case the values are left unchanged. If the step is valid but another
+    /// step fails the final revalidation, the values of the step are kept, so the other step can
+    /// be submitted again.
+    pub fn submit_step<K: Into<String>, V: Into<String>>(
+        &mut self,
+        n: usize,
+        inputs: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<StepOutcome, WizardError> {
+        let step = self
+            .wizard
+            .steps
+            .get(n)
+            .ok_or(WizardError::UnknownStep(n))?;
+        if n > self.current_step {
+            return Err(WizardError::StepNotReached(n));
+        }
+        let mut values: Vec<(String, String)> = self
+            .values
+            .iter()
+            .filter(|(field, _)| !step.fields.contains(field))
+            .cloned()
+            .collect();
+        values.extend(
+            inputs
+                .into_iter()
+                .map(|(field, value)| (field.into(), value.into()))
+                .filter(|(field, _)| step.fields.contains(field)),
+        );
+        step.validate(&values)
+            .into_result()
+            .map_err(WizardError::Invalid)?;
+        self.values = values;
+        self.current_step = self.current_step.max(n + 1);
+        if !self.is_complete() {
+            return Ok(StepOutcome::Next(self.current_step));
+        }
+        self.validate_all().map_err(WizardError::Invalid)?;
+        Ok(StepOutcome::Complete)
+    }
+
+    /// Validates every step again with all the values submitted so far.
+    pub fn validate_all(&self) -> Result<(), ValidationReport> {
+        let mut report = ValidationReport::new();
+        for step in &self.wizard.steps {
+            report.0.extend(step.validate(&self.values).0);
+        }
+        report.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::Name;
+    use crate::types::password::Password;
+
+    fn signup() -> Wizard {
+        Wizard::new()
+            .step(WizardStep::new("password", ["password"], |form| {
+                let _ = form.field("password", Password::parse);
+            }))
+            .step(WizardStep::new("confirm", ["confirm"], |form| {
+                let password = Password::parse(form.get("password"));
+                let _ = form.field("confirm", |confirm| {
+                    password?.parse_confirm(confirm.unwrap_or_default())
+                });
+            }))
+            .step(WizardStep::new("name", ["name"], |form| {
+                let _ = form.field("name", Name::parse);
+            }))
+    }
+
+    #[test]
+    fn test_steps_carry_values_forward() {
+        let wizard = signup();
+        let mut state = wizard.start();
+
+        assert_eq!(
+            state.submit_step(1, [("confirm", "Pa55word!")]),
+            Err(WizardError::StepNotReached(1))
+        );
+        assert_eq!(
+            state.submit_step(0, [("password", "Pa55word!"), ("name", "Ignored")]),
+            Ok(StepOutcome::Next(1))
+        );
+        assert_eq!(state.get("name"), None);
+
+        let error = state
+            .submit_step(1, [("confirm", "Different1!")])
+            .expect_err("Expected confirmation to differ");
+        assert_eq!(error.report().map(|r| r.fields()), Some(vec!["confirm"]));
+        assert_eq!(state.current_step, 1);
+
+        assert!(state.submit_step(1, [("confirm", "Pa55word!")]).is_ok());
+        assert!(state.submit_step(2, [("name", "Alice Smith")]).is_ok());
+        assert!(state.is_complete());
+    }
+
+    #[test]
+    fn test_editing_earlier_step_revalidates_all() {
+        let wizard = signup();
+        let mut state = wizard.resume(
+            vec![
+                ("password".to_string(), "Pa55word!".to_string()),
+                ("confirm".to_string(), "Pa55word!".to_string()),
+                ("name".to_string(), "Alice Smith".to_string()),
+            ],
+            3,
+        );
+
+        let error = state
+            .submit_step(0, [("password", "N3wPassword!")])
+            .expect_err("Expected confirmation to no longer match");
+        assert_eq!(error.report().map(|r| r.fields()), Some(vec!["confirm"]));
+        assert_eq!(state.get("password"), Some("N3wPassword!"));
+        assert_eq!(
+            state.submit_step(7, Vec::<(String, String)>::new()),
+            Err(WizardError::UnknownStep(7))
+        );
+    }
+}
@@ -10,3 +10,11 @@
 pub mod base;
 pub mod common;
 pub mod types;
+
+#[cfg(feature = "async-graphql")]
+#[doc(hidden)]
+pub use ::async_graphql as __async_graphql;
+
+#[cfg(feature = "diesel")]
+#[doc(hidden)]
+pub use ::diesel as __diesel;
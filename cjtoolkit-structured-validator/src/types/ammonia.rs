@@ -0,0 +1,307 @@
+//! This module contains the `ammonia` support of `Description`, sanitizing the HTML posted by
+//! rich-text editors with `Description::parse_sanitized`.
+//!
+//! The HTML is sanitized before being validated, so the length rules apply to the sanitized
+//! HTML. The tags and attributes that were stripped, e.g. `<script>` or `onclick`, do not fail
+//! the validation, they are reported as a `validate-html-stripped` warning in
+//! `CheckOutcome::warnings`, so the user can be told their content was altered.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::ammonia::HtmlSanitizeRules;
+//! use cjtoolkit_structured_validator::types::description::Description;
+//!
+//! let html = "<p onclick=\"steal()\">Hello <b>world</b></p><script>steal()</script>";
+//! let outcome = Description::parse_sanitized(Some(html), HtmlSanitizeRules::default())
+//!     .expect("Expected sanitized description to be valid");
+//! assert_eq!(outcome.value.as_str(), "<p>Hello <b>world</b></p>");
+//! assert_eq!(
+//!     outcome.warnings.as_original_message_vec(),
+//!     vec!["Removed unsupported HTML: <script>, onclick"]
+//! );
+//!
+//! let rules = HtmlSanitizeRules {
+//!     max_allowed_tags: Some(1),
+//!     ..HtmlSanitizeRules::default()
+//! };
+//! assert!(Description::parse_sanitized(Some(html), rules).is_err());
+//! ```
+
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+};
+use crate::common::locale_keys;
+use crate::common::observer::rule_result;
+use crate::types::description::DescriptionRules;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// The rules of a description sanitized with `Description::parse_sanitized`.
+///
+/// # Fields
+/// * `description` - The rules of the sanitized HTML, without a maximum length by default, as
+///   the markup counts towards the length.
+/// * `allowed_tags` - The tags kept by the sanitization, `None` for the default tags of
+///   `ammonia`, e.g. `p`, `b` or `a`.
+/// * `max_allowed_tags` - The maximum number of tags in the sanitized HTML, `None` for no
+///   maximum.
+#[derive(Clone)]
+pub struct HtmlSanitizeRules {
+    pub description: DescriptionRules,
+    pub allowed_tags: Option<Vec<String>>,
+    pub max_allowed_tags: Option<usize>,
+}
+
+impl Default for HtmlSanitizeRules {
+    fn default() -> Self {
+        Self {
+            description: DescriptionRules {
+                max_length: None,
+                ..DescriptionRules::default()
+            },
+            allowed_tags: None,
+            max_allowed_tags: None,
+        }
+    }
+}
+
+impl HtmlSanitizeRules {
+    /// Sanitizes `html` with the allowed tags, and reports what was stripped.
+    pub fn sanitize(&self, html: &str) -> SanitizedHtml {
+        let mut builder = ammonia::Builder::default();
+        if let Some(allowed_tags) = &self.allowed_tags {
+            builder
+                .tags(allowed_tags.iter().map(String::as_str).collect())
+                .rm_clean_content_tags(allowed_tags.iter().map(String::as_str));
+        }
+        let sanitized = builder.clean(html).to_string();
+
+        let kept = start_tags(&sanitized);
+        let kept_names: HashSet<&str> = kept.iter().map(|(name, _)| name.as_str()).collect();
+        let kept_attributes: HashSet<(&str, &str)> = kept
+            .iter()
+            .flat_map(|(name, attributes)| {
+                attributes
+                    .iter()
+                    .map(move |attribute| (name.as_str(), attribute.as_str()))
+            })
+            .collect();
+        let mut stripped_tags: Vec<String> = Vec::new();
+        let mut stripped_attributes: Vec<String> = Vec::new();
+        for (name, attributes) in start_tags(html) {
+            if !kept_names.contains(name.as_str()) {
+                if !stripped_tags.contains(&name) {
+                    stripped_tags.push(name);
+                }
+                continue;
+            }
+            for attribute in attributes {
+                if !kept_attributes.contains(&(name.as_str(), attribute.as_str()))
+                    && !stripped_attributes.contains(&attribute)
+                {
+                    stripped_attributes.push(attribute);
+                }
+            }
+        }
+
+        SanitizedHtml {
+            tag_count: kept.len(),
+            html: sanitized,
+            stripped_tags,
+            stripped_attributes,
+        }
+    }
+
+    pub(crate) fn check(&self, messages: &mut ValidateErrorCollector, sanitized: &SanitizedHtml) {
+        let Some(max) = self.max_allowed_tags else {
+            return;
+        };
+        let is_within_max = sanitized.tag_count <= max;
+        rule_result(locale_keys::VALIDATE_HTML_MAX_TAGS, is_within_max);
+        if !is_within_max {
+            messages.push((
+                format!("Must have at most {} HTML tags", max),
+                Box::new(HtmlMaxTagsLocale { max }),
+            ));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = self.description.describe();
+        if let Some(max) = self.max_allowed_tags {
+            constraints.push(Constraint::from_locale(&HtmlMaxTagsLocale { max }));
+        }
+        constraints
+    }
+}
+
+/// The result of `HtmlSanitizeRules::sanitize`.
+///
+/// # Fields
+/// * `html` - The sanitized HTML.
+/// * `stripped_tags` - The names of the tags that were removed, in order of appearance.
+/// * `stripped_attributes` - The names of the attributes removed from the kept tags, in order
+///   of appearance.
+/// * `tag_count` - The number of tags in the sanitized HTML.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SanitizedHtml {
+    pub html: String,
+    pub stripped_tags: Vec<String>,
+    pub stripped_attributes: Vec<String>,
+    pub tag_count: usize,
+}
+
+impl SanitizedHtml {
+    /// Returns `true` if the sanitization removed any tag or attribute.
+    pub fn is_altered(&self) -> bool {
+        !self.stripped_tags.is_empty() || !self.stripped_attributes.is_empty()
+    }
+
+    pub(crate) fn warn_stripped(&self, warnings: &mut ValidateErrorCollector) {
+        if !self.is_altered() {
+            return;
+        }
+        let stripped = self
+            .stripped_tags
+            .iter()
+            .map(|name| format!("<{}>", name))
+            .chain(self.stripped_attributes.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(", ");
+        warnings.push((
+            format!("Removed unsupported HTML: {}", stripped),
+            Box::new(HtmlStrippedLocale { stripped }),
+        ));
+    }
+}
+
+/// The locale of the tags and attributes stripped by the sanitization, returned as a warning.
+///
+/// # Key
+/// `validate-html-stripped`
+pub struct HtmlStrippedLocale {
+    pub stripped: String,
+}
+
+impl LocaleMessage for HtmlStrippedLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_HTML_STRIPPED,
+            vec![(
+                "stripped".to_string(),
+                LocaleValue::from(self.stripped.clone()),
+            )],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Sanitized {
+            removed: self.stripped.clone(),
+        }
+    }
+}
+
+/// The locale of a sanitized HTML with more tags than allowed.
+///
+/// # Key
+/// `validate-html-max-tags`
+pub struct HtmlMaxTagsLocale {
+    pub max: usize,
+}
+
+impl LocaleMessage for HtmlMaxTagsLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_HTML_MAX_TAGS,
+            vec![("max".to_string(), LocaleValue::from(self.max))],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::MaxItems { max: self.max }
+    }
+}
+
+/// Returns the lowercase names and attribute names of the start tags of `html`, in order.
+fn start_tags(html: &str) -> Vec<(String, Vec<String>)> {
+    let is_name_end = |c: char| c.is_ascii_whitespace() || matches!(c, '=' | '>' | '/');
+    let mut tags = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            continue;
+        }
+        let name_end = rest.find(is_name_end).unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = &rest[name_end..];
+        let mut attributes = Vec::new();
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+            if rest.is_empty() || rest.starts_with('>') {
+                break;
+            }
+            let attribute_end = rest.find(is_name_end).unwrap_or(rest.len());
+            if attribute_end > 0 {
+                attributes.push(rest[..attribute_end].to_ascii_lowercase());
+            }
+            rest = rest[attribute_end..].trim_start();
+            let Some(value) = rest.strip_prefix('=') else {
+                continue;
+            };
+            let value = value.trim_start();
+            rest = match value.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    value[1..].find(quote).map_or("", |end| &value[end + 2..])
+                }
+                _ => {
+                    let end = value
+                        .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                        .unwrap_or(value.len());
+                    &value[end..]
+                }
+            };
+        }
+        tags.push((name, attributes));
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_tags() {
+        let tags = start_tags("<P Class='a b' hidden data-x=1><!-- c --></p><img src=\"x.png\"/>");
+        assert_eq!(
+            tags,
+            vec![
+                (
+                    "p".to_string(),
+                    vec![
+                        "class".to_string(),
+                        "hidden".to_string(),
+                        "data-x".to_string()
+                    ]
+                ),
+                ("img".to_string(), vec!["src".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sanitize_with_allowed_tags() {
+        let rules = HtmlSanitizeRules {
+            allowed_tags: Some(vec!["p".to_string()]),
+            ..HtmlSanitizeRules::default()
+        };
+        let sanitized = rules.sanitize("<p>Hi <em>there</em></p><style>p {}</style>");
+        assert_eq!(sanitized.html, "<p>Hi there</p>");
+        assert_eq!(sanitized.stripped_tags, vec!["em", "style"]);
+        assert_eq!(sanitized.tag_count, 1);
+        assert!(!rules.sanitize("<p>Hi</p>").is_altered());
+    }
+}
@@ -0,0 +1,266 @@
+//! This module contains the `async-graphql` support of the value types.
+//!
+//! The string types are GraphQL scalars, parsed with the default rules of the type, so they can
+//! be used directly as arguments and fields of input objects. A value that was not provided is
+//! written as `null`. The errors of a scalar, and of a `ValidationReport` converted into an
+//! `async_graphql::Error`, carry their messages, locale keys and arguments as extensions, so
+//! clients can translate them.
+//!
+//! # Example
+//! ```
+//! use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn greet(&self, name: Name) -> String {
+//!         format!("Hello {}", name)
+//!     }
+//! }
+//!
+//! # tokio::runtime::Runtime::new().expect("Expected runtime").block_on(async {
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//! let response = schema.execute(r#"{ greet(name: "No") }"#).await;
+//! let extensions = response.errors[0].extensions.as_ref().expect("Expected extensions");
+//! let errors = extensions.get("errors").expect("Expected errors extension");
+//! assert!(errors.to_string().contains("validate-min-length"));
+//! # });
+//! ```
+
+use crate::common::locale::{LocaleData, LocaleValue, ValidateErrorStore};
+use crate::common::validation_report::ValidationReport;
+use ::async_graphql::indexmap::IndexMap;
+use ::async_graphql::{
+    Error, ErrorExtensionValues, InputType, InputValueError, InputValueResult, Name, Number,
+    Scalar, ScalarType, Value,
+};
+
+fn locale_value(value: &LocaleValue) -> Value {
+    match value {
+        LocaleValue::String(s) => Value::from(s.as_str()),
+        LocaleValue::Uint(n) => Value::from(*n),
+        LocaleValue::Int(n) => Value::from(*n),
+        LocaleValue::Float(n) => Number::from_f64(*n).map_or(Value::Null, Value::Number),
+    }
+}
+
+fn locale_args(data: &LocaleData) -> Value {
+    Value::Object(
+        data.args
+            .iter()
+            .map(|(name, value)| (Name::new(name), locale_value(value)))
+            .collect(),
+    )
+}
+
+/// Returns the errors of `store` as a list of objects with the `message`, `key` and `args` of
+/// each error.
+pub fn error_values(store: &ValidateErrorStore) -> Value {
+    store
+        .0
+        .iter()
+        .map(|(message, locale_message)| {
+            let data = locale_message.get_locale_data();
+            let mut error = IndexMap::new();
+            error.insert(Name::new("message"), Value::from(message.as_ref()));
+            error.insert(Name::new("key"), Value::from(data.name.as_str()));
+            error.insert(Name::new("args"), locale_args(&data));
+            Value::Object(error)
+        })
+        .collect()
+}
+
+/// The report is converted into a single error, with a `fields` extension mapping each field
+/// to its errors, see `error_values`.
+impl From<ValidationReport> for Error {
+    fn from(report: ValidationReport) -> Self {
+        let fields: IndexMap<Name, Value> = report
+            .0
+            .iter()
+            .map(|(field, store)| (Name::new(field), error_values(store)))
+            .collect();
+        let mut extensions = ErrorExtensionValues::default();
+        extensions.set("fields", Value::Object(fields));
+        Self {
+            message: "Validation failed".to_string(),
+            source: None,
+            extensions: Some(extensions),
+        }
+    }
+}
+
+/// Returns one error per error of each field of `report`, with the original message and the
+/// `field`, `key` and `args` extensions, e.g. to return them as separate GraphQL errors.
+pub fn field_errors(report: &ValidationReport) -> Vec<Error> {
+    let mut errors = Vec::new();
+    for (field, store) in &report.0 {
+        for (message, locale_message) in store.0.iter() {
+            let data = locale_message.get_locale_data();
+            let mut extensions = ErrorExtensionValues::default();
+            extensions.set("field", field.as_str());
+            extensions.set("key", data.name.as_str());
+            extensions.set("args", locale_args(&data));
+            errors.push(Error {
+                message: message.to_string(),
+                source: None,
+                extensions: Some(extensions),
+            });
+        }
+    }
+    errors
+}
+
+/// Parses the string scalar `value` with `parse`, the errors of `parse` being returned with an
+/// `errors` extension, see `error_values`. Used by the scalars of `define_name_type!`.
+#[doc(hidden)]
+pub fn parse_str_scalar<T, E>(
+    value: Value,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> InputValueResult<T>
+where
+    T: InputType,
+    for<'a> &'a E: Into<ValidateErrorStore>,
+{
+    match value {
+        Value::String(s) => parse(&s).map_err(|e| {
+            let store: ValidateErrorStore = (&e).into();
+            InputValueError::custom(store.as_original_message_vec().join(", "))
+                .with_extension("errors", error_values(&store))
+        }),
+        other => Err(InputValueError::expected_type(other)),
+    }
+}
+
+macro_rules! graphql_str {
+    ($type:ty, $name:literal) => {
+        #[Scalar(name = $name)]
+        impl ScalarType for $type {
+            fn parse(value: Value) -> InputValueResult<Self> {
+                parse_str_scalar(value, |s| <$type>::parse(Some(s)))
+            }
+
+            fn is_valid(value: &Value) -> bool {
+                matches!(value, Value::String(_))
+            }
+
+            fn to_value(&self) -> Value {
+                if self.is_none() {
+                    Value::Null
+                } else {
+                    Value::from(self.as_str())
+                }
+            }
+        }
+    };
+}
+
+graphql_str!(crate::types::name::Name, "Name");
+graphql_str!(crate::types::description::Description, "Description");
+graphql_str!(crate::types::username::Username, "Username");
+
+#[cfg(feature = "email")]
+graphql_str!(crate::types::email::Email, "Email");
+
+#[cfg(feature = "url")]
+graphql_str!(crate::types::url::Url, "Url");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::Name as ValidatedName;
+    use crate::types::name::name_alias::FirstName;
+    use crate::types::numbers::integer::{Integer, IntegerRules};
+    use ::async_graphql::{EmptyMutation, EmptySubscription, InputObject, Object, Schema};
+
+    #[derive(InputObject)]
+    struct SignupInput {
+        name: ValidatedName,
+        nickname: Option<ValidatedName>,
+        first_name: Option<FirstName>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn signup(&self, input: SignupInput) -> ValidatedName {
+            input.nickname.unwrap_or(input.name)
+        }
+
+        async fn first_name(&self, input: SignupInput) -> Option<FirstName> {
+            input.first_name
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scalar_in_input_object() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let response = schema
+            .execute(r#"{ signup(input: { name: "Valid Name" }) }"#)
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(response.data.to_string(), r#"{signup: "Valid Name"}"#);
+
+        let response = schema.execute(r#"{ signup(input: { name: "No" }) }"#).await;
+        let extensions = response.errors[0]
+            .extensions
+            .as_ref()
+            .expect("Expected extensions");
+        let errors = extensions.get("errors").expect("Expected errors extension");
+        let Value::List(errors) = errors else {
+            panic!("Expected list of errors");
+        };
+        let Value::Object(error) = &errors[0] else {
+            panic!("Expected error object");
+        };
+        assert_eq!(error["key"], Value::from("validate-min-length"));
+        let Value::Object(args) = &error["args"] else {
+            panic!("Expected args object");
+        };
+        assert_eq!(args["min"], Value::from(5usize));
+    }
+
+    #[tokio::test]
+    async fn test_name_type_scalar() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let response = schema
+            .execute(r#"{ firstName(input: { name: "Valid Name", firstName: "Al" }) }"#)
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(response.data.to_string(), r#"{firstName: "Al"}"#);
+        assert!(schema.sdl().contains("scalar FirstName"));
+    }
+
+    #[test]
+    fn test_report_into_error() {
+        let mut report = ValidationReport::new();
+        let _ = report.check(
+            "age",
+            Integer::parse_custom(
+                Some(200),
+                IntegerRules {
+                    max: Some(150),
+                    ..IntegerRules::default()
+                },
+            ),
+        );
+
+        let errors = field_errors(&report);
+        assert_eq!(errors.len(), 1);
+        let extensions = errors[0].extensions.as_ref().expect("Expected extensions");
+        assert_eq!(extensions.get("field"), Some(&Value::from("age")));
+        assert_eq!(
+            extensions.get("key"),
+            Some(&Value::from("validate-number-max-value"))
+        );
+
+        let error: Error = report.into();
+        let extensions = error.extensions.expect("Expected extensions");
+        let Some(Value::Object(fields)) = extensions.get("fields") else {
+            panic!("Expected fields object");
+        };
+        assert!(fields.contains_key("age"));
+    }
+}
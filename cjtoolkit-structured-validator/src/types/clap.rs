@@ -0,0 +1,60 @@
+//! This module contains the `clap` support of the value types.
+//!
+//! `clap_parser` builds a value parser running the default rules of a type, so command line
+//! arguments are validated the same way as form fields. The original messages are rendered
+//! on the command line, separated by `", "`.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::clap::clap_parser;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use clap::{Arg, Command};
+//!
+//! let command = Command::new("app").arg(Arg::new("name").value_parser(clap_parser::<Name>()));
+//!
+//! let matches = command.clone().try_get_matches_from(["app", "ValidName"]);
+//! let name = matches.ok().and_then(|m| m.get_one::<Name>("name").cloned());
+//! assert_eq!(name.as_ref().map(|n| n.as_str()), Some("ValidName"));
+//!
+//! assert!(command.try_get_matches_from(["app", "No"]).is_err());
+//! ```
+
+use crate::common::locale::ValidateErrorStore;
+use ::clap::builder::TypedValueParser;
+
+/// Returns a `clap` value parser validating the argument with the default rules of `T`.
+///
+/// # Errors
+/// The parser fails with the original messages of the validation error, joined with `", "`.
+pub fn clap_parser<T>() -> impl TypedValueParser<Value = T>
+where
+    T: for<'a> TryFrom<&'a str> + Clone + Send + Sync + 'static,
+    for<'a, 'b> &'b <T as TryFrom<&'a str>>::Error: Into<ValidateErrorStore>,
+{
+    |s: &str| -> Result<T, String> {
+        T::try_from(s).map_err(|e| {
+            let store: ValidateErrorStore = (&e).into();
+            store.as_original_message_vec().join(", ")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::username::Username;
+    use ::clap::{Arg, Command};
+
+    #[test]
+    fn test_clap_parser_renders_original_messages() {
+        let err = Command::new("app")
+            .arg(Arg::new("username").value_parser(clap_parser::<Username>()))
+            .try_get_matches_from(["app", "abc"])
+            .expect_err("Expected error");
+        assert!(
+            err.to_string().contains("Must be at least 5 characters"),
+            "{}",
+            err
+        );
+    }
+}
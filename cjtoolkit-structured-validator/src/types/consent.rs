@@ -0,0 +1,295 @@
+//! This module contains structures for working with checkboxes.
+//!
+//! `Consent` is a single checkbox, e.g. accepting the terms of service, which may have to be
+//! checked. `CheckboxGroup` is a group of checkboxes, with a minimum and a maximum number of
+//! options to select.
+
+use crate::base::collection_rules::CollectionRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, RuleViolation, ValidateErrorCollector, ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::observer::{observe_parse, rule_result};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// A structure to define the rules of a consent checkbox.
+///
+/// # Fields
+/// * `must_be_true` - Whether the checkbox must be checked, e.g. to accept the terms of service.
+///
+/// The default requires the checkbox to be checked.
+#[derive(Clone)]
+pub struct ConsentRules {
+    pub must_be_true: bool,
+}
+
+impl Default for ConsentRules {
+    fn default() -> Self {
+        Self { must_be_true: true }
+    }
+}
+
+impl ConsentRules {
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.must_be_true {
+            constraints.push(Constraint::from_locale(&ConsentLocale));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during consent validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Consent Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Consent Validation Error")]
+pub struct ConsentError(pub ValidateErrorStore);
+
+impl ValidationCheck for ConsentError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&ConsentError> for ValidateErrorStore {
+    fn from(error: &ConsentError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// The locale of a consent checkbox which must be checked but is not.
+///
+/// # Key
+/// `validate-consent-required`
+pub struct ConsentLocale;
+
+impl LocaleMessage for ConsentLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new(locale_keys::VALIDATE_CONSENT_REQUIRED)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Mandatory
+    }
+}
+
+/// A structure representing a validated consent checkbox, `true` if checked.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Consent(bool);
+
+impl Consent {
+    /// Parses a consent checkbox based on the provided validation rules. A missing value is
+    /// unchecked, as browsers do not submit unchecked checkboxes.
+    ///
+    /// # Errors
+    /// Returns a `ConsentError` if the checkbox is not checked while `must_be_true` is set.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::consent::{Consent, ConsentRules};
+    ///
+    /// assert!(Consent::parse(Some(true), ConsentRules::default()).is_ok());
+    /// assert!(Consent::parse(None, ConsentRules::default()).is_err());
+    /// let newsletter = ConsentRules { must_be_true: false };
+    /// assert_eq!(Consent::parse(None, newsletter).map(|c| c.is_given()).ok(), Some(false));
+    /// ```
+    pub fn parse(s: Option<bool>, rules: ConsentRules) -> Result<Self, ConsentError> {
+        observe_parse(|| {
+            let is_given = s.unwrap_or_default();
+            let mut messages = ValidateErrorCollector::new();
+            if rules.must_be_true {
+                rule_result(locale_keys::VALIDATE_CONSENT_REQUIRED, is_given);
+                if !is_given {
+                    messages.push(("Must be accepted".to_string(), Box::new(ConsentLocale)));
+                }
+            }
+            ConsentError::validate_check(messages)?;
+            Ok(Self(is_given))
+        })
+    }
+
+    /// Parses the value of a checkbox submitted with a form, checked unless it is missing or is
+    /// `false`, `off` or `0`, e.g. `on`, the default value of HTML checkboxes.
+    ///
+    /// # Errors
+    /// Returns a `ConsentError` if the checkbox is not checked while `must_be_true` is set.
+    pub fn parse_form(s: Option<&str>, rules: ConsentRules) -> Result<Self, ConsentError> {
+        let is_given = s.map(|s| !matches!(s.trim(), "" | "false" | "off" | "0"));
+        Self::parse(is_given, rules)
+    }
+
+    /// Returns `true` if the checkbox is checked.
+    pub fn is_given(&self) -> bool {
+        self.0
+    }
+}
+
+impl Validator<Option<bool>, Consent, ConsentError> for ConsentRules {
+    fn validate(&self, input: Option<bool>) -> Result<Consent, ConsentError> {
+        Consent::parse(input, self.clone())
+    }
+}
+
+/// A structure to define the rules of a group of checkboxes.
+///
+/// # Fields
+/// * `min_selected` - The minimum number of options to select, `None` for no minimum.
+/// * `max_selected` - The maximum number of options to select, `None` for no maximum.
+///
+/// The default has no limits.
+#[derive(Clone, Default)]
+pub struct CheckboxGroupRules {
+    pub min_selected: Option<usize>,
+    pub max_selected: Option<usize>,
+}
+
+impl From<&CheckboxGroupRules> for CollectionRules {
+    fn from(rules: &CheckboxGroupRules) -> Self {
+        Self {
+            is_mandatory: false,
+            min_items: rules.min_selected,
+            max_items: rules.max_selected,
+        }
+    }
+}
+
+impl CheckboxGroupRules {
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        CollectionRules::from(self).describe()
+    }
+}
+
+/// Represents an error that occurs during checkbox group validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Checkbox Group Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Checkbox Group Validation Error")]
+pub struct CheckboxGroupError(pub ValidateErrorStore);
+
+impl ValidationCheck for CheckboxGroupError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&CheckboxGroupError> for ValidateErrorStore {
+    fn from(error: &CheckboxGroupError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A structure representing the validated options selected in a group of checkboxes, in the
+/// order they were submitted, without duplicates.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckboxGroup(Vec<String>);
+
+impl CheckboxGroup {
+    /// Parses the options selected in a group of checkboxes based on the provided validation
+    /// rules. A missing value selects no options, and an option selected twice counts once.
+    ///
+    /// # Errors
+    /// Returns a `CheckboxGroupError` if too few or too many options are selected.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::consent::{CheckboxGroup, CheckboxGroupRules};
+    ///
+    /// let rules = CheckboxGroupRules {
+    ///     min_selected: Some(1),
+    ///     max_selected: Some(2),
+    /// };
+    /// let group = CheckboxGroup::parse(Some(&["rust", "go", "rust"]), rules.clone());
+    /// assert_eq!(group.map(|g| g.len()).ok(), Some(2));
+    /// assert!(CheckboxGroup::parse(Some(&["rust", "go", "zig"]), rules.clone()).is_err());
+    /// assert!(CheckboxGroup::parse::<&str>(None, rules).is_err());
+    /// ```
+    pub fn parse<S: AsRef<str>>(
+        s: Option<&[S]>,
+        rules: CheckboxGroupRules,
+    ) -> Result<Self, CheckboxGroupError> {
+        observe_parse(|| {
+            let mut selected: Vec<String> = Vec::new();
+            for option in s.unwrap_or_default() {
+                let option = option.as_ref();
+                if !selected.iter().any(|s| s == option) {
+                    selected.push(option.to_string());
+                }
+            }
+            let mut messages = ValidateErrorCollector::new();
+            CollectionRules::from(&rules).check(&mut messages, Some(selected.len()));
+            CheckboxGroupError::validate_check(messages)?;
+            Ok(Self(selected))
+        })
+    }
+
+    /// Returns the selected options.
+    pub fn as_slice(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Returns `true` if `option` is selected.
+    pub fn contains(&self, option: &str) -> bool {
+        self.0.iter().any(|s| s == option)
+    }
+
+    /// Returns the number of selected options.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no options are selected.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Validator<Option<&[String]>, CheckboxGroup, CheckboxGroupError> for CheckboxGroupRules {
+    fn validate(&self, input: Option<&[String]>) -> Result<CheckboxGroup, CheckboxGroupError> {
+        CheckboxGroup::parse(input, self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consent() {
+        let error = Consent::parse(Some(false), ConsentRules::default())
+            .expect_err("Expected consent to be required");
+        assert_eq!(error.0.as_original_message_vec(), vec!["Must be accepted"]);
+        assert!(Consent::parse_form(Some("on"), ConsentRules::default()).is_ok());
+        assert!(Consent::parse_form(Some("off"), ConsentRules::default()).is_err());
+    }
+
+    #[test]
+    fn test_checkbox_group() {
+        let rules = CheckboxGroupRules {
+            min_selected: Some(2),
+            max_selected: None,
+        };
+        let error = CheckboxGroup::parse(Some(&["a", "a"]), rules.clone())
+            .expect_err("Expected too few options");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must have at least 2 items"]
+        );
+        let group = CheckboxGroup::parse(Some(&["a", "b"]), rules).expect("Expected valid options");
+        assert!(group.contains("b"));
+    }
+}
@@ -0,0 +1,713 @@
+//! This module contains structures and traits for working with cron expressions.
+//!
+//! Expressions have five fields, `minute hour day-of-month month day-of-week`, preceded by a
+//! `second` field when seconds are allowed. Each field is a comma separated list of `*`, values
+//! (`5`), ranges (`1-5`) and steps (`*/15`, `1-30/5` or `10/5`). Months accept the names `JAN` to
+//! `DEC` and days of the week `SUN` to `SAT`, case-insensitively, and Sunday is both `0` and `7`.
+//!
+//! As with cron, when both the day of the month and the day of the week are restricted, that is
+//! they do not start with `*`, a day matches when either of them matches.
+
+use crate::base::calendar::civil_from_days;
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDateTime, TimeDelta, Timelike};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// The number of days searched for matching days, eight years to cover the leap days skipped at
+/// the turn of a century.
+const SEARCH_DAYS: i64 = 8 * 366;
+
+/// 2000-01-01, as days since 1970-01-01, the start of the search for the shortest interval.
+const SEARCH_START: i64 = 10_957;
+
+struct CronField {
+    name: &'static str,
+    min: u8,
+    max: u8,
+    names: &'static [&'static str],
+}
+
+const SECOND: CronField = CronField {
+    name: "second",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+
+const MINUTE: CronField = CronField {
+    name: "minute",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+
+const HOUR: CronField = CronField {
+    name: "hour",
+    min: 0,
+    max: 23,
+    names: &[],
+};
+
+const DAY_OF_MONTH: CronField = CronField {
+    name: "day-of-month",
+    min: 1,
+    max: 31,
+    names: &[],
+};
+
+const MONTH: CronField = CronField {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &[
+        "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+    ],
+};
+
+const DAY_OF_WEEK: CronField = CronField {
+    name: "day-of-week",
+    min: 0,
+    max: 7,
+    names: &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"],
+};
+
+impl CronField {
+    fn parse_value(&self, s: &str) -> Option<u8> {
+        let value = match s.parse::<u8>() {
+            Ok(value) => value,
+            Err(_) => {
+                let position = self.names.iter().position(|n| n.eq_ignore_ascii_case(s))?;
+                self.min + position as u8
+            }
+        };
+        (self.min..=self.max).contains(&value).then_some(value)
+    }
+
+    fn parse(&self, s: &str) -> Option<u64> {
+        let mut bits = 0u64;
+        for item in s.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, Some(step.parse::<u8>().ok().filter(|s| *s > 0)?)),
+                None => (item, None),
+            };
+            let (start, end) = if range == "*" {
+                (self.min, self.max)
+            } else if let Some((start, end)) = range.split_once('-') {
+                (self.parse_value(start)?, self.parse_value(end)?)
+            } else {
+                let value = self.parse_value(range)?;
+                (value, if step.is_some() { self.max } else { value })
+            };
+            if start > end {
+                return None;
+            }
+            for value in (start..=end).step_by(step.unwrap_or(1) as usize) {
+                bits |= 1 << value;
+            }
+        }
+        Some(bits)
+    }
+}
+
+fn bits(set: u64) -> impl Iterator<Item = u64> {
+    (0..64).filter(move |bit| set & (1 << bit) != 0)
+}
+
+/// A parsed cron schedule, holding the matching values of each field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    restricted_days: bool,
+}
+
+impl CronSchedule {
+    fn parse(fields: &[&str]) -> Result<Self, &'static str> {
+        let (seconds, fields) = match fields {
+            [second, rest @ ..] if fields.len() == 6 => {
+                (SECOND.parse(second).ok_or(SECOND.name)?, rest)
+            }
+            _ => (1, fields),
+        };
+        let parse = |field: &CronField, s: &str| field.parse(s).ok_or(field.name);
+        let days_of_week = parse(&DAY_OF_WEEK, fields[4])?;
+        Ok(Self {
+            seconds,
+            minutes: parse(&MINUTE, fields[0])?,
+            hours: parse(&HOUR, fields[1])?,
+            days_of_month: parse(&DAY_OF_MONTH, fields[2])?,
+            months: parse(&MONTH, fields[3])?,
+            days_of_week: (days_of_week | days_of_week >> 7) & 0x7f,
+            restricted_days: !fields[2].starts_with('*') && !fields[4].starts_with('*'),
+        })
+    }
+
+    /// Returns `true` if the schedule runs on the given day, with `weekday` from 0 for Sunday.
+    pub fn matches_day(&self, day: u8, month: u8, weekday: u8) -> bool {
+        if self.months & (1 << month) == 0 {
+            return false;
+        }
+        let day_of_month = self.days_of_month & (1 << day) != 0;
+        let day_of_week = self.days_of_week & (1 << weekday) != 0;
+        if self.restricted_days {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+
+    /// Returns the times of the day the schedule runs at, in seconds since midnight, ascending.
+    pub fn times_of_day(&self) -> impl Iterator<Item = u64> + '_ {
+        bits(self.hours).flat_map(move |hour| {
+            bits(self.minutes).flat_map(move |minute| {
+                bits(self.seconds).map(move |second| hour * 3600 + minute * 60 + second)
+            })
+        })
+    }
+
+    fn matches_days_since_epoch(&self, days: i64) -> bool {
+        let (_, month, day) = civil_from_days(days);
+        let weekday = (days + 4).rem_euclid(7) as u8;
+        self.matches_day(day, month, weekday)
+    }
+
+    /// Returns the shortest interval between two runs of the schedule, `None` if the schedule
+    /// never runs, or runs only once in eight years.
+    pub fn min_interval(&self) -> Option<Duration> {
+        let mut matching_days = (SEARCH_START..SEARCH_START + SEARCH_DAYS)
+            .filter(|d| self.matches_days_since_epoch(*d));
+        let mut previous = matching_days.next()?;
+        let mut day_gap = None;
+        for day in matching_days {
+            day_gap = Some(day_gap.map_or(day - previous, |gap: i64| gap.min(day - previous)));
+            previous = day;
+        }
+        let times: Vec<u64> = self.times_of_day().collect();
+        let (first, last) = (*times.first()?, *times.last()?);
+        let across_days = day_gap.map(|gap| gap as u64 * SECONDS_PER_DAY + first - last);
+        let within_day = times.windows(2).map(|w| w[1] - w[0]).min();
+        let interval = match (within_day, across_days) {
+            (Some(within_day), Some(across_days)) => within_day.min(across_days),
+            (within_day, across_days) => within_day.or(across_days)?,
+        };
+        Some(Duration::from_secs(interval))
+    }
+
+    /// Returns `true` if the schedule runs at least once in eight years.
+    pub fn runs(&self) -> bool {
+        (SEARCH_START..SEARCH_START + SEARCH_DAYS).any(|d| self.matches_days_since_epoch(d))
+    }
+
+    /// Returns the first run of the schedule strictly after `after`, in the same wall-clock
+    /// time, or `None` if the schedule does not run within eight years.
+    #[cfg(feature = "chrono")]
+    pub fn next_occurrence(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let start = after.with_nanosecond(0)? + TimeDelta::seconds(1);
+        let mut date = start.date();
+        let mut from = u64::from(start.num_seconds_from_midnight());
+        for _ in 0..SEARCH_DAYS {
+            let weekday = date.weekday().num_days_from_sunday() as u8;
+            if self.matches_day(date.day() as u8, date.month() as u8, weekday)
+                && let Some(time) = self.times_of_day().find(|t| *t >= from)
+            {
+                return date.and_hms_opt(
+                    (time / 3600) as u32,
+                    (time / 60 % 60) as u32,
+                    (time % 60) as u32,
+                );
+            }
+            date = date.succ_opt()?;
+            from = 0;
+        }
+        None
+    }
+}
+
+/// A structure to define rules or constraints associated with a cron expression.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean field indicating whether the cron expression is mandatory or
+///   optional.
+/// * `allow_seconds` - A boolean field indicating whether a leading `second` field is accepted,
+///   making the expression six fields long.
+/// * `min_interval` - The shortest allowed interval between two runs, `None` for no limit.
+#[derive(Clone)]
+pub struct CronRules {
+    pub is_mandatory: bool,
+    pub allow_seconds: bool,
+    pub min_interval: Option<Duration>,
+}
+
+impl Default for CronRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            allow_seconds: false,
+            min_interval: None,
+        }
+    }
+}
+
+impl From<&CronRules> for StringMandatoryRules {
+    fn from(rules: &CronRules) -> Self {
+        StringMandatoryRules {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: true,
+        }
+    }
+}
+
+impl CronRules {
+    fn rule(&self) -> StringMandatoryRules {
+        self.into()
+    }
+
+    fn max_fields(&self) -> usize {
+        if self.allow_seconds { 6 } else { 5 }
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let rule = self.rule();
+        rule.check(messages, subject);
+    }
+
+    fn check_schedule(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        s: &str,
+    ) -> Option<CronSchedule> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        if !(5..=self.max_fields()).contains(&fields.len()) {
+            let message = if self.allow_seconds {
+                "Must have 5 or 6 fields"
+            } else {
+                "Must have 5 fields"
+            };
+            messages.push((
+                message.to_string(),
+                Box::new(CronLocale::FieldCount(self.max_fields())),
+            ));
+            return None;
+        }
+        let schedule = match CronSchedule::parse(&fields) {
+            Ok(schedule) => schedule,
+            Err(field) => {
+                messages.push((
+                    format!("Invalid {} field", field),
+                    Box::new(CronLocale::InvalidField(field)),
+                ));
+                return None;
+            }
+        };
+        if !schedule.runs() {
+            messages.push(("Never runs".to_string(), Box::new(CronLocale::NeverRuns)));
+            return None;
+        }
+        if let Some(min_interval) = self.min_interval
+            && schedule
+                .min_interval()
+                .is_some_and(|interval| interval < min_interval)
+        {
+            messages.push((
+                format!(
+                    "Must not run more often than every {} seconds",
+                    min_interval.as_secs()
+                ),
+                Box::new(CronLocale::MinInterval(min_interval.as_secs() as usize)),
+            ));
+        }
+        Some(schedule)
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = self.rule().describe();
+        constraints.push(Constraint::from_locale(&CronLocale::FieldCount(
+            self.max_fields(),
+        )));
+        if let Some(min_interval) = self.min_interval {
+            constraints.push(Constraint::from_locale(&CronLocale::MinInterval(
+                min_interval.as_secs() as usize,
+            )));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during cron expression validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Cron Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Cron Validation Error")]
+pub struct CronError(pub ValidateErrorStore);
+
+impl ValidationCheck for CronError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&CronError> for ValidateErrorStore {
+    fn from(error: &CronError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// An enumeration of the cron expression specific validation failures.
+pub enum CronLocale {
+    /// The expression does not have between 5 and the given number of fields.
+    /// # Key
+    /// `validate-cron-field-count`
+    FieldCount(usize),
+    /// The named field is not valid.
+    /// # Key
+    /// `validate-cron-invalid-field`
+    InvalidField(&'static str),
+    /// The expression never runs, e.g. on the 30th of February.
+    /// # Key
+    /// `validate-cron-never-runs`
+    NeverRuns,
+    /// The expression runs more often than every given number of seconds.
+    /// # Key
+    /// `validate-cron-min-interval`
+    MinInterval(usize),
+}
+
+impl LocaleMessage for CronLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::FieldCount(max) => ld::new_with_vec(
+                locale_keys::VALIDATE_CRON_FIELD_COUNT,
+                vec![("max".to_string(), lv::from(*max))],
+            ),
+            Self::InvalidField(field) => ld::new_with_vec(
+                locale_keys::VALIDATE_CRON_INVALID_FIELD,
+                vec![("field".to_string(), lv::from(*field))],
+            ),
+            Self::NeverRuns => ld::new(locale_keys::VALIDATE_CRON_NEVER_RUNS),
+            Self::MinInterval(min) => ld::new_with_vec(
+                locale_keys::VALIDATE_CRON_MIN_INTERVAL,
+                vec![("min".to_string(), lv::from(*min))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::FieldCount(_) | Self::NeverRuns => RuleViolation::pattern("cron"),
+            Self::InvalidField(field) => RuleViolation::pattern(&format!("cron-{}", field)),
+            Self::MinInterval(min) => RuleViolation::Range {
+                min: Some(LocaleValue::from(*min)),
+                max: None,
+            },
+        }
+    }
+}
+
+/// A structure representing a validated cron expression.
+///
+/// # Fields
+/// - `0: String` - The cron expression, trimmed.
+/// - `1: Option<CronSchedule>` - The parsed schedule, `None` if the expression was not provided.
+/// - `2: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cron(String, Option<CronSchedule>, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for Cron {
+    fn default() -> Self {
+        Self(String::new(), None, true)
+    }
+}
+
+impl Cron {
+    /// Parses a cron expression based on the provided validation rules.
+    ///
+    /// # Errors
+    /// Returns a `CronError` if the expression is empty while mandatory, does not have the
+    /// expected number of fields, has an invalid field, never runs, or runs more often than
+    /// `min_interval`.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::cron::{Cron, CronRules};
+    /// use std::time::Duration;
+    ///
+    /// let rules = CronRules {
+    ///     min_interval: Some(Duration::from_secs(15 * 60)),
+    ///     ..CronRules::default()
+    /// };
+    /// assert!(Cron::parse_custom(Some("*/15 9-17 * * MON-FRI"), rules.clone()).is_ok());
+    ///
+    /// let error = Cron::parse_custom(Some("*/5 9-17 * * MON-FRI"), rules)
+    ///     .expect_err("Expected cron to run too often");
+    /// assert_eq!(
+    ///     error.0.as_original_message_vec(),
+    ///     vec!["Must not run more often than every 900 seconds"]
+    /// );
+    /// ```
+    pub fn parse_custom(s: Option<&str>, rules: CronRules) -> Result<Self, CronError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default().trim();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            CronError::validate_check(messages)?;
+            if s.is_empty() && !rules.is_mandatory {
+                return Ok(Self(String::new(), None, true));
+            }
+            let mut messages = ValidateErrorCollector::new();
+            let schedule = rules.check_schedule(&mut messages, s);
+            CronError::validate_check(messages)?;
+            Ok(Self(s.to_string(), schedule, is_none))
+        })
+    }
+
+    /// Parses a cron expression using the default `CronRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, CronError> {
+        Self::parse_custom(s, CronRules::default())
+    }
+
+    /// Returns the parsed schedule, `None` if the expression was not provided.
+    pub fn schedule(&self) -> Option<&CronSchedule> {
+        self.1.as_ref()
+    }
+
+    /// Returns the first run of the schedule strictly after `after`, in the same wall-clock
+    /// time, or `None` if the expression was not provided.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use cjtoolkit_structured_validator::types::cron::Cron;
+    ///
+    /// let cron = Cron::parse(Some("30 9 * * MON")).unwrap_or_else(|_| unreachable!());
+    /// // 2024-06-01 is a Saturday.
+    /// let after = NaiveDate::from_ymd_opt(2024, 6, 1).and_then(|d| d.and_hms_opt(12, 0, 0));
+    /// let next = NaiveDate::from_ymd_opt(2024, 6, 3).and_then(|d| d.and_hms_opt(9, 30, 0));
+    /// assert_eq!(after.and_then(|after| cron.next_occurrence(after)), next);
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn next_occurrence(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        self.1.as_ref()?.next_occurrence(after)
+    }
+
+    /// Returns the cron expression as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the cron expression was not provided.
+    pub fn is_none(&self) -> bool {
+        self.2
+    }
+
+    /// Converts the current instance into an `Option<Cron>`.
+    ///
+    /// # Returns
+    /// - `None` if the cron expression was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<Cron> {
+        if self.2 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for Cron {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&Cron> for String {
+    fn from(cron: &Cron) -> Self {
+        cron.as_str().to_string()
+    }
+}
+
+impl Display for Cron {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Cron {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<Option<&str>> for Cron {
+    type Error = CronError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Cron {
+    type Error = CronError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl Validator<Option<&str>, Cron, CronError> for CronRules {
+    fn validate(&self, input: Option<&str>) -> Result<Cron, CronError> {
+        Cron::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for CronRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_cron() {
+        for s in [
+            "* * * * *",
+            "0 0 1 1 *",
+            "*/15 9-17 * * MON-FRI",
+            "0,30 8-18/2 1,15 jan-jun,DEC sun",
+            "5/10 * * * 7",
+        ] {
+            assert!(Cron::parse(Some(s)).is_ok(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_invalid_cron() {
+        for (s, message) in [
+            ("* * * *", "Must have 5 fields"),
+            ("0 * * * * *", "Must have 5 fields"),
+            ("60 * * * *", "Invalid minute field"),
+            ("* 24 * * *", "Invalid hour field"),
+            ("* * 0 * *", "Invalid day-of-month field"),
+            ("* * * FOO *", "Invalid month field"),
+            ("* * * * 8", "Invalid day-of-week field"),
+            ("*/0 * * * *", "Invalid minute field"),
+            ("5-1 * * * *", "Invalid minute field"),
+            ("1,,2 * * * *", "Invalid minute field"),
+            ("0 0 30 2 *", "Never runs"),
+        ] {
+            let error = Cron::parse(Some(s)).expect_err(s);
+            assert_eq!(error.0.as_original_message_vec(), vec![message], "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_seconds() {
+        let rules = CronRules {
+            allow_seconds: true,
+            ..CronRules::default()
+        };
+        assert!(Cron::parse_custom(Some("*/10 * * * * *"), rules.clone()).is_ok());
+        assert!(Cron::parse_custom(Some("* * * * *"), rules.clone()).is_ok());
+        assert!(Cron::parse_custom(Some("60 * * * * *"), rules).is_err());
+    }
+
+    #[test]
+    fn test_min_interval() {
+        let interval = |s: &str| {
+            let rules = CronRules {
+                allow_seconds: true,
+                ..CronRules::default()
+            };
+            Cron::parse_custom(Some(s), rules)
+                .ok()
+                .and_then(|cron| cron.schedule().and_then(|s| s.min_interval()))
+                .map(|interval| interval.as_secs())
+        };
+        assert_eq!(interval("* * * * *"), Some(60));
+        assert_eq!(interval("*/10 * * * * *"), Some(10));
+        assert_eq!(interval("0 22,2 * * *"), Some(4 * 3600));
+        assert_eq!(interval("0 0 * * MON,WED"), Some(2 * SECONDS_PER_DAY));
+        assert_eq!(interval("0 0 1 * *"), Some(28 * SECONDS_PER_DAY));
+        assert_eq!(interval("0 0 1 * MON"), Some(SECONDS_PER_DAY));
+        assert_eq!(interval("0 0 29 2 *"), Some(1461 * SECONDS_PER_DAY));
+    }
+
+    #[test]
+    fn test_none_cron() {
+        assert!(Cron::parse(None).is_err());
+        let rules = CronRules {
+            is_mandatory: false,
+            ..CronRules::default()
+        };
+        let cron = Cron::parse_custom(None, rules).unwrap_or_default();
+        assert!(cron.is_none());
+        assert!(cron.schedule().is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_next_occurrence() {
+        use chrono::NaiveDate;
+
+        let at = |y, m, d, h, min, s| {
+            NaiveDate::from_ymd_opt(y, m, d).and_then(|date| date.and_hms_opt(h, min, s))
+        };
+        let next = |s: &str, after: Option<NaiveDateTime>| {
+            let cron = Cron::parse(Some(s)).unwrap_or_default();
+            after.and_then(|after| cron.next_occurrence(after))
+        };
+        assert_eq!(
+            next("*/15 * * * *", at(2024, 6, 1, 12, 0, 0)),
+            at(2024, 6, 1, 12, 15, 0)
+        );
+        assert_eq!(
+            next("0 0 29 2 *", at(2024, 3, 1, 0, 0, 0)),
+            at(2028, 2, 29, 0, 0, 0)
+        );
+        assert_eq!(
+            next("0 9 * * *", at(2024, 12, 31, 23, 59, 59)),
+            at(2025, 1, 1, 9, 0, 0)
+        );
+    }
+}
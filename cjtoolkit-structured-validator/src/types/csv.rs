@@ -0,0 +1,122 @@
+//! This module contains the `csv` support of the value types.
+//!
+//! `CsvRow` maps the columns of a `csv::StringRecord`, looked up by header name, to the parse
+//! of each value type, and keeps the errors in a `ValidationReport` keyed by column. An empty
+//! cell or a missing column is parsed as `None`, so the mandatory rules report it.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::batch::BatchValidator;
+//! use cjtoolkit_structured_validator::types::csv::CsvRow;
+//! use cjtoolkit_structured_validator::types::description::Description;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! struct Product {
+//!     name: Name,
+//!     description: Description,
+//! }
+//!
+//! let data = "name,description\nTeapot,Short and stout\nMug,\n";
+//! let mut reader = csv::Reader::from_reader(data.as_bytes());
+//! let headers = reader.headers().expect("Expected headers").clone();
+//!
+//! let report = BatchValidator::new(|record: csv::StringRecord| {
+//!     let mut row = CsvRow::new(&headers, &record);
+//!     let name = row.field("name", Name::parse);
+//!     let description = row.field("description", Description::parse);
+//!     row.into_result()?;
+//!     Ok(Product {
+//!         name: name.expect("Expected valid name"),
+//!         description: description.expect("Expected valid description"),
+//!     })
+//! })
+//! .validate(reader.records().map(|record| record.expect("Expected record")));
+//!
+//! assert_eq!(report.valid_count(), 1);
+//! assert_eq!(report.failed[0].0, 1);
+//! assert_eq!(report.failed[0].1.fields(), vec!["name", "description"]);
+//! ```
+
+use crate::common::locale::ValidateErrorStore;
+use crate::common::validation_report::ValidationReport;
+use ::csv::StringRecord;
+
+/// A record of a CSV file being validated, column by column.
+pub struct CsvRow<'r> {
+    headers: &'r StringRecord,
+    record: &'r StringRecord,
+    report: ValidationReport,
+}
+
+impl<'r> CsvRow<'r> {
+    /// Creates a row from the `headers` of the file and one of its `record`s.
+    pub fn new(headers: &'r StringRecord, record: &'r StringRecord) -> Self {
+        Self {
+            headers,
+            record,
+            report: ValidationReport::new(),
+        }
+    }
+
+    /// Returns the cell of the column named `column`, `None` if the column is missing or the
+    /// cell is empty.
+    pub fn get(&self, column: &str) -> Option<&'r str> {
+        let index = self.headers.iter().position(|header| header == column)?;
+        self.record.get(index).filter(|cell| !cell.is_empty())
+    }
+
+    /// Parses the cell of the column named `column` with `parse`, recording its error under the
+    /// name of the column, then returns the result of `parse`.
+    pub fn field<T, E>(
+        &mut self,
+        column: &str,
+        parse: impl FnOnce(Option<&'r str>) -> Result<T, E>,
+    ) -> Result<T, E>
+    where
+        for<'a> &'a E: Into<ValidateErrorStore>,
+    {
+        let result = parse(self.get(column));
+        self.report.check(column, result)
+    }
+
+    /// Returns the report of the columns parsed so far.
+    pub fn report(&self) -> &ValidationReport {
+        &self.report
+    }
+
+    /// Converts the row into the report of its columns.
+    pub fn into_report(self) -> ValidationReport {
+        self.report
+    }
+
+    /// Returns `Ok(())` if no column has errors, otherwise the report of the row.
+    pub fn into_result(self) -> Result<(), ValidationReport> {
+        self.report.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::{Name, NameRules};
+
+    #[test]
+    fn test_empty_and_missing_cells_are_none() {
+        let headers = StringRecord::from(vec!["first", "middle"]);
+        let record = StringRecord::from(vec!["Alice", ""]);
+        let mut row = CsvRow::new(&headers, &record);
+        let optional = || NameRules {
+            is_mandatory: false,
+            min_length: None,
+            ..NameRules::default()
+        };
+
+        assert_eq!(row.get("first"), Some("Alice"));
+        assert!(
+            row.field("middle", |s| Name::parse_custom(s, optional()))
+                .is_ok()
+        );
+        assert!(row.field("last", Name::parse).is_err());
+        assert_eq!(row.report().fields(), vec!["last"]);
+    }
+}
@@ -1,9 +1,19 @@
 //! This module contains structures and traits for working with text-based descriptions.
 
-use crate::base::string_rules::{StringLengthRules, StringMandatoryRules};
+use crate::base::string_rules::{
+    LengthUnit, SpamHeuristicsRules, StringLengthRules, StringMandatoryRules,
+};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::rejected_input::observe_parse_input;
 use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::tenant_policy::{TightenRules, tighter_max, tighter_min};
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 use thiserror::Error;
 
 /// A struct representing the rules for a description field.
@@ -24,10 +34,23 @@ use thiserror::Error;
 /// * `max_length` (`Option<usize>`): The maximum allowable length for the description.
 ///   - `Some(usize)`: The maximum length is specified.
 ///   - `None`: No maximum length is enforced.
+///
+/// * `length_unit` (`LengthUnit`): The unit the length of the description is counted in,
+///   graphemes by default.
+///
+/// * `treat_whitespace_as_empty` (`bool`): Whether a description made only of whitespace is
+///   treated as empty, `true` by default.
+///
+/// * `spam_heuristics` (`SpamHeuristicsRules`): The spam heuristics applied to the description,
+///   e.g. the maximum number of links, none by default.
+#[derive(Clone)]
 pub struct DescriptionRules {
     pub is_mandatory: bool,
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
+    pub length_unit: LengthUnit,
+    pub treat_whitespace_as_empty: bool,
+    pub spam_heuristics: SpamHeuristicsRules,
 }
 
 impl Default for DescriptionRules {
@@ -36,6 +59,9 @@ impl Default for DescriptionRules {
             is_mandatory: true,
             min_length: None,
             max_length: Some(40),
+            length_unit: LengthUnit::Graphemes,
+            treat_whitespace_as_empty: true,
+            spam_heuristics: SpamHeuristicsRules::default(),
         }
     }
 }
@@ -45,10 +71,12 @@ impl Into<(StringMandatoryRules, StringLengthRules)> for &DescriptionRules {
         (
             StringMandatoryRules {
                 is_mandatory: self.is_mandatory,
+                treat_whitespace_as_empty: self.treat_whitespace_as_empty,
             },
             StringLengthRules {
                 min_length: self.min_length,
                 max_length: self.max_length,
+                unit: self.length_unit,
             },
         )
     }
@@ -74,6 +102,16 @@ impl DescriptionRules {
             return;
         }
         length_rule.check(messages, subject);
+        self.spam_heuristics.check(messages, subject);
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, length_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(length_rule.describe());
+        constraints.extend(self.spam_heuristics.describe());
+        constraints
     }
 }
 
@@ -112,7 +150,12 @@ impl Into<ValidateErrorStore> for &DescriptionError {
 /// - `Clone`: Allows for creating a copy of a `Description` instance.
 ///
 /// Note: The inner fields are private and can only be accessed or manipulated through related methods or functions if implemented.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
 pub struct Description(String, bool);
 
 #[cfg(any(feature = "allow-default-value", test))]
@@ -173,13 +216,15 @@ impl Description {
         s: Option<&str>,
         rules: DescriptionRules,
     ) -> Result<Self, DescriptionError> {
-        let is_none = s.is_none();
-        let s = s.unwrap_or_default();
-        let subject = s.as_string_validator();
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, &subject, is_none);
-        DescriptionError::validate_check(messages)?;
-        Ok(Self(s.to_string(), is_none))
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            DescriptionError::validate_check(messages)?;
+            Ok(Self(s.to_string(), is_none))
+        })
     }
 
     /// Parses an optional string slice into an instance of the implementing type, using the default parsing rules.
@@ -219,17 +264,98 @@ impl Description {
         &self.0
     }
 
+    /// Returns `true` if the description was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
     pub fn into_option(self) -> Option<Description> {
         if self.1 { None } else { Some(self) }
     }
 }
 
+impl OptionalValue for Description {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
 impl Into<String> for &Description {
     fn into(self) -> String {
         self.as_str().to_string()
     }
 }
 
+impl Display for Description {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Description {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Description {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `Description` key can be found by
+/// a `&str`.
+impl PartialEq for Description {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Description {}
+
+impl Hash for Description {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for Description {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Description {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for Description {
+    type Error = DescriptionError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Description {
+    type Error = DescriptionError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for Description {
+    type Error = DescriptionError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
 pub mod description_alias {
     use super::*;
 
@@ -249,3 +375,120 @@ pub mod description_alias {
     pub type IngredientsError = DescriptionError;
     pub type Ingredients = Description;
 }
+
+impl TightenRules for DescriptionRules {
+    fn tighten(&self, other: &Self) -> Self {
+        Self {
+            is_mandatory: self.is_mandatory || other.is_mandatory,
+            min_length: tighter_min(self.min_length, other.min_length),
+            max_length: tighter_max(self.max_length, other.max_length),
+            length_unit: self.length_unit,
+            treat_whitespace_as_empty: self.treat_whitespace_as_empty
+                || other.treat_whitespace_as_empty,
+            spam_heuristics: self.spam_heuristics.tighten(&other.spam_heuristics),
+        }
+    }
+}
+
+impl Validator<Option<&str>, Description, DescriptionError> for DescriptionRules {
+    fn validate(&self, input: Option<&str>) -> Result<Description, DescriptionError> {
+        Description::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for DescriptionRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl DescriptionRules {
+    /// Returns an input satisfying the rules, `Sample description` repeated or truncated to fit the length
+    /// constraints.
+    pub fn arbitrary_valid_input(&self) -> String {
+        crate::common::fixtures::sample_string(
+            "Sample description",
+            self.min_length,
+            self.max_length,
+        )
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl Description {
+    /// Returns a valid `Description` for the default `DescriptionRules`.
+    pub fn valid_sample() -> Self {
+        Self::sample_matching(DescriptionRules::default())
+    }
+
+    /// Returns a valid `Description` for the given rules, parsed from `arbitrary_valid_input`.
+    ///
+    /// # Panics
+    /// Panics if no input can satisfy the rules, e.g. `min_length` above `max_length`.
+    pub fn sample_matching(rules: DescriptionRules) -> Self {
+        let input = rules.arbitrary_valid_input();
+        Self::parse_custom(Some(&input), rules).expect("Expected sample to be valid")
+    }
+}
+
+#[cfg(feature = "ammonia")]
+impl Description {
+    /// Parses the HTML of a rich-text editor, sanitized with `ammonia` before being checked
+    /// against the rules, see the `ammonia` module.
+    ///
+    /// # Errors
+    /// Returns a `DescriptionError` if the sanitized HTML violates the description rules, or has
+    /// more tags than `max_allowed_tags`. The tags and attributes that were stripped are not
+    /// errors, they are returned as warnings in `CheckOutcome::warnings`.
+    pub fn parse_sanitized(
+        s: Option<&str>,
+        rules: crate::types::ammonia::HtmlSanitizeRules,
+    ) -> Result<crate::common::check_budget::CheckOutcome<Self>, DescriptionError> {
+        let sanitized = s.map(|s| rules.sanitize(s));
+        let value = observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let html = sanitized
+                .as_ref()
+                .map(|sanitized| sanitized.html.as_str())
+                .unwrap_or_default();
+            let mut messages = ValidateErrorCollector::new();
+            rules
+                .description
+                .check(&mut messages, &html.as_string_validator(), is_none);
+            if let Some(sanitized) = &sanitized {
+                rules.check(&mut messages, sanitized);
+            }
+            DescriptionError::validate_check(messages)?;
+            Ok(Self(html.to_string(), is_none))
+        })?;
+        let mut warnings = ValidateErrorCollector::new();
+        if let Some(sanitized) = &sanitized {
+            sanitized.warn_stripped(&mut warnings);
+        }
+        Ok(crate::common::check_budget::CheckOutcome {
+            value,
+            warnings: warnings.into(),
+        })
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Description {
+    /// Creates an instance of `Description` without validation, e.g. when loading values which were
+    /// validated before being stored. The string is kept as is, `None` gives an empty value.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(s.to_string(), is_none)
+    }
+
+    /// Creates an instance of `Description` from a stored string without validation, so stored values
+    /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
@@ -0,0 +1,178 @@
+//! This module contains the `diesel` support of the value types.
+//!
+//! The string types are stored as `Text`, `Integer` and the unsigned types as `BigInt` and `Float`
+//! as `Double`. A value that was not provided is written as `NULL`, use `Option<T>` for
+//! nullable columns.
+//!
+//! Reading a value back does not validate, the stored values are loaded with `from_stored` and
+//! `new_unchecked`, as they were validated with the rules of the application before being
+//! stored, and may no longer satisfy the rules, or the default rules of the type. Only the
+//! numbers out of the range of the type are reported as deserialization errors.
+//!
+//! The numeric types can only be written to backends collecting binds as raw bytes, such as
+//! PostgreSQL and MySQL, they can be read back from every backend.
+
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql};
+use ::diesel::query_builder::bind_collector::RawBytesBindCollector;
+use ::diesel::serialize::{self, IsNull, Output, ToSql};
+use ::diesel::sql_types::{BigInt, Double, Text};
+
+macro_rules! diesel_str {
+    ($type:ty) => {
+        impl<DB> ToSql<Text, DB> for $type
+        where
+            DB: Backend,
+            str: ToSql<Text, DB>,
+        {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                if self.is_none() {
+                    return Ok(IsNull::Yes);
+                }
+                self.as_str().to_sql(out)
+            }
+        }
+
+        impl<DB> FromSql<Text, DB> for $type
+        where
+            DB: Backend,
+            String: FromSql<Text, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                let s = String::from_sql(bytes)?;
+                Ok(<$type>::from_stored(&s))
+            }
+        }
+    };
+}
+
+macro_rules! diesel_number {
+    ($type:ty, $sql_type:ty, $inner:ty, |$value:ident| $to_inner:expr, |$decoded:ident| $from_inner:expr) => {
+        impl<DB> ToSql<$sql_type, DB> for $type
+        where
+            for<'c> DB: Backend<BindCollector<'c> = RawBytesBindCollector<DB>>,
+            $inner: ToSql<$sql_type, DB>,
+        {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                let $value = self;
+                if $value.is_none() {
+                    return Ok(IsNull::Yes);
+                }
+                let inner: $inner = $to_inner;
+                inner.to_sql(&mut out.reborrow())
+            }
+        }
+
+        impl<DB> FromSql<$sql_type, DB> for $type
+        where
+            DB: Backend,
+            $inner: FromSql<$sql_type, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                let $decoded = <$inner>::from_sql(bytes)?;
+                Ok($from_inner)
+            }
+        }
+    };
+}
+
+diesel_str!(crate::types::name::Name);
+diesel_str!(crate::types::description::Description);
+diesel_str!(crate::types::username::Username);
+diesel_str!(crate::types::social::Handle);
+diesel_str!(crate::types::net::domain::Domain);
+diesel_str!(crate::types::net::hostname::Hostname);
+diesel_str!(crate::types::net::mac_address::MacAddress);
+
+#[cfg(feature = "email")]
+diesel_str!(crate::types::email::Email);
+
+#[cfg(feature = "url")]
+diesel_str!(crate::types::url::Url);
+
+diesel_number!(
+    crate::types::numbers::float::Float,
+    Double,
+    f64,
+    |value| value.as_f64(),
+    |decoded| crate::types::numbers::float::Float::new_unchecked(Some(decoded))
+);
+
+diesel_number!(
+    crate::types::numbers::integer::Integer,
+    BigInt,
+    i64,
+    |value| i64::try_from(value.as_isize())?,
+    |decoded| crate::types::numbers::integer::Integer::new_unchecked(Some(isize::try_from(
+        decoded
+    )?))
+);
+
+diesel_number!(
+    crate::types::numbers::unsigned::Unsigned,
+    BigInt,
+    i64,
+    |value| i64::try_from(value.as_usize())?,
+    |decoded| {
+        crate::types::numbers::unsigned::Unsigned::new_unchecked(Some(usize::try_from(decoded)?))
+    }
+);
+
+diesel_number!(
+    crate::types::numbers::unsigned::U64,
+    BigInt,
+    i64,
+    |value| i64::try_from(value.as_u64())?,
+    |decoded| crate::types::numbers::unsigned::U64::new_unchecked(Some(u64::try_from(decoded)?))
+);
+
+diesel_number!(
+    crate::types::numbers::unsigned::U32,
+    BigInt,
+    i64,
+    |value| i64::from(value.as_u32()),
+    |decoded| crate::types::numbers::unsigned::U32::new_unchecked(Some(u32::try_from(decoded)?))
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::name_alias::FirstName;
+    use crate::types::name::{Name, NameRules};
+    use crate::types::numbers::integer::Integer;
+    use ::diesel::dsl::sql;
+    use ::diesel::sql_types::Nullable;
+    use ::diesel::{Connection, IntoSql, RunQueryDsl, SqliteConnection};
+
+    fn connect() -> SqliteConnection {
+        SqliteConnection::establish(":memory:").expect("Expected in-memory database")
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut connection = connect();
+        let name = Name::parse(Some("Valid Name")).expect("Expected valid name");
+        let selected =
+            ::diesel::select(name.clone().into_sql::<Text>()).get_result::<Name>(&mut connection);
+        assert_eq!(selected.ok(), Some(name));
+        let optional = NameRules {
+            is_mandatory: false,
+            ..NameRules::default()
+        };
+        let absent = Name::parse_custom(None, optional).expect("Expected absent name");
+        let selected = ::diesel::select(absent.into_sql::<Nullable<Text>>())
+            .get_result::<Option<Name>>(&mut connection);
+        assert_eq!(selected.ok(), Some(None));
+    }
+
+    #[test]
+    fn test_from_sql_does_not_validate() {
+        let mut connection = connect();
+        let (name, first_name, count) =
+            ::diesel::select(sql::<(Text, Text, BigInt)>("'Al', 'Al', 1000"))
+                .get_result::<(Name, FirstName, Integer)>(&mut connection)
+                .expect("Expected row");
+        assert_eq!((name.as_str(), first_name.as_str()), ("Al", "Al"));
+        assert_eq!(count.as_isize(), 1000);
+    }
+}
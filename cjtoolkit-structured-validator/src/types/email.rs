@@ -1,12 +1,27 @@
 //! This module contains structures and traits for working with email addresses.
+//!
+//! `EmailList` validates a list of addresses separated by commas or semicolons, e.g. the
+//! recipients of a message, keeping the errors of each address with its position.
 
-use crate::base::string_rules::StringMandatoryRules;
+use crate::base::collection_rules::CollectionRules;
+use crate::base::string_rules::{LengthUnit, StringLengthRules, StringMandatoryRules};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{
-    LocaleData, LocaleMessage, ValidateErrorCollector, ValidateErrorStore,
+    LocaleData, LocaleMessage, RuleViolation, ValidateErrorCollector, ValidateErrorStore,
 };
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::{observe_parse, rule_result};
+use crate::common::rejected_input::observe_parse_input;
 use crate::common::string_validator::{StrValidationExtension, StringValidator};
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validation_report::ValidationReport;
+use crate::common::validator::Validator;
 use email_address_parser::EmailAddress;
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -16,26 +31,106 @@ use thiserror::Error;
 /// - `is_mandatory` (bool): Determines whether the email field is mandatory or optional.
 ///   - `true`: The email field is required and must be provided.
 ///   - `false`: The email field is optional and can be left empty.
+/// - `min_length` (Option<usize>): The minimum number of characters, `None` for no minimum.
+/// - `max_length` (Option<usize>): The maximum number of characters, `None` for no maximum.
+/// - `forbid_plus_addressing` (bool): Rejects addresses with a `+` tag in the local part, such as
+///   `user+tag@example.com`.
+/// - `require_tld` (bool): Rejects addresses whose domain has no top-level domain, such as
+///   `user@localhost`.
+/// - `max_errors` (Option<usize>): The maximum number of errors reported, the most relevant
+///   being kept, `None` to report every error.
+/// - `allow_unicode` (bool): Accepts internationalized addresses, with non-ASCII characters in
+///   the local part or the domain, such as `josé@bücher.de`. With the `idna` feature, the domain
+///   must also convert to punycode, see `Email::ascii_compatible`.
+/// - `normalize` (EmailNormalization): The normalization of the address returned by
+///   `Email::as_str`, the address being kept as entered by default.
+///
+/// The default is mandatory and allows Unicode, without any of the other policies.
+#[derive(Clone)]
 pub struct EmailRules {
     pub is_mandatory: bool,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub forbid_plus_addressing: bool,
+    pub require_tld: bool,
+    pub max_errors: Option<usize>,
+    pub allow_unicode: bool,
+    pub normalize: EmailNormalization,
 }
 
 impl Default for EmailRules {
     fn default() -> Self {
-        Self { is_mandatory: true }
+        Self {
+            is_mandatory: true,
+            min_length: None,
+            max_length: None,
+            forbid_plus_addressing: false,
+            require_tld: false,
+            max_errors: None,
+            allow_unicode: true,
+            normalize: EmailNormalization::None,
+        }
     }
 }
 
-impl Into<StringMandatoryRules> for &EmailRules {
-    fn into(self) -> StringMandatoryRules {
-        StringMandatoryRules {
-            is_mandatory: self.is_mandatory,
+/// The normalization of an email address, e.g. to compare addresses when deduplicating them.
+///
+/// # Variants
+/// - `None`: The address as entered, the default.
+/// - `LowercaseDomain`: The domain in lowercase, e.g. `John.Doe@example.com` for
+///   `John.Doe@Example.COM`, domains being case-insensitive.
+/// - `Canonical`: The address in lowercase without the plus addressing tag, and without the dots
+///   of the local part for Gmail addresses, e.g. `johndoe@gmail.com` for
+///   `John.Doe+news@GMail.com`, so the aliases of a mailbox compare equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmailNormalization {
+    #[default]
+    None,
+    LowercaseDomain,
+    Canonical,
+}
+
+impl EmailNormalization {
+    const GMAIL_DOMAINS: [&'static str; 2] = ["gmail.com", "googlemail.com"];
+
+    fn apply(&self, email: &EmailAddress) -> String {
+        let local_part = email.get_local_part();
+        let domain = email.get_domain().to_lowercase();
+        match self {
+            Self::None => email.to_string(),
+            Self::LowercaseDomain => format!("{local_part}@{domain}"),
+            Self::Canonical => {
+                let mut local_part = local_part.to_lowercase();
+                if let Some(index) = local_part.find('+') {
+                    local_part.truncate(index);
+                }
+                if Self::GMAIL_DOMAINS.contains(&domain.as_str()) {
+                    local_part.retain(|c| c != '.');
+                }
+                format!("{local_part}@{domain}")
+            }
         }
     }
 }
 
+impl From<&EmailRules> for (StringMandatoryRules, StringLengthRules) {
+    fn from(rules: &EmailRules) -> Self {
+        (
+            StringMandatoryRules {
+                is_mandatory: rules.is_mandatory,
+                treat_whitespace_as_empty: false,
+            },
+            StringLengthRules {
+                min_length: rules.min_length,
+                max_length: rules.max_length,
+                unit: LengthUnit::Chars,
+            },
+        )
+    }
+}
+
 impl EmailRules {
-    fn rule(&self) -> StringMandatoryRules {
+    fn rules(&self) -> (StringMandatoryRules, StringLengthRules) {
         self.into()
     }
 
@@ -48,8 +143,77 @@ impl EmailRules {
         if !self.is_mandatory && is_none {
             return;
         }
-        let rule = self.rule();
-        rule.check(messages, subject);
+        let (mandatory_rule, length_rule) = self.rules();
+        mandatory_rule.check(messages, subject);
+        if !messages.is_empty() {
+            return;
+        }
+        length_rule.check(messages, subject);
+    }
+
+    fn check_address(&self, messages: &mut ValidateErrorCollector, email: Option<&EmailAddress>) {
+        rule_result(locale_keys::VALIDATE_EMAIL_INVALID, email.is_some());
+        let Some(email) = email else {
+            messages.push((
+                "Invalid Email".to_string(),
+                Box::new(EmailAddressLocale::InvalidEmail),
+            ));
+            return;
+        };
+        if !self.allow_unicode {
+            let is_ascii = email.get_local_part().is_ascii() && email.get_domain().is_ascii();
+            rule_result(locale_keys::VALIDATE_EMAIL_UNICODE, is_ascii);
+            if !is_ascii {
+                messages.push((
+                    "Must only contain ASCII characters".to_string(),
+                    Box::new(EmailAddressLocale::Unicode),
+                ));
+            }
+        }
+        if self.forbid_plus_addressing {
+            let is_plus_addressed = email.get_local_part().contains('+');
+            rule_result(
+                locale_keys::VALIDATE_EMAIL_PLUS_ADDRESSING,
+                !is_plus_addressed,
+            );
+            if is_plus_addressed {
+                messages.push((
+                    "Must not use plus addressing".to_string(),
+                    Box::new(EmailAddressLocale::PlusAddressing),
+                ));
+            }
+        }
+        if self.require_tld {
+            let has_tld = email
+                .get_domain()
+                .rsplit_once('.')
+                .is_some_and(|(_, tld)| !tld.is_empty() && tld.chars().all(char::is_alphanumeric));
+            rule_result(locale_keys::VALIDATE_EMAIL_MISSING_TLD, has_tld);
+            if !has_tld {
+                messages.push((
+                    "Domain must have a top-level domain".to_string(),
+                    Box::new(EmailAddressLocale::MissingTld),
+                ));
+            }
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, length_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(length_rule.describe());
+        constraints.push(Constraint::from_locale(&EmailAddressLocale::InvalidEmail));
+        if self.forbid_plus_addressing {
+            constraints.push(Constraint::from_locale(&EmailAddressLocale::PlusAddressing));
+        }
+        if !self.allow_unicode {
+            constraints.push(Constraint::from_locale(&EmailAddressLocale::Unicode));
+        }
+        if self.require_tld {
+            constraints.push(Constraint::from_locale(&EmailAddressLocale::MissingTld));
+        }
+        constraints
     }
 }
 
@@ -92,7 +256,7 @@ impl Into<ValidateErrorStore> for &EmailError {
 /// and a boolean flag to indicate whether the email is none or not.
 ///
 /// # Fields
-/// - `0: String` - The raw email address as a string.
+/// - `0: String` - The email address as a string, normalized as set by `EmailRules::normalize`.
 /// - `1: Option<EmailAddress>` - An optional `EmailAddress` type representing a validated email, if applicable.
 /// - `2: bool` - A boolean indicating whether the email is none (`true`) or not (`false`).
 ///
@@ -100,7 +264,12 @@ impl Into<ValidateErrorStore> for &EmailError {
 /// - `Debug` - Enables formatting of the struct using the `{:?}` formatter for debugging purposes.
 /// - `PartialEq` - Enables comparison of two `Email` instances for equality.
 /// - `Clone` - Allows creating a clone (deep copy) of an `Email` instance.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
 pub struct Email(String, Option<EmailAddress>, bool);
 
 #[cfg(any(feature = "allow-default-value", test))]
@@ -137,14 +306,49 @@ pub enum EmailAddressLocale {
     /// # Key
     /// `validate-email-does-not-match`
     DoesNotMatch,
+    /// Indicates that the email address uses plus addressing, forbidden by the rules.
+    /// # Key
+    /// `validate-email-plus-addressing`
+    PlusAddressing,
+    /// Indicates that the domain of the email address has no top-level domain.
+    /// # Key
+    /// `validate-email-missing-tld`
+    MissingTld,
+    /// Indicates that the email address contains non-ASCII characters, forbidden by the rules.
+    /// # Key
+    /// `validate-email-unicode`
+    Unicode,
+    /// Indicates that the email address is listed more than once in an `EmailList`.
+    /// # Key
+    /// `validate-email-duplicate`
+    Duplicate,
 }
 
 impl LocaleMessage for EmailAddressLocale {
     fn get_locale_data(&self) -> Arc<LocaleData> {
         use LocaleData as ld;
         match self {
-            Self::InvalidEmail => ld::new("validate-email-invalid"),
-            Self::DoesNotMatch => ld::new("validate-email-does-not-match"),
+            Self::InvalidEmail => ld::new(locale_keys::VALIDATE_EMAIL_INVALID),
+            Self::DoesNotMatch => ld::new(locale_keys::VALIDATE_EMAIL_DOES_NOT_MATCH),
+            Self::PlusAddressing => ld::new(locale_keys::VALIDATE_EMAIL_PLUS_ADDRESSING),
+            Self::MissingTld => ld::new(locale_keys::VALIDATE_EMAIL_MISSING_TLD),
+            Self::Unicode => ld::new(locale_keys::VALIDATE_EMAIL_UNICODE),
+            Self::Duplicate => ld::new(locale_keys::VALIDATE_EMAIL_DUPLICATE),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::InvalidEmail => RuleViolation::pattern("email"),
+            Self::DoesNotMatch => RuleViolation::DoesNotMatch,
+            Self::PlusAddressing => {
+                RuleViolation::Custom(locale_keys::VALIDATE_EMAIL_PLUS_ADDRESSING.to_string())
+            }
+            Self::MissingTld => RuleViolation::pattern("email-tld"),
+            Self::Unicode => RuleViolation::forbidden_chars("non-ascii"),
+            Self::Duplicate => {
+                RuleViolation::Custom(locale_keys::VALIDATE_EMAIL_DUPLICATE.to_string())
+            }
         }
     }
 }
@@ -167,10 +371,10 @@ impl Email {
     ///
     /// 1. Determines if the input is `None`. If `None`, treats it as an empty string.
     /// 2. Validates the string using `rules` by invoking its `check` method, collecting any validation errors.
-    /// 3. If validation errors are present, returns an `EmailError`.
-    /// 4. Attempts to parse the string into an `EmailAddress`:
-    ///    - If parsing succeeds, wraps the parsed email and relevant details in the result.
-    ///    - If parsing fails, creates and returns an `EmailError` indicating an invalid email format.
+    /// 3. Unless the input is `None`, or empty and mandatory, parses it into an `EmailAddress` and
+    ///    checks the format, Unicode, plus addressing and top-level domain policies, collecting
+    ///    errors with those of step 2.
+    /// 4. If validation errors are present, returns an `EmailError` holding all of them.
     ///
     /// # Errors
     ///
@@ -197,26 +401,34 @@ impl Email {
     /// - The email parsing relies on the functionality of the `EmailAddress` type.
     /// - Validation errors are accumulated and returned collectively within an `EmailError`.
     pub fn parse_custom(s: Option<&str>, rules: EmailRules) -> Result<Self, EmailError> {
-        let is_none = s.is_none();
-        let s = s.unwrap_or_default();
-        let subject = s.as_string_validator();
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, &subject, is_none);
-        EmailError::validate_check(messages)?;
-
-        let email = match EmailAddress::parse(s, None) {
-            Some(email) => email,
-            None => {
-                let mut messages = ValidateErrorCollector::new();
-                messages.push((
-                    "Invalid Email".to_string(),
-                    Box::new(EmailAddressLocale::InvalidEmail),
-                ));
-                return Err(EmailError(messages.into()));
-            }
-        };
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+
+            // An empty string is already reported by the mandatory rule.
+            let email = if is_none || (s.is_empty() && rules.is_mandatory) {
+                None
+            } else {
+                let email = EmailAddress::parse(s, None);
+                #[cfg(feature = "idna")]
+                let email = email.filter(|email| idna::domain_to_ascii(email.get_domain()).is_ok());
+                rules.check_address(&mut messages, email.as_ref());
+                email
+            };
+            messages.truncate_by_priority(rules.max_errors);
+            EmailError::validate_check(messages)?;
 
-        Ok(Self(s.to_string(), Some(email), is_none))
+            let s = match &email {
+                Some(email) if rules.normalize != EmailNormalization::None => {
+                    rules.normalize.apply(email)
+                }
+                _ => s.to_string(),
+            };
+            Ok(Self(s, email, is_none))
+        })
     }
 
     /// Parses an optional string slice into an instance of the current type.
@@ -273,15 +485,23 @@ impl Email {
     /// # Errors
     /// - `EmailError`: Encapsulates a collection of one or more validation errors indicating the mismatch or other issues.
     pub fn parse_confirm(&self, confirm_email: &str) -> Result<Self, EmailError> {
-        let mut messages = ValidateErrorCollector::new();
-        if self.0 != confirm_email.to_string() {
-            messages.push((
-                "Email does not match".to_string(),
-                Box::new(EmailAddressLocale::DoesNotMatch),
-            ));
-        }
-        EmailError::validate_check(messages)?;
-        Ok(self.clone())
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            let is_match = self.0 == confirm_email
+                || self
+                    .1
+                    .as_ref()
+                    .is_some_and(|email| email.to_string() == confirm_email);
+            rule_result(locale_keys::VALIDATE_EMAIL_DOES_NOT_MATCH, is_match);
+            if !is_match {
+                messages.push((
+                    "Email does not match".to_string(),
+                    Box::new(EmailAddressLocale::DoesNotMatch),
+                ));
+            }
+            EmailError::validate_check(messages)?;
+            Ok(self.clone())
+        })
     }
 
     /// Retrieves the email address associated with the object, if available.
@@ -294,6 +514,59 @@ impl Email {
         self.1.as_ref()
     }
 
+    /// Returns the address in its ASCII-compatible form, the domain being converted to punycode,
+    /// e.g. `user@xn--bcher-kva.de` for `user@bücher.de`.
+    ///
+    /// Returns `None` if the email was not provided, or if the local part contains non-ASCII
+    /// characters, as it has no ASCII-compatible form.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::email::Email;
+    ///
+    /// let email = Email::parse(Some("user@bücher.de")).expect("Expected valid email");
+    /// assert_eq!(email.ascii_compatible().as_deref(), Some("user@xn--bcher-kva.de"));
+    /// ```
+    #[cfg(feature = "idna")]
+    pub fn ascii_compatible(&self) -> Option<String> {
+        let email = self.as_email()?;
+        let local_part = email.get_local_part();
+        if !local_part.is_ascii() {
+            return None;
+        }
+        let domain = idna::domain_to_ascii(email.get_domain()).ok()?;
+        Some(format!("{local_part}@{domain}"))
+    }
+
+    /// Returns the local part of the address as entered, e.g. `John.Doe` for
+    /// `John.Doe@example.com`, `None` if the email was not provided.
+    pub fn local_part(&self) -> Option<&str> {
+        self.as_email().map(EmailAddress::get_local_part)
+    }
+
+    /// Returns the domain of the address as entered, e.g. `example.com` for
+    /// `John.Doe@example.com`, `None` if the email was not provided.
+    pub fn domain(&self) -> Option<&str> {
+        self.as_email().map(EmailAddress::get_domain)
+    }
+
+    /// Returns the address normalized with `normalization`, e.g. to compare addresses when
+    /// deduplicating them, `None` if the email was not provided.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::email::{Email, EmailNormalization};
+    ///
+    /// let email = Email::parse(Some("John.Doe+news@GMail.com")).expect("Expected valid email");
+    /// assert_eq!(
+    ///     email.normalized(EmailNormalization::Canonical).as_deref(),
+    ///     Some("johndoe@gmail.com")
+    /// );
+    /// ```
+    pub fn normalized(&self, normalization: EmailNormalization) -> Option<String> {
+        self.as_email().map(|email| normalization.apply(email))
+    }
+
     /// Returns a string slice (`&str`) that represents the value stored in the current instance.
     ///
     /// # Returns
@@ -303,6 +576,11 @@ impl Email {
         &self.0
     }
 
+    /// Returns `true` if the email was not provided.
+    pub fn is_none(&self) -> bool {
+        self.2
+    }
+
     /// Converts the current instance of the type into an `Option<Email>`.
     ///
     /// This method checks the value of a boolean flag (assumed to be the
@@ -320,16 +598,417 @@ impl Email {
     }
 }
 
+impl OptionalValue for Email {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
 impl Into<String> for &Email {
     fn into(self) -> String {
         self.as_str().to_string()
     }
 }
 
+impl Display for Email {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Email {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `Email` key can be found by
+/// a `&str`.
+impl PartialEq for Email {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Email {}
+
+impl Hash for Email {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for Email {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Email {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for Email {
+    type Error = EmailError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Email {
+    type Error = EmailError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = EmailError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, Email, EmailError> for EmailRules {
+    fn validate(&self, input: Option<&str>) -> Result<Email, EmailError> {
+        Email::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for EmailRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+/// A structure to define the rules of a list of email addresses, e.g. the recipients of a
+/// message.
+///
+/// # Fields
+/// - `is_mandatory` (bool): Whether at least one address must be provided.
+/// - `max_recipients` (Option<usize>): The maximum number of addresses, `None` for no maximum.
+/// - `forbid_duplicates` (bool): Rejects the addresses listed more than once, compared once
+///   normalized with `duplicate_normalization`.
+/// - `duplicate_normalization` (EmailNormalization): The normalization of the addresses compared
+///   for duplicates, e.g. `Canonical` to also reject the aliases of a mailbox.
+/// - `email` (EmailRules): The rules of each address.
+///
+/// The default is mandatory and rejects duplicates with a lowercase domain, with the default
+/// `EmailRules`.
+#[derive(Clone)]
+pub struct EmailListRules {
+    pub is_mandatory: bool,
+    pub max_recipients: Option<usize>,
+    pub forbid_duplicates: bool,
+    pub duplicate_normalization: EmailNormalization,
+    pub email: EmailRules,
+}
+
+impl Default for EmailListRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            max_recipients: None,
+            forbid_duplicates: true,
+            duplicate_normalization: EmailNormalization::LowercaseDomain,
+            email: EmailRules::default(),
+        }
+    }
+}
+
+impl From<&EmailListRules> for CollectionRules {
+    fn from(rules: &EmailListRules) -> Self {
+        Self {
+            is_mandatory: rules.is_mandatory,
+            min_items: None,
+            max_items: rules.max_recipients,
+        }
+    }
+}
+
+impl EmailListRules {
+    /// Returns the constraints enforced on the list, those of each address being returned by
+    /// `EmailRules::describe`.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let rule: CollectionRules = self.into();
+        let mut constraints = rule.describe();
+        if self.forbid_duplicates {
+            constraints.push(Constraint::from_locale(&EmailAddressLocale::Duplicate));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during the validation of an email list.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Email List Validation Error"`.
+///
+/// # Fields
+/// - `list`: The errors of the list itself, e.g. too many addresses.
+/// - `entries`: The errors of each invalid address, with its position in the list.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Email List Validation Error")]
+pub struct EmailListError {
+    pub list: ValidateErrorStore,
+    pub entries: Vec<(usize, EmailError)>,
+}
+
+impl EmailListError {
+    /// Returns the errors as a report, those of the list keyed by `field` and those of each
+    /// address keyed by `field` and its position, e.g. `to[1]`.
+    pub fn report(&self, field: &str) -> ValidationReport {
+        let mut report = ValidationReport::new();
+        if !self.list.0.is_empty() {
+            report.0.push((field.to_string(), self.list.clone()));
+        }
+        for (index, error) in &self.entries {
+            report
+                .0
+                .push((format!("{}[{}]", field, index), error.0.clone()));
+        }
+        report
+    }
+}
+
+/// Every error, those of the list followed by those of each address, without their position.
+impl From<&EmailListError> for ValidateErrorStore {
+    fn from(error: &EmailListError) -> Self {
+        let mut messages = error.list.as_validate_error_collector();
+        for (_, error) in &error.entries {
+            messages.0.extend(error.0.as_validate_error_collector().0);
+        }
+        messages.into()
+    }
+}
+
+/// A structure representing a validated list of email addresses.
+///
+/// # Fields
+/// - `0: Vec<Email>` - The addresses, in the order they were listed.
+/// - `1: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EmailList(Vec<Email>, bool);
+
+impl EmailList {
+    /// Parses a list of email addresses separated by commas or semicolons, ignoring the blanks
+    /// around them and the empty entries, validating each address with the rules.
+    ///
+    /// # Errors
+    /// Returns an `EmailListError` if the list is empty while mandatory, has too many
+    /// addresses, or any address is invalid or listed more than once, the errors of each
+    /// address being kept with its position.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::email::{EmailList, EmailListRules};
+    ///
+    /// let list = EmailList::parse(Some("ada@example.com; alan@example.com,"), EmailListRules::default());
+    /// assert_eq!(list.map(|l| l.len()).ok(), Some(2));
+    ///
+    /// let error = EmailList::parse(Some("ada@example.com, nope, ada@Example.com"), EmailListRules::default())
+    ///     .expect_err("Expected invalid list");
+    /// assert_eq!(error.report("to").fields(), vec!["to[1]", "to[2]"]);
+    /// ```
+    pub fn parse(s: Option<&str>, rules: EmailListRules) -> Result<Self, EmailListError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let entries: Vec<&str> = s
+                .unwrap_or_default()
+                .split([',', ';'])
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .collect();
+
+            let mut list = ValidateErrorCollector::new();
+            let rule: CollectionRules = (&rules).into();
+            rule.check(&mut list, (!entries.is_empty()).then_some(entries.len()));
+
+            let mut emails = Vec::with_capacity(entries.len());
+            let mut errors = Vec::new();
+            let mut seen = HashSet::new();
+            for (index, entry) in entries.into_iter().enumerate() {
+                match Email::parse_custom(Some(entry), rules.email.clone()) {
+                    Ok(email) => {
+                        let key = email.normalized(rules.duplicate_normalization);
+                        let is_unique = !rules.forbid_duplicates || seen.insert(key);
+                        if rules.forbid_duplicates {
+                            rule_result(locale_keys::VALIDATE_EMAIL_DUPLICATE, is_unique);
+                        }
+                        if is_unique {
+                            emails.push(email);
+                        } else {
+                            let mut messages = ValidateErrorCollector::new();
+                            messages.push((
+                                "Duplicate email".to_string(),
+                                Box::new(EmailAddressLocale::Duplicate),
+                            ));
+                            errors.push((index, EmailError(messages.into())));
+                        }
+                    }
+                    Err(error) => errors.push((index, error)),
+                }
+            }
+
+            if !list.is_empty() || !errors.is_empty() {
+                return Err(EmailListError {
+                    list: list.into(),
+                    entries: errors,
+                });
+            }
+            Ok(Self(emails, is_none))
+        })
+    }
+
+    /// Returns the addresses, in the order they were listed.
+    pub fn as_slice(&self) -> &[Email] {
+        &self.0
+    }
+
+    /// Returns the number of addresses.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the list has no addresses.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if the list was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
+    /// Converts the list into its addresses.
+    pub fn into_vec(self) -> Vec<Email> {
+        self.0
+    }
+}
+
+impl OptionalValue for EmailList {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl Display for EmailList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, email) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(email.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl Validator<Option<&str>, EmailList, EmailListError> for EmailListRules {
+    fn validate(&self, input: Option<&str>) -> Result<EmailList, EmailListError> {
+        EmailList::parse(input, self.clone())
+    }
+}
+
+impl OptionalRules for EmailListRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl EmailRules {
+    /// Returns an input satisfying the rules, `user@example.com`, with the local part repeated
+    /// up to its 64 characters, then subdomains added, to reach `min_length`.
+    pub fn arbitrary_valid_input(&self) -> String {
+        let domain = "example.com";
+        let min = self
+            .min_length
+            .unwrap_or_default()
+            .saturating_sub(domain.len() + 1);
+        let local = crate::common::fixtures::sample_string("user", Some(min), Some(64));
+        let mut padding = min.saturating_sub(local.len());
+        let mut subdomains = String::new();
+        while padding > 0 {
+            let label = padding.clamp(2, 64) - 1;
+            subdomains.push_str(&"a".repeat(label));
+            subdomains.push('.');
+            padding = padding.saturating_sub(label + 1);
+        }
+        format!("{local}@{subdomains}{domain}")
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl Email {
+    /// Returns a valid `Email` for the default `EmailRules`.
+    pub fn valid_sample() -> Self {
+        Self::sample_matching(EmailRules::default())
+    }
+
+    /// Returns a valid `Email` for the given rules, parsed from `arbitrary_valid_input`.
+    ///
+    /// # Panics
+    /// Panics if no input can satisfy the rules, e.g. `max_length` below the 16 characters of
+    /// `user@example.com`, or `min_length` above the length of the longest address.
+    pub fn sample_matching(rules: EmailRules) -> Self {
+        let input = rules.arbitrary_valid_input();
+        Self::parse_custom(Some(&input), rules).expect("Expected sample to be valid")
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Email {
+    /// Creates an instance of `Email` without validation, e.g. when loading values which were
+    /// validated before being stored. The string is kept as is, `None` gives an empty value.
+    ///
+    /// The address is still parsed for `as_email`, which returns `None` if it cannot be parsed.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(s.to_string(), EmailAddress::parse(s, None), is_none)
+    }
+
+    /// Creates an instance of `Email` from a stored string without validation, so stored values
+    /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "test-fixtures")]
+    #[test]
+    fn test_email_sample_matching_min_length() {
+        for min_length in [16, 17, 30, 76, 77, 78, 150] {
+            let email = Email::sample_matching(EmailRules {
+                min_length: Some(min_length),
+                ..EmailRules::default()
+            });
+            assert!(email.as_str().len() >= min_length);
+        }
+    }
+
     #[test]
     fn test_valid_email() {
         let email = Email::parse(Some("test@example.com"));
@@ -355,4 +1034,163 @@ mod tests {
         let email_confirm = email.parse_confirm("test");
         assert!(email_confirm.is_err());
     }
+
+    #[test]
+    fn test_email_try_from() {
+        assert!(Email::try_from("test@example.com").is_ok());
+        assert!(Email::try_from("test".to_string()).is_err());
+        assert!(Email::try_from(None).is_err());
+    }
+
+    #[test]
+    fn test_email_collects_all_errors() {
+        let rules = EmailRules {
+            min_length: Some(30),
+            forbid_plus_addressing: true,
+            require_tld: true,
+            ..EmailRules::default()
+        };
+        let error = Email::parse_custom(Some("user+tag@localhost"), rules.clone())
+            .expect_err("Expected the email to break every policy");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec![
+                "Must be at least 30 characters",
+                "Must not use plus addressing",
+                "Domain must have a top-level domain",
+            ]
+        );
+        let error = Email::parse_custom(Some("user"), rules.clone())
+            .expect_err("Expected the email to be invalid");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be at least 30 characters", "Invalid Email"]
+        );
+        let error =
+            Email::parse_custom(Some(""), rules).expect_err("Expected the email to be empty");
+        assert_eq!(error.0.as_original_message_vec(), vec!["Cannot be empty"]);
+    }
+
+    #[test]
+    fn test_email_optional() {
+        let rules = EmailRules {
+            is_mandatory: false,
+            ..EmailRules::default()
+        };
+        let email =
+            Email::parse_custom(None, rules.clone()).expect("Expected missing email to be valid");
+        assert!(email.is_none());
+        assert!(email.as_email().is_none());
+        let error =
+            Email::parse_custom(Some(""), rules).expect_err("Expected empty email to be invalid");
+        assert_eq!(error.0.as_original_message_vec(), vec!["Invalid Email"]);
+    }
+
+    #[test]
+    #[cfg(feature = "trusted-input")]
+    fn test_email_new_unchecked() {
+        let email = Email::from_stored("test@example.com");
+        assert!(email.as_email().is_some());
+        let email = Email::from_stored("legacy");
+        assert_eq!(email.as_str(), "legacy");
+        assert!(email.as_email().is_none());
+    }
+
+    #[test]
+    fn test_email_allow_unicode() {
+        assert!(Email::parse(Some("josé@bücher.de")).is_ok());
+        let rules = EmailRules {
+            allow_unicode: false,
+            ..EmailRules::default()
+        };
+        assert!(Email::parse_custom(Some("user@example.com"), rules.clone()).is_ok());
+        let error = Email::parse_custom(Some("user@bücher.de"), rules)
+            .expect_err("Expected Unicode to be rejected");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must only contain ASCII characters"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "idna")]
+    fn test_email_ascii_compatible() {
+        let email = Email::parse(Some("user@bücher.de")).expect("Expected valid email");
+        assert_eq!(
+            email.ascii_compatible().as_deref(),
+            Some("user@xn--bcher-kva.de")
+        );
+        let email = Email::parse(Some("josé@example.com")).expect("Expected valid email");
+        assert_eq!(email.ascii_compatible(), None);
+        let email = Email::parse(Some("user@example.com")).expect("Expected valid email");
+        assert_eq!(
+            email.ascii_compatible().as_deref(),
+            Some("user@example.com")
+        );
+    }
+
+    #[test]
+    fn test_email_parts_and_normalization() {
+        let rules = EmailRules {
+            normalize: EmailNormalization::LowercaseDomain,
+            ..EmailRules::default()
+        };
+        let email = Email::parse_custom(Some("John.Doe+news@GMail.com"), rules)
+            .expect("Expected valid email");
+        assert_eq!(email.as_str(), "John.Doe+news@gmail.com");
+        assert_eq!(email.local_part(), Some("John.Doe+news"));
+        assert_eq!(email.domain(), Some("GMail.com"));
+        assert_eq!(
+            email.normalized(EmailNormalization::Canonical).as_deref(),
+            Some("johndoe@gmail.com")
+        );
+        assert!(email.parse_confirm("John.Doe+news@GMail.com").is_ok());
+
+        let email = Email::parse(Some("John.Doe+news@Example.com")).expect("Expected valid email");
+        assert_eq!(
+            email.normalized(EmailNormalization::Canonical).as_deref(),
+            Some("john.doe@example.com")
+        );
+    }
+
+    #[test]
+    fn test_email_list() {
+        let rules = || EmailListRules {
+            max_recipients: Some(2),
+            ..EmailListRules::default()
+        };
+        let list = EmailList::parse(Some(" ada@example.com ;alan@example.com, "), rules())
+            .expect("Expected valid list");
+        assert_eq!(list.to_string(), "ada@example.com, alan@example.com");
+
+        let error = EmailList::parse(Some("ada@example.com, nope, ada@Example.com"), rules())
+            .expect_err("Expected invalid list");
+        assert_eq!(
+            error.list.as_original_message_vec(),
+            vec!["Must have at most 2 items"]
+        );
+        let entries: Vec<(usize, Vec<String>)> = error
+            .entries
+            .iter()
+            .map(|(index, error)| (*index, error.0.as_original_message_vec()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (1, vec!["Invalid Email".to_string()]),
+                (2, vec!["Duplicate email".to_string()]),
+            ]
+        );
+
+        let error = EmailList::parse(Some(" , "), rules()).expect_err("Expected empty list");
+        assert_eq!(
+            error.list.as_original_message_vec(),
+            vec!["Cannot be empty"]
+        );
+        let rules = EmailListRules {
+            is_mandatory: false,
+            ..EmailListRules::default()
+        };
+        assert!(EmailList::parse(None, rules).is_ok_and(|list| list.is_none()));
+    }
 }
@@ -0,0 +1,309 @@
+//! This module contains the query string and `application/x-www-form-urlencoded` support of the
+//! value types.
+//!
+//! `FormMap` maps the fields of a form, looked up by name, to the parse of each value type, and
+//! keeps the errors in a `ValidationReport` keyed by field. An empty or missing field is parsed
+//! as `None`, so the mandatory rules report it, and the numbers are read from their text with the
+//! `parse_str` variants of the number types. The optional fields of the string types are parsed
+//! into a `MaybeValidated` with `FormMap::optional_field`. The fields that were not parsed can be
+//! reported as unknown with `FormMap::deny_unknown_fields`.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::form::FormMap;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use cjtoolkit_structured_validator::types::numbers::integer::Integer;
+//!
+//! let mut form = FormMap::parse("name=Alice+Smith&age=forty&admin=1").deny_unknown_fields();
+//! let name = form.field("name", Name::parse);
+//! let age = form.field("age", Integer::parse_str);
+//!
+//! assert_eq!(name.map(|name| name.as_str().to_string()).ok(), Some("Alice Smith".to_string()));
+//! assert!(age.is_err());
+//! assert_eq!(form.into_report().fields(), vec!["age", "admin"]);
+//! ```
+
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{MaybeValidated, OptionalRules, OptionalValue};
+use crate::common::partial::Partial;
+use crate::common::validation_report::ValidationReport;
+use crate::common::validator::Validator;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// An enumeration of the form specific validation failures.
+pub enum FormLocale {
+    /// The field is not one of the fields of the form, holding the name of the field.
+    /// # Key
+    /// `validate-unknown-field`
+    UnknownField(String),
+}
+
+impl LocaleMessage for FormLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        match self {
+            Self::UnknownField(field) => LocaleData::new_with_vec(
+                locale_keys::VALIDATE_UNKNOWN_FIELD,
+                vec![("field".to_string(), LocaleValue::from(field.as_str()))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::UnknownField(field) => RuleViolation::FieldNotAllowed {
+                field: field.clone(),
+            },
+        }
+    }
+}
+
+/// The fields of a query string or form being validated, field by field.
+///
+/// When a field is repeated, its first value is used.
+pub struct FormMap {
+    pairs: Vec<(String, String)>,
+    parsed: Vec<String>,
+    deny_unknown_fields: bool,
+    report: ValidationReport,
+}
+
+impl FormMap {
+    /// Creates a form from its name and value pairs, already decoded.
+    pub fn new<K: Into<String>, V: Into<String>>(pairs: impl IntoIterator<Item = (K, V)>) -> Self {
+        Self {
+            pairs: pairs
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+            parsed: Vec::new(),
+            deny_unknown_fields: false,
+            report: ValidationReport::new(),
+        }
+    }
+
+    /// Creates a form by decoding a query string or an `application/x-www-form-urlencoded` body,
+    /// e.g. `a=1&b=x`. A leading `?` is ignored.
+    pub fn parse(query: &str) -> Self {
+        let query = query.strip_prefix('?').unwrap_or(query);
+        Self::new(
+            query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| match pair.split_once('=') {
+                    Some((name, value)) => (decode(name), decode(value)),
+                    None => (decode(pair), String::new()),
+                }),
+        )
+    }
+
+    /// Creates a form from a map of field names to values, already decoded.
+    pub fn from_map(map: &HashMap<String, String>) -> Self {
+        Self::new(
+            map.iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        )
+    }
+
+    /// Reports the fields of the form that were not parsed as unknown, when converting it into
+    /// its report.
+    pub fn deny_unknown_fields(mut self) -> Self {
+        self.deny_unknown_fields = true;
+        self
+    }
+
+    /// Returns the value of the field named `name`, `None` if the field is missing or empty.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        lookup(&self.pairs, name)
+    }
+
+    /// Parses the value of the field named `name` with `parse`, recording its error under the
+    /// name of the field, then returns the result of `parse`.
+    pub fn field<T, E>(
+        &mut self,
+        name: &str,
+        parse: impl FnOnce(Option<&str>) -> Result<T, E>,
+    ) -> Result<T, E>
+    where
+        for<'a> &'a E: Into<ValidateErrorStore>,
+    {
+        let result = parse(lookup(&self.pairs, name));
+        self.parsed.push(name.to_string());
+        self.report.check(name, result)
+    }
+
+    /// Parses the value of the field named `name` as an optional field with `rules`, like
+    /// `OptionalValue::parse_optional`, recording its error under the name of the field. A
+    /// missing or empty field is `Absent`.
+    pub fn optional_field<T, E>(
+        &mut self,
+        name: &str,
+        rules: impl for<'a> Validator<Option<&'a str>, T, E> + OptionalRules,
+    ) -> Result<MaybeValidated<T>, E>
+    where
+        T: OptionalValue,
+        for<'a> &'a E: Into<ValidateErrorStore>,
+    {
+        self.field(name, |s| T::parse_optional(s, rules))
+    }
+
+    /// Parses the value of the field named `name` with `parse` like `FormMap::field`, if the
+    /// field is present, for partial updates. An absent field is skipped, while a present but
+    /// empty field is still parsed as `None`.
+    pub fn partial_field<T, E>(
+        &mut self,
+        name: &str,
+        parse: impl FnOnce(Option<&str>) -> Result<T, E>,
+    ) -> Result<Partial<T>, E>
+    where
+        for<'a> &'a E: Into<ValidateErrorStore>,
+    {
+        if !self.pairs.iter().any(|(field, _)| field == name) {
+            self.parsed.push(name.to_string());
+            return Ok(Partial::Absent);
+        }
+        self.field(name, parse).map(Partial::Present)
+    }
+
+    /// Returns the report of the fields parsed so far.
+    pub fn report(&self) -> &ValidationReport {
+        &self.report
+    }
+
+    /// Converts the form into the report of its fields, including the unknown fields if they
+    /// are denied.
+    pub fn into_report(mut self) -> ValidationReport {
+        if self.deny_unknown_fields {
+            for (name, _) in &self.pairs {
+                if self.parsed.contains(name) {
+                    continue;
+                }
+                self.parsed.push(name.clone());
+                let mut messages = ValidateErrorCollector::new();
+                messages.push((
+                    "Unknown field",
+                    Box::new(FormLocale::UnknownField(name.clone())),
+                ));
+                self.report.0.push((name.clone(), messages.into()));
+            }
+        }
+        self.report
+    }
+
+    /// Returns `Ok(())` if no field has errors, otherwise the report of the form.
+    pub fn into_result(self) -> Result<(), ValidationReport> {
+        self.into_report().into_result()
+    }
+}
+
+fn lookup<'p>(pairs: &'p [(String, String)], name: &str) -> Option<&'p str> {
+    pairs
+        .iter()
+        .find(|(field, _)| field == name)
+        .map(|(_, value)| value.as_str())
+        .filter(|value| !value.is_empty())
+}
+
+/// Decodes a component of a query string, `+` being a space and `%XX` an encoded byte.
+fn decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => match bytes
+                .get(i + 1..i + 3)
+                .and_then(|hex| hex_pair(hex[0], hex[1]))
+            {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 2;
+                }
+                None => decoded.push(b'%'),
+            },
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn hex_pair(high: u8, low: u8) -> Option<u8> {
+    let digit = |b: u8| (b as char).to_digit(16);
+    Some((digit(high)? * 16 + digit(low)?) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::{Name, NameRules};
+    use crate::types::numbers::integer::Integer;
+    use crate::types::username::{Username, UsernameRules};
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("a%20b+c%2Bd"), "a b c+d");
+        assert_eq!(decode("caf%C3%A9"), "café");
+        assert_eq!(decode("100%"), "100%");
+        assert_eq!(decode("%zz"), "%zz");
+    }
+
+    #[test]
+    fn test_missing_extra_and_typed_errors() {
+        let mut map = HashMap::new();
+        map.insert("age".to_string(), "12.5".to_string());
+        map.insert("token".to_string(), "abc".to_string());
+        let mut form = FormMap::from_map(&map).deny_unknown_fields();
+
+        assert!(form.field("username", Username::parse).is_err());
+        let age = form
+            .field("age", Integer::parse_str)
+            .expect_err("Expected age error");
+        assert_eq!(
+            age.0.violations(),
+            vec![RuleViolation::InvalidType {
+                expected: "integer".to_string()
+            }]
+        );
+
+        let report = form.into_report();
+        assert_eq!(report.fields(), vec!["username", "age", "token"]);
+        assert_eq!(
+            report.0[2].1.violations(),
+            vec![RuleViolation::FieldNotAllowed {
+                field: "token".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_partial_field() {
+        let mut form = FormMap::parse("age=&username=alice_smith").deny_unknown_fields();
+        assert_eq!(form.partial_field("name", Name::parse), Ok(Partial::Absent));
+        assert!(
+            form.partial_field("username", Username::parse)
+                .is_ok_and(|u| u.is_present())
+        );
+        assert!(form.partial_field("age", Integer::parse_str).is_err());
+        assert_eq!(form.into_report().fields(), vec!["age"]);
+    }
+
+    #[test]
+    fn test_optional_field() {
+        let mut form = FormMap::parse("nickname=&username=al&name=Alice+Smith");
+        let nickname = form.optional_field("nickname", NameRules::default());
+        assert_eq!(nickname, Ok(MaybeValidated::Absent));
+        let name = form.optional_field("name", NameRules::default());
+        assert!(name.is_ok_and(|name| name.is_present()));
+        assert!(
+            form.optional_field("username", UsernameRules::default())
+                .is_err()
+        );
+        assert_eq!(form.into_report().fields(), vec!["username"]);
+    }
+}
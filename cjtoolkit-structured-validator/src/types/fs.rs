@@ -0,0 +1,932 @@
+//! This module contains structures and traits for working with the names and paths of files,
+//! e.g. the names of uploads or attachments, before they are used on a filesystem.
+//!
+//! `FileName` is a single file name, without path separators, and `SafeRelativePath` is a path
+//! relative to a base directory, which cannot escape it. Both reject the names reserved by
+//! Windows, e.g. `CON` or `nul.txt`, the characters it does not allow, e.g. `:`, the names
+//! ending with a dot or a space, and control characters, whatever the platform, as files are
+//! often shared between platforms.
+
+use crate::base::string_rules::{
+    LengthUnit, StringLengthRules, StringMandatoryRules, StringSafetyRules,
+};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::rule_result;
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::Arc;
+use thiserror::Error;
+
+const PATH_SEPARATORS: [char; 2] = ['/', '\\'];
+
+/// The characters which Windows does not allow in a file name, besides the path separators.
+const WINDOWS_INVALID_CHARS: [char; 7] = ['<', '>', ':', '"', '|', '?', '*'];
+
+/// Returns `true` if `name` is `.`, `..`, or is reserved by Windows, e.g. `CON`, `com1` or
+/// `NUL.txt`, whatever its extension.
+fn is_reserved_name(name: &str) -> bool {
+    if name == "." || name == ".." {
+        return true;
+    }
+    let stem = name
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(' ')
+        .to_ascii_uppercase();
+    match stem.as_str() {
+        "CON" | "PRN" | "AUX" | "NUL" => true,
+        _ => ["COM", "LPT"].iter().any(|prefix| {
+            stem.strip_prefix(prefix)
+                .is_some_and(|n| n.len() == 1 && matches!(n.as_bytes()[0], b'1'..=b'9'))
+        }),
+    }
+}
+
+/// Splits `name` into its stem and extension, `None` for a name without extension or starting
+/// with its only dot, e.g. `.gitignore`.
+fn split_extension(name: &str) -> (&str, Option<&str>) {
+    match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, Some(extension)),
+        _ => (name, None),
+    }
+}
+
+/// An enumeration of the file name specific validation failures.
+#[derive(Clone)]
+pub enum FileNameLocale {
+    /// The name contains a path separator, `/` or `\`.
+    /// # Key
+    /// `validate-filename-path-separator`
+    PathSeparator,
+    /// The name is `.`, `..`, or is reserved by Windows, with the reserved `name`.
+    /// # Key
+    /// `validate-filename-reserved`
+    Reserved(String),
+    /// The extension is not one of the comma separated `allowed` extensions.
+    /// # Key
+    /// `validate-filename-extension-not-allowed`
+    ExtensionNotAllowed(String),
+    /// The name contains a character which Windows does not allow, `<>:"|?*`.
+    /// # Key
+    /// `validate-filename-invalid-char`
+    InvalidChar,
+    /// The name ends with a dot or a space, which Windows strips, e.g. `a.txt.` is `a.txt`.
+    /// # Key
+    /// `validate-filename-trailing-dot-or-space`
+    TrailingDotOrSpace,
+}
+
+impl LocaleMessage for FileNameLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::PathSeparator => ld::new(locale_keys::VALIDATE_FILENAME_PATH_SEPARATOR),
+            Self::Reserved(name) => ld::new_with_vec(
+                locale_keys::VALIDATE_FILENAME_RESERVED,
+                vec![("name".to_string(), lv::from(name.clone()))],
+            ),
+            Self::ExtensionNotAllowed(allowed) => ld::new_with_vec(
+                locale_keys::VALIDATE_FILENAME_EXTENSION_NOT_ALLOWED,
+                vec![("allowed".to_string(), lv::from(allowed.clone()))],
+            ),
+            Self::InvalidChar => ld::new(locale_keys::VALIDATE_FILENAME_INVALID_CHAR),
+            Self::TrailingDotOrSpace => {
+                ld::new(locale_keys::VALIDATE_FILENAME_TRAILING_DOT_OR_SPACE)
+            }
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::PathSeparator => RuleViolation::forbidden_chars("path-separator"),
+            Self::Reserved(_) => {
+                RuleViolation::Custom(locale_keys::VALIDATE_FILENAME_RESERVED.to_string())
+            }
+            Self::ExtensionNotAllowed(allowed) => RuleViolation::NotAllowed {
+                allowed: allowed.clone(),
+            },
+            Self::InvalidChar => RuleViolation::forbidden_chars("windows-invalid"),
+            Self::TrailingDotOrSpace => RuleViolation::Custom(
+                locale_keys::VALIDATE_FILENAME_TRAILING_DOT_OR_SPACE.to_string(),
+            ),
+        }
+    }
+}
+
+fn push_reserved(messages: &mut ValidateErrorCollector, name: &str) {
+    messages.push((
+        format!("\"{}\" is a reserved name", name),
+        Box::new(FileNameLocale::Reserved(name.to_string())),
+    ));
+}
+
+fn check_windows_name(
+    messages: &mut ValidateErrorCollector,
+    has_invalid_char: bool,
+    has_trailing_dot_or_space: bool,
+) {
+    rule_result(
+        locale_keys::VALIDATE_FILENAME_INVALID_CHAR,
+        !has_invalid_char,
+    );
+    if has_invalid_char {
+        messages.push((
+            "Must not contain < > : \" | ? *".to_string(),
+            Box::new(FileNameLocale::InvalidChar),
+        ));
+    }
+    rule_result(
+        locale_keys::VALIDATE_FILENAME_TRAILING_DOT_OR_SPACE,
+        !has_trailing_dot_or_space,
+    );
+    if has_trailing_dot_or_space {
+        messages.push((
+            "Must not end with a dot or a space".to_string(),
+            Box::new(FileNameLocale::TrailingDotOrSpace),
+        ));
+    }
+}
+
+/// Returns `true` if `component` is made only of dots and spaces, e.g. `..` or `.. `, which
+/// Windows reads as `..` once it strips the trailing dots and spaces.
+fn is_traversal(component: &str) -> bool {
+    component.chars().all(|c| c == '.' || c == ' ')
+}
+
+/// A structure to define the rules of a file name.
+///
+/// # Fields
+///
+/// * `is_mandatory` - Whether the file name is mandatory.
+/// * `max_length` - The maximum length of the name in UTF-8 bytes, 255 by default, the limit of
+///   most filesystems.
+/// * `allowed_extensions` - The extensions the name may have, compared case-insensitively, with
+///   or without the leading dot, e.g. `pdf` or `.png`. `None` for any extension, or none.
+#[derive(Clone)]
+pub struct FileNameRules {
+    pub is_mandatory: bool,
+    pub max_length: Option<usize>,
+    pub allowed_extensions: Option<Vec<String>>,
+}
+
+impl Default for FileNameRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            max_length: Some(255),
+            allowed_extensions: None,
+        }
+    }
+}
+
+impl From<&FileNameRules> for (StringMandatoryRules, StringLengthRules, StringSafetyRules) {
+    fn from(rules: &FileNameRules) -> Self {
+        (
+            StringMandatoryRules {
+                is_mandatory: rules.is_mandatory,
+                treat_whitespace_as_empty: true,
+            },
+            StringLengthRules {
+                min_length: None,
+                max_length: rules.max_length,
+                unit: LengthUnit::Bytes,
+            },
+            StringSafetyRules {
+                forbid_control_chars: true,
+                ..StringSafetyRules::default()
+            },
+        )
+    }
+}
+
+impl FileNameRules {
+    fn rules(&self) -> (StringMandatoryRules, StringLengthRules, StringSafetyRules) {
+        self.into()
+    }
+
+    fn allowed_extensions(&self) -> Option<Vec<String>> {
+        self.allowed_extensions.as_ref().map(|allowed| {
+            allowed
+                .iter()
+                .map(|extension| extension.trim_start_matches('.').to_lowercase())
+                .collect()
+        })
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        s: &str,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let (mandatory_rule, length_rule, safety_rule) = self.rules();
+        mandatory_rule.check(messages, subject);
+        if !messages.is_empty() {
+            return;
+        }
+        length_rule.check(messages, subject);
+        safety_rule.check(messages, subject);
+
+        let has_separator = s.contains(PATH_SEPARATORS);
+        rule_result(
+            locale_keys::VALIDATE_FILENAME_PATH_SEPARATOR,
+            !has_separator,
+        );
+        if has_separator {
+            messages.push((
+                "Must not contain / or \\".to_string(),
+                Box::new(FileNameLocale::PathSeparator),
+            ));
+        }
+        let is_reserved = is_reserved_name(s);
+        rule_result(locale_keys::VALIDATE_FILENAME_RESERVED, !is_reserved);
+        if is_reserved {
+            push_reserved(messages, s);
+        }
+        check_windows_name(
+            messages,
+            s.contains(WINDOWS_INVALID_CHARS),
+            !is_reserved && s.ends_with(['.', ' ']),
+        );
+        if let Some(allowed) = self.allowed_extensions() {
+            let extension = split_extension(s).1.map(str::to_lowercase);
+            let is_allowed = extension.is_some_and(|extension| allowed.contains(&extension));
+            rule_result(
+                locale_keys::VALIDATE_FILENAME_EXTENSION_NOT_ALLOWED,
+                is_allowed,
+            );
+            if !is_allowed {
+                let allowed = allowed.join(", ");
+                messages.push((
+                    format!("Must have one of the extensions: {}", allowed),
+                    Box::new(FileNameLocale::ExtensionNotAllowed(allowed)),
+                ));
+            }
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, length_rule, safety_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(length_rule.describe());
+        constraints.extend(safety_rule.describe());
+        constraints.push(Constraint::from_locale(&FileNameLocale::PathSeparator));
+        constraints.push(Constraint::from_locale(&FileNameLocale::Reserved(
+            String::new(),
+        )));
+        constraints.push(Constraint::from_locale(&FileNameLocale::InvalidChar));
+        constraints.push(Constraint::from_locale(&FileNameLocale::TrailingDotOrSpace));
+        if let Some(allowed) = self.allowed_extensions() {
+            constraints.push(Constraint::from_locale(
+                &FileNameLocale::ExtensionNotAllowed(allowed.join(", ")),
+            ));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during file name validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"File Name Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("File Name Validation Error")]
+pub struct FileNameError(pub ValidateErrorStore);
+
+impl ValidationCheck for FileNameError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&FileNameError> for ValidateErrorStore {
+    fn from(error: &FileNameError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A structure representing a validated file name.
+///
+/// # Fields
+/// - `0: String` - The file name.
+/// - `1: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+pub struct FileName(String, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for FileName {
+    fn default() -> Self {
+        Self(String::new(), true)
+    }
+}
+
+impl FileName {
+    /// Parses a file name based on the provided validation rules.
+    ///
+    /// # Errors
+    /// Returns a `FileNameError` if the name is empty while mandatory, is too long, contains a
+    /// path separator, a control character or a character which Windows does not allow, is
+    /// reserved, ends with a dot or a space, or has an extension which is not allowed.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::fs::{FileName, FileNameRules};
+    ///
+    /// let rules = FileNameRules {
+    ///     allowed_extensions: Some(vec!["pdf".to_string(), ".png".to_string()]),
+    ///     ..FileNameRules::default()
+    /// };
+    /// let name = FileName::parse_custom(Some("Invoice 2024.PDF"), rules.clone())
+    ///     .expect("Expected name to be valid");
+    /// assert_eq!(name.extension(), Some("PDF"));
+    /// assert!(FileName::parse_custom(Some("run.exe"), rules.clone()).is_err());
+    /// assert!(FileName::parse_custom(Some("../secret.pdf"), rules.clone()).is_err());
+    /// assert!(FileName::parse_custom(Some("con.png"), rules).is_err());
+    /// ```
+    pub fn parse_custom(s: Option<&str>, rules: FileNameRules) -> Result<Self, FileNameError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, s, is_none);
+            FileNameError::validate_check(messages)?;
+            Ok(Self(s.to_string(), is_none))
+        })
+    }
+
+    /// Parses a file name using the default `FileNameRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, FileNameError> {
+        Self::parse_custom(s, FileNameRules::default())
+    }
+
+    /// Returns the file name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the name without its extension, e.g. `report` for `report.pdf`.
+    pub fn stem(&self) -> &str {
+        split_extension(&self.0).0
+    }
+
+    /// Returns the extension, as written, without the dot, e.g. `pdf` for `report.pdf`, or
+    /// `None` if the name has none.
+    pub fn extension(&self) -> Option<&str> {
+        split_extension(&self.0).1
+    }
+
+    /// Returns `true` if the file name was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
+    /// Converts the current instance into an `Option<FileName>`.
+    ///
+    /// # Returns
+    /// - `None` if the file name was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<FileName> {
+        if self.1 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for FileName {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&FileName> for String {
+    fn from(name: &FileName) -> Self {
+        name.as_str().to_string()
+    }
+}
+
+impl Display for FileName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for FileName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for FileName {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `FileName` key can be found by
+/// a `&str`.
+impl PartialEq for FileName {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for FileName {}
+
+impl Hash for FileName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for FileName {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for FileName {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for FileName {
+    type Error = FileNameError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for FileName {
+    type Error = FileNameError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for FileName {
+    type Error = FileNameError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, FileName, FileNameError> for FileNameRules {
+    fn validate(&self, input: Option<&str>) -> Result<FileName, FileNameError> {
+        FileName::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for FileNameRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+/// An enumeration of the relative path specific validation failures.
+#[derive(Clone)]
+pub enum SafeRelativePathLocale {
+    /// The path is absolute, e.g. `/etc/passwd` or `C:\Windows`.
+    /// # Key
+    /// `validate-path-absolute`
+    Absolute,
+    /// The path has a component made only of dots and spaces, e.g. `..` or `.. `, which could
+    /// escape the base directory.
+    /// # Key
+    /// `validate-path-traversal`
+    Traversal,
+}
+
+impl LocaleMessage for SafeRelativePathLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        match self {
+            Self::Absolute => LocaleData::new(locale_keys::VALIDATE_PATH_ABSOLUTE),
+            Self::Traversal => LocaleData::new(locale_keys::VALIDATE_PATH_TRAVERSAL),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::Absolute => RuleViolation::pattern("relative-path"),
+            Self::Traversal => RuleViolation::pattern("path-traversal"),
+        }
+    }
+}
+
+/// A structure to define the rules of a relative path.
+///
+/// # Fields
+///
+/// * `is_mandatory` - Whether the path is mandatory.
+/// * `max_length` - The maximum length of the path in UTF-8 bytes, 4096 by default.
+#[derive(Clone)]
+pub struct SafeRelativePathRules {
+    pub is_mandatory: bool,
+    pub max_length: Option<usize>,
+}
+
+impl Default for SafeRelativePathRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            max_length: Some(4096),
+        }
+    }
+}
+
+impl From<&SafeRelativePathRules> for (StringMandatoryRules, StringLengthRules, StringSafetyRules) {
+    fn from(rules: &SafeRelativePathRules) -> Self {
+        (
+            StringMandatoryRules {
+                is_mandatory: rules.is_mandatory,
+                treat_whitespace_as_empty: true,
+            },
+            StringLengthRules {
+                min_length: None,
+                max_length: rules.max_length,
+                unit: LengthUnit::Bytes,
+            },
+            StringSafetyRules {
+                forbid_control_chars: true,
+                ..StringSafetyRules::default()
+            },
+        )
+    }
+}
+
+impl SafeRelativePathRules {
+    fn rules(&self) -> (StringMandatoryRules, StringLengthRules, StringSafetyRules) {
+        self.into()
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        s: &str,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let (mandatory_rule, length_rule, safety_rule) = self.rules();
+        mandatory_rule.check(messages, subject);
+        if !messages.is_empty() {
+            return;
+        }
+        length_rule.check(messages, subject);
+        safety_rule.check(messages, subject);
+
+        let bytes = s.as_bytes();
+        let is_absolute = s.starts_with(PATH_SEPARATORS)
+            || (bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':');
+        rule_result(locale_keys::VALIDATE_PATH_ABSOLUTE, !is_absolute);
+        if is_absolute {
+            messages.push((
+                "Must be a relative path".to_string(),
+                Box::new(SafeRelativePathLocale::Absolute),
+            ));
+        }
+        let components = SafeRelativePath::split_components(s);
+        let has_traversal = components.iter().any(|component| is_traversal(component));
+        rule_result(locale_keys::VALIDATE_PATH_TRAVERSAL, !has_traversal);
+        if has_traversal {
+            messages.push((
+                "Must not contain ..".to_string(),
+                Box::new(SafeRelativePathLocale::Traversal),
+            ));
+        }
+        let reserved = components
+            .iter()
+            .find(|component| !is_traversal(component) && is_reserved_name(component));
+        rule_result(locale_keys::VALIDATE_FILENAME_RESERVED, reserved.is_none());
+        if let Some(reserved) = reserved {
+            push_reserved(messages, reserved);
+        }
+        check_windows_name(
+            messages,
+            components
+                .iter()
+                .any(|component| component.contains(WINDOWS_INVALID_CHARS)),
+            components.iter().any(|component| {
+                !is_traversal(component)
+                    && !is_reserved_name(component)
+                    && component.ends_with(['.', ' '])
+            }),
+        );
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, length_rule, safety_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(length_rule.describe());
+        constraints.extend(safety_rule.describe());
+        constraints.push(Constraint::from_locale(&SafeRelativePathLocale::Absolute));
+        constraints.push(Constraint::from_locale(&SafeRelativePathLocale::Traversal));
+        constraints.push(Constraint::from_locale(&FileNameLocale::Reserved(
+            String::new(),
+        )));
+        constraints.push(Constraint::from_locale(&FileNameLocale::InvalidChar));
+        constraints.push(Constraint::from_locale(&FileNameLocale::TrailingDotOrSpace));
+        constraints
+    }
+}
+
+/// Represents an error that occurs during relative path validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Relative Path Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Relative Path Validation Error")]
+pub struct SafeRelativePathError(pub ValidateErrorStore);
+
+impl ValidationCheck for SafeRelativePathError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&SafeRelativePathError> for ValidateErrorStore {
+    fn from(error: &SafeRelativePathError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A structure representing a validated relative path, which cannot escape its base directory.
+///
+/// # Fields
+/// - `0: String` - The path as it was provided.
+/// - `1: Vec<String>` - The components of the path, without the empty and `.` components.
+/// - `2: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SafeRelativePath(String, Vec<String>, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for SafeRelativePath {
+    fn default() -> Self {
+        Self(String::new(), Vec::new(), true)
+    }
+}
+
+impl SafeRelativePath {
+    fn split_components(s: &str) -> Vec<&str> {
+        s.split(PATH_SEPARATORS)
+            .filter(|component| !component.is_empty() && *component != ".")
+            .collect()
+    }
+
+    /// Parses a relative path based on the provided validation rules. Both `/` and `\` are
+    /// separators.
+    ///
+    /// # Errors
+    /// Returns a `SafeRelativePathError` if the path is empty while mandatory, is too long,
+    /// contains a control character, is absolute, has a component made only of dots and spaces,
+    /// e.g. `..`, or has a component which is reserved, contains a character which Windows does
+    /// not allow, or ends with a dot or a space.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::fs::SafeRelativePath;
+    /// use std::path::Path;
+    ///
+    /// let path = SafeRelativePath::parse(Some("avatars/./2024\\me.png"))
+    ///     .expect("Expected path to be valid");
+    /// assert_eq!(path.to_path_buf(), Path::new("avatars").join("2024").join("me.png"));
+    /// assert!(SafeRelativePath::parse(Some("avatars/../../etc/passwd")).is_err());
+    /// assert!(SafeRelativePath::parse(Some("/etc/passwd")).is_err());
+    /// assert!(SafeRelativePath::parse(Some("C:\\Windows")).is_err());
+    /// ```
+    pub fn parse_custom(
+        s: Option<&str>,
+        rules: SafeRelativePathRules,
+    ) -> Result<Self, SafeRelativePathError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, s, is_none);
+            SafeRelativePathError::validate_check(messages)?;
+            let components = Self::split_components(s)
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+            Ok(Self(s.to_string(), components, is_none))
+        })
+    }
+
+    /// Parses a relative path using the default `SafeRelativePathRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, SafeRelativePathError> {
+        Self::parse_custom(s, SafeRelativePathRules::default())
+    }
+
+    /// Returns the path as it was provided.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the components of the path, without the empty and `.` components.
+    pub fn components(&self) -> &[String] {
+        &self.1
+    }
+
+    /// Returns the last component of the path, `None` if the path has no components.
+    pub fn file_name(&self) -> Option<&str> {
+        self.1.last().map(String::as_str)
+    }
+
+    /// Returns the path with the separator of the platform, to be joined to a base directory.
+    pub fn to_path_buf(&self) -> PathBuf {
+        self.1.iter().collect()
+    }
+
+    /// Returns `true` if the path was not provided.
+    pub fn is_none(&self) -> bool {
+        self.2
+    }
+
+    /// Converts the current instance into an `Option<SafeRelativePath>`.
+    ///
+    /// # Returns
+    /// - `None` if the path was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<SafeRelativePath> {
+        if self.2 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for SafeRelativePath {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&SafeRelativePath> for String {
+    fn from(path: &SafeRelativePath) -> Self {
+        path.as_str().to_string()
+    }
+}
+
+impl Display for SafeRelativePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for SafeRelativePath {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<Option<&str>> for SafeRelativePath {
+    type Error = SafeRelativePathError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for SafeRelativePath {
+    type Error = SafeRelativePathError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for SafeRelativePath {
+    type Error = SafeRelativePathError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, SafeRelativePath, SafeRelativePathError> for SafeRelativePathRules {
+    fn validate(&self, input: Option<&str>) -> Result<SafeRelativePath, SafeRelativePathError> {
+        SafeRelativePath::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for SafeRelativePathRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserved_names() {
+        for name in ["CON", "nul.txt", "Com1", "lpt9.tar.gz", "aux ", ".", ".."] {
+            assert!(is_reserved_name(name), "{}", name);
+        }
+        for name in ["console", "com0", "com10", "nullable.txt", ".gitignore"] {
+            assert!(!is_reserved_name(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_file_name() {
+        let name = FileName::parse(Some("archive.tar.gz")).expect("Expected valid name");
+        assert_eq!((name.stem(), name.extension()), ("archive.tar", Some("gz")));
+        let name = FileName::parse(Some(".env")).expect("Expected valid name");
+        assert_eq!(name.extension(), None);
+        assert!(FileName::parse(Some("a\\b")).is_err());
+        assert!(FileName::parse(Some("a\nb")).is_err());
+        assert!(FileName::parse(Some(&"a".repeat(256))).is_err());
+        let rules = FileNameRules {
+            allowed_extensions: Some(vec!["txt".to_string()]),
+            ..FileNameRules::default()
+        };
+        assert!(FileName::parse_custom(Some("README"), rules).is_err());
+    }
+
+    #[test]
+    fn test_file_name_windows_invalid() {
+        for name in ["a:b.txt", "a<b", "a>b", "a\"b", "a|b", "a?b", "a*b"] {
+            let error = FileName::parse(Some(name)).expect_err("Expected invalid name");
+            assert_eq!(
+                error.0.as_original_message_vec(),
+                vec!["Must not contain < > : \" | ? *"],
+                "{}",
+                name
+            );
+        }
+        for name in ["a.txt.", "a.txt ", "..."] {
+            let error = FileName::parse(Some(name)).expect_err("Expected invalid name");
+            assert_eq!(
+                error.0.as_original_message_vec(),
+                vec!["Must not end with a dot or a space"],
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_safe_relative_path() {
+        let path = SafeRelativePath::parse(Some("a//b/./c.txt")).expect("Expected valid path");
+        assert_eq!(path.components(), ["a", "b", "c.txt"]);
+        assert_eq!(path.file_name(), Some("c.txt"));
+        let error = SafeRelativePath::parse(Some("a/../prn/x")).expect_err("Expected invalid path");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must not contain ..", "\"prn\" is a reserved name"]
+        );
+        for path in ["a/.. /b", "a/.../b", "a\\ ..\\b"] {
+            let error = SafeRelativePath::parse(Some(path)).expect_err("Expected invalid path");
+            assert_eq!(
+                error.0.as_original_message_vec(),
+                vec!["Must not contain .."],
+                "{}",
+                path
+            );
+        }
+        let error = SafeRelativePath::parse(Some("a./b /c:d")).expect_err("Expected invalid path");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec![
+                "Must not contain < > : \" | ? *",
+                "Must not end with a dot or a space"
+            ]
+        );
+    }
+}
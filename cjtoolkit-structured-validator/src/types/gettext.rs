@@ -0,0 +1,95 @@
+//! This module contains the `gettext` support of the value types, translating the errors with
+//! the PO catalogs bound with `gettextrs::bindtextdomain`.
+//!
+//! The msgid of an error is its locale key, e.g. `validate-min-length`, and its arguments are
+//! filled in the translated message either by name, e.g. `{min}`, or by position, e.g. `{0}`, in
+//! the order of `locale_keys::key_args`. An error whose key is not in the catalog keeps its
+//! original English message.
+//!
+//! ```po
+//! msgid "validate-min-length"
+//! msgstr "Doit contenir au moins {min} caractères"
+//! ```
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::translator::TranslateStore;
+//! use cjtoolkit_structured_validator::types::gettext::GettextTranslator;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//!
+//! let translator = GettextTranslator::with_domain("myapp");
+//! let messages = Name::parse(Some("No")).as_translated_messages(&translator);
+//! assert_eq!(messages, vec!["Must be at least 5 characters"]);
+//! ```
+
+use crate::common::interpolate::{format_value, interpolate};
+use crate::common::locale::LocaleData;
+use crate::common::locale_keys;
+use crate::common::translator::Translator;
+
+/// Translates the errors with `gettext`, see the module documentation.
+///
+/// # Fields
+/// - `domain`: The text domain of the catalog, `None` for the domain set with
+///   `gettextrs::textdomain`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GettextTranslator {
+    pub domain: Option<String>,
+}
+
+impl GettextTranslator {
+    /// Creates a translator using the domain set with `gettextrs::textdomain`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a translator using the catalog of `domain`.
+    pub fn with_domain(domain: &str) -> Self {
+        Self {
+            domain: Some(domain.to_string()),
+        }
+    }
+}
+
+impl Translator for GettextTranslator {
+    fn translate(&self, data: &LocaleData, original: &str) -> String {
+        let translated = match &self.domain {
+            Some(domain) => gettextrs::dgettext(domain.as_str(), data.name.as_str()),
+            None => gettextrs::gettext(data.name.as_str()),
+        };
+        if translated == data.name {
+            return original.to_string();
+        }
+        fill_args(translated, data)
+    }
+}
+
+fn fill_args(mut message: String, data: &LocaleData) -> String {
+    for (i, name) in locale_keys::key_args(&data.name).iter().enumerate() {
+        if let Some(value) = data.args.get(*name) {
+            message = message.replace(&format!("{{{}}}", i), &format_value(value));
+        }
+    }
+    interpolate(&message, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::locale::LocaleValue;
+
+    #[test]
+    fn test_named_and_positional_args() {
+        let data = LocaleData::new_with_vec(
+            locale_keys::VALIDATE_NPS_RANGE,
+            vec![
+                ("min".to_string(), LocaleValue::from(1isize)),
+                ("max".to_string(), LocaleValue::from(9isize)),
+            ],
+        );
+        assert_eq!(
+            fill_args("Entre {min} et {1}".to_string(), &data),
+            "Entre 1 et 9"
+        );
+    }
+}
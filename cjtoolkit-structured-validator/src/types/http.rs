@@ -0,0 +1,543 @@
+//! This module contains structures and traits for working with HTTP headers, e.g. the
+//! `Accept-Language` header, to pick the translation bundle of a request.
+//!
+//! An `Accept-Language` header is a comma separated list of language ranges, e.g. `fr-CH` or
+//! `*`, each with an optional quality, e.g. `fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5`. The header
+//! comes from the client, so the number of entries is limited by `AcceptLanguageRules`.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::http::AcceptLanguage;
+//!
+//! let header = AcceptLanguage::parse(Some("en;q=0.5, fr-CH, de;q=0"))
+//!     .expect("Expected header to be valid");
+//! let tags: Vec<&str> = header.ranges().iter().map(|range| range.tag()).collect();
+//! assert_eq!(tags, vec!["fr-CH", "en", "de"]);
+//! assert_eq!(header.negotiate(&["de", "en", "fr"]), Some(&"fr"));
+//! assert_eq!(header.negotiate(&["de"]), None);
+//! ```
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::rule_result;
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// An enumeration of the `Accept-Language` specific validation failures.
+#[derive(Clone)]
+pub enum AcceptLanguageLocale {
+    /// An entry is not a language range with an optional quality.
+    /// # Key
+    /// `validate-accept-language-invalid`
+    Invalid,
+    /// The header has more than `max` entries.
+    /// # Key
+    /// `validate-accept-language-max-entries`
+    MaxEntries(usize),
+}
+
+impl LocaleMessage for AcceptLanguageLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        match self {
+            Self::Invalid => LocaleData::new(locale_keys::VALIDATE_ACCEPT_LANGUAGE_INVALID),
+            Self::MaxEntries(max) => LocaleData::new_with_vec(
+                locale_keys::VALIDATE_ACCEPT_LANGUAGE_MAX_ENTRIES,
+                vec![("max".to_string(), LocaleValue::from(*max))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::Invalid => RuleViolation::pattern("accept-language"),
+            Self::MaxEntries(max) => RuleViolation::MaxItems { max: *max },
+        }
+    }
+}
+
+/// A structure to define the rules of an `Accept-Language` header.
+///
+/// # Fields
+///
+/// * `is_mandatory` - Whether the header is mandatory.
+/// * `max_entries` - The maximum number of language ranges, `None` for no maximum.
+///
+/// # Defaults
+/// By default, the header is optional, as clients may omit it, and has at most 16 entries.
+#[derive(Clone)]
+pub struct AcceptLanguageRules {
+    pub is_mandatory: bool,
+    pub max_entries: Option<usize>,
+}
+
+impl Default for AcceptLanguageRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: false,
+            max_entries: Some(16),
+        }
+    }
+}
+
+impl From<&AcceptLanguageRules> for StringMandatoryRules {
+    fn from(rules: &AcceptLanguageRules) -> Self {
+        Self {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: true,
+        }
+    }
+}
+
+impl AcceptLanguageRules {
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        StringMandatoryRules::from(self).check(messages, subject);
+    }
+
+    fn check_entries(&self, messages: &mut ValidateErrorCollector, entries: usize) {
+        let Some(max) = self.max_entries else {
+            return;
+        };
+        let is_within_max = entries <= max;
+        rule_result(
+            locale_keys::VALIDATE_ACCEPT_LANGUAGE_MAX_ENTRIES,
+            is_within_max,
+        );
+        if !is_within_max {
+            messages.push((
+                format!("Must have at most {} languages", max),
+                Box::new(AcceptLanguageLocale::MaxEntries(max)),
+            ));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = StringMandatoryRules::from(self).describe();
+        constraints.push(Constraint::from_locale(&AcceptLanguageLocale::Invalid));
+        if let Some(max) = self.max_entries {
+            constraints.push(Constraint::from_locale(&AcceptLanguageLocale::MaxEntries(
+                max,
+            )));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during `Accept-Language` validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Accept-Language Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Accept-Language Validation Error")]
+pub struct AcceptLanguageError(pub ValidateErrorStore);
+
+impl ValidationCheck for AcceptLanguageError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&AcceptLanguageError> for ValidateErrorStore {
+    fn from(error: &AcceptLanguageError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A language range of an `Accept-Language` header, with its quality.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LanguageRange {
+    tag: String,
+    quality: u16,
+}
+
+impl LanguageRange {
+    /// Parses `entry`, e.g. `fr-CH;q=0.9`, or returns `None` if it is not a language range with
+    /// an optional quality.
+    fn parse(entry: &str) -> Option<Self> {
+        let mut parts = entry.split(';');
+        let tag = parts.next().unwrap_or_default().trim();
+        if !Self::is_language_range(tag) {
+            return None;
+        }
+        let mut quality = None;
+        for parameter in parts {
+            let (name, value) = parameter.split_once('=')?;
+            if !name.trim().eq_ignore_ascii_case("q") || quality.is_some() {
+                return None;
+            }
+            quality = Some(Self::parse_quality(value.trim())?);
+        }
+        Some(Self {
+            tag: tag.to_string(),
+            quality: quality.unwrap_or(1000),
+        })
+    }
+
+    /// Returns `true` if `tag` is `*`, or subtags of 1 to 8 letters or digits separated by `-`,
+    /// the first being letters only.
+    fn is_language_range(tag: &str) -> bool {
+        tag == "*"
+            || tag.split('-').enumerate().all(|(index, subtag)| {
+                (1..=8).contains(&subtag.len())
+                    && subtag.chars().all(|c| match index {
+                        0 => c.is_ascii_alphabetic(),
+                        _ => c.is_ascii_alphanumeric(),
+                    })
+            })
+    }
+
+    /// Parses a quality of `0` to `1`, with at most 3 decimals, in thousandths.
+    fn parse_quality(value: &str) -> Option<u16> {
+        let (integer, decimals) = value.split_once('.').unwrap_or((value, ""));
+        if !matches!(integer, "0" | "1")
+            || decimals.len() > 3
+            || !decimals.chars().all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let decimals = format!("{:0<3}", decimals).parse::<u16>().ok()?;
+        let quality = integer.parse::<u16>().ok()? * 1000 + decimals;
+        (quality <= 1000).then_some(quality)
+    }
+
+    /// Returns the language tag, e.g. `fr-CH`, or `*`.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns the quality, from `0.0`, not acceptable, to `1.0`, the default.
+    pub fn quality(&self) -> f32 {
+        f32::from(self.quality) / 1000.0
+    }
+
+    /// Returns `true` if the range is `*`, matching any language.
+    pub fn is_wildcard(&self) -> bool {
+        self.tag == "*"
+    }
+}
+
+/// A structure representing a validated `Accept-Language` header.
+///
+/// # Fields
+/// - `0: String` - The original header.
+/// - `1: Vec<LanguageRange>` - The language ranges, by descending quality.
+/// - `2: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+pub struct AcceptLanguage(String, Vec<LanguageRange>, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for AcceptLanguage {
+    fn default() -> Self {
+        Self(String::new(), vec![], true)
+    }
+}
+
+impl AcceptLanguage {
+    /// Parses an `Accept-Language` header based on the provided validation rules, the empty
+    /// entries being ignored.
+    ///
+    /// # Errors
+    /// Returns an `AcceptLanguageError` if the header is empty while mandatory, has more
+    /// entries than allowed, or has an entry which is not a language range with an optional
+    /// quality.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::http::{AcceptLanguage, AcceptLanguageRules};
+    ///
+    /// let rules = AcceptLanguageRules {
+    ///     max_entries: Some(2),
+    ///     ..AcceptLanguageRules::default()
+    /// };
+    /// let header = AcceptLanguage::parse_custom(Some("en-GB, en;q=0.7"), rules.clone())
+    ///     .expect("Expected header to be valid");
+    /// assert_eq!(header.ranges()[1].quality(), 0.7);
+    /// assert!(AcceptLanguage::parse_custom(Some("en, fr, de"), rules.clone()).is_err());
+    /// assert!(AcceptLanguage::parse_custom(Some("en;q=2"), rules).is_err());
+    /// ```
+    pub fn parse_custom(
+        s: Option<&str>,
+        rules: AcceptLanguageRules,
+    ) -> Result<Self, AcceptLanguageError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            AcceptLanguageError::validate_check(messages)?;
+            let entries: Vec<&str> = s
+                .split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .collect();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check_entries(&mut messages, entries.len());
+            AcceptLanguageError::validate_check(messages)?;
+            let ranges: Option<Vec<LanguageRange>> =
+                entries.into_iter().map(LanguageRange::parse).collect();
+            rule_result(
+                locale_keys::VALIDATE_ACCEPT_LANGUAGE_INVALID,
+                ranges.is_some(),
+            );
+            let Some(mut ranges) = ranges else {
+                let mut messages = ValidateErrorCollector::new();
+                messages.push((
+                    "Invalid Accept-Language header".to_string(),
+                    Box::new(AcceptLanguageLocale::Invalid),
+                ));
+                return Err(AcceptLanguageError(messages.into()));
+            };
+            ranges.sort_by_key(|range| Reverse(range.quality));
+            Ok(Self(s.to_string(), ranges, is_none))
+        })
+    }
+
+    /// Parses an `Accept-Language` header using the default `AcceptLanguageRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, AcceptLanguageError> {
+        Self::parse_custom(s, AcceptLanguageRules::default())
+    }
+
+    /// Returns the language ranges, by descending quality, the header order being kept between
+    /// equal qualities.
+    pub fn ranges(&self) -> &[LanguageRange] {
+        &self.1
+    }
+
+    /// Returns the first of the `available` languages, e.g. the translation bundles, accepted
+    /// by the header, or `None` if none is accepted.
+    ///
+    /// The ranges are looked up by descending quality, case-insensitively, a range being
+    /// truncated until a language is found, e.g. `fr-CH` then `fr`, as defined by RFC 4647.
+    /// `*` accepts the first available language, and a range with a quality of `0` excludes
+    /// its language.
+    pub fn negotiate<'a, S: AsRef<str>>(&self, available: &'a [S]) -> Option<&'a S> {
+        let is_excluded = |language: &str| {
+            self.1
+                .iter()
+                .any(|range| range.quality == 0 && range.tag.eq_ignore_ascii_case(language))
+        };
+        let find = |tag: &str| {
+            available.iter().find(|language| {
+                let language = language.as_ref();
+                language.eq_ignore_ascii_case(tag) && !is_excluded(language)
+            })
+        };
+        self.1
+            .iter()
+            .filter(|range| range.quality > 0)
+            .find_map(|range| {
+                if range.is_wildcard() {
+                    return available
+                        .iter()
+                        .find(|language| !is_excluded(language.as_ref()));
+                }
+                let mut tag = range.tag.as_str();
+                loop {
+                    if let Some(language) = find(tag) {
+                        return Some(language);
+                    }
+                    let (truncated, _) = tag.rsplit_once('-')?;
+                    tag = match truncated.rsplit_once('-') {
+                        Some((rest, subtag)) if subtag.len() == 1 => rest,
+                        _ => truncated,
+                    };
+                }
+            })
+    }
+
+    /// Returns the original header as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the header was not provided.
+    pub fn is_none(&self) -> bool {
+        self.2
+    }
+
+    /// Converts the current instance into an `Option<AcceptLanguage>`.
+    ///
+    /// # Returns
+    /// - `None` if the header was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<AcceptLanguage> {
+        if self.2 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for AcceptLanguage {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&AcceptLanguage> for String {
+    fn from(header: &AcceptLanguage) -> Self {
+        header.as_str().to_string()
+    }
+}
+
+impl Display for AcceptLanguage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for AcceptLanguage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for AcceptLanguage {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `AcceptLanguage` key can be found by
+/// a `&str`.
+impl PartialEq for AcceptLanguage {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for AcceptLanguage {}
+
+impl Hash for AcceptLanguage {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for AcceptLanguage {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for AcceptLanguage {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for AcceptLanguage {
+    type Error = AcceptLanguageError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for AcceptLanguage {
+    type Error = AcceptLanguageError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for AcceptLanguage {
+    type Error = AcceptLanguageError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, AcceptLanguage, AcceptLanguageError> for AcceptLanguageRules {
+    fn validate(&self, input: Option<&str>) -> Result<AcceptLanguage, AcceptLanguageError> {
+        AcceptLanguage::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for AcceptLanguageRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_range() {
+        let range = LanguageRange::parse("zh-Hant-TW ; Q=0.25").unwrap_or_else(|| LanguageRange {
+            tag: String::new(),
+            quality: 0,
+        });
+        assert_eq!((range.tag(), range.quality()), ("zh-Hant-TW", 0.25));
+        for entry in [
+            "",
+            "e1",
+            "en-",
+            "toolongtag",
+            "en;q=1.5",
+            "en;q=.5",
+            "en;q",
+            "en;v=1",
+        ] {
+            assert!(LanguageRange::parse(entry).is_none(), "{}", entry);
+        }
+        assert!(LanguageRange::parse("en;q=0.5;q=0.5").is_none());
+    }
+
+    #[test]
+    fn test_negotiate() {
+        let header = AcceptLanguage::parse(Some("de-CH-x-a, *;q=0.1, EN;q=0")).unwrap_or_default();
+        assert_eq!(header.negotiate(&["fr", "DE"]), Some(&"DE"));
+        assert_eq!(header.negotiate(&["en", "fr"]), Some(&"fr"));
+        assert_eq!(header.negotiate(&["en"]), None);
+    }
+
+    #[test]
+    fn test_accept_language_errors() {
+        let error = AcceptLanguage::parse(Some(&["en"; 17].join(",")))
+            .err()
+            .unwrap_or_default();
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must have at most 16 languages"]
+        );
+        assert!(AcceptLanguage::parse(Some("en, , fr,")).is_ok());
+        assert!(AcceptLanguage::parse(Some("en, <script>")).is_err());
+        let rules = AcceptLanguageRules {
+            is_mandatory: true,
+            ..AcceptLanguageRules::default()
+        };
+        assert!(AcceptLanguage::parse_custom(Some(" "), rules).is_err());
+        assert!(AcceptLanguage::parse(None).is_ok_and(|header| header.is_none()));
+    }
+}
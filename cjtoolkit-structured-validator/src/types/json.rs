@@ -0,0 +1,327 @@
+//! This module contains the `serde_json` support of the value types.
+//!
+//! `JsonValidator` walks a `serde_json::Value` against a `Schema` built from the rules of the
+//! value types, e.g. a webhook payload or the settings of a plugin, and keeps the errors in a
+//! `ValidationReport` keyed by path, e.g. `items[0].name`. A missing value or `null` is parsed
+//! as `None`, so the mandatory rules report it, and the fields of an object that are not in its
+//! schema are ignored.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::base::collection_rules::CollectionRules;
+//! use cjtoolkit_structured_validator::types::json::{JsonValidator, Schema};
+//! use cjtoolkit_structured_validator::types::name::NameRules;
+//! use cjtoolkit_structured_validator::types::numbers::unsigned::UnsignedRules;
+//! use serde_json::json;
+//!
+//! let validator = JsonValidator::new(Schema::object([
+//!     ("name", Schema::Name(NameRules::default())),
+//!     (
+//!         "items",
+//!         Schema::array(
+//!             Schema::object([
+//!                 ("name", Schema::Name(NameRules::default())),
+//!                 ("quantity", Schema::Unsigned(UnsignedRules::default())),
+//!             ]),
+//!             CollectionRules {
+//!                 min_items: Some(1),
+//!                 ..CollectionRules::default()
+//!             },
+//!         ),
+//!     ),
+//! ]));
+//!
+//! let report = validator.validate(&json!({
+//!     "name": "Alice Smith",
+//!     "items": [
+//!         { "name": "Teapot", "quantity": 1 },
+//!         { "name": "Mug", "quantity": "two" },
+//!     ],
+//! }));
+//!
+//! assert_eq!(report.fields(), vec!["items[1].name", "items[1].quantity"]);
+//! ```
+
+use crate::base::collection_rules::CollectionRules;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::validation_report::ValidationReport;
+use crate::types::description::{Description, DescriptionRules};
+#[cfg(feature = "email")]
+use crate::types::email::{Email, EmailRules};
+use crate::types::name::{Name, NameRules};
+use crate::types::numbers::float::{Float, FloatRules};
+use crate::types::numbers::integer::{Integer, IntegerRules};
+use crate::types::numbers::unsigned::{Unsigned, UnsignedRules};
+#[cfg(feature = "url")]
+use crate::types::url::{Url, UrlRules};
+use crate::types::username::{Username, UsernameRules};
+use serde_json::Value;
+use std::sync::Arc;
+
+/// The value of a JSON payload does not have the type expected by its schema.
+///
+/// Holds the name of the expected type, e.g. `string`, `integer`, `object` or `array`.
+///
+/// # Key
+/// `validate-invalid-type`
+#[derive(Clone)]
+pub struct JsonTypeLocale(pub &'static str);
+
+impl LocaleMessage for JsonTypeLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_INVALID_TYPE,
+            vec![("expected".to_string(), LocaleValue::from(self.0))],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::InvalidType {
+            expected: self.0.to_string(),
+        }
+    }
+}
+
+/// The declarative schema of a JSON value.
+///
+/// The leaves are validated by the value type matching their rules, objects by the schema of
+/// each of their fields, and arrays by `CollectionRules` and the schema of each of their items.
+#[derive(Clone)]
+pub enum Schema {
+    Name(NameRules),
+    Username(UsernameRules),
+    Description(DescriptionRules),
+    #[cfg(feature = "email")]
+    Email(EmailRules),
+    #[cfg(feature = "url")]
+    Url(UrlRules),
+    Integer(IntegerRules),
+    Unsigned(UnsignedRules),
+    Float(FloatRules),
+    Object(Vec<(String, Schema)>),
+    Array(Box<Schema>, CollectionRules),
+}
+
+impl Schema {
+    /// Creates the schema of an object from the name and schema of each of its fields.
+    pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Schema)>) -> Self {
+        Self::Object(
+            fields
+                .into_iter()
+                .map(|(name, schema)| (name.into(), schema))
+                .collect(),
+        )
+    }
+
+    /// Creates the schema of an array from the schema of its items and the rules of the array.
+    pub fn array(items: Schema, rules: CollectionRules) -> Self {
+        Self::Array(Box::new(items), rules)
+    }
+}
+
+/// A validator of JSON values against a `Schema`.
+#[derive(Clone)]
+pub struct JsonValidator {
+    pub schema: Schema,
+}
+
+impl JsonValidator {
+    /// Creates a validator for `schema`.
+    pub fn new(schema: Schema) -> Self {
+        Self { schema }
+    }
+
+    /// Validates `value` against the schema, and returns the errors keyed by path.
+    ///
+    /// The errors of the value itself, rather than one of its fields or items, are keyed by the
+    /// empty path.
+    pub fn validate(&self, value: &Value) -> ValidationReport {
+        let mut report = ValidationReport::new();
+        walk(&self.schema, Some(value), "", &mut report);
+        report
+    }
+}
+
+fn type_error(expected: &'static str) -> ValidateErrorStore {
+    let mut messages = ValidateErrorCollector::new();
+    messages.push((
+        format!("Must be of type {}", expected),
+        Box::new(JsonTypeLocale(expected)),
+    ));
+    messages.into()
+}
+
+fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+/// Treats `null` as a missing value.
+fn present(value: Option<&Value>) -> Option<&Value> {
+    value.filter(|value| !value.is_null())
+}
+
+fn as_str(value: Option<&Value>) -> Result<Option<&str>, ValidateErrorStore> {
+    match present(value) {
+        None => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(_) => Err(type_error("string")),
+    }
+}
+
+fn as_number<T>(
+    value: Option<&Value>,
+    expected: &'static str,
+    convert: impl FnOnce(&Value) -> Option<T>,
+) -> Result<Option<T>, ValidateErrorStore> {
+    match present(value) {
+        None => Ok(None),
+        Some(value) => convert(value).map(Some).ok_or_else(|| type_error(expected)),
+    }
+}
+
+fn check_leaf<T, E>(
+    report: &mut ValidationReport,
+    path: &str,
+    input: Result<T, ValidateErrorStore>,
+    parse: impl FnOnce(T) -> Result<(), E>,
+) where
+    for<'a> &'a E: Into<ValidateErrorStore>,
+{
+    match input {
+        Ok(input) => {
+            let _ = report.check(path, parse(input));
+        }
+        Err(store) => report.0.push((path.to_string(), store)),
+    }
+}
+
+fn walk(schema: &Schema, value: Option<&Value>, path: &str, report: &mut ValidationReport) {
+    match schema {
+        Schema::Name(rules) => check_leaf(report, path, as_str(value), |s| {
+            Name::parse_custom(s, rules.clone()).map(|_| ())
+        }),
+        Schema::Username(rules) => check_leaf(report, path, as_str(value), |s| {
+            Username::parse_custom(s, rules.clone()).map(|_| ())
+        }),
+        Schema::Description(rules) => check_leaf(report, path, as_str(value), |s| {
+            Description::parse_custom(s, rules.clone()).map(|_| ())
+        }),
+        #[cfg(feature = "email")]
+        Schema::Email(rules) => check_leaf(report, path, as_str(value), |s| {
+            Email::parse_custom(s, rules.clone()).map(|_| ())
+        }),
+        #[cfg(feature = "url")]
+        Schema::Url(rules) => check_leaf(report, path, as_str(value), |s| {
+            Url::parse_custom(s, rules.clone()).map(|_| ())
+        }),
+        Schema::Integer(rules) => check_leaf(
+            report,
+            path,
+            as_number(value, "integer", |v| {
+                v.as_i64().and_then(|n| isize::try_from(n).ok())
+            }),
+            |n| Integer::parse_custom(n, rules.clone()).map(|_| ()),
+        ),
+        Schema::Unsigned(rules) => check_leaf(
+            report,
+            path,
+            as_number(value, "unsigned integer", |v| {
+                v.as_u64().and_then(|n| usize::try_from(n).ok())
+            }),
+            |n| Unsigned::parse_custom(n, rules.clone()).map(|_| ()),
+        ),
+        Schema::Float(rules) => check_leaf(
+            report,
+            path,
+            as_number(value, "number", Value::as_f64),
+            |n| Float::parse_custom(n, rules.clone()).map(|_| ()),
+        ),
+        Schema::Object(fields) => match present(value) {
+            None => {
+                for (name, schema) in fields {
+                    walk(schema, None, &field_path(path, name), report);
+                }
+            }
+            Some(Value::Object(map)) => {
+                for (name, schema) in fields {
+                    walk(schema, map.get(name), &field_path(path, name), report);
+                }
+            }
+            Some(_) => report.0.push((path.to_string(), type_error("object"))),
+        },
+        Schema::Array(items, rules) => {
+            let array = match present(value) {
+                None => None,
+                Some(Value::Array(array)) => Some(array),
+                Some(_) => {
+                    report.0.push((path.to_string(), type_error("array")));
+                    return;
+                }
+            };
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, array.map(Vec::len));
+            if !messages.is_empty() {
+                report.0.push((path.to_string(), messages.into()));
+            }
+            for (index, item) in array.into_iter().flatten().enumerate() {
+                walk(items, Some(item), &format!("{}[{}]", path, index), report);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_type_mismatch_and_missing_values() {
+        let validator = JsonValidator::new(Schema::object([
+            ("name", Schema::Name(NameRules::default())),
+            ("age", Schema::Unsigned(UnsignedRules::default())),
+            (
+                "tags",
+                Schema::array(
+                    Schema::Name(NameRules::default()),
+                    CollectionRules {
+                        max_items: Some(1),
+                        ..CollectionRules::default()
+                    },
+                ),
+            ),
+        ]));
+
+        let report = validator.validate(&json!({ "name": 42, "age": -1, "tags": null }));
+        assert_eq!(report.fields(), vec!["name", "age", "tags"]);
+        assert_eq!(
+            report.0[0].1.violations(),
+            vec![RuleViolation::InvalidType {
+                expected: "string".to_string()
+            }]
+        );
+        assert_eq!(report.0[2].1.violations(), vec![RuleViolation::Mandatory]);
+
+        let report = validator.validate(&json!({
+            "name": "Alice Smith",
+            "age": 30,
+            "tags": ["Al", "Bo"],
+        }));
+        assert_eq!(report.fields(), vec!["tags", "tags[0]", "tags[1]"]);
+        assert_eq!(
+            report.0[0].1.violations(),
+            vec![RuleViolation::MaxItems { max: 1 }]
+        );
+
+        let report = validator.validate(&json!([]));
+        assert_eq!(report.fields(), vec![""]);
+    }
+}
@@ -0,0 +1,427 @@
+//! This module contains structures and traits for working with MIME types, e.g. the content
+//! type of an upload, or a type to negotiate.
+//!
+//! A MIME type is `type/subtype`, with an optional structured syntax suffix, e.g.
+//! `application/vnd.api+json`. The type and subtype are restricted names, as defined by
+//! RFC 6838, and compared case-insensitively, so the MIME type is stored in lowercase.
+//! Parameters, e.g. `; charset=utf-8`, are not accepted.
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::rule_result;
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Returns `true` if `name` is a restricted name of RFC 6838, 1 to 127 characters, starting
+/// with a letter or a digit.
+fn is_restricted_name(name: &str) -> bool {
+    name.len() <= 127
+        && name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && name.chars().all(|c| {
+            c.is_ascii_alphanumeric()
+                || matches!(c, '!' | '#' | '$' | '&' | '-' | '^' | '_' | '.' | '+')
+        })
+}
+
+/// An enumeration of the MIME type specific validation failures.
+#[derive(Clone)]
+pub enum MimeTypeLocale {
+    /// The MIME type is not `type/subtype`.
+    /// # Key
+    /// `validate-mime-type-invalid`
+    Invalid,
+    /// The MIME type is not one of the comma separated `allowed` MIME types.
+    /// # Key
+    /// `validate-mime-type-not-allowed`
+    NotAllowed(String),
+}
+
+impl LocaleMessage for MimeTypeLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        match self {
+            Self::Invalid => LocaleData::new(locale_keys::VALIDATE_MIME_TYPE_INVALID),
+            Self::NotAllowed(allowed) => LocaleData::new_with_vec(
+                locale_keys::VALIDATE_MIME_TYPE_NOT_ALLOWED,
+                vec![("allowed".to_string(), LocaleValue::from(allowed.clone()))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::Invalid => RuleViolation::pattern("mime-type"),
+            Self::NotAllowed(allowed) => RuleViolation::NotAllowed {
+                allowed: allowed.clone(),
+            },
+        }
+    }
+}
+
+/// A structure to define the rules of a MIME type.
+///
+/// # Fields
+///
+/// * `is_mandatory` - Whether the MIME type is mandatory.
+/// * `allowed` - The MIME types allowed, compared case-insensitively, either exactly, e.g.
+///   `image/png`, or by type, e.g. `image/*`. `None` for any MIME type.
+#[derive(Clone)]
+pub struct MimeTypeRules {
+    pub is_mandatory: bool,
+    pub allowed: Option<Vec<String>>,
+}
+
+impl Default for MimeTypeRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            allowed: None,
+        }
+    }
+}
+
+impl From<&MimeTypeRules> for StringMandatoryRules {
+    fn from(rules: &MimeTypeRules) -> Self {
+        Self {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: true,
+        }
+    }
+}
+
+impl MimeTypeRules {
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        StringMandatoryRules::from(self).check(messages, subject);
+    }
+
+    fn check_allowed(&self, messages: &mut ValidateErrorCollector, mime_type: &MimeType) {
+        let Some(allowed) = &self.allowed else {
+            return;
+        };
+        let is_allowed = allowed.iter().any(|allowed| mime_type.matches(allowed));
+        rule_result(locale_keys::VALIDATE_MIME_TYPE_NOT_ALLOWED, is_allowed);
+        if !is_allowed {
+            let allowed = allowed.join(", ");
+            messages.push((
+                format!("Must be one of: {}", allowed),
+                Box::new(MimeTypeLocale::NotAllowed(allowed)),
+            ));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = StringMandatoryRules::from(self).describe();
+        constraints.push(Constraint::from_locale(&MimeTypeLocale::Invalid));
+        if let Some(allowed) = &self.allowed {
+            constraints.push(Constraint::from_locale(&MimeTypeLocale::NotAllowed(
+                allowed.join(", "),
+            )));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during MIME type validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"MIME Type Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("MIME Type Validation Error")]
+pub struct MimeTypeError(pub ValidateErrorStore);
+
+impl ValidationCheck for MimeTypeError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&MimeTypeError> for ValidateErrorStore {
+    fn from(error: &MimeTypeError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A structure representing a validated MIME type.
+///
+/// # Fields
+/// - `0: String` - The MIME type, in lowercase.
+/// - `1: usize` - The position of the `/` separator.
+/// - `2: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+pub struct MimeType(String, usize, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for MimeType {
+    fn default() -> Self {
+        Self(String::new(), 0, true)
+    }
+}
+
+impl MimeType {
+    /// Returns the position of the `/` of `s`, or `None` if `s` is not `type/subtype`.
+    fn parse_separator(s: &str) -> Option<usize> {
+        let (type_name, subtype) = s.split_once('/')?;
+        (is_restricted_name(type_name) && is_restricted_name(subtype)).then_some(type_name.len())
+    }
+
+    /// Parses a MIME type based on the provided validation rules, surrounding whitespace being
+    /// ignored.
+    ///
+    /// # Errors
+    /// Returns a `MimeTypeError` if the MIME type is empty while mandatory, is not
+    /// `type/subtype`, or is not allowed.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::mime::{MimeType, MimeTypeRules};
+    ///
+    /// let rules = MimeTypeRules {
+    ///     allowed: Some(vec!["image/*".to_string(), "application/pdf".to_string()]),
+    ///     ..MimeTypeRules::default()
+    /// };
+    /// let mime = MimeType::parse_custom(Some("Image/SVG+XML"), rules.clone())
+    ///     .expect("Expected MIME type to be valid");
+    /// assert_eq!((mime.type_name(), mime.subtype()), ("image", "svg+xml"));
+    /// assert_eq!(mime.suffix(), Some("xml"));
+    /// assert!(MimeType::parse_custom(Some("text/html"), rules.clone()).is_err());
+    /// assert!(MimeType::parse_custom(Some("application/pdf; q=1"), rules).is_err());
+    /// ```
+    pub fn parse_custom(s: Option<&str>, rules: MimeTypeRules) -> Result<Self, MimeTypeError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default().trim().to_ascii_lowercase();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            MimeTypeError::validate_check(messages)?;
+            if is_none && !rules.is_mandatory {
+                return Ok(Self(String::new(), 0, is_none));
+            }
+            let separator = Self::parse_separator(&s);
+            rule_result(locale_keys::VALIDATE_MIME_TYPE_INVALID, separator.is_some());
+            let Some(separator) = separator else {
+                let mut messages = ValidateErrorCollector::new();
+                messages.push((
+                    "Invalid MIME type".to_string(),
+                    Box::new(MimeTypeLocale::Invalid),
+                ));
+                return Err(MimeTypeError(messages.into()));
+            };
+            let mime_type = Self(s, separator, is_none);
+            let mut messages = ValidateErrorCollector::new();
+            rules.check_allowed(&mut messages, &mime_type);
+            MimeTypeError::validate_check(messages)?;
+            Ok(mime_type)
+        })
+    }
+
+    /// Parses a MIME type using the default `MimeTypeRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, MimeTypeError> {
+        Self::parse_custom(s, MimeTypeRules::default())
+    }
+
+    /// Returns the MIME type, in lowercase.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the type, e.g. `image` for `image/svg+xml`.
+    pub fn type_name(&self) -> &str {
+        &self.0[..self.1]
+    }
+
+    /// Returns the subtype, with its suffix, e.g. `svg+xml` for `image/svg+xml`.
+    pub fn subtype(&self) -> &str {
+        self.0.get(self.1 + 1..).unwrap_or_default()
+    }
+
+    /// Returns the structured syntax suffix, e.g. `xml` for `image/svg+xml`, or `None` if the
+    /// subtype has none.
+    pub fn suffix(&self) -> Option<&str> {
+        self.subtype()
+            .rsplit_once('+')
+            .map(|(_, suffix)| suffix)
+            .filter(|suffix| !suffix.is_empty())
+    }
+
+    /// Returns `true` if the MIME type matches `pattern`, compared case-insensitively, either
+    /// exactly, e.g. `image/png`, by type, e.g. `image/*`, or `*/*`.
+    pub fn matches(&self, pattern: &str) -> bool {
+        let pattern = pattern.trim().to_ascii_lowercase();
+        match pattern.split_once('/') {
+            Some(("*", "*")) => true,
+            Some((type_name, "*")) => type_name == self.type_name(),
+            _ => pattern == self.0,
+        }
+    }
+
+    /// Returns `true` if the MIME type was not provided.
+    pub fn is_none(&self) -> bool {
+        self.2
+    }
+
+    /// Converts the current instance into an `Option<MimeType>`.
+    ///
+    /// # Returns
+    /// - `None` if the MIME type was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<MimeType> {
+        if self.2 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for MimeType {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&MimeType> for String {
+    fn from(mime_type: &MimeType) -> Self {
+        mime_type.as_str().to_string()
+    }
+}
+
+impl Display for MimeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for MimeType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for MimeType {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `MimeType` key can be found by
+/// a `&str`.
+impl PartialEq for MimeType {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for MimeType {}
+
+impl Hash for MimeType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for MimeType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for MimeType {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for MimeType {
+    type Error = MimeTypeError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for MimeType {
+    type Error = MimeTypeError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for MimeType {
+    type Error = MimeTypeError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, MimeType, MimeTypeError> for MimeTypeRules {
+    fn validate(&self, input: Option<&str>) -> Result<MimeType, MimeTypeError> {
+        MimeType::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for MimeTypeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_mime_type() {
+        let mime = MimeType::parse(Some(" application/vnd.api+json ")).unwrap_or_default();
+        assert_eq!(mime.as_str(), "application/vnd.api+json");
+        assert_eq!(mime.suffix(), Some("json"));
+        let mime = MimeType::parse(Some("text/plain")).unwrap_or_default();
+        assert_eq!(mime.suffix(), None);
+        assert!(mime.matches("*/*") && mime.matches("TEXT/*") && !mime.matches("text/html"));
+    }
+
+    #[test]
+    fn test_invalid_mime_type() {
+        for s in [
+            "text",
+            "text/",
+            "/plain",
+            "text/plain/x",
+            "text/pl ain",
+            "-text/plain",
+        ] {
+            assert!(MimeType::parse(Some(s)).is_err(), "{}", s);
+        }
+        assert!(MimeType::parse(None).is_err());
+        let rules = MimeTypeRules {
+            is_mandatory: false,
+            ..MimeTypeRules::default()
+        };
+        assert!(MimeType::parse_custom(None, rules).is_ok_and(|mime| mime.is_none()));
+    }
+}
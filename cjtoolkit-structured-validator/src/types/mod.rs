@@ -1,13 +1,61 @@
+#[cfg(feature = "ammonia")]
+pub mod ammonia;
+#[cfg(feature = "async-graphql")]
+pub mod async_graphql;
+#[cfg(feature = "clap")]
+pub mod clap;
+pub mod consent;
+pub mod cron;
+#[cfg(feature = "csv")]
+pub mod csv;
 pub mod description;
+#[cfg(feature = "diesel")]
+pub mod diesel;
 #[cfg(feature = "email")]
 pub mod email;
+pub mod form;
+pub mod fs;
+#[cfg(feature = "gettext")]
+pub mod gettext;
+pub mod http;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod mime;
 pub mod name;
+pub mod national_id;
+pub mod net;
 pub mod numbers;
+#[cfg(feature = "openapi")]
+pub mod openapi;
 pub mod password;
+pub mod person;
+#[cfg(feature = "poem")]
+pub mod poem;
+#[cfg(feature = "postcode")]
+pub mod postcode;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+pub mod rating;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod sms;
+pub mod social;
+pub mod sorting;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+pub mod template;
 #[cfg(feature = "chrono")]
 pub mod times_chrono;
 #[cfg(feature = "humantime")]
 pub mod times_humantime;
+#[cfg(feature = "jiff")]
+pub mod times_jiff;
+#[cfg(feature = "time")]
+pub mod times_time;
+#[cfg(feature = "tonic")]
+pub mod tonic;
+#[cfg(feature = "dev-tools")]
+pub mod typescript;
 #[cfg(feature = "url")]
 pub mod url;
 pub mod username;
@@ -9,10 +9,20 @@
 //!
 //! The `NameError` type is used to encapsulate validation errors specific to names
 
-use crate::base::string_rules::{StringLengthRules, StringMandatoryRules};
+use crate::base::string_rules::{
+    LengthUnit, SpamHeuristicsRules, StringLengthRules, StringMandatoryRules,
+};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::rejected_input::observe_parse_input;
 use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::tenant_policy::{TightenRules, tighter_max, tighter_min};
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 use thiserror::Error;
 
 /// A structure representing the rules and constraints associated with a name field.
@@ -34,10 +44,23 @@ use thiserror::Error;
 ///   An optional field specifying the maximum allowable length for the name.
 ///   If it is `Some(value)`, the name must not exceed `value` characters. If it is `None`,
 ///   no maximum length is enforced.
+///
+/// * `length_unit` (`LengthUnit`):
+///   The unit the length of the name is counted in, graphemes by default.
+///
+/// * `treat_whitespace_as_empty` (`bool`):
+///   Whether a name made only of whitespace is treated as empty, `true` by default.
+///
+/// * `spam_heuristics` (`SpamHeuristicsRules`):
+///   The spam heuristics applied to the name, e.g. the maximum number of links, none by default.
+#[derive(Clone)]
 pub struct NameRules {
     pub is_mandatory: bool,
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
+    pub length_unit: LengthUnit,
+    pub treat_whitespace_as_empty: bool,
+    pub spam_heuristics: SpamHeuristicsRules,
 }
 
 impl Default for NameRules {
@@ -46,6 +69,9 @@ impl Default for NameRules {
             is_mandatory: true,
             min_length: Some(5),
             max_length: Some(20),
+            length_unit: LengthUnit::Graphemes,
+            treat_whitespace_as_empty: true,
+            spam_heuristics: SpamHeuristicsRules::default(),
         }
     }
 }
@@ -55,10 +81,12 @@ impl Into<(StringMandatoryRules, StringLengthRules)> for &NameRules {
         (
             StringMandatoryRules {
                 is_mandatory: self.is_mandatory,
+                treat_whitespace_as_empty: self.treat_whitespace_as_empty,
             },
             StringLengthRules {
                 min_length: self.min_length,
                 max_length: self.max_length,
+                unit: self.length_unit,
             },
         )
     }
@@ -84,6 +112,16 @@ impl NameRules {
             return;
         }
         length_rule.check(messages, subject);
+        self.spam_heuristics.check(messages, subject);
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, length_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(length_rule.describe());
+        constraints.extend(self.spam_heuristics.describe());
+        constraints
     }
 }
 
@@ -140,7 +178,12 @@ impl Into<ValidateErrorStore> for &NameError {
 /// # Fields:
 /// - `0: String` - The name represented as a string.
 /// - `1: bool` - A boolean flag associated with the name, none if `true`, otherwise `false`
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
 pub struct Name(String, bool);
 
 #[cfg(any(feature = "allow-default-value", test))]
@@ -184,13 +227,15 @@ impl Name {
     /// - Validation errors are collected using `ValidateErrorCollector` and checked against the rules.
     /// - A `Self` instance is created with the parsed string and whether the input was `None`.
     pub fn parse_custom(s: Option<&str>, rules: NameRules) -> Result<Self, NameError> {
-        let is_none = s.is_none();
-        let s = s.unwrap_or_default();
-        let subject = s.as_string_validator();
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, &subject, is_none);
-        NameError::validate_check(messages)?;
-        Ok(Self(s.to_string(), is_none))
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            NameError::validate_check(messages)?;
+            Ok(Self(s.to_string(), is_none))
+        })
     }
 
     /// Parses the given optional string reference into an instance of `Self` using the default
@@ -243,6 +288,11 @@ impl Name {
         &self.0
     }
 
+    /// Returns `true` if the name was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
     /// Converts the current instance into an `Option<Name>`.
     ///
     /// # Returns
@@ -260,40 +310,705 @@ impl Name {
     }
 }
 
+impl OptionalValue for Name {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
 impl Into<String> for &Name {
     fn into(self) -> String {
         self.0.as_str().to_string()
     }
 }
 
+impl Display for Name {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Name {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `Name` key can be found by
+/// a `&str`.
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Name {}
+
+impl Hash for Name {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for Name {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Name {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for Name {
+    type Error = NameError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Name {
+    type Error = NameError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for Name {
+    type Error = NameError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl NameRules {
+    /// Returns an input satisfying the rules, `Sample` repeated or truncated to fit the length
+    /// constraints.
+    pub fn arbitrary_valid_input(&self) -> String {
+        crate::common::fixtures::sample_string("Sample", self.min_length, self.max_length)
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl Name {
+    /// Returns a valid `Name` for the default `NameRules`.
+    pub fn valid_sample() -> Self {
+        Self::sample_matching(NameRules::default())
+    }
+
+    /// Returns a valid `Name` for the given rules, parsed from `arbitrary_valid_input`.
+    ///
+    /// # Panics
+    /// Panics if no input can satisfy the rules, e.g. `min_length` above `max_length`.
+    pub fn sample_matching(rules: NameRules) -> Self {
+        let input = rules.arbitrary_valid_input();
+        Self::parse_custom(Some(&input), rules).expect("Expected sample to be valid")
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Name {
+    /// Creates an instance of `Name` without validation, e.g. when loading values which were
+    /// validated before being stored. The string is kept as is, `None` gives an empty value.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(s.to_string(), is_none)
+    }
+
+    /// Creates an instance of `Name` from a stored string without validation, so stored values
+    /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
+
+/// Defines a name type, a newtype of `Name` with its own rules and error types, so that e.g. a
+/// `Title` cannot be passed where a `LastName` is expected.
+///
+/// The rules type wraps `NameRules`, dereferencing to it, and defaults to `default_rules`. The
+/// error type wraps a `ValidateErrorStore`, like `NameError`. Parsing reuses the `Name` rules.
+///
+/// With the `diesel` feature, the name type is stored as `Text`, like `Name`, which requires the
+/// crate calling the macro to depend on `diesel` for its derives.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::define_name_type;
+/// use cjtoolkit_structured_validator::types::name::NameRules;
+///
+/// define_name_type!(
+///     /// A nickname, up to 10 characters.
+///     Nickname,
+///     NicknameRules,
+///     NicknameError,
+///     default_rules = NameRules {
+///         min_length: Some(1),
+///         max_length: Some(10),
+///         ..NameRules::default()
+///     }
+/// );
+///
+/// let nickname = Nickname::parse(Some("Ada")).expect("Expected valid nickname");
+/// assert_eq!(nickname.as_str(), "Ada");
+/// assert!(Nickname::parse(Some("Ada Lovelace")).is_err());
+/// ```
+#[macro_export]
+macro_rules! define_name_type {
+    (
+        $(#[$meta:meta])*
+        $name:ident,
+        $rules:ident,
+        $error:ident,
+        default_rules = $default_rules:expr $(,)?
+    ) => {
+        #[doc = concat!("The validation rules of `", stringify!($name), "`, wrapping `NameRules`.")]
+        #[derive(Clone)]
+        pub struct $rules(pub $crate::types::name::NameRules);
+
+        impl Default for $rules {
+            fn default() -> Self {
+                Self($default_rules)
+            }
+        }
+
+        impl From<$crate::types::name::NameRules> for $rules {
+            fn from(rules: $crate::types::name::NameRules) -> Self {
+                Self(rules)
+            }
+        }
+
+        impl std::ops::Deref for $rules {
+            type Target = $crate::types::name::NameRules;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl std::ops::DerefMut for $rules {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        #[doc = concat!("An error of `", stringify!($name), "` validation.")]
+        #[derive(Debug, PartialEq, Clone, Default)]
+        pub struct $error(pub $crate::common::locale::ValidateErrorStore);
+
+        impl std::fmt::Display for $error {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(concat!(stringify!($name), " Validation Error"))
+            }
+        }
+
+        impl std::error::Error for $error {}
+
+        impl $crate::common::validation_check::ValidationCheck for $error {
+            fn validate_new(messages: $crate::common::locale::ValidateErrorStore) -> Self {
+                Self(messages)
+            }
+        }
+
+        impl From<&$error> for $crate::common::locale::ValidateErrorStore {
+            fn from(error: &$error) -> Self {
+                error.0.clone()
+            }
+        }
+
+        impl From<$crate::types::name::NameError> for $error {
+            fn from(error: $crate::types::name::NameError) -> Self {
+                Self(error.0)
+            }
+        }
+
+        $crate::__name_type_diesel! {
+            $(#[$meta])*
+            #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+            pub struct $name($crate::types::name::Name);
+        }
+
+        impl $name {
+            /// Parses the name based on the provided validation rules, either the rules of the
+            /// type or `NameRules`.
+            ///
+            /// # Errors
+            /// Returns an error if the name fails validation based on the rules.
+            pub fn parse_custom(
+                s: Option<&str>,
+                rules: impl Into<$rules>,
+            ) -> Result<Self, $error> {
+                $crate::types::name::Name::parse_custom(s, rules.into().0)
+                    .map(Self)
+                    .map_err(<$error>::from)
+            }
+
+            /// Parses the name based on the default rules.
+            ///
+            /// # Errors
+            /// Returns an error if the name fails validation based on the default rules.
+            pub fn parse(s: Option<&str>) -> Result<Self, $error> {
+                Self::parse_custom(s, <$rules>::default())
+            }
+
+            /// Returns the name as a string slice.
+            pub fn as_str(&self) -> &str {
+                self.0.as_str()
+            }
+
+            /// Returns `true` if the name was not provided.
+            pub fn is_none(&self) -> bool {
+                self.0.is_none()
+            }
+
+            /// Converts the name into an `Option`, `None` if it was not provided.
+            pub fn into_option(self) -> Option<Self> {
+                if self.is_none() { None } else { Some(self) }
+            }
+
+            /// Returns the underlying `Name`.
+            pub fn as_name(&self) -> &$crate::types::name::Name {
+                &self.0
+            }
+
+            /// Converts into the underlying `Name`.
+            pub fn into_name(self) -> $crate::types::name::Name {
+                self.0
+            }
+        }
+
+        impl $crate::common::maybe_validated::OptionalValue for $name {
+            fn is_absent(&self) -> bool {
+                self.is_none()
+            }
+        }
+
+        impl From<&$name> for String {
+            fn from(name: &$name) -> Self {
+                name.as_str().to_string()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl std::borrow::Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl TryFrom<Option<&str>> for $name {
+            type Error = $error;
+
+            fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+                Self::parse(s)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = $error;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                Self::parse(Some(s))
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = $error;
+
+            fn try_from(s: String) -> Result<Self, Self::Error> {
+                Self::parse(Some(&s))
+            }
+        }
+
+        impl $crate::common::validator::Validator<Option<&str>, $name, $error> for $rules {
+            fn validate(&self, input: Option<&str>) -> Result<$name, $error> {
+                $name::parse_custom(input, self.clone())
+            }
+        }
+
+        impl $crate::common::maybe_validated::OptionalRules for $rules {
+            fn optional(self) -> Self {
+                Self($crate::common::maybe_validated::OptionalRules::optional(self.0))
+            }
+        }
+
+        impl $crate::common::schema::SchemaField for $name {
+            type Input = String;
+            type Rules = $rules;
+            type Error = $error;
+
+            fn parse_field(input: Option<&String>, rules: $rules) -> Result<Self, $error> {
+                Self::parse_custom(input.map(String::as_str), rules)
+            }
+        }
+
+        $crate::__name_type_trusted_input!($name);
+        $crate::__name_type_test_fixtures!($name, $rules);
+        $crate::__name_type_graphql!($name);
+    };
+}
+
+// The impls of `define_name_type!` behind a feature of this crate are generated by the macros
+// below, defined according to the features of this crate, as a `#[cfg]` in the expansion of
+// `define_name_type!` would be evaluated against the features of the calling crate.
+
+#[cfg(feature = "trusted-input")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __name_type_trusted_input {
+    ($name:ident) => {
+        impl $name {
+            /// Creates an instance without validation, e.g. when loading values which were
+            /// validated before being stored. The string is kept as is, `None` gives an empty
+            /// value.
+            pub fn new_unchecked(s: Option<&str>) -> Self {
+                Self($crate::types::name::Name::new_unchecked(s))
+            }
+
+            /// Creates an instance from a stored string without validation, so stored values
+            /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+            pub fn from_stored(s: &str) -> Self {
+                Self($crate::types::name::Name::from_stored(s))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "trusted-input"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __name_type_trusted_input {
+    ($name:ident) => {};
+}
+
+#[cfg(feature = "test-fixtures")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __name_type_test_fixtures {
+    ($name:ident, $rules:ident) => {
+        impl $name {
+            /// Returns a valid instance for the default rules.
+            pub fn valid_sample() -> Self {
+                Self::sample_matching(<$rules>::default())
+            }
+
+            /// Returns a valid instance for the given rules, parsed from
+            /// `NameRules::arbitrary_valid_input`.
+            ///
+            /// # Panics
+            /// Panics if no input can satisfy the rules, e.g. `min_length` above `max_length`.
+            pub fn sample_matching(rules: impl Into<$rules>) -> Self {
+                let rules = rules.into();
+                let input = rules.arbitrary_valid_input();
+                Self::parse_custom(Some(&input), rules).expect("Expected sample to be valid")
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "test-fixtures"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __name_type_test_fixtures {
+    ($name:ident, $rules:ident) => {};
+}
+
+#[cfg(feature = "diesel")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __name_type_diesel {
+    ($(#[$meta:meta])* pub struct $name:ident($inner:ty);) => {
+        $(#[$meta])*
+        #[derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)]
+        #[diesel(sql_type = diesel::sql_types::Text)]
+        pub struct $name($inner);
+
+        impl<DB> $crate::__diesel::serialize::ToSql<$crate::__diesel::sql_types::Text, DB>
+            for $name
+        where
+            DB: $crate::__diesel::backend::Backend,
+            $inner: $crate::__diesel::serialize::ToSql<$crate::__diesel::sql_types::Text, DB>,
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut $crate::__diesel::serialize::Output<'b, '_, DB>,
+            ) -> $crate::__diesel::serialize::Result {
+                <$inner as $crate::__diesel::serialize::ToSql<
+                    $crate::__diesel::sql_types::Text,
+                    DB,
+                >>::to_sql(&self.0, out)
+            }
+        }
+
+        impl<DB> $crate::__diesel::deserialize::FromSql<$crate::__diesel::sql_types::Text, DB>
+            for $name
+        where
+            DB: $crate::__diesel::backend::Backend,
+            $inner: $crate::__diesel::deserialize::FromSql<$crate::__diesel::sql_types::Text, DB>,
+        {
+            fn from_sql(
+                bytes: DB::RawValue<'_>,
+            ) -> $crate::__diesel::deserialize::Result<Self> {
+                <$inner as $crate::__diesel::deserialize::FromSql<
+                    $crate::__diesel::sql_types::Text,
+                    DB,
+                >>::from_sql(bytes)
+                .map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "diesel"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __name_type_diesel {
+    ($item:item) => {
+        $item
+    };
+}
+
+#[cfg(feature = "async-graphql")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __name_type_graphql {
+    ($name:ident) => {
+        #[$crate::__async_graphql::Scalar(crate = $crate::__async_graphql)]
+        impl $crate::__async_graphql::ScalarType for $name {
+            fn parse(
+                value: $crate::__async_graphql::Value,
+            ) -> $crate::__async_graphql::InputValueResult<Self> {
+                $crate::types::async_graphql::parse_str_scalar(value, |s| Self::parse(Some(s)))
+            }
+
+            fn is_valid(value: &$crate::__async_graphql::Value) -> bool {
+                matches!(value, $crate::__async_graphql::Value::String(_))
+            }
+
+            fn to_value(&self) -> $crate::__async_graphql::Value {
+                if self.is_none() {
+                    $crate::__async_graphql::Value::Null
+                } else {
+                    $crate::__async_graphql::Value::from(self.as_str())
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "async-graphql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __name_type_graphql {
+    ($name:ident) => {};
+}
+
+/// The name types built with `define_name_type!`, each with its own rules, error and defaults.
 pub mod name_alias {
-    use super::*;
+    use super::NameRules;
+
+    crate::define_name_type!(
+        /// A title, 5 to 20 characters by default.
+        Title,
+        TitleRules,
+        TitleError,
+        default_rules = NameRules::default()
+    );
+
+    crate::define_name_type!(
+        /// A first name, 1 to 50 characters by default.
+        FirstName,
+        FirstNameRules,
+        FirstNameError,
+        default_rules = NameRules {
+            min_length: Some(1),
+            max_length: Some(50),
+            ..NameRules::default()
+        }
+    );
+
+    crate::define_name_type!(
+        /// A forename, 1 to 50 characters by default.
+        ForeName,
+        ForeNameRules,
+        ForeNameError,
+        default_rules = NameRules {
+            min_length: Some(1),
+            max_length: Some(50),
+            ..NameRules::default()
+        }
+    );
+
+    crate::define_name_type!(
+        /// A middle name, optional and up to 50 characters by default.
+        MiddleName,
+        MiddleNameRules,
+        MiddleNameError,
+        default_rules = NameRules {
+            is_mandatory: false,
+            min_length: None,
+            max_length: Some(50),
+            ..NameRules::default()
+        }
+    );
+
+    crate::define_name_type!(
+        /// A last name, 1 to 50 characters by default.
+        LastName,
+        LastNameRules,
+        LastNameError,
+        default_rules = NameRules {
+            min_length: Some(1),
+            max_length: Some(50),
+            ..NameRules::default()
+        }
+    );
 
-    pub type TitleRules = NameRules;
-    pub type TitleError = NameError;
-    pub type Title = Name;
+    crate::define_name_type!(
+        /// A line of an address, 1 to 100 characters by default.
+        AddressLine,
+        AddressLineRules,
+        AddressLineError,
+        default_rules = NameRules {
+            min_length: Some(1),
+            max_length: Some(100),
+            ..NameRules::default()
+        }
+    );
+
+    crate::define_name_type!(
+        /// A generic text field, 5 to 20 characters by default.
+        Field,
+        FieldRules,
+        FieldError,
+        default_rules = NameRules::default()
+    );
+}
+
+impl TightenRules for NameRules {
+    fn tighten(&self, other: &Self) -> Self {
+        Self {
+            is_mandatory: self.is_mandatory || other.is_mandatory,
+            min_length: tighter_min(self.min_length, other.min_length),
+            max_length: tighter_max(self.max_length, other.max_length),
+            length_unit: self.length_unit,
+            treat_whitespace_as_empty: self.treat_whitespace_as_empty
+                || other.treat_whitespace_as_empty,
+            spam_heuristics: self.spam_heuristics.tighten(&other.spam_heuristics),
+        }
+    }
+}
+
+impl Validator<Option<&str>, Name, NameError> for NameRules {
+    fn validate(&self, input: Option<&str>) -> Result<Name, NameError> {
+        Name::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for NameRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::name_alias::*;
+    use super::*;
+    use std::collections::HashMap;
 
-    pub type FirstNameRules = NameRules;
-    pub type FirstNameError = NameError;
-    pub type FirstName = Name;
+    #[test]
+    fn test_name_type_default_rules() {
+        assert!(FirstName::parse(Some("Al")).is_ok());
+        assert!(FirstName::parse(Some(&"a".repeat(51))).is_err());
+        assert!(Title::parse(Some("Al")).is_err());
+        assert!(MiddleName::parse(None).is_ok_and(|name| name.is_none()));
+    }
 
-    pub type ForeNameRules = NameRules;
-    pub type ForeNameError = NameError;
-    pub type ForeName = Name;
+    #[test]
+    fn test_name_type_parse_custom_with_name_rules() {
+        let rules = NameRules {
+            min_length: Some(1),
+            ..NameRules::default()
+        };
+        let title = Title::parse_custom(Some("Dr"), rules).map(Title::into_name);
+        assert!(title.is_ok_and(|name| name == "Dr"));
+    }
 
-    pub type MiddleNameRules = NameRules;
-    pub type MiddleNameError = NameError;
-    pub type MiddleName = Name;
+    #[test]
+    fn test_name_type_error_from_name_error() {
+        let error = Name::parse(Some("")).expect_err("Expected name to be empty");
+        let error = LastNameError::from(error.clone());
+        assert_eq!(error.0.as_original_message_vec(), vec!["Cannot be empty"]);
+        assert_eq!(error.to_string(), "LastName Validation Error");
+    }
 
-    pub type LastNameRules = NameRules;
-    pub type LastNameError = NameError;
-    pub type LastName = Name;
+    #[test]
+    fn test_name_lookup_by_str() {
+        let name = Name::parse(Some("Valid Name")).expect("Expected name to be valid");
+        let first_name = FirstName::parse(Some("Al")).expect("Expected name to be valid");
+        let names = HashMap::from([(name, 1)]);
+        let first_names = HashMap::from([(first_name, 2)]);
+        assert_eq!(names.get("Valid Name"), Some(&1));
+        assert_eq!(names.get("Other Name"), None);
+        assert_eq!(first_names.get("Al"), Some(&2));
+    }
 
-    pub type AddressLineRules = NameRules;
-    pub type AddressLineError = NameError;
-    pub type AddressLine = Name;
+    #[cfg(feature = "trusted-input")]
+    #[test]
+    fn test_name_type_from_stored() {
+        let name = FirstName::from_stored(&"a".repeat(60));
+        assert_eq!(name.as_str().len(), 60);
+        assert!(FirstName::new_unchecked(None).is_none());
+    }
 
-    pub type FieldRules = NameRules;
-    pub type FieldError = NameError;
-    pub type Field = Name;
+    #[cfg(feature = "test-fixtures")]
+    #[test]
+    fn test_name_type_valid_sample() {
+        assert!(FirstName::parse(Some(FirstName::valid_sample().as_str())).is_ok());
+        let rules = NameRules {
+            min_length: Some(30),
+            max_length: Some(40),
+            ..NameRules::default()
+        };
+        assert!((30..=40).contains(&AddressLine::sample_matching(rules).as_str().len()));
+    }
 }
@@ -0,0 +1,209 @@
+//! The EU VAT identification number scheme.
+
+use crate::types::national_id::NationalIdScheme;
+
+/// The EU VAT identification number, e.g. `DE136695976`.
+///
+/// The number starts with the two letter prefix of a member state (`EL` for Greece and `XI`
+/// for Northern Ireland), followed by 2 to 12 letters or digits. The national format and
+/// check digits are validated for the following member states:
+/// - `BE`: 10 digits, modulo 97.
+/// - `DE`: 9 digits, ISO 7064 MOD 11,10.
+/// - `DK`: 8 digits, weighted modulo 11.
+/// - `FR`: 2 character key and 9 digit SIREN, the numeric key is checked with modulo 97.
+/// - `IT`: 11 digits, Luhn.
+/// - `NL`: 9 digits, `B` and 2 digits, weighted modulo 11 or ISO 7064 MOD 97-10.
+///
+/// Only the prefix and the generic format are validated for the other member states.
+pub struct EuVat;
+
+impl EuVat {
+    /// The prefixes of the member states.
+    pub const PREFIXES: [&'static str; 28] = [
+        "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "EL", "ES", "FI", "FR", "HR", "HU", "IE",
+        "IT", "LT", "LU", "LV", "MT", "NL", "PL", "PT", "RO", "SE", "SI", "SK", "XI",
+    ];
+
+    fn digits(s: &str) -> Option<Vec<u32>> {
+        s.chars().map(|c| c.to_digit(10)).collect()
+    }
+
+    fn is_valid_be(number: &str) -> bool {
+        let Some(digits) = Self::digits(number) else {
+            return false;
+        };
+        if digits.len() != 10 || digits[0] > 1 {
+            return false;
+        }
+        let (base, check) = number.split_at(8);
+        match (base.parse::<u64>(), check.parse::<u64>()) {
+            (Ok(base), Ok(check)) => 97 - base % 97 == check,
+            _ => false,
+        }
+    }
+
+    fn is_valid_de(number: &str) -> bool {
+        let Some(digits) = Self::digits(number) else {
+            return false;
+        };
+        if digits.len() != 9 || digits[0] == 0 {
+            return false;
+        }
+        let mut product = 10;
+        for digit in &digits[..8] {
+            let mut sum = (digit + product) % 10;
+            if sum == 0 {
+                sum = 10;
+            }
+            product = (2 * sum) % 11;
+        }
+        let check = match 11 - product {
+            10 => 0,
+            check => check,
+        };
+        check == digits[8]
+    }
+
+    fn is_valid_dk(number: &str) -> bool {
+        let Some(digits) = Self::digits(number) else {
+            return false;
+        };
+        const WEIGHTS: [u32; 8] = [2, 7, 6, 5, 4, 3, 2, 1];
+        digits.len() == 8 && digits.iter().zip(WEIGHTS).map(|(d, w)| d * w).sum::<u32>() % 11 == 0
+    }
+
+    fn is_valid_fr(number: &str) -> bool {
+        if number.len() != 11 || !number.is_ascii() {
+            return false;
+        }
+        let (key, siren) = number.split_at(2);
+        let Ok(siren) = siren.parse::<u64>() else {
+            return false;
+        };
+        match key.parse::<u64>() {
+            Ok(key) => (12 + 3 * (siren % 97)) % 97 == key,
+            Err(_) => key.chars().all(|c| c.is_ascii_alphanumeric()),
+        }
+    }
+
+    fn is_valid_it(number: &str) -> bool {
+        let Some(digits) = Self::digits(number) else {
+            return false;
+        };
+        let sum: u32 = digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, d)| match i % 2 {
+                0 => *d,
+                _ if d * 2 > 9 => d * 2 - 9,
+                _ => d * 2,
+            })
+            .sum();
+        digits.len() == 11 && sum.is_multiple_of(10)
+    }
+
+    fn is_valid_nl(number: &str) -> bool {
+        if number.len() != 12 || number.as_bytes()[9] != b'B' {
+            return false;
+        }
+        let (Some(digits), Some(_)) = (Self::digits(&number[..9]), Self::digits(&number[10..]))
+        else {
+            return false;
+        };
+        let weighted = digits[..8]
+            .iter()
+            .zip((2..=9).rev())
+            .map(|(d, w)| d * w)
+            .sum::<u32>()
+            % 11;
+        if weighted == digits[8] {
+            return true;
+        }
+        // Numbers issued to sole proprietors since 2020 use ISO 7064 MOD 97-10 over "NL" + number.
+        format!("NL{}", number)
+            .chars()
+            .map(|c| c.to_digit(36).unwrap_or_default())
+            .fold(0u64, |acc, v| {
+                if v > 9 {
+                    (acc * 100 + v as u64) % 97
+                } else {
+                    (acc * 10 + v as u64) % 97
+                }
+            })
+            == 1
+    }
+}
+
+impl NationalIdScheme for EuVat {
+    fn name(&self) -> &'static str {
+        "eu-vat"
+    }
+
+    fn is_valid_format(&self, s: &str) -> bool {
+        let Some((prefix, number)) = s.split_at_checked(2) else {
+            return false;
+        };
+        Self::PREFIXES.contains(&prefix)
+            && (2..=12).contains(&number.len())
+            && number.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+
+    fn is_valid_checksum(&self, s: &str) -> bool {
+        let (prefix, number) = s.split_at(2);
+        match prefix {
+            "BE" => Self::is_valid_be(number),
+            "DE" => Self::is_valid_de(number),
+            "DK" => Self::is_valid_dk(number),
+            "FR" => Self::is_valid_fr(number),
+            "IT" => Self::is_valid_it(number),
+            "NL" => Self::is_valid_nl(number),
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::national_id::NationalId;
+
+    #[test]
+    fn test_valid_eu_vat() {
+        for s in [
+            "BE0403019261",
+            "DE136695976",
+            "DK13585628",
+            "FR40303265045",
+            "IT00743110157",
+            "NL004495445B01",
+            "ATU12345678",
+        ] {
+            assert!(
+                NationalId::parse_with_scheme(Some(s), &EuVat).is_ok(),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_eu_vat() {
+        for s in [
+            "BE0403019262",
+            "DE136695977",
+            "DK13585627",
+            "FR41303265045",
+            "IT00743110158",
+            "NL004495446B01",
+            "US123456789",
+            "DE",
+        ] {
+            assert!(
+                NationalId::parse_with_scheme(Some(s), &EuVat).is_err(),
+                "{}",
+                s
+            );
+        }
+    }
+}
@@ -0,0 +1,472 @@
+//! This module contains structures and traits for working with tax and national identification
+//! numbers.
+//!
+//! The format and checksum of an identifier differ from one scheme to another, so they are
+//! provided by an implementation of the `NationalIdScheme` trait. The following schemes are
+//! shipped behind features:
+//! - `national-id-uk`: `uk_nino::UkNino`, the UK National Insurance number.
+//! - `national-id-us`: `us_ssn::UsSsn`, the US Social Security number.
+//! - `national-id-eu-vat`: `eu_vat::EuVat`, the EU VAT identification number.
+
+#[cfg(feature = "national-id-eu-vat")]
+pub mod eu_vat;
+#[cfg(feature = "national-id-uk")]
+pub mod uk_nino;
+#[cfg(feature = "national-id-us")]
+pub mod us_ssn;
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::rule_result;
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// A trait describing the format and checksum of a national identification scheme.
+///
+/// # Required Methods
+/// - `name`: A short, stable name for the scheme, passed to the locale as the `scheme` argument.
+/// - `is_valid_format`: Whether the normalized identifier has the expected format.
+///
+/// # Provided Methods
+/// - `normalize`: Removes spaces and hyphens and converts the identifier to uppercase.
+/// - `is_valid_checksum`: Whether the check digits of the normalized identifier are valid,
+///   only called when the format is valid. Defaults to `true` for schemes without checksum.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::types::national_id::{NationalId, NationalIdScheme};
+///
+/// struct EvenDigits;
+///
+/// impl NationalIdScheme for EvenDigits {
+///     fn name(&self) -> &'static str {
+///         "even-digits"
+///     }
+///
+///     fn is_valid_format(&self, s: &str) -> bool {
+///         s.len() == 4 && s.chars().all(|c| c.is_ascii_digit())
+///     }
+///
+///     fn is_valid_checksum(&self, s: &str) -> bool {
+///         s.bytes().map(|b| (b - b'0') as u32).sum::<u32>().is_multiple_of(2)
+///     }
+/// }
+///
+/// assert!(NationalId::parse_with_scheme(Some("12-34"), &EvenDigits).is_ok());
+/// assert!(NationalId::parse_with_scheme(Some("1235"), &EvenDigits).is_err());
+/// ```
+pub trait NationalIdScheme: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn normalize(&self, s: &str) -> String {
+        s.chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect::<String>()
+            .to_uppercase()
+    }
+
+    fn is_valid_format(&self, s: &str) -> bool;
+
+    fn is_valid_checksum(&self, _s: &str) -> bool {
+        true
+    }
+}
+
+/// A structure to define rules or constraints associated with a national identification number.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean field indicating whether the identifier is mandatory or optional.
+#[derive(Clone)]
+pub struct NationalIdRules {
+    pub is_mandatory: bool,
+}
+
+impl Default for NationalIdRules {
+    fn default() -> Self {
+        Self { is_mandatory: true }
+    }
+}
+
+impl OptionalRules for NationalIdRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+        }
+    }
+}
+
+impl From<&NationalIdRules> for StringMandatoryRules {
+    fn from(rules: &NationalIdRules) -> Self {
+        StringMandatoryRules {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: false,
+        }
+    }
+}
+
+impl NationalIdRules {
+    fn rule(&self) -> StringMandatoryRules {
+        self.into()
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let rule = self.rule();
+        rule.check(messages, subject);
+    }
+
+    /// Returns the constraints enforced by the rules, the format and checksum constraints
+    /// depending on the scheme, see `NationalIdRules::describe_with_scheme`.
+    pub fn describe(&self) -> Vec<Constraint> {
+        self.rule().describe()
+    }
+
+    /// Returns the constraints enforced by the rules with the given scheme.
+    pub fn describe_with_scheme<S: NationalIdScheme>(&self, scheme: &S) -> Vec<Constraint> {
+        let mut constraints = self.describe();
+        constraints.push(Constraint::from_locale(&NationalIdLocale::InvalidFormat(
+            scheme.name(),
+        )));
+        constraints.push(Constraint::from_locale(&NationalIdLocale::InvalidChecksum(
+            scheme.name(),
+        )));
+        constraints
+    }
+}
+
+/// An enumeration of the national identification number specific validation failures.
+///
+/// Both variants hold the name of the scheme.
+pub enum NationalIdLocale {
+    /// The identifier does not have the format of the scheme.
+    /// # Key
+    /// `validate-national-id-invalid-format`
+    InvalidFormat(&'static str),
+    /// The check digits of the identifier are not valid.
+    /// # Key
+    /// `validate-national-id-invalid-checksum`
+    InvalidChecksum(&'static str),
+}
+
+impl LocaleMessage for NationalIdLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::InvalidFormat(scheme) => ld::new_with_vec(
+                locale_keys::VALIDATE_NATIONAL_ID_INVALID_FORMAT,
+                vec![("scheme".to_string(), lv::from(*scheme))],
+            ),
+            Self::InvalidChecksum(scheme) => ld::new_with_vec(
+                locale_keys::VALIDATE_NATIONAL_ID_INVALID_CHECKSUM,
+                vec![("scheme".to_string(), lv::from(*scheme))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::InvalidFormat(scheme) => RuleViolation::pattern(scheme),
+            Self::InvalidChecksum(scheme) => RuleViolation::Checksum {
+                name: scheme.to_string(),
+            },
+        }
+    }
+}
+
+/// Represents an error that occurs during national identification number validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"National ID Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("National ID Validation Error")]
+pub struct NationalIdError(pub ValidateErrorStore);
+
+impl ValidationCheck for NationalIdError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&NationalIdError> for ValidateErrorStore {
+    fn from(error: &NationalIdError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A structure representing a validated national identification number.
+///
+/// # Fields
+/// - `0: String` - The normalized identifier.
+/// - `1: &'static str` - The name of the scheme it was validated with.
+/// - `2: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+pub struct NationalId(String, &'static str, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for NationalId {
+    fn default() -> Self {
+        Self(String::new(), "", true)
+    }
+}
+
+impl NationalId {
+    /// Parses an identifier with the given scheme, based on the provided validation rules.
+    ///
+    /// # Errors
+    /// Returns a `NationalIdError` if the identifier is empty while mandatory,
+    /// does not have the format of the scheme, or has invalid check digits.
+    pub fn parse_custom_with_scheme<S: NationalIdScheme>(
+        s: Option<&str>,
+        rules: NationalIdRules,
+        scheme: &S,
+    ) -> Result<Self, NationalIdError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            NationalIdError::validate_check(messages)?;
+            if is_none && !rules.is_mandatory {
+                return Ok(Self(String::new(), scheme.name(), is_none));
+            }
+            let s = scheme.normalize(s);
+            let mut messages = ValidateErrorCollector::new();
+            let is_valid_format = scheme.is_valid_format(&s);
+            rule_result(
+                locale_keys::VALIDATE_NATIONAL_ID_INVALID_FORMAT,
+                is_valid_format,
+            );
+            if !is_valid_format {
+                messages.push((
+                    "Invalid format".to_string(),
+                    Box::new(NationalIdLocale::InvalidFormat(scheme.name())),
+                ));
+            } else {
+                let is_valid_checksum = scheme.is_valid_checksum(&s);
+                rule_result(
+                    locale_keys::VALIDATE_NATIONAL_ID_INVALID_CHECKSUM,
+                    is_valid_checksum,
+                );
+                if !is_valid_checksum {
+                    messages.push((
+                        "Invalid check digits".to_string(),
+                        Box::new(NationalIdLocale::InvalidChecksum(scheme.name())),
+                    ));
+                }
+            }
+            NationalIdError::validate_check(messages)?;
+            Ok(Self(s, scheme.name(), is_none))
+        })
+    }
+
+    /// Parses an identifier with the given scheme, using the default `NationalIdRules`.
+    pub fn parse_with_scheme<S: NationalIdScheme>(
+        s: Option<&str>,
+        scheme: &S,
+    ) -> Result<Self, NationalIdError> {
+        Self::parse_custom_with_scheme(s, NationalIdRules::default(), scheme)
+    }
+
+    /// Returns the name of the scheme the identifier was validated with.
+    pub fn scheme(&self) -> &'static str {
+        self.1
+    }
+
+    /// Returns the normalized identifier as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the identifier was not provided.
+    pub fn is_none(&self) -> bool {
+        self.2
+    }
+
+    /// Converts the current instance into an `Option<NationalId>`.
+    ///
+    /// # Returns
+    /// - `None` if the identifier was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<NationalId> {
+        if self.2 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for NationalId {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&NationalId> for String {
+    fn from(national_id: &NationalId) -> Self {
+        national_id.as_str().to_string()
+    }
+}
+
+impl Display for NationalId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for NationalId {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for NationalId {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `NationalId` key can be found by
+/// a `&str`.
+impl PartialEq for NationalId {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for NationalId {}
+
+impl Hash for NationalId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for NationalId {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for NationalId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+/// The rules alone do not say which scheme to validate with, so the validator is the pair of the
+/// rules and the scheme.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::validator::Validator;
+/// use cjtoolkit_structured_validator::types::national_id::{NationalIdRules, NationalIdScheme};
+///
+/// struct FourDigits;
+///
+/// impl NationalIdScheme for FourDigits {
+///     fn name(&self) -> &'static str {
+///         "four-digits"
+///     }
+///
+///     fn is_valid_format(&self, s: &str) -> bool {
+///         s.len() == 4 && s.chars().all(|c| c.is_ascii_digit())
+///     }
+/// }
+///
+/// let validator = (NationalIdRules::default(), FourDigits);
+/// assert!(validator.validate(Some("1234")).is_ok());
+/// assert!(validator.validate(Some("123")).is_err());
+/// ```
+impl<S: NationalIdScheme> Validator<Option<&str>, NationalId, NationalIdError>
+    for (NationalIdRules, S)
+{
+    fn validate(&self, input: Option<&str>) -> Result<NationalId, NationalIdError> {
+        NationalId::parse_custom_with_scheme(input, self.0.clone(), &self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FourDigits;
+
+    impl NationalIdScheme for FourDigits {
+        fn name(&self) -> &'static str {
+            "four-digits"
+        }
+
+        fn is_valid_format(&self, s: &str) -> bool {
+            s.len() == 4 && s.chars().all(|c| c.is_ascii_digit())
+        }
+
+        fn is_valid_checksum(&self, s: &str) -> bool {
+            !s.starts_with('0')
+        }
+    }
+
+    #[test]
+    fn test_parse_with_scheme() {
+        let id = NationalId::parse_with_scheme(Some("12 34"), &FourDigits).unwrap_or_default();
+        assert_eq!(id.as_str(), "1234");
+        assert_eq!(id.scheme(), "four-digits");
+    }
+
+    #[test]
+    fn test_invalid_format_and_checksum() {
+        let err = NationalId::parse_with_scheme(Some("123"), &FourDigits)
+            .err()
+            .unwrap_or_default();
+        assert_eq!(err.0.as_original_message_vec(), vec!["Invalid format"]);
+        let err = NationalId::parse_with_scheme(Some("0123"), &FourDigits)
+            .err()
+            .unwrap_or_default();
+        assert_eq!(
+            err.0.as_original_message_vec(),
+            vec!["Invalid check digits"]
+        );
+    }
+
+    #[test]
+    fn test_none_national_id() {
+        assert!(NationalId::parse_with_scheme(None, &FourDigits).is_err());
+        let rules = NationalIdRules {
+            is_mandatory: false,
+        };
+        assert!(NationalId::parse_custom_with_scheme(None, rules, &FourDigits).is_ok());
+    }
+
+    #[test]
+    fn test_validator() {
+        let validator: Box<dyn for<'a> Validator<Option<&'a str>, NationalId, NationalIdError>> =
+            Box::new((NationalIdRules::default(), FourDigits));
+        assert_eq!(
+            validator.validate(Some("12-34")).map(|id| id.to_string()),
+            Ok("1234".to_string())
+        );
+        assert!(validator.validate(Some("0123")).is_err());
+    }
+}
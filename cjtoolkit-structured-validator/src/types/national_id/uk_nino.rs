@@ -0,0 +1,54 @@
+//! The UK National Insurance number (NINO) scheme.
+
+use crate::types::national_id::NationalIdScheme;
+
+/// The UK National Insurance number, e.g. `QQ 12 34 56 C`.
+///
+/// The number is made of a two letter prefix, six digits and a suffix from `A` to `D`.
+/// The first letter cannot be `D`, `F`, `I`, `Q`, `U` or `V`, the second letter cannot be
+/// `D`, `F`, `I`, `O`, `Q`, `U` or `V`, and the prefixes `BG`, `GB`, `KN`, `NK`, `NT`, `TN`
+/// and `ZZ` are not allocated. There are no check digits.
+pub struct UkNino;
+
+impl UkNino {
+    const INVALID_FIRST: [char; 6] = ['D', 'F', 'I', 'Q', 'U', 'V'];
+    const INVALID_SECOND: [char; 7] = ['D', 'F', 'I', 'O', 'Q', 'U', 'V'];
+    const UNALLOCATED_PREFIXES: [&'static str; 7] = ["BG", "GB", "KN", "NK", "NT", "TN", "ZZ"];
+}
+
+impl NationalIdScheme for UkNino {
+    fn name(&self) -> &'static str {
+        "uk-nino"
+    }
+
+    fn is_valid_format(&self, s: &str) -> bool {
+        let chars: Vec<char> = s.chars().collect();
+        if chars.len() != 9 {
+            return false;
+        }
+        let (first, second) = (chars[0], chars[1]);
+        first.is_ascii_uppercase()
+            && second.is_ascii_uppercase()
+            && !Self::INVALID_FIRST.contains(&first)
+            && !Self::INVALID_SECOND.contains(&second)
+            && !Self::UNALLOCATED_PREFIXES.contains(&&s[..2])
+            && chars[2..8].iter().all(|c| c.is_ascii_digit())
+            && ('A'..='D').contains(&chars[8])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::national_id::NationalId;
+
+    #[test]
+    fn test_uk_nino() {
+        assert!(NationalId::parse_with_scheme(Some("AB 12 34 56 C"), &UkNino).is_ok());
+        assert!(NationalId::parse_with_scheme(Some("ab123456d"), &UkNino).is_ok());
+        assert!(NationalId::parse_with_scheme(Some("QQ123456C"), &UkNino).is_err());
+        assert!(NationalId::parse_with_scheme(Some("GB123456A"), &UkNino).is_err());
+        assert!(NationalId::parse_with_scheme(Some("AB123456E"), &UkNino).is_err());
+        assert!(NationalId::parse_with_scheme(Some("AB12345C"), &UkNino).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+//! The US Social Security number (SSN) scheme.
+
+use crate::types::national_id::NationalIdScheme;
+
+/// The US Social Security number, e.g. `123-45-6789`.
+///
+/// The number is made of a three digit area, a two digit group and a four digit serial.
+/// The area cannot be `000`, `666` or start with `9`, the group cannot be `00` and the serial
+/// cannot be `0000`. There are no check digits, so only the format is validated.
+pub struct UsSsn;
+
+impl NationalIdScheme for UsSsn {
+    fn name(&self) -> &'static str {
+        "us-ssn"
+    }
+
+    fn is_valid_format(&self, s: &str) -> bool {
+        if s.len() != 9 || !s.chars().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+        let (area, group, serial) = (&s[..3], &s[3..5], &s[5..]);
+        area != "000"
+            && area != "666"
+            && !area.starts_with('9')
+            && group != "00"
+            && serial != "0000"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::national_id::NationalId;
+
+    #[test]
+    fn test_us_ssn() {
+        assert!(NationalId::parse_with_scheme(Some("123-45-6789"), &UsSsn).is_ok());
+        assert!(NationalId::parse_with_scheme(Some("000-45-6789"), &UsSsn).is_err());
+        assert!(NationalId::parse_with_scheme(Some("666-45-6789"), &UsSsn).is_err());
+        assert!(NationalId::parse_with_scheme(Some("912-45-6789"), &UsSsn).is_err());
+        assert!(NationalId::parse_with_scheme(Some("123-00-6789"), &UsSsn).is_err());
+        assert!(NationalId::parse_with_scheme(Some("123-45-0000"), &UsSsn).is_err());
+        assert!(NationalId::parse_with_scheme(Some("123-45-678"), &UsSsn).is_err());
+    }
+}
@@ -0,0 +1,587 @@
+//! This module contains structures and traits for working with DNS domain names.
+//!
+//! A domain is a hostname made of at least two labels with an alphabetic top level label.
+//! With the `psl` feature enabled, the public suffix list is used to find the suffix
+//! (e.g. `co.uk`) and the registrable domain (e.g. `example.co.uk`), and `DomainRules` can
+//! require the domain to be exactly a registrable domain, neither a suffix nor a subdomain.
+//!
+//! With the `idna` feature enabled, internationalized domains such as `bücher.de` are accepted
+//! and stored in their ASCII form, `xn--bcher-kva.de`, and `DomainRules` can reject labels
+//! mixing scripts, such as `pаypal.com` with a Cyrillic `а`, used for homograph phishing.
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use crate::types::net::hostname::Hostname;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// A structure to define rules or constraints associated with a domain name.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean field indicating whether the domain is mandatory or optional.
+/// * `must_be_registrable` - A boolean field indicating whether the domain must be a registrable
+///   domain according to the public suffix list, rejecting suffixes such as `co.uk` and
+///   subdomains such as `www.example.co.uk`. Without the `psl` feature the domain cannot be
+///   verified, and is rejected when this is set.
+/// * `forbid_mixed_script_host` - A boolean field indicating whether a label mixing characters
+///   of different scripts is rejected. Without the `idna` feature the domain cannot be verified,
+///   and is rejected when this is set.
+#[derive(Clone)]
+pub struct DomainRules {
+    pub is_mandatory: bool,
+    pub must_be_registrable: bool,
+    pub forbid_mixed_script_host: bool,
+}
+
+impl Default for DomainRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            must_be_registrable: false,
+            forbid_mixed_script_host: false,
+        }
+    }
+}
+
+impl From<&DomainRules> for StringMandatoryRules {
+    fn from(rules: &DomainRules) -> Self {
+        StringMandatoryRules {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: false,
+        }
+    }
+}
+
+impl DomainRules {
+    fn rule(&self) -> StringMandatoryRules {
+        self.into()
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let rule = self.rule();
+        rule.check(messages, subject);
+    }
+
+    fn check_domain(&self, messages: &mut ValidateErrorCollector, domain: &str) {
+        let labels: Vec<&str> = domain.split('.').collect();
+        let is_valid = domain.len() <= Hostname::MAX_LENGTH
+            && labels.len() >= 2
+            && labels.iter().all(|label| Hostname::is_valid_label(label))
+            && labels.last().is_some_and(|tld| {
+                tld.starts_with("xn--") || tld.chars().all(|c| c.is_ascii_alphabetic())
+            });
+        if !is_valid {
+            messages.push((
+                "Invalid domain".to_string(),
+                Box::new(DomainLocale::Invalid),
+            ));
+        }
+        #[cfg(feature = "idna")]
+        if is_valid && self.forbid_mixed_script_host && is_mixed_script_host(domain) {
+            messages.push((
+                "Must not mix characters of different scripts".to_string(),
+                Box::new(DomainLocale::MixedScript),
+            ));
+        }
+        #[cfg(not(feature = "idna"))]
+        if is_valid && self.forbid_mixed_script_host {
+            push_unverifiable(messages, "idna");
+        }
+        #[cfg(feature = "psl")]
+        if is_valid && self.must_be_registrable && psl::domain_str(domain) != Some(domain) {
+            messages.push((
+                "Must be a registrable domain".to_string(),
+                Box::new(DomainLocale::NotRegistrable),
+            ));
+        }
+        #[cfg(not(feature = "psl"))]
+        if is_valid && self.must_be_registrable {
+            push_unverifiable(messages, "psl");
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = self.rule().describe();
+        constraints.push(Constraint::from_locale(&DomainLocale::Invalid));
+        #[cfg(feature = "psl")]
+        if self.must_be_registrable {
+            constraints.push(Constraint::from_locale(&DomainLocale::NotRegistrable));
+        }
+        #[cfg(not(feature = "psl"))]
+        if self.must_be_registrable {
+            constraints.push(Constraint::from_locale(&RuleUnverifiableLocale("psl")));
+        }
+        #[cfg(feature = "idna")]
+        if self.forbid_mixed_script_host {
+            constraints.push(Constraint::from_locale(&DomainLocale::MixedScript));
+        }
+        #[cfg(not(feature = "idna"))]
+        if self.forbid_mixed_script_host {
+            constraints.push(Constraint::from_locale(&RuleUnverifiableLocale("idna")));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during domain validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Domain Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Domain Validation Error")]
+pub struct DomainError(pub ValidateErrorStore);
+
+impl ValidationCheck for DomainError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&DomainError> for ValidateErrorStore {
+    fn from(error: &DomainError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// An enumeration of the domain specific validation failures.
+pub enum DomainLocale {
+    /// The domain is not a valid DNS domain name.
+    /// # Key
+    /// `validate-domain-invalid`
+    Invalid,
+    /// The domain is a public suffix or a subdomain of a registrable domain.
+    /// # Key
+    /// `validate-domain-not-registrable`
+    NotRegistrable,
+    /// A label of the domain mixes characters of different scripts.
+    /// # Key
+    /// `validate-host-mixed-script`
+    MixedScript,
+}
+
+impl LocaleMessage for DomainLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        match self {
+            Self::Invalid => ld::new(locale_keys::VALIDATE_DOMAIN_INVALID),
+            Self::NotRegistrable => ld::new(locale_keys::VALIDATE_DOMAIN_NOT_REGISTRABLE),
+            Self::MixedScript => ld::new(locale_keys::VALIDATE_HOST_MIXED_SCRIPT),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::Invalid => RuleViolation::pattern("domain"),
+            Self::NotRegistrable => RuleViolation::pattern("registrable-domain"),
+            Self::MixedScript => RuleViolation::forbidden_chars("mixed-script"),
+        }
+    }
+}
+
+/// A locale message for a rule which cannot be verified because the crate feature it needs is
+/// disabled, e.g. `must_be_registrable` without `psl`, so the value is rejected rather than
+/// accepted unchecked.
+///
+/// # Fields
+/// - `0`: The name of the disabled feature.
+///
+/// # Key
+/// `validate-rule-unverifiable`
+pub struct RuleUnverifiableLocale(pub &'static str);
+
+impl LocaleMessage for RuleUnverifiableLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_RULE_UNVERIFIABLE,
+            vec![("feature".to_string(), LocaleValue::from(self.0))],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Unverified {
+            name: self.0.to_string(),
+        }
+    }
+}
+
+#[cfg(any(not(feature = "psl"), not(feature = "idna")))]
+pub(crate) fn push_unverifiable(messages: &mut ValidateErrorCollector, feature: &'static str) {
+    messages.push((
+        format!("Cannot be verified without the {} feature", feature),
+        Box::new(RuleUnverifiableLocale(feature)),
+    ));
+}
+
+/// Returns `true` if a label of `host`, in ASCII or Unicode form, mixes characters of different
+/// scripts once converted to Unicode.
+#[cfg(feature = "idna")]
+pub(crate) fn is_mixed_script_host(host: &str) -> bool {
+    use unicode_security::MixedScript;
+    let (host, _) = idna::domain_to_unicode(host);
+    host.split('.').any(|label| !label.is_single_script())
+}
+
+/// A structure representing a validated domain name.
+///
+/// # Fields
+/// - `0: String` - The domain in lowercase, without the trailing dot, in ASCII form with the
+///   `idna` feature.
+/// - `1: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct Domain(String, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for Domain {
+    fn default() -> Self {
+        Self(String::new(), true)
+    }
+}
+
+impl Domain {
+    /// Parses a domain name based on the provided validation rules.
+    ///
+    /// # Errors
+    /// Returns a `DomainError` if the domain is empty while mandatory, is not a valid domain
+    /// name, or is not a registrable domain when `must_be_registrable` is set.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::net::domain::{Domain, DomainRules};
+    ///
+    /// let domain = Domain::parse_custom(Some("Example.COM"), DomainRules::default());
+    /// assert_eq!(domain.map(|d| d.tld().to_string()).ok().as_deref(), Some("com"));
+    /// ```
+    pub fn parse_custom(s: Option<&str>, rules: DomainRules) -> Result<Self, DomainError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            DomainError::validate_check(messages)?;
+            if is_none && !rules.is_mandatory {
+                return Ok(Self(String::new(), is_none));
+            }
+            let s = s.strip_suffix('.').unwrap_or(s);
+            #[cfg(feature = "idna")]
+            let s = idna::domain_to_ascii(s).unwrap_or_else(|_| s.to_ascii_lowercase());
+            #[cfg(not(feature = "idna"))]
+            let s = s.to_ascii_lowercase();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check_domain(&mut messages, &s);
+            DomainError::validate_check(messages)?;
+            Ok(Self(s, is_none))
+        })
+    }
+
+    /// Parses a domain name using the default `DomainRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, DomainError> {
+        Self::parse_custom(s, DomainRules::default())
+    }
+
+    /// Returns the top level domain.
+    ///
+    /// With the `psl` feature this is the public suffix, which can span several labels
+    /// (e.g. `co.uk`), otherwise it is the last label.
+    pub fn tld(&self) -> &str {
+        #[cfg(feature = "psl")]
+        if let Some(suffix) = psl::suffix_str(&self.0) {
+            return suffix;
+        }
+        self.0.rsplit('.').next().unwrap_or_default()
+    }
+
+    /// Returns the registrable domain, that is the top level domain plus one label.
+    ///
+    /// With the `psl` feature the public suffix list is used, and `None` is returned when the
+    /// domain is itself a public suffix. Otherwise, the last two labels are returned.
+    pub fn registrable_domain(&self) -> Option<&str> {
+        #[cfg(feature = "psl")]
+        {
+            psl::domain_str(&self.0)
+        }
+        #[cfg(not(feature = "psl"))]
+        {
+            if self.0.is_empty() {
+                return None;
+            }
+            match self.0.rmatch_indices('.').nth(1) {
+                Some((index, _)) => self.0.get(index + 1..),
+                None => Some(&self.0),
+            }
+        }
+    }
+
+    /// Returns the domain as a string slice, in ASCII form with the `idna` feature.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the domain in Unicode form, e.g. `bücher.de` for `xn--bcher-kva.de`.
+    #[cfg(feature = "idna")]
+    pub fn to_unicode(&self) -> String {
+        idna::domain_to_unicode(&self.0).0
+    }
+
+    /// Returns `true` if the domain was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
+    /// Converts the current instance into an `Option<Domain>`.
+    ///
+    /// # Returns
+    /// - `None` if the domain was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<Domain> {
+        if self.1 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for Domain {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&Domain> for String {
+    fn from(domain: &Domain) -> Self {
+        domain.as_str().to_string()
+    }
+}
+
+impl Display for Domain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Domain {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Domain {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `Domain` key can be found by
+/// a `&str`.
+impl PartialEq for Domain {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Domain {}
+
+impl Hash for Domain {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for Domain {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Domain {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for Domain {
+    type Error = DomainError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Domain {
+    type Error = DomainError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for Domain {
+    type Error = DomainError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, Domain, DomainError> for DomainRules {
+    fn validate(&self, input: Option<&str>) -> Result<Domain, DomainError> {
+        Domain::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for DomainRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Domain {
+    /// Creates an instance of `Domain` without validation, e.g. when loading values which were
+    /// validated before being stored. The string is kept as is, `None` gives an empty value.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(s.to_string(), is_none)
+    }
+
+    /// Creates an instance of `Domain` from a stored string without validation, so stored values
+    /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_domain() {
+        let domain = Domain::parse(Some("www.Example.com.")).unwrap_or_default();
+        assert_eq!(domain.as_str(), "www.example.com");
+        assert_eq!(domain.tld(), "com");
+        assert_eq!(domain.registrable_domain(), Some("example.com"));
+    }
+
+    #[test]
+    fn test_invalid_domain() {
+        assert!(Domain::parse(Some("localhost")).is_err());
+        assert!(Domain::parse(Some("example.123")).is_err());
+        assert!(Domain::parse(Some("exa_mple.com")).is_err());
+    }
+
+    #[cfg(feature = "psl")]
+    #[test]
+    fn test_public_suffix() {
+        let domain = Domain::parse(Some("www.example.co.uk")).unwrap_or_default();
+        assert_eq!(domain.tld(), "co.uk");
+        assert_eq!(domain.registrable_domain(), Some("example.co.uk"));
+    }
+
+    #[cfg(feature = "psl")]
+    #[test]
+    fn test_must_be_registrable() {
+        let rules = || DomainRules {
+            is_mandatory: true,
+            must_be_registrable: true,
+            ..DomainRules::default()
+        };
+        assert!(Domain::parse_custom(Some("example.co.uk"), rules()).is_ok());
+        assert!(Domain::parse_custom(Some("co.uk"), rules()).is_err());
+        assert!(Domain::parse_custom(Some("www.example.co.uk"), rules()).is_err());
+    }
+
+    #[cfg(not(feature = "psl"))]
+    #[test]
+    fn test_must_be_registrable_rejected_without_psl() {
+        let rules = DomainRules {
+            must_be_registrable: true,
+            ..DomainRules::default()
+        };
+        let error = Domain::parse_custom(Some("example.co.uk"), rules)
+            .expect_err("Expected the rule to be unverifiable");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Cannot be verified without the psl feature"]
+        );
+        assert_eq!(
+            error.0.violations(),
+            vec![RuleViolation::Unverified {
+                name: "psl".to_string()
+            }]
+        );
+    }
+
+    #[cfg(feature = "idna")]
+    #[test]
+    fn test_internationalized_domain() {
+        let domain = Domain::parse(Some("Bücher.de")).unwrap_or_default();
+        assert_eq!(domain.as_str(), "xn--bcher-kva.de");
+        assert_eq!(domain.to_unicode(), "bücher.de");
+        assert!(Domain::parse(Some("example.xn--p1ai")).is_ok());
+    }
+
+    #[cfg(feature = "idna")]
+    #[test]
+    fn test_forbid_mixed_script_host() {
+        let rules = || DomainRules {
+            forbid_mixed_script_host: true,
+            ..DomainRules::default()
+        };
+        assert!(Domain::parse_custom(Some("bücher.рф"), rules()).is_ok());
+        assert!(Domain::parse(Some("p\u{430}ypal.com")).is_ok());
+        let error = Domain::parse_custom(Some("p\u{430}ypal.com"), rules())
+            .expect_err("Expected mixed scripts to be rejected");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must not mix characters of different scripts"]
+        );
+    }
+
+    #[cfg(not(feature = "idna"))]
+    #[test]
+    fn test_forbid_mixed_script_host_rejected_without_idna() {
+        let rules = DomainRules {
+            forbid_mixed_script_host: true,
+            ..DomainRules::default()
+        };
+        let error = Domain::parse_custom(Some("example.com"), rules)
+            .expect_err("Expected the rule to be unverifiable");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Cannot be verified without the idna feature"]
+        );
+    }
+}
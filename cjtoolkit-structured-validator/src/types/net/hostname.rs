@@ -0,0 +1,430 @@
+//! This module contains structures and traits for working with hostnames.
+//!
+//! Hostnames are validated with the RFC 1123 label rules: each label is made of 1 to 63
+//! ASCII letters, digits or hyphens, and cannot start or end with a hyphen. The whole
+//! hostname cannot be longer than 253 characters, a single trailing dot is accepted.
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// A structure to define rules or constraints associated with a hostname.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean field indicating whether the hostname is mandatory or optional.
+/// * `require_fqdn` - A boolean field indicating whether the hostname must be fully qualified,
+///   that is made of at least two labels (e.g. `host.example`).
+#[derive(Clone)]
+pub struct HostnameRules {
+    pub is_mandatory: bool,
+    pub require_fqdn: bool,
+}
+
+impl Default for HostnameRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            require_fqdn: false,
+        }
+    }
+}
+
+impl From<&HostnameRules> for StringMandatoryRules {
+    fn from(rules: &HostnameRules) -> Self {
+        StringMandatoryRules {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: false,
+        }
+    }
+}
+
+impl HostnameRules {
+    fn rule(&self) -> StringMandatoryRules {
+        self.into()
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let rule = self.rule();
+        rule.check(messages, subject);
+    }
+
+    fn check_hostname(&self, messages: &mut ValidateErrorCollector, hostname: &str) {
+        if hostname.len() > Hostname::MAX_LENGTH {
+            messages.push((
+                format!("Must be at most {} characters", Hostname::MAX_LENGTH),
+                Box::new(HostnameLocale::TooLong(Hostname::MAX_LENGTH)),
+            ));
+            return;
+        }
+        let labels: Vec<&str> = hostname.split('.').collect();
+        for label in &labels {
+            if !Hostname::is_valid_label(label) {
+                messages.push((
+                    format!("Invalid hostname label '{}'", label),
+                    Box::new(HostnameLocale::InvalidLabel(label.to_string())),
+                ));
+            }
+        }
+        if self.require_fqdn && labels.len() < 2 {
+            messages.push((
+                "Must be a fully qualified domain name".to_string(),
+                Box::new(HostnameLocale::NotFqdn),
+            ));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = self.rule().describe();
+        constraints.push(Constraint::from_locale(&HostnameLocale::TooLong(
+            Hostname::MAX_LENGTH,
+        )));
+        if self.require_fqdn {
+            constraints.push(Constraint::from_locale(&HostnameLocale::NotFqdn));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during hostname validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Hostname Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Hostname Validation Error")]
+pub struct HostnameError(pub ValidateErrorStore);
+
+impl ValidationCheck for HostnameError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&HostnameError> for ValidateErrorStore {
+    fn from(error: &HostnameError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// An enumeration of the hostname specific validation failures.
+pub enum HostnameLocale {
+    /// The hostname is longer than the maximum length.
+    /// # Key
+    /// `validate-hostname-too-long`
+    TooLong(usize),
+    /// A label does not follow the RFC 1123 rules.
+    /// # Key
+    /// `validate-hostname-invalid-label`
+    InvalidLabel(String),
+    /// The hostname is not fully qualified.
+    /// # Key
+    /// `validate-hostname-not-fqdn`
+    NotFqdn,
+}
+
+impl LocaleMessage for HostnameLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::TooLong(max) => ld::new_with_vec(
+                locale_keys::VALIDATE_HOSTNAME_TOO_LONG,
+                vec![("max".to_string(), lv::from(*max))],
+            ),
+            Self::InvalidLabel(label) => ld::new_with_vec(
+                locale_keys::VALIDATE_HOSTNAME_INVALID_LABEL,
+                vec![("label".to_string(), lv::from(label.clone()))],
+            ),
+            Self::NotFqdn => ld::new(locale_keys::VALIDATE_HOSTNAME_NOT_FQDN),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::TooLong(max) => RuleViolation::MaxLength { max: *max },
+            Self::InvalidLabel(_) => RuleViolation::pattern("hostname-label"),
+            Self::NotFqdn => RuleViolation::pattern("fqdn"),
+        }
+    }
+}
+
+/// A structure representing a validated hostname.
+///
+/// # Fields
+/// - `0: String` - The hostname, without the trailing dot.
+/// - `1: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct Hostname(String, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for Hostname {
+    fn default() -> Self {
+        Self(String::new(), true)
+    }
+}
+
+impl Hostname {
+    /// The maximum length of a hostname.
+    pub const MAX_LENGTH: usize = 253;
+
+    /// The maximum length of a single hostname label.
+    pub const MAX_LABEL_LENGTH: usize = 63;
+
+    pub(crate) fn is_valid_label(label: &str) -> bool {
+        !label.is_empty()
+            && label.len() <= Self::MAX_LABEL_LENGTH
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    }
+
+    /// Parses a hostname based on the provided validation rules.
+    ///
+    /// # Errors
+    /// Returns a `HostnameError` if the hostname is empty while mandatory, is too long,
+    /// contains an invalid label, or is not fully qualified when `require_fqdn` is set.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::net::hostname::{Hostname, HostnameRules};
+    ///
+    /// let rules = HostnameRules {
+    ///     is_mandatory: true,
+    ///     require_fqdn: true,
+    /// };
+    /// let hostname = Hostname::parse_custom(Some("db-01.internal.example."), rules);
+    /// assert_eq!(hostname.map(|h| h.as_str().to_string()).ok().as_deref(), Some("db-01.internal.example"));
+    /// ```
+    pub fn parse_custom(s: Option<&str>, rules: HostnameRules) -> Result<Self, HostnameError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            HostnameError::validate_check(messages)?;
+            if is_none && !rules.is_mandatory {
+                return Ok(Self(String::new(), is_none));
+            }
+            let s = s.strip_suffix('.').unwrap_or(s);
+            let mut messages = ValidateErrorCollector::new();
+            rules.check_hostname(&mut messages, s);
+            HostnameError::validate_check(messages)?;
+            Ok(Self(s.to_string(), is_none))
+        })
+    }
+
+    /// Parses a hostname using the default `HostnameRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, HostnameError> {
+        Self::parse_custom(s, HostnameRules::default())
+    }
+
+    /// Returns an iterator over the labels of the hostname.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.0.split('.')
+    }
+
+    /// Returns the hostname as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the hostname was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
+    /// Converts the current instance into an `Option<Hostname>`.
+    ///
+    /// # Returns
+    /// - `None` if the hostname was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<Hostname> {
+        if self.1 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for Hostname {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&Hostname> for String {
+    fn from(hostname: &Hostname) -> Self {
+        hostname.as_str().to_string()
+    }
+}
+
+impl Display for Hostname {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Hostname {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Hostname {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `Hostname` key can be found by
+/// a `&str`.
+impl PartialEq for Hostname {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Hostname {}
+
+impl Hash for Hostname {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for Hostname {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Hostname {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for Hostname {
+    type Error = HostnameError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Hostname {
+    type Error = HostnameError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for Hostname {
+    type Error = HostnameError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, Hostname, HostnameError> for HostnameRules {
+    fn validate(&self, input: Option<&str>) -> Result<Hostname, HostnameError> {
+        Hostname::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for HostnameRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Hostname {
+    /// Creates an instance of `Hostname` without validation, e.g. when loading values which were
+    /// validated before being stored. The string is kept as is, `None` gives an empty value.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(s.to_string(), is_none)
+    }
+
+    /// Creates an instance of `Hostname` from a stored string without validation, so stored values
+    /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_hostname() {
+        assert!(Hostname::parse(Some("localhost")).is_ok());
+        assert!(Hostname::parse(Some("web-1.example.com")).is_ok());
+        assert!(Hostname::parse(Some("1and1.com")).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_hostname() {
+        assert!(Hostname::parse(Some("-web.example.com")).is_err());
+        assert!(Hostname::parse(Some("web_1.example.com")).is_err());
+        assert!(Hostname::parse(Some("web..example.com")).is_err());
+        assert!(Hostname::parse(Some(&format!("{}.com", "a".repeat(64)))).is_err());
+    }
+
+    #[test]
+    fn test_hostname_too_long() {
+        let hostname = vec!["a".repeat(63); 4].join(".");
+        let err = Hostname::parse(Some(&hostname)).err().unwrap_or_default();
+        assert_eq!(
+            err.0.as_original_message_vec(),
+            vec!["Must be at most 253 characters"]
+        );
+    }
+
+    #[test]
+    fn test_require_fqdn() {
+        let rules = || HostnameRules {
+            is_mandatory: true,
+            require_fqdn: true,
+        };
+        assert!(Hostname::parse_custom(Some("localhost"), rules()).is_err());
+        assert!(Hostname::parse_custom(Some("host.example"), rules()).is_ok());
+    }
+}
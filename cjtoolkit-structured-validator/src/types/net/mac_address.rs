@@ -0,0 +1,388 @@
+//! This module contains structures and traits for working with MAC addresses.
+//!
+//! The following formats are accepted, case-insensitively:
+//! - Colon separated: `00:1A:2B:3C:4D:5E`
+//! - Hyphen separated: `00-1A-2B-3C-4D-5E`
+//! - Bare: `001A2B3C4D5E`
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, RuleViolation, ValidateErrorCollector, ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// A structure to define rules or constraints associated with a MAC address.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean field indicating whether the MAC address is mandatory or optional.
+#[derive(Clone)]
+pub struct MacAddressRules {
+    pub is_mandatory: bool,
+}
+
+impl Default for MacAddressRules {
+    fn default() -> Self {
+        Self { is_mandatory: true }
+    }
+}
+
+impl From<&MacAddressRules> for StringMandatoryRules {
+    fn from(rules: &MacAddressRules) -> Self {
+        StringMandatoryRules {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: false,
+        }
+    }
+}
+
+impl MacAddressRules {
+    fn rule(&self) -> StringMandatoryRules {
+        self.into()
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let rule = self.rule();
+        rule.check(messages, subject);
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = self.rule().describe();
+        constraints.push(Constraint::from_locale(&MacAddressLocale));
+        constraints
+    }
+}
+
+/// Represents an error that occurs during MAC address validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"MAC Address Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("MAC Address Validation Error")]
+pub struct MacAddressError(pub ValidateErrorStore);
+
+impl ValidationCheck for MacAddressError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&MacAddressError> for ValidateErrorStore {
+    fn from(error: &MacAddressError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A locale message for a malformed MAC address.
+///
+/// # Key
+/// `validate-mac-address-invalid`
+pub struct MacAddressLocale;
+
+impl LocaleMessage for MacAddressLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new(locale_keys::VALIDATE_MAC_ADDRESS_INVALID)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::pattern("mac-address")
+    }
+}
+
+/// A structure representing a validated MAC address.
+///
+/// # Fields
+/// - `0: String` - The MAC address as it was provided.
+/// - `1: [u8; 6]` - The six octets of the MAC address.
+/// - `2: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct MacAddress(String, [u8; 6], bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for MacAddress {
+    fn default() -> Self {
+        Self(String::new(), [0; 6], true)
+    }
+}
+
+impl MacAddress {
+    fn parse_octets(s: &str) -> Option<[u8; 6]> {
+        let hex: Vec<&str> = match s.len() {
+            17 => {
+                let separator = if s.contains(':') { ':' } else { '-' };
+                s.split(separator).collect()
+            }
+            12 => (0..6).filter_map(|i| s.get(i * 2..i * 2 + 2)).collect(),
+            _ => return None,
+        };
+        if hex.len() != 6 {
+            return None;
+        }
+        let mut octets = [0u8; 6];
+        for (octet, hex) in octets.iter_mut().zip(hex) {
+            if hex.len() != 2 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            *octet = u8::from_str_radix(hex, 16).ok()?;
+        }
+        Some(octets)
+    }
+
+    /// Parses a MAC address based on the provided validation rules.
+    ///
+    /// # Errors
+    /// Returns a `MacAddressError` if the MAC address is empty while mandatory,
+    /// or if it is not in one of the accepted formats.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::net::mac_address::{MacAddress, MacAddressRules};
+    ///
+    /// let mac = MacAddress::parse_custom(Some("00-1a-2b-3c-4d-5e"), MacAddressRules::default());
+    /// assert!(mac.is_ok());
+    /// ```
+    pub fn parse_custom(s: Option<&str>, rules: MacAddressRules) -> Result<Self, MacAddressError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            MacAddressError::validate_check(messages)?;
+            if is_none && !rules.is_mandatory {
+                return Ok(Self(String::new(), [0; 6], is_none));
+            }
+            let octets = match Self::parse_octets(s) {
+                Some(octets) => octets,
+                None => {
+                    let mut messages = ValidateErrorCollector::new();
+                    messages.push((
+                        "Invalid MAC address".to_string(),
+                        Box::new(MacAddressLocale),
+                    ));
+                    return Err(MacAddressError(messages.into()));
+                }
+            };
+            Ok(Self(s.to_string(), octets, is_none))
+        })
+    }
+
+    /// Parses a MAC address using the default `MacAddressRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, MacAddressError> {
+        Self::parse_custom(s, MacAddressRules::default())
+    }
+
+    /// Returns the six octets of the MAC address.
+    pub fn as_octets(&self) -> [u8; 6] {
+        self.1
+    }
+
+    /// Returns the MAC address in the canonical lowercase, colon separated format.
+    pub fn to_canonical(&self) -> String {
+        self.1
+            .iter()
+            .map(|octet| format!("{:02x}", octet))
+            .collect::<Vec<_>>()
+            .join(":")
+    }
+
+    /// Returns the MAC address as it was provided.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the MAC address was not provided.
+    pub fn is_none(&self) -> bool {
+        self.2
+    }
+
+    /// Converts the current instance into an `Option<MacAddress>`.
+    ///
+    /// # Returns
+    /// - `None` if the MAC address was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<MacAddress> {
+        if self.2 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for MacAddress {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&MacAddress> for String {
+    fn from(mac_address: &MacAddress) -> Self {
+        mac_address.as_str().to_string()
+    }
+}
+
+impl Display for MacAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for MacAddress {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for MacAddress {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `MacAddress` key can be found by
+/// a `&str`.
+impl PartialEq for MacAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for MacAddress {}
+
+impl Hash for MacAddress {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for MacAddress {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for MacAddress {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for MacAddress {
+    type Error = MacAddressError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for MacAddress {
+    type Error = MacAddressError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for MacAddress {
+    type Error = MacAddressError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, MacAddress, MacAddressError> for MacAddressRules {
+    fn validate(&self, input: Option<&str>) -> Result<MacAddress, MacAddressError> {
+        MacAddress::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for MacAddressRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+        }
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl MacAddress {
+    /// Creates an instance of `MacAddress` without validation, e.g. when loading values which
+    /// were validated before being stored. The string is kept as is, `None` gives an empty value.
+    ///
+    /// The octets are still parsed for `octets`, which are zero if they cannot be parsed.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(
+            s.to_string(),
+            Self::parse_octets(s).unwrap_or_default(),
+            is_none,
+        )
+    }
+
+    /// Creates an instance of `MacAddress` from a stored string without validation, so stored
+    /// values which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_mac_address() {
+        for s in ["00:1A:2B:3C:4D:5E", "00-1a-2b-3c-4d-5e", "001a2b3c4d5e"] {
+            let mac = MacAddress::parse(Some(s)).unwrap_or_default();
+            assert_eq!(mac.to_canonical(), "00:1a:2b:3c:4d:5e");
+        }
+    }
+
+    #[test]
+    fn test_invalid_mac_address() {
+        for s in [
+            "00:1A:2B:3C:4D",
+            "00:1A-2B:3C:4D:5E",
+            "001a2b3c4d5g",
+            "+0:1a:2b:3c:4d:5e",
+        ] {
+            assert!(MacAddress::parse(Some(s)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_none_mac_address() {
+        assert!(MacAddress::parse(None).is_err());
+        let rules = MacAddressRules {
+            is_mandatory: false,
+        };
+        assert!(MacAddress::parse_custom(None, rules).is_ok());
+    }
+}
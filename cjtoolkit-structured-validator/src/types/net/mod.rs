@@ -0,0 +1,5 @@
+//! This module contains structures and traits for working with network identifiers.
+
+pub mod domain;
+pub mod hostname;
+pub mod mac_address;
@@ -1,8 +1,17 @@
 //! This module contains structures and traits for working with floating-point numbers.
 
-use crate::base::number_rules::{NumberMandatoryRules, NumberRangeRules};
+use crate::base::number_rules::{
+    ExternalBounds, ExternalBoundsProvider, ExternalBoundsProviderAsync, NumberMandatoryRules,
+    NumberRangeRules, number_checked, number_from_str,
+};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
+use crate::common::tenant_policy::{TightenRules, tighter_max, tighter_min};
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use thiserror::Error;
 
 /// A structure representing rules and constraints for floating-point values.
 ///
@@ -19,6 +28,7 @@ use crate::common::validation_check::ValidationCheck;
 ///   If `None`, there is no maximum constraint.
 ///
 /// This structure can be used to validate or enforce business logic with respect to floating-point numbers.
+#[derive(Clone)]
 pub struct FloatRules {
     pub is_mandatory: bool,
     pub min: Option<f64>,
@@ -65,6 +75,23 @@ impl FloatRules {
         }
         length_rule.check(messages, subject);
     }
+
+    /// Returns the rules with the bounds replaced by the `bounds` which are `Some`.
+    pub fn with_external_bounds(self, bounds: ExternalBounds<f64>) -> Self {
+        Self {
+            min: bounds.min.or(self.min),
+            max: bounds.max.or(self.max),
+            ..self
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        constraints
+    }
 }
 
 /// A structure representing an error that occurs during validation of a floating-point value.
@@ -82,7 +109,8 @@ impl FloatRules {
 ///
 /// * `0: ValidateErrorStore` - The underlying store containing validation error details.
 ///
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Float Validation Error")]
 pub struct FloatError(pub ValidateErrorStore);
 
 impl ValidationCheck for FloatError {
@@ -112,6 +140,11 @@ impl Into<ValidateErrorStore> for &FloatError {
 /// - `f64`: Represents the numeric value of the floating-point number.
 /// - `bool`: Represents the metadata flag associated with the float, which can be used for custom purposes.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Double)
+)]
 pub struct Float(f64, bool);
 
 #[cfg(any(feature = "allow-default-value", test))]
@@ -162,11 +195,13 @@ impl Float {
     /// # Errors
     /// - Returns a `FloatError` if the input value does not satisfy the validation rules provided in `rules`.
     pub fn parse_custom(s: Option<f64>, rules: FloatRules) -> Result<Self, FloatError> {
-        let is_none = s.is_none();
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, s);
-        FloatError::validate_check(messages)?;
-        Ok(Self(s.unwrap_or_default(), is_none))
+        observe_parse(|| {
+            let is_none = s.is_none();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, s);
+            FloatError::validate_check(messages)?;
+            Ok(Self(s.unwrap_or_default(), is_none))
+        })
     }
 
     ///
@@ -215,6 +250,83 @@ impl Float {
         Self::parse_custom(s, FloatRules::default())
     }
 
+    /// Parses the text of a number, e.g. a query string or form field, according to the
+    /// provided `FloatRules`.
+    ///
+    /// A missing or blank text is parsed as `None`, so the mandatory rule reports it.
+    ///
+    /// # Errors
+    /// Returns a `FloatError` if the text is not a valid `f64`, or if the number does not satisfy
+    /// the `rules`.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::numbers::float::{Float, FloatRules};
+    ///
+    /// let number = Float::parse_str_custom(Some("4.2"), FloatRules::default());
+    /// assert_eq!(number.map(|n| n.as_f64()).ok(), Some(4.2));
+    /// assert!(Float::parse_str_custom(Some("abc"), FloatRules::default()).is_err());
+    /// ```
+    pub fn parse_str_custom(s: Option<&str>, rules: FloatRules) -> Result<Self, FloatError> {
+        match number_from_str(s, "number") {
+            Ok(number) => Self::parse_custom(number, rules),
+            Err(messages) => observe_parse(|| Err(FloatError::validate_new(messages.into()))),
+        }
+    }
+
+    /// Parses an optional value according to the provided `FloatRules`, with the bounds fetched
+    /// from `provider` replacing those of the rules.
+    ///
+    /// # Errors
+    /// Returns a `FloatError` if the value does not satisfy the rules with the fetched bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::base::number_rules::{ExternalBounds, ExternalBoundsProvider};
+    /// use cjtoolkit_structured_validator::types::numbers::float::{Float, FloatRules};
+    ///
+    /// struct Tier(f64);
+    ///
+    /// impl ExternalBoundsProvider<f64> for Tier {
+    ///     fn bounds(&self) -> ExternalBounds<f64> {
+    ///         ExternalBounds {
+    ///             min: None,
+    ///             max: Some(self.0),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let rules = FloatRules::default();
+    /// assert!(Float::parse_with_bounds(Some(250.0), rules.clone(), &Tier(500.0)).is_ok());
+    /// assert!(Float::parse_with_bounds(Some(250.0), rules, &Tier(100.0)).is_err());
+    /// ```
+    pub fn parse_with_bounds<P: ExternalBoundsProvider<f64>>(
+        s: Option<f64>,
+        rules: FloatRules,
+        provider: &P,
+    ) -> Result<Self, FloatError> {
+        Self::parse_custom(s, rules.with_external_bounds(provider.bounds()))
+    }
+
+    /// Parses an optional value according to the provided `FloatRules`, with the bounds fetched
+    /// asynchronously from `provider` replacing those of the rules.
+    ///
+    /// # Errors
+    /// Returns a `FloatError` if the value does not satisfy the rules with the fetched bounds.
+    pub async fn parse_with_bounds_async<P: ExternalBoundsProviderAsync<f64>>(
+        s: Option<f64>,
+        rules: FloatRules,
+        provider: &P,
+    ) -> Result<Self, FloatError> {
+        let bounds = provider.bounds_async().await;
+        Self::parse_custom(s, rules.with_external_bounds(bounds))
+    }
+
+    /// Parses the text of a number, using the default `FloatRules`.
+    pub fn parse_str(s: Option<&str>) -> Result<Self, FloatError> {
+        Self::parse_str_custom(s, FloatRules::default())
+    }
+
     /// Returns the inner value as a `f64`.
     ///
     /// This method provides access to the stored value of the type `f64` encapsulated within the struct.
@@ -226,6 +338,11 @@ impl Float {
         self.0
     }
 
+    /// Returns `true` if the float was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
     /// Converts the current instance into an `Option<Float>` depending on its internal state.
     ///
     /// # Returns
@@ -239,6 +356,58 @@ impl Float {
     pub fn into_option(self) -> Option<Float> {
         if self.1 { None } else { Some(self) }
     }
+
+    /// Returns the sum of `self` and `other`, validated against `rules`. The result is not
+    /// provided if either value was not provided.
+    ///
+    /// # Errors
+    /// Returns a `FloatError` if the sum is not finite, or does not satisfy the rules.
+    pub fn checked_add(&self, other: &Self, rules: &FloatRules) -> Result<Self, FloatError> {
+        self.checked_op(other, rules, std::ops::Add::add)
+    }
+
+    /// Returns the difference of `self` and `other`, validated against `rules`. The result is not
+    /// provided if either value was not provided.
+    ///
+    /// # Errors
+    /// Returns a `FloatError` if the difference is not finite, or does not satisfy the rules.
+    pub fn checked_sub(&self, other: &Self, rules: &FloatRules) -> Result<Self, FloatError> {
+        self.checked_op(other, rules, std::ops::Sub::sub)
+    }
+
+    /// Returns the product of `self` and `other`, validated against `rules`. The result is not
+    /// provided if either value was not provided.
+    ///
+    /// # Errors
+    /// Returns a `FloatError` if the product is not finite, or does not satisfy the rules.
+    pub fn checked_mul(&self, other: &Self, rules: &FloatRules) -> Result<Self, FloatError> {
+        self.checked_op(other, rules, std::ops::Mul::mul)
+    }
+
+    fn checked_op(
+        &self,
+        other: &Self,
+        rules: &FloatRules,
+        op: fn(f64, f64) -> f64,
+    ) -> Result<Self, FloatError> {
+        let value = if self.is_none() || other.is_none() {
+            None
+        } else {
+            match number_checked(Some(op(self.0, other.0)).filter(|value| value.is_finite())) {
+                Ok(value) => Some(value),
+                Err(messages) => {
+                    return observe_parse(|| Err(FloatError::validate_new(messages.into())));
+                }
+            }
+        };
+        Self::parse_custom(value, rules.clone())
+    }
+}
+
+impl OptionalValue for Float {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
 }
 
 pub trait AsFloatOnResult {
@@ -251,6 +420,67 @@ impl<E> AsFloatOnResult for Result<Float, E> {
     }
 }
 
+impl TightenRules for FloatRules {
+    fn tighten(&self, other: &Self) -> Self {
+        Self {
+            is_mandatory: self.is_mandatory || other.is_mandatory,
+            min: tighter_min(self.min, other.min),
+            max: tighter_max(self.max, other.max),
+        }
+    }
+}
+
+impl Validator<Option<f64>, Float, FloatError> for FloatRules {
+    fn validate(&self, input: Option<f64>) -> Result<Float, FloatError> {
+        Float::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for FloatRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl FloatRules {
+    /// Returns an input satisfying the rules, the lower bound if any, otherwise zero capped by
+    /// the upper bound.
+    pub fn arbitrary_valid_input(&self) -> f64 {
+        self.min
+            .unwrap_or_else(|| self.max.map_or(0.0, |max| max.min(0.0)))
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl Float {
+    /// Returns a valid `Float` for the default `FloatRules`.
+    pub fn valid_sample() -> Self {
+        Self::sample_matching(FloatRules::default())
+    }
+
+    /// Returns a valid `Float` for the given rules, parsed from `arbitrary_valid_input`.
+    ///
+    /// # Panics
+    /// Panics if no input can satisfy the rules, e.g. `min` above `max`.
+    pub fn sample_matching(rules: FloatRules) -> Self {
+        let input = rules.arbitrary_valid_input();
+        Self::parse_custom(Some(input), rules).expect("Expected sample to be valid")
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Float {
+    /// Creates an instance of `Float` without validation, e.g. when loading values which were
+    /// validated before being stored. `None` gives the default value.
+    pub fn new_unchecked(value: Option<f64>) -> Self {
+        Self(value.unwrap_or_default(), value.is_none())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,4 +500,27 @@ mod tests {
         let float = Float::parse(None);
         assert!(float.is_err());
     }
+
+    #[test]
+    fn test_float_checked_arithmetic() {
+        let rules = FloatRules {
+            min: None,
+            max: None,
+            ..FloatRules::default()
+        };
+        let max = Float::parse_custom(Some(f64::MAX), rules.clone()).expect("Expected valid float");
+        assert!(max.checked_mul(&max, &rules).is_err());
+        let optional = FloatRules {
+            is_mandatory: false,
+            ..rules
+        };
+        let absent = Float::parse_custom(None, optional.clone()).expect("Expected missing float");
+        assert!(
+            absent
+                .checked_add(&max, &optional)
+                .expect("Expected no result")
+                .is_none()
+        );
+        assert!(absent.checked_add(&max, &rules).is_err());
+    }
 }
@@ -1,8 +1,17 @@
 //! This module contains structures and traits for working with integer values.
 
-use crate::base::number_rules::{NumberMandatoryRules, NumberRangeRules};
+use crate::base::number_rules::{
+    ExternalBounds, ExternalBoundsProvider, ExternalBoundsProviderAsync, NumberMandatoryRules,
+    NumberRangeRules, NumberValueRules, number_checked, number_from_str,
+};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
+use crate::common::tenant_policy::{TightenRules, tighter_allowed, tighter_max, tighter_min};
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use thiserror::Error;
 
 /// A structure representing validation rules for an integer value.
 ///
@@ -24,10 +33,24 @@ use crate::common::validation_check::ValidationCheck;
 /// * `max` - An optional maximum bound for the integer. If `Some(value)`,
 ///   the integer must be less than or equal to `value`. If `None`,
 ///   no maximum constraint is applied.
+///
+/// * `forbid_zero` - A boolean flag indicating whether zero is rejected.
+///
+/// * `must_be_even` - A boolean flag indicating whether the integer must be even.
+///
+/// * `must_be_odd` - A boolean flag indicating whether the integer must be odd.
+///
+/// * `allowed_values` - An optional list of values. If `Some(values)`,
+///   the integer must be one of `values`. If `None`, any value is allowed.
+#[derive(Clone)]
 pub struct IntegerRules {
     pub is_mandatory: bool,
     pub min: Option<isize>,
     pub max: Option<isize>,
+    pub forbid_zero: bool,
+    pub must_be_even: bool,
+    pub must_be_odd: bool,
+    pub allowed_values: Option<Vec<isize>>,
 }
 
 impl Default for IntegerRules {
@@ -36,12 +59,28 @@ impl Default for IntegerRules {
             is_mandatory: true,
             min: Some(0),
             max: Some(255),
+            forbid_zero: false,
+            must_be_even: false,
+            must_be_odd: false,
+            allowed_values: None,
         }
     }
 }
 
-impl Into<(NumberMandatoryRules, NumberRangeRules<isize>)> for &IntegerRules {
-    fn into(self) -> (NumberMandatoryRules, NumberRangeRules<isize>) {
+impl
+    Into<(
+        NumberMandatoryRules,
+        NumberRangeRules<isize>,
+        NumberValueRules<isize>,
+    )> for &IntegerRules
+{
+    fn into(
+        self,
+    ) -> (
+        NumberMandatoryRules,
+        NumberRangeRules<isize>,
+        NumberValueRules<isize>,
+    ) {
         (
             NumberMandatoryRules {
                 is_mandatory: self.is_mandatory,
@@ -50,12 +89,24 @@ impl Into<(NumberMandatoryRules, NumberRangeRules<isize>)> for &IntegerRules {
                 min: self.min,
                 max: self.max,
             },
+            NumberValueRules {
+                forbid_zero: self.forbid_zero,
+                must_be_even: self.must_be_even,
+                must_be_odd: self.must_be_odd,
+                allowed_values: self.allowed_values.clone(),
+            },
         )
     }
 }
 
 impl IntegerRules {
-    fn rules(&self) -> (NumberMandatoryRules, NumberRangeRules<isize>) {
+    fn rules(
+        &self,
+    ) -> (
+        NumberMandatoryRules,
+        NumberRangeRules<isize>,
+        NumberValueRules<isize>,
+    ) {
         self.into()
     }
 
@@ -63,12 +114,31 @@ impl IntegerRules {
         if !self.is_mandatory && subject.is_none() {
             return;
         }
-        let (mandatory_rule, length_rule) = self.rules();
+        let (mandatory_rule, length_rule, value_rule) = self.rules();
         mandatory_rule.check(messages, subject);
         if !messages.is_empty() {
             return;
         }
         length_rule.check(messages, subject);
+        value_rule.check(messages, subject);
+    }
+
+    /// Returns the rules with the bounds replaced by the `bounds` which are `Some`.
+    pub fn with_external_bounds(self, bounds: ExternalBounds<isize>) -> Self {
+        Self {
+            min: bounds.min.or(self.min),
+            max: bounds.max.or(self.max),
+            ..self
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule, value_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        constraints.extend(value_rule.describe());
+        constraints
     }
 }
 
@@ -85,7 +155,8 @@ impl IntegerRules {
 ///
 /// # Fields
 /// - `0: ValidateErrorStore`: The underlying error storage containing detailed validation error information.
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Integer Validation Error")]
 pub struct IntegerError(pub ValidateErrorStore);
 
 impl ValidationCheck for IntegerError {
@@ -115,6 +186,11 @@ impl Into<ValidateErrorStore> for &IntegerError {
 /// - `isize`: The signed integer value.
 /// - `bool`: The boolean flag associated with the integer.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::BigInt)
+)]
 pub struct Integer(isize, bool);
 
 #[cfg(any(feature = "allow-default-value", test))]
@@ -161,6 +237,10 @@ impl Integer {
     ///     is_mandatory: true,
     ///     min: Some(0),
     ///     max: Some(5),
+    ///     forbid_zero: false,
+    ///     must_be_even: false,
+    ///     must_be_odd: false,
+    ///     allowed_values: None,
     /// };
     /// let result = Integer::parse_custom(Some(42), rules);
     ///
@@ -170,11 +250,13 @@ impl Integer {
     /// }
     /// ```
     pub fn parse_custom(s: Option<isize>, rules: IntegerRules) -> Result<Self, IntegerError> {
-        let is_none = s.is_none();
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, s);
-        IntegerError::validate_check(messages)?;
-        Ok(Self(s.unwrap_or_default(), is_none))
+        observe_parse(|| {
+            let is_none = s.is_none();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, s);
+            IntegerError::validate_check(messages)?;
+            Ok(Self(s.unwrap_or_default(), is_none))
+        })
     }
 
     /// Parses an optional integer (`Option<isize>`) into a `Self` type,
@@ -218,6 +300,83 @@ impl Integer {
         Self::parse_custom(s, IntegerRules::default())
     }
 
+    /// Parses the text of a number, e.g. a query string or form field, according to the
+    /// provided `IntegerRules`.
+    ///
+    /// A missing or blank text is parsed as `None`, so the mandatory rule reports it.
+    ///
+    /// # Errors
+    /// Returns a `IntegerError` if the text is not a valid `isize`, or if the number does not satisfy
+    /// the `rules`.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::numbers::integer::{Integer, IntegerRules};
+    ///
+    /// let number = Integer::parse_str_custom(Some(" 42 "), IntegerRules::default());
+    /// assert_eq!(number.map(|n| n.as_isize()).ok(), Some(42));
+    /// assert!(Integer::parse_str_custom(Some("abc"), IntegerRules::default()).is_err());
+    /// ```
+    pub fn parse_str_custom(s: Option<&str>, rules: IntegerRules) -> Result<Self, IntegerError> {
+        match number_from_str(s, "integer") {
+            Ok(number) => Self::parse_custom(number, rules),
+            Err(messages) => observe_parse(|| Err(IntegerError::validate_new(messages.into()))),
+        }
+    }
+
+    /// Parses an optional value according to the provided `IntegerRules`, with the bounds fetched
+    /// from `provider` replacing those of the rules.
+    ///
+    /// # Errors
+    /// Returns a `IntegerError` if the value does not satisfy the rules with the fetched bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::base::number_rules::{ExternalBounds, ExternalBoundsProvider};
+    /// use cjtoolkit_structured_validator::types::numbers::integer::{Integer, IntegerRules};
+    ///
+    /// struct Tier(isize);
+    ///
+    /// impl ExternalBoundsProvider<isize> for Tier {
+    ///     fn bounds(&self) -> ExternalBounds<isize> {
+    ///         ExternalBounds {
+    ///             min: None,
+    ///             max: Some(self.0),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let rules = IntegerRules::default();
+    /// assert!(Integer::parse_with_bounds(Some(250), rules.clone(), &Tier(500)).is_ok());
+    /// assert!(Integer::parse_with_bounds(Some(250), rules, &Tier(100)).is_err());
+    /// ```
+    pub fn parse_with_bounds<P: ExternalBoundsProvider<isize>>(
+        s: Option<isize>,
+        rules: IntegerRules,
+        provider: &P,
+    ) -> Result<Self, IntegerError> {
+        Self::parse_custom(s, rules.with_external_bounds(provider.bounds()))
+    }
+
+    /// Parses an optional value according to the provided `IntegerRules`, with the bounds fetched
+    /// asynchronously from `provider` replacing those of the rules.
+    ///
+    /// # Errors
+    /// Returns a `IntegerError` if the value does not satisfy the rules with the fetched bounds.
+    pub async fn parse_with_bounds_async<P: ExternalBoundsProviderAsync<isize>>(
+        s: Option<isize>,
+        rules: IntegerRules,
+        provider: &P,
+    ) -> Result<Self, IntegerError> {
+        let bounds = provider.bounds_async().await;
+        Self::parse_custom(s, rules.with_external_bounds(bounds))
+    }
+
+    /// Parses the text of a number, using the default `IntegerRules`.
+    pub fn parse_str(s: Option<&str>) -> Result<Self, IntegerError> {
+        Self::parse_str_custom(s, IntegerRules::default())
+    }
+
     /// Converts the value contained in the type to an `isize`.
     ///
     /// # Returns
@@ -226,6 +385,11 @@ impl Integer {
         self.0
     }
 
+    /// Returns `true` if the integer was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
     /// Converts the `Integer` to an `Option<Integer>`.
     ///
     /// # Description
@@ -241,6 +405,73 @@ impl Integer {
     pub fn into_option(self) -> Option<Integer> {
         if self.1 { None } else { Some(self) }
     }
+
+    /// Returns the sum of `self` and `other`, validated against `rules`. The result is not
+    /// provided if either value was not provided.
+    ///
+    /// # Errors
+    /// Returns a `IntegerError` if the sum overflows, or does not satisfy the rules.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::numbers::integer::{Integer, IntegerRules};
+    ///
+    /// let rules = IntegerRules {
+    ///     max: Some(100),
+    ///     ..IntegerRules::default()
+    /// };
+    /// let quantity = Integer::parse_custom(Some(6), rules.clone()).expect("Expected valid quantity");
+    /// let price = Integer::parse_custom(Some(20), rules.clone()).expect("Expected valid price");
+    ///
+    /// assert_eq!(quantity.checked_add(&price, &rules).map(|n| n.as_isize()).ok(), Some(26));
+    /// assert!(quantity.checked_mul(&price, &rules).is_err());
+    /// ```
+    pub fn checked_add(&self, other: &Self, rules: &IntegerRules) -> Result<Self, IntegerError> {
+        self.checked_op(other, rules, isize::checked_add)
+    }
+
+    /// Returns the difference of `self` and `other`, validated against `rules`. The result is not
+    /// provided if either value was not provided.
+    ///
+    /// # Errors
+    /// Returns a `IntegerError` if the difference overflows, or does not satisfy the rules.
+    pub fn checked_sub(&self, other: &Self, rules: &IntegerRules) -> Result<Self, IntegerError> {
+        self.checked_op(other, rules, isize::checked_sub)
+    }
+
+    /// Returns the product of `self` and `other`, validated against `rules`. The result is not
+    /// provided if either value was not provided.
+    ///
+    /// # Errors
+    /// Returns a `IntegerError` if the product overflows, or does not satisfy the rules.
+    pub fn checked_mul(&self, other: &Self, rules: &IntegerRules) -> Result<Self, IntegerError> {
+        self.checked_op(other, rules, isize::checked_mul)
+    }
+
+    fn checked_op(
+        &self,
+        other: &Self,
+        rules: &IntegerRules,
+        op: fn(isize, isize) -> Option<isize>,
+    ) -> Result<Self, IntegerError> {
+        let value = if self.is_none() || other.is_none() {
+            None
+        } else {
+            match number_checked(op(self.0, other.0)) {
+                Ok(value) => Some(value),
+                Err(messages) => {
+                    return observe_parse(|| Err(IntegerError::validate_new(messages.into())));
+                }
+            }
+        };
+        Self::parse_custom(value, rules.clone())
+    }
+}
+
+impl OptionalValue for Integer {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
 }
 
 pub trait AsIntegerOnResult {
@@ -253,6 +484,88 @@ impl<E> AsIntegerOnResult for Result<Integer, E> {
     }
 }
 
+impl TightenRules for IntegerRules {
+    fn tighten(&self, other: &Self) -> Self {
+        Self {
+            is_mandatory: self.is_mandatory || other.is_mandatory,
+            min: tighter_min(self.min, other.min),
+            max: tighter_max(self.max, other.max),
+            forbid_zero: self.forbid_zero || other.forbid_zero,
+            must_be_even: self.must_be_even || other.must_be_even,
+            must_be_odd: self.must_be_odd || other.must_be_odd,
+            allowed_values: tighter_allowed(&self.allowed_values, &other.allowed_values),
+        }
+    }
+}
+
+impl Validator<Option<isize>, Integer, IntegerError> for IntegerRules {
+    fn validate(&self, input: Option<isize>) -> Result<Integer, IntegerError> {
+        Integer::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for IntegerRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl IntegerRules {
+    /// Returns the first value from the allowed values, or else from the lower bound upward,
+    /// satisfying the rules.
+    ///
+    /// Falls back to the lower bound when no value is found, which `sample_matching` rejects.
+    pub fn arbitrary_valid_input(&self) -> isize {
+        let start = match (self.min, self.max) {
+            (Some(min), _) => min,
+            (None, Some(max)) if max < 2 => max.saturating_sub(2),
+            _ => 0,
+        };
+        let candidates = match &self.allowed_values {
+            Some(allowed_values) => allowed_values.clone(),
+            None => (0..3).filter_map(|i| start.checked_add(i)).collect(),
+        };
+        candidates
+            .into_iter()
+            .find(|candidate| {
+                let mut messages = ValidateErrorCollector::new();
+                self.check(&mut messages, Some(*candidate));
+                messages.is_empty()
+            })
+            .unwrap_or(start)
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl Integer {
+    /// Returns a valid `Integer` for the default `IntegerRules`.
+    pub fn valid_sample() -> Self {
+        Self::sample_matching(IntegerRules::default())
+    }
+
+    /// Returns a valid `Integer` for the given rules, parsed from `arbitrary_valid_input`.
+    ///
+    /// # Panics
+    /// Panics if no input can satisfy the rules, e.g. `min` above `max`.
+    pub fn sample_matching(rules: IntegerRules) -> Self {
+        let input = rules.arbitrary_valid_input();
+        Self::parse_custom(Some(input), rules).expect("Expected sample to be valid")
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Integer {
+    /// Creates an instance of `Integer` without validation, e.g. when loading values which were
+    /// validated before being stored. `None` gives the default value.
+    pub fn new_unchecked(value: Option<isize>) -> Self {
+        Self(value.unwrap_or_default(), value.is_none())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -272,4 +585,92 @@ mod tests {
         let integer = Integer::parse(None);
         assert!(integer.is_err());
     }
+
+    #[test]
+    fn test_integer_value_rules() {
+        let rules = || IntegerRules {
+            forbid_zero: true,
+            must_be_odd: true,
+            ..IntegerRules::default()
+        };
+        assert!(Integer::parse_custom(Some(0), rules()).is_err());
+        assert!(Integer::parse_custom(Some(4), rules()).is_err());
+        assert!(Integer::parse_custom(Some(5), rules()).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "test-fixtures")]
+    fn test_integer_sample_matching() {
+        let rules = || IntegerRules {
+            min: None,
+            max: Some(0),
+            forbid_zero: true,
+            must_be_odd: true,
+            ..IntegerRules::default()
+        };
+        assert_eq!(rules().arbitrary_valid_input(), -1);
+        assert_eq!(Integer::sample_matching(rules()).as_isize(), -1);
+    }
+
+    #[test]
+    fn test_integer_checked_arithmetic() {
+        let rules = IntegerRules {
+            min: None,
+            max: None,
+            ..IntegerRules::default()
+        };
+        let max =
+            Integer::parse_custom(Some(isize::MAX), rules.clone()).expect("Expected valid integer");
+        let one = Integer::parse(Some(1)).expect("Expected valid integer");
+        let error = max
+            .checked_add(&one, &rules)
+            .expect_err("Expected overflow");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Result is out of range"]
+        );
+        assert_eq!(
+            max.checked_sub(&one, &rules).map(|n| n.as_isize()).ok(),
+            Some(isize::MAX - 1)
+        );
+        let error = one
+            .checked_sub(&max, &IntegerRules::default())
+            .expect_err("Expected below the default minimum");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be at least 0"]
+        );
+    }
+
+    struct Tier(isize);
+
+    impl ExternalBoundsProviderAsync<isize> for Tier {
+        async fn bounds_async(&self) -> ExternalBounds<isize> {
+            ExternalBounds {
+                min: Some(1),
+                max: Some(self.0),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_integer_parse_with_bounds_async() {
+        let gold = Tier(1000);
+        let integer = Integer::parse_with_bounds_async(Some(800), IntegerRules::default(), &gold)
+            .await
+            .expect("Expected valid integer");
+        assert_eq!(integer.as_isize(), 800);
+        assert!(
+            Integer::parse_with_bounds_async(Some(1001), IntegerRules::default(), &gold)
+                .await
+                .is_err()
+        );
+        let error = Integer::parse_with_bounds_async(Some(0), IntegerRules::default(), &gold)
+            .await
+            .expect_err("Expected below the fetched minimum");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be at least 1"]
+        );
+    }
 }
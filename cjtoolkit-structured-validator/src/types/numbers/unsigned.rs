@@ -1,8 +1,21 @@
 //! This module contains structures and traits for working with unsigned numerical values.
+//!
+//! `Unsigned` holds a `usize`, which is 32 bits wide on 32-bit targets. `U64` and `U32` hold an
+//! integer of a fixed width instead, e.g. for database IDs, and `NonZeroUnsigned` holds a
+//! `NonZeroU64`.
 
-use crate::base::number_rules::{NumberMandatoryRules, NumberRangeRules};
+use crate::base::number_rules::{
+    NumberMandatoryRules, NumberRangeRules, NumberValueRules, number_checked, number_from_str,
+};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
+use crate::common::tenant_policy::{TightenRules, tighter_allowed, tighter_max, tighter_min};
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::num::NonZeroU64;
+use thiserror::Error;
 
 /// A structure representing rules and constraints for unsigned numerical values.
 ///
@@ -16,10 +29,20 @@ use crate::common::validation_check::ValidationCheck;
 ///            If `None`, no minimum constraint is applied.
 /// * `max` - An optional maximum value (inclusive) of a type `usize`.
 ///            If `None`, no maximum constraint is applied.
+/// * `forbid_zero` - A boolean flag that specifies whether zero is rejected.
+/// * `must_be_even` - A boolean flag that specifies whether the value must be even.
+/// * `must_be_odd` - A boolean flag that specifies whether the value must be odd.
+/// * `allowed_values` - An optional list of values the value must be one of.
+///   If `None`, any value is allowed.
+#[derive(Clone)]
 pub struct UnsignedRules {
     pub is_mandatory: bool,
     pub min: Option<usize>,
     pub max: Option<usize>,
+    pub forbid_zero: bool,
+    pub must_be_even: bool,
+    pub must_be_odd: bool,
+    pub allowed_values: Option<Vec<usize>>,
 }
 
 impl Default for UnsignedRules {
@@ -28,12 +51,28 @@ impl Default for UnsignedRules {
             is_mandatory: true,
             min: Some(0),
             max: Some(255),
+            forbid_zero: false,
+            must_be_even: false,
+            must_be_odd: false,
+            allowed_values: None,
         }
     }
 }
 
-impl Into<(NumberMandatoryRules, NumberRangeRules<usize>)> for &UnsignedRules {
-    fn into(self) -> (NumberMandatoryRules, NumberRangeRules<usize>) {
+impl
+    Into<(
+        NumberMandatoryRules,
+        NumberRangeRules<usize>,
+        NumberValueRules<usize>,
+    )> for &UnsignedRules
+{
+    fn into(
+        self,
+    ) -> (
+        NumberMandatoryRules,
+        NumberRangeRules<usize>,
+        NumberValueRules<usize>,
+    ) {
         (
             NumberMandatoryRules {
                 is_mandatory: self.is_mandatory,
@@ -42,12 +81,24 @@ impl Into<(NumberMandatoryRules, NumberRangeRules<usize>)> for &UnsignedRules {
                 min: self.min,
                 max: self.max,
             },
+            NumberValueRules {
+                forbid_zero: self.forbid_zero,
+                must_be_even: self.must_be_even,
+                must_be_odd: self.must_be_odd,
+                allowed_values: self.allowed_values.clone(),
+            },
         )
     }
 }
 
 impl UnsignedRules {
-    fn rules(&self) -> (NumberMandatoryRules, NumberRangeRules<usize>) {
+    fn rules(
+        &self,
+    ) -> (
+        NumberMandatoryRules,
+        NumberRangeRules<usize>,
+        NumberValueRules<usize>,
+    ) {
         self.into()
     }
 
@@ -55,12 +106,22 @@ impl UnsignedRules {
         if !self.is_mandatory && subject.is_none() {
             return;
         }
-        let (mandatory_rule, length_rule) = self.rules();
+        let (mandatory_rule, length_rule, value_rule) = self.rules();
         mandatory_rule.check(messages, subject);
         if !messages.is_empty() {
             return;
         }
         length_rule.check(messages, subject);
+        value_rule.check(messages, subject);
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule, value_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        constraints.extend(value_rule.describe());
+        constraints
     }
 }
 
@@ -78,7 +139,8 @@ impl UnsignedRules {
 /// - `Clone`: Allows duplication of `UnsignedError` instances to create new copies.
 /// - `Default`: Provides a default value for `UnsignedError`, which initializes the wrapped
 ///              `ValidateErrorStore` to its default state.
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Unsigned Validation Error")]
 pub struct UnsignedError(pub ValidateErrorStore);
 
 impl ValidationCheck for UnsignedError {
@@ -107,6 +169,11 @@ impl Into<ValidateErrorStore> for &UnsignedError {
 /// - `PartialEq`: Enables comparison for equality between two `Unsigned` instances.
 /// - `Clone`: Allows cloning of `Unsigned` values for producing duplicates.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::BigInt)
+)]
 pub struct Unsigned(usize, bool);
 
 #[cfg(any(feature = "allow-default-value", test))]
@@ -139,11 +206,13 @@ impl Unsigned {
     ///       boolean indicator of whether the original input was `None`.
     ///
     pub fn parse_custom(s: Option<usize>, rules: UnsignedRules) -> Result<Self, UnsignedError> {
-        let is_none = s.is_none();
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, s);
-        UnsignedError::validate_check(messages)?;
-        Ok(Self(s.unwrap_or_default(), is_none))
+        observe_parse(|| {
+            let is_none = s.is_none();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, s);
+            UnsignedError::validate_check(messages)?;
+            Ok(Self(s.unwrap_or_default(), is_none))
+        })
     }
 
     /// Parses an optional `usize` value into the current type, applying the default rules.
@@ -168,6 +237,35 @@ impl Unsigned {
         Self::parse_custom(s, UnsignedRules::default())
     }
 
+    /// Parses the text of a number, e.g. a query string or form field, according to the
+    /// provided `UnsignedRules`.
+    ///
+    /// A missing or blank text is parsed as `None`, so the mandatory rule reports it.
+    ///
+    /// # Errors
+    /// Returns a `UnsignedError` if the text is not a valid `usize`, or if the number does not satisfy
+    /// the `rules`.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::numbers::unsigned::{Unsigned, UnsignedRules};
+    ///
+    /// let number = Unsigned::parse_str_custom(Some("42"), UnsignedRules::default());
+    /// assert_eq!(number.map(|n| n.as_usize()).ok(), Some(42));
+    /// assert!(Unsigned::parse_str_custom(Some("abc"), UnsignedRules::default()).is_err());
+    /// ```
+    pub fn parse_str_custom(s: Option<&str>, rules: UnsignedRules) -> Result<Self, UnsignedError> {
+        match number_from_str(s, "unsigned integer") {
+            Ok(number) => Self::parse_custom(number, rules),
+            Err(messages) => observe_parse(|| Err(UnsignedError::validate_new(messages.into()))),
+        }
+    }
+
+    /// Parses the text of a number, using the default `UnsignedRules`.
+    pub fn parse_str(s: Option<&str>) -> Result<Self, UnsignedError> {
+        Self::parse_str_custom(s, UnsignedRules::default())
+    }
+
     /// Returns the inner value of the implementing type as a `usize`.
     ///
     /// This method accesses the inner representation of the type
@@ -181,6 +279,11 @@ impl Unsigned {
         self.0
     }
 
+    /// Returns `true` if the unsigned integer was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
     /// Converts the instance into an `Option<Unsigned>`.
     ///
     /// # Description
@@ -194,6 +297,73 @@ impl Unsigned {
     pub fn into_option(self) -> Option<Unsigned> {
         if self.1 { None } else { Some(self) }
     }
+
+    /// Returns the sum of `self` and `other`, validated against `rules`. The result is not
+    /// provided if either value was not provided.
+    ///
+    /// # Errors
+    /// Returns a `UnsignedError` if the sum overflows, or does not satisfy the rules.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::numbers::unsigned::{Unsigned, UnsignedRules};
+    ///
+    /// let rules = UnsignedRules {
+    ///     max: Some(100),
+    ///     ..UnsignedRules::default()
+    /// };
+    /// let quantity = Unsigned::parse_custom(Some(6), rules.clone()).expect("Expected valid quantity");
+    /// let price = Unsigned::parse_custom(Some(20), rules.clone()).expect("Expected valid price");
+    ///
+    /// assert_eq!(quantity.checked_add(&price, &rules).map(|n| n.as_usize()).ok(), Some(26));
+    /// assert!(quantity.checked_mul(&price, &rules).is_err());
+    /// ```
+    pub fn checked_add(&self, other: &Self, rules: &UnsignedRules) -> Result<Self, UnsignedError> {
+        self.checked_op(other, rules, usize::checked_add)
+    }
+
+    /// Returns the difference of `self` and `other`, validated against `rules`. The result is not
+    /// provided if either value was not provided.
+    ///
+    /// # Errors
+    /// Returns a `UnsignedError` if the difference overflows, or does not satisfy the rules.
+    pub fn checked_sub(&self, other: &Self, rules: &UnsignedRules) -> Result<Self, UnsignedError> {
+        self.checked_op(other, rules, usize::checked_sub)
+    }
+
+    /// Returns the product of `self` and `other`, validated against `rules`. The result is not
+    /// provided if either value was not provided.
+    ///
+    /// # Errors
+    /// Returns a `UnsignedError` if the product overflows, or does not satisfy the rules.
+    pub fn checked_mul(&self, other: &Self, rules: &UnsignedRules) -> Result<Self, UnsignedError> {
+        self.checked_op(other, rules, usize::checked_mul)
+    }
+
+    fn checked_op(
+        &self,
+        other: &Self,
+        rules: &UnsignedRules,
+        op: fn(usize, usize) -> Option<usize>,
+    ) -> Result<Self, UnsignedError> {
+        let value = if self.is_none() || other.is_none() {
+            None
+        } else {
+            match number_checked(op(self.0, other.0)) {
+                Ok(value) => Some(value),
+                Err(messages) => {
+                    return observe_parse(|| Err(UnsignedError::validate_new(messages.into())));
+                }
+            }
+        };
+        Self::parse_custom(value, rules.clone())
+    }
+}
+
+impl OptionalValue for Unsigned {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
 }
 
 pub trait AsUnsignedOnResult {
@@ -206,6 +376,419 @@ impl<E> AsUnsignedOnResult for Result<Unsigned, E> {
     }
 }
 
+impl TightenRules for UnsignedRules {
+    fn tighten(&self, other: &Self) -> Self {
+        Self {
+            is_mandatory: self.is_mandatory || other.is_mandatory,
+            min: tighter_min(self.min, other.min),
+            max: tighter_max(self.max, other.max),
+            forbid_zero: self.forbid_zero || other.forbid_zero,
+            must_be_even: self.must_be_even || other.must_be_even,
+            must_be_odd: self.must_be_odd || other.must_be_odd,
+            allowed_values: tighter_allowed(&self.allowed_values, &other.allowed_values),
+        }
+    }
+}
+
+impl Validator<Option<usize>, Unsigned, UnsignedError> for UnsignedRules {
+    fn validate(&self, input: Option<usize>) -> Result<Unsigned, UnsignedError> {
+        Unsigned::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for UnsignedRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl UnsignedRules {
+    /// Returns the first value from the allowed values, or else from the lower bound upward,
+    /// satisfying the rules.
+    ///
+    /// Falls back to the lower bound when no value is found, which `sample_matching` rejects.
+    pub fn arbitrary_valid_input(&self) -> usize {
+        let start = self.min.unwrap_or_default();
+        let candidates = match &self.allowed_values {
+            Some(allowed_values) => allowed_values.clone(),
+            None => (0..3).filter_map(|i| start.checked_add(i)).collect(),
+        };
+        candidates
+            .into_iter()
+            .find(|candidate| {
+                let mut messages = ValidateErrorCollector::new();
+                self.check(&mut messages, Some(*candidate));
+                messages.is_empty()
+            })
+            .unwrap_or(start)
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl Unsigned {
+    /// Returns a valid `Unsigned` for the default `UnsignedRules`.
+    pub fn valid_sample() -> Self {
+        Self::sample_matching(UnsignedRules::default())
+    }
+
+    /// Returns a valid `Unsigned` for the given rules, parsed from `arbitrary_valid_input`.
+    ///
+    /// # Panics
+    /// Panics if no input can satisfy the rules, e.g. `min` above `max`.
+    pub fn sample_matching(rules: UnsignedRules) -> Self {
+        let input = rules.arbitrary_valid_input();
+        Self::parse_custom(Some(input), rules).expect("Expected sample to be valid")
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Unsigned {
+    /// Creates an instance of `Unsigned` without validation, e.g. when loading values which were
+    /// validated before being stored. `None` gives the default value.
+    pub fn new_unchecked(value: Option<usize>) -> Self {
+        Self(value.unwrap_or_default(), value.is_none())
+    }
+}
+
+macro_rules! fixed_width_unsigned {
+    (
+        $name:ident,
+        $rules:ident,
+        $error:ident,
+        $on_result:ident,
+        $int:ty,
+        $as_int:ident,
+        $type_name:literal
+    ) => {
+        #[doc = concat!("The rules of `", stringify!($name), "`, like `UnsignedRules` for a `", stringify!($int), "`.")]
+        ///
+        /// # Default
+        ///
+        /// The value is mandatory, without bounds or value rules.
+        #[derive(Clone)]
+        pub struct $rules {
+            pub is_mandatory: bool,
+            pub min: Option<$int>,
+            pub max: Option<$int>,
+            pub forbid_zero: bool,
+            pub must_be_even: bool,
+            pub must_be_odd: bool,
+            pub allowed_values: Option<Vec<$int>>,
+        }
+
+        impl Default for $rules {
+            fn default() -> Self {
+                Self {
+                    is_mandatory: true,
+                    min: None,
+                    max: None,
+                    forbid_zero: false,
+                    must_be_even: false,
+                    must_be_odd: false,
+                    allowed_values: None,
+                }
+            }
+        }
+
+        impl
+            Into<(
+                NumberMandatoryRules,
+                NumberRangeRules<$int>,
+                NumberValueRules<$int>,
+            )> for &$rules
+        {
+            fn into(
+                self,
+            ) -> (
+                NumberMandatoryRules,
+                NumberRangeRules<$int>,
+                NumberValueRules<$int>,
+            ) {
+                (
+                    NumberMandatoryRules {
+                        is_mandatory: self.is_mandatory,
+                    },
+                    NumberRangeRules {
+                        min: self.min,
+                        max: self.max,
+                    },
+                    NumberValueRules {
+                        forbid_zero: self.forbid_zero,
+                        must_be_even: self.must_be_even,
+                        must_be_odd: self.must_be_odd,
+                        allowed_values: self.allowed_values.clone(),
+                    },
+                )
+            }
+        }
+
+        impl $rules {
+            fn rules(
+                &self,
+            ) -> (
+                NumberMandatoryRules,
+                NumberRangeRules<$int>,
+                NumberValueRules<$int>,
+            ) {
+                self.into()
+            }
+
+            fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<$int>) {
+                if !self.is_mandatory && subject.is_none() {
+                    return;
+                }
+                let (mandatory_rule, range_rule, value_rule) = self.rules();
+                mandatory_rule.check(messages, subject);
+                if !messages.is_empty() {
+                    return;
+                }
+                range_rule.check(messages, subject);
+                value_rule.check(messages, subject);
+            }
+
+            /// Returns the constraints enforced by the rules.
+            pub fn describe(&self) -> Vec<Constraint> {
+                let (mandatory_rule, range_rule, value_rule) = self.rules();
+                let mut constraints = mandatory_rule.describe();
+                constraints.extend(range_rule.describe());
+                constraints.extend(value_rule.describe());
+                constraints
+            }
+        }
+
+        #[doc = concat!("Represents an error of `", stringify!($name), "` validation.")]
+        #[derive(Debug, Error, PartialEq, Clone, Default)]
+        #[error("Unsigned Validation Error")]
+        pub struct $error(pub ValidateErrorStore);
+
+        impl ValidationCheck for $error {
+            fn validate_new(messages: ValidateErrorStore) -> Self {
+                Self(messages)
+            }
+        }
+
+        impl From<&$error> for ValidateErrorStore {
+            fn from(error: &$error) -> Self {
+                error.0.clone()
+            }
+        }
+
+        #[doc = concat!("A validated `", stringify!($int), "`, with a flag set if it was not provided.")]
+        #[derive(Debug, PartialEq, Clone)]
+        #[cfg_attr(
+            feature = "diesel",
+            derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+            diesel(sql_type = diesel::sql_types::BigInt)
+        )]
+        pub struct $name($int, bool);
+
+        #[cfg(any(feature = "allow-default-value", test))]
+        impl Default for $name {
+            fn default() -> Self {
+                Self(0, true)
+            }
+        }
+
+        impl $name {
+            /// Parses an optional value according to the provided rules.
+            ///
+            /// # Errors
+            /// Returns an error if the value does not satisfy the `rules`.
+            pub fn parse_custom(s: Option<$int>, rules: $rules) -> Result<Self, $error> {
+                observe_parse(|| {
+                    let is_none = s.is_none();
+                    let mut messages = ValidateErrorCollector::new();
+                    rules.check(&mut messages, s);
+                    $error::validate_check(messages)?;
+                    Ok(Self(s.unwrap_or_default(), is_none))
+                })
+            }
+
+            /// Parses an optional value, applying the default rules.
+            pub fn parse(s: Option<$int>) -> Result<Self, $error> {
+                Self::parse_custom(s, $rules::default())
+            }
+
+            /// Parses the text of a number according to the provided rules. A missing or blank
+            /// text is parsed as `None`, so the mandatory rule reports it.
+            ///
+            /// # Errors
+            #[doc = concat!("Returns an error if the text is not a valid `", stringify!($int), "`, or if the number does not satisfy the `rules`.")]
+            pub fn parse_str_custom(s: Option<&str>, rules: $rules) -> Result<Self, $error> {
+                match number_from_str(s, $type_name) {
+                    Ok(number) => Self::parse_custom(number, rules),
+                    Err(messages) => observe_parse(|| Err($error::validate_new(messages.into()))),
+                }
+            }
+
+            /// Parses the text of a number, using the default rules.
+            pub fn parse_str(s: Option<&str>) -> Result<Self, $error> {
+                Self::parse_str_custom(s, $rules::default())
+            }
+
+            #[doc = concat!("Returns the value as a `", stringify!($int), "`.")]
+            pub fn $as_int(&self) -> $int {
+                self.0
+            }
+
+            /// Returns `true` if the value was not provided.
+            pub fn is_none(&self) -> bool {
+                self.1
+            }
+
+            /// Converts the value into an `Option`, `None` if it was not provided.
+            pub fn into_option(self) -> Option<Self> {
+                if self.1 { None } else { Some(self) }
+            }
+        }
+
+        impl OptionalValue for $name {
+            fn is_absent(&self) -> bool {
+                self.is_none()
+            }
+        }
+
+        pub trait $on_result {
+            fn $as_int(&self) -> $int;
+        }
+
+        impl<E> $on_result for Result<$name, E> {
+            fn $as_int(&self) -> $int {
+                self.as_ref().ok().map_or(0, |u| u.$as_int())
+            }
+        }
+
+        impl Validator<Option<$int>, $name, $error> for $rules {
+            fn validate(&self, input: Option<$int>) -> Result<$name, $error> {
+                $name::parse_custom(input, self.clone())
+            }
+        }
+
+        impl OptionalRules for $rules {
+            fn optional(self) -> Self {
+                Self {
+                    is_mandatory: false,
+                    ..self
+                }
+            }
+        }
+
+        #[cfg(feature = "trusted-input")]
+        impl $name {
+            /// Creates an instance without validation, e.g. when loading values which were
+            /// validated before being stored. `None` gives the default value.
+            pub fn new_unchecked(value: Option<$int>) -> Self {
+                Self(value.unwrap_or_default(), value.is_none())
+            }
+        }
+    };
+}
+
+fixed_width_unsigned!(
+    U64,
+    U64Rules,
+    U64Error,
+    AsU64OnResult,
+    u64,
+    as_u64,
+    "unsigned integer"
+);
+
+fixed_width_unsigned!(
+    U32,
+    U32Rules,
+    U32Error,
+    AsU32OnResult,
+    u32,
+    as_u32,
+    "unsigned integer"
+);
+
+/// A validated, optional `NonZeroU64`, e.g. for database IDs starting at one.
+///
+/// Parsed with `U64Rules`, zero being rejected whatever the `forbid_zero` rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "allow-default-value", test), derive(Default))]
+pub struct NonZeroUnsigned(Option<NonZeroU64>);
+
+impl NonZeroUnsigned {
+    /// Parses an optional value according to the provided rules, rejecting zero.
+    ///
+    /// # Errors
+    /// Returns a `U64Error` if the value is zero or does not satisfy the `rules`.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::numbers::unsigned::{NonZeroUnsigned, U64Rules};
+    ///
+    /// let id = NonZeroUnsigned::parse_str_custom(Some("4294967296"), U64Rules::default())
+    ///     .expect("Expected valid id");
+    /// assert_eq!(id.as_u64(), Some(4_294_967_296));
+    /// assert!(NonZeroUnsigned::parse_str_custom(Some("0"), U64Rules::default()).is_err());
+    /// ```
+    pub fn parse_custom(s: Option<u64>, rules: U64Rules) -> Result<Self, U64Error> {
+        let rules = U64Rules {
+            forbid_zero: true,
+            ..rules
+        };
+        U64::parse_custom(s, rules)
+            .map(|u| Self(u.into_option().and_then(|u| NonZeroU64::new(u.0))))
+    }
+
+    /// Parses an optional value, applying the default `U64Rules`.
+    pub fn parse(s: Option<u64>) -> Result<Self, U64Error> {
+        Self::parse_custom(s, U64Rules::default())
+    }
+
+    /// Parses the text of a number according to the provided rules, rejecting zero.
+    ///
+    /// # Errors
+    /// Returns a `U64Error` if the text is not a valid `u64`, is zero, or the number does not
+    /// satisfy the `rules`.
+    pub fn parse_str_custom(s: Option<&str>, rules: U64Rules) -> Result<Self, U64Error> {
+        match number_from_str(s, "unsigned integer") {
+            Ok(number) => Self::parse_custom(number, rules),
+            Err(messages) => observe_parse(|| Err(U64Error::validate_new(messages.into()))),
+        }
+    }
+
+    /// Parses the text of a number, using the default `U64Rules`.
+    pub fn parse_str(s: Option<&str>) -> Result<Self, U64Error> {
+        Self::parse_str_custom(s, U64Rules::default())
+    }
+
+    /// Returns the value, `None` if it was not provided.
+    pub fn as_non_zero_u64(&self) -> Option<NonZeroU64> {
+        self.0
+    }
+
+    /// Returns the value as a `u64`, `None` if it was not provided.
+    pub fn as_u64(&self) -> Option<u64> {
+        self.0.map(NonZeroU64::get)
+    }
+
+    /// Returns `true` if the value was not provided.
+    pub fn is_none(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl OptionalValue for NonZeroUnsigned {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl Validator<Option<u64>, NonZeroUnsigned, U64Error> for U64Rules {
+    fn validate(&self, input: Option<u64>) -> Result<NonZeroUnsigned, U64Error> {
+        NonZeroUnsigned::parse_custom(input, self.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,4 +806,43 @@ mod tests {
         let unsigned = Unsigned::parse(None);
         assert!(unsigned.is_err());
     }
+
+    #[test]
+    fn test_unsigned_value_rules() {
+        let rules = || UnsignedRules {
+            min: Some(1),
+            max: Some(100),
+            allowed_values: Some(vec![10, 25, 50, 100]),
+            ..UnsignedRules::default()
+        };
+        assert!(Unsigned::parse_custom(Some(20), rules()).is_err());
+        assert!(Unsigned::parse_custom(Some(25), rules()).is_ok());
+    }
+
+    #[test]
+    fn test_u64_parse_str() {
+        let id = U64::parse_str(Some(" 18446744073709551615 ")).expect("Expected valid u64");
+        assert_eq!(id.as_u64(), u64::MAX);
+        assert!(U64::parse_str(Some("-1")).is_err());
+        let rules = U32Rules {
+            max: Some(10),
+            ..U32Rules::default()
+        };
+        let error = U32::parse_str_custom(Some("11"), rules).expect_err("Expected too large");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be at most 10"]
+        );
+    }
+
+    #[test]
+    fn test_non_zero_unsigned() {
+        assert!(NonZeroUnsigned::parse(Some(0)).is_err());
+        let rules = U64Rules {
+            is_mandatory: false,
+            ..U64Rules::default()
+        };
+        let id = NonZeroUnsigned::parse_custom(None, rules).expect("Expected missing id");
+        assert!(id.is_none());
+    }
 }
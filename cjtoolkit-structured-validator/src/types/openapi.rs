@@ -0,0 +1,358 @@
+//! This module contains the OpenAPI 3.1 support of the rules.
+//!
+//! `OpenApiSchema` turns the constraints listed by the `describe` method of the rules into a
+//! raw OpenAPI 3.1 schema object, so the API documentation reflects the constraints enforced by
+//! the crate. The constraints without JSON Schema keyword, e.g. the characters a password must
+//! contain, are listed by locale key under the `x-constraints` extension. A `json::Schema`
+//! becomes an object or array schema, with the mandatory fields of objects under `required`.
+//!
+//! The schemas can be embedded in the components of a hand-written document, or of one
+//! generated by `utoipa`, as raw JSON.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::json::Schema;
+//! use cjtoolkit_structured_validator::types::name::NameRules;
+//! use cjtoolkit_structured_validator::types::numbers::unsigned::UnsignedRules;
+//! use cjtoolkit_structured_validator::types::openapi::OpenApiSchema;
+//! use serde_json::json;
+//!
+//! let schema = Schema::object([
+//!     ("name", Schema::Name(NameRules::default())),
+//!     (
+//!         "age",
+//!         Schema::Unsigned(UnsignedRules {
+//!             is_mandatory: false,
+//!             ..UnsignedRules::default()
+//!         }),
+//!     ),
+//! ]);
+//!
+//! assert_eq!(
+//!     schema.openapi_schema(),
+//!     json!({
+//!         "type": "object",
+//!         "properties": {
+//!             "name": { "type": "string", "minLength": 5, "maxLength": 20 },
+//!             "age": { "type": ["integer", "null"], "minimum": 0, "maximum": 255 },
+//!         },
+//!         "required": ["name"],
+//!     })
+//! );
+//! ```
+
+use crate::base::collection_rules::CollectionRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{LocaleValue, RuleViolation};
+use crate::common::locale_keys;
+use crate::types::description::DescriptionRules;
+#[cfg(feature = "email")]
+use crate::types::email::EmailRules;
+use crate::types::json::Schema;
+use crate::types::name::NameRules;
+use crate::types::net::domain::DomainRules;
+use crate::types::net::hostname::HostnameRules;
+use crate::types::numbers::float::FloatRules;
+use crate::types::numbers::integer::IntegerRules;
+use crate::types::numbers::unsigned::UnsignedRules;
+use crate::types::password::PasswordRules;
+#[cfg(feature = "url")]
+use crate::types::url::UrlRules;
+use crate::types::username::UsernameRules;
+use serde_json::{Map, Value, json};
+
+/// A trait for rules that can be documented as an OpenAPI 3.1 schema object.
+///
+/// # Required Methods
+/// - `openapi_schema`: Returns the schema object of the values satisfying the rules.
+/// - `is_required`: Whether the value is mandatory, i.e. listed under `required` when it is a
+///   field of an object.
+pub trait OpenApiSchema {
+    fn openapi_schema(&self) -> Value;
+
+    fn is_required(&self) -> bool;
+}
+
+fn locale_value(value: &LocaleValue) -> Value {
+    match value {
+        LocaleValue::String(s) => json!(s),
+        LocaleValue::Uint(n) => json!(n),
+        LocaleValue::Int(n) => json!(n),
+        LocaleValue::Float(n) => json!(n),
+    }
+}
+
+/// Builds the schema of a value of the JSON type `type_name`, nullable unless mandatory.
+fn constraints_schema(type_name: &str, is_mandatory: bool, constraints: &[Constraint]) -> Value {
+    let mut schema = Map::new();
+    schema.insert(
+        "type".to_string(),
+        if is_mandatory {
+            json!(type_name)
+        } else {
+            json!([type_name, "null"])
+        },
+    );
+    let mut extensions = Vec::new();
+    for constraint in constraints {
+        match &constraint.kind {
+            RuleViolation::Mandatory => {}
+            RuleViolation::MinLength { min } => {
+                schema.insert("minLength".to_string(), json!(min));
+            }
+            RuleViolation::MaxLength { max } => {
+                schema.insert("maxLength".to_string(), json!(max));
+            }
+            RuleViolation::Range { min, max } => {
+                if let Some(min) = min {
+                    schema.insert("minimum".to_string(), locale_value(min));
+                }
+                if let Some(max) = max {
+                    schema.insert("maximum".to_string(), locale_value(max));
+                }
+            }
+            RuleViolation::MinItems { min } => {
+                schema.insert("minItems".to_string(), json!(min));
+            }
+            RuleViolation::MaxItems { max } => {
+                schema.insert("maxItems".to_string(), json!(max));
+            }
+            RuleViolation::MustBeEven => {
+                schema.insert("multipleOf".to_string(), json!(2));
+            }
+            RuleViolation::ForbidZero => {
+                schema.insert("not".to_string(), json!({ "const": 0 }));
+            }
+            RuleViolation::Pattern { name } if name == "email" => {
+                schema.insert("format".to_string(), json!("email"));
+            }
+            RuleViolation::Pattern { name } if name == "url" => {
+                schema.insert("format".to_string(), json!("uri"));
+            }
+            RuleViolation::Pattern { name } if name == "domain" || name == "fqdn" => {
+                schema.insert("format".to_string(), json!("hostname"));
+            }
+            _ => extensions.push(json!(constraint.locale_key)),
+        }
+    }
+    if !extensions.is_empty() {
+        schema.insert("x-constraints".to_string(), Value::Array(extensions));
+    }
+    Value::Object(schema)
+}
+
+/// Replaces the `x-constraints` entry of the allowed values by an `enum`.
+fn with_allowed_values<T: Into<Value> + Copy>(
+    mut schema: Value,
+    allowed: &Option<Vec<T>>,
+) -> Value {
+    let (Some(allowed), Value::Object(map)) = (allowed, &mut schema) else {
+        return schema;
+    };
+    map.insert(
+        "enum".to_string(),
+        allowed.iter().map(|value| (*value).into()).collect(),
+    );
+    if let Some(Value::Array(extensions)) = map.get_mut("x-constraints") {
+        extensions.retain(|key| key != locale_keys::VALIDATE_NUMBER_NOT_ALLOWED);
+        if extensions.is_empty() {
+            map.remove("x-constraints");
+        }
+    }
+    schema
+}
+
+macro_rules! string_schema {
+    ($rules:ty) => {
+        impl OpenApiSchema for $rules {
+            fn openapi_schema(&self) -> Value {
+                constraints_schema("string", self.is_mandatory, &self.describe())
+            }
+
+            fn is_required(&self) -> bool {
+                self.is_mandatory
+            }
+        }
+    };
+}
+
+string_schema!(NameRules);
+string_schema!(UsernameRules);
+string_schema!(DescriptionRules);
+#[cfg(feature = "email")]
+string_schema!(EmailRules);
+#[cfg(feature = "url")]
+string_schema!(UrlRules);
+string_schema!(DomainRules);
+
+impl OpenApiSchema for HostnameRules {
+    fn openapi_schema(&self) -> Value {
+        let mut schema = constraints_schema("string", self.is_mandatory, &self.describe());
+        schema["format"] = json!("hostname");
+        schema
+    }
+
+    fn is_required(&self) -> bool {
+        self.is_mandatory
+    }
+}
+
+impl OpenApiSchema for PasswordRules {
+    fn openapi_schema(&self) -> Value {
+        let mut schema = constraints_schema("string", self.is_mandatory, &self.describe());
+        schema["format"] = json!("password");
+        schema["writeOnly"] = json!(true);
+        schema
+    }
+
+    fn is_required(&self) -> bool {
+        self.is_mandatory
+    }
+}
+
+impl OpenApiSchema for IntegerRules {
+    fn openapi_schema(&self) -> Value {
+        let schema = constraints_schema("integer", self.is_mandatory, &self.describe());
+        with_allowed_values(schema, &self.allowed_values)
+    }
+
+    fn is_required(&self) -> bool {
+        self.is_mandatory
+    }
+}
+
+impl OpenApiSchema for UnsignedRules {
+    fn openapi_schema(&self) -> Value {
+        let mut schema = constraints_schema("integer", self.is_mandatory, &self.describe());
+        if schema.get("minimum").is_none() {
+            schema["minimum"] = json!(0);
+        }
+        with_allowed_values(schema, &self.allowed_values)
+    }
+
+    fn is_required(&self) -> bool {
+        self.is_mandatory
+    }
+}
+
+impl OpenApiSchema for FloatRules {
+    fn openapi_schema(&self) -> Value {
+        constraints_schema("number", self.is_mandatory, &self.describe())
+    }
+
+    fn is_required(&self) -> bool {
+        self.is_mandatory
+    }
+}
+
+impl Schema {
+    fn as_openapi_schema(&self) -> &dyn OpenApiSchema {
+        match self {
+            Self::Name(rules) => rules,
+            Self::Username(rules) => rules,
+            Self::Description(rules) => rules,
+            #[cfg(feature = "email")]
+            Self::Email(rules) => rules,
+            #[cfg(feature = "url")]
+            Self::Url(rules) => rules,
+            Self::Integer(rules) => rules,
+            Self::Unsigned(rules) => rules,
+            Self::Float(rules) => rules,
+            Self::Object(_) | Self::Array(..) => self,
+        }
+    }
+}
+
+fn array_schema(items: &Schema, rules: &CollectionRules) -> Value {
+    let mut schema = constraints_schema("array", rules.is_mandatory, &rules.describe());
+    schema["items"] = items.openapi_schema();
+    schema
+}
+
+impl OpenApiSchema for Schema {
+    fn openapi_schema(&self) -> Value {
+        match self {
+            Self::Object(fields) => {
+                let properties: Map<String, Value> = fields
+                    .iter()
+                    .map(|(name, schema)| (name.clone(), schema.openapi_schema()))
+                    .collect();
+                let required: Vec<&str> = fields
+                    .iter()
+                    .filter(|(_, schema)| schema.is_required())
+                    .map(|(name, _)| name.as_str())
+                    .collect();
+                let mut schema = json!({ "type": "object", "properties": properties });
+                if !required.is_empty() {
+                    schema["required"] = json!(required);
+                }
+                schema
+            }
+            Self::Array(items, rules) => array_schema(items, rules),
+            _ => self.as_openapi_schema().openapi_schema(),
+        }
+    }
+
+    fn is_required(&self) -> bool {
+        match self {
+            // A missing object is validated field by field, so it is required if any field is.
+            Self::Object(fields) => fields.iter().any(|(_, schema)| schema.is_required()),
+            Self::Array(_, rules) => rules.is_mandatory,
+            _ => self.as_openapi_schema().is_required(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_schema() {
+        assert_eq!(
+            PasswordRules::default().openapi_schema(),
+            json!({
+                "type": "string",
+                "minLength": 8,
+                "maxLength": 64,
+                "format": "password",
+                "writeOnly": true,
+                "x-constraints": [
+                    "validate-must-have-special-chars",
+                    "validate-must-have-uppercase-and-lowercase",
+                    "validate-must-have-digit",
+                ],
+            })
+        );
+        let rules = IntegerRules {
+            must_be_even: true,
+            allowed_values: Some(vec![2, 4]),
+            ..IntegerRules::default()
+        };
+        assert_eq!(
+            rules.openapi_schema(),
+            json!({
+                "type": "integer",
+                "minimum": 0,
+                "maximum": 255,
+                "multipleOf": 2,
+                "enum": [2, 4],
+            })
+        );
+        let schema = Schema::array(
+            Schema::Name(NameRules::default()),
+            CollectionRules {
+                max_items: Some(3),
+                ..CollectionRules::default()
+            },
+        );
+        assert_eq!(
+            schema.openapi_schema(),
+            json!({
+                "type": "array",
+                "maxItems": 3,
+                "items": { "type": "string", "minLength": 5, "maxLength": 20 },
+            })
+        );
+    }
+}
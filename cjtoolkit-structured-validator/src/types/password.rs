@@ -1,11 +1,21 @@
 //! This module contains structures and traits for working with passwords.
 
-use crate::base::string_rules::{StringLengthRules, StringMandatoryRules, StringSpecialCharRules};
+use crate::base::string_rules::{
+    LengthUnit, StringLengthRules, StringMandatoryRules, StringSpecialCharRules,
+};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{
-    LocaleData, LocaleMessage, ValidateErrorCollector, ValidateErrorStore,
+    LocaleData, LocaleMessage, RuleViolation, ValidateErrorCollector, ValidateErrorStore,
 };
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::{observe_parse, rule_result};
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::rule_outcomes::RuleOutcomes;
 use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::tenant_policy::{TightenRules, tighter_max, tighter_min};
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -35,6 +45,14 @@ use thiserror::Error;
 /// - `max_length`:
 ///   The maximum allowed length for the password, if specified.
 ///   If `None`, there is no maximum length restriction.
+///
+/// - `length_unit`:
+///   The unit the length of the password is counted in, graphemes by default.
+///
+/// - `max_errors`:
+///   The maximum number of errors reported, the most relevant being kept, e.g. `Some(1)` to
+///   show one requirement at a time. If `None`, every error is reported.
+#[derive(Clone)]
 pub struct PasswordRules {
     pub is_mandatory: bool,
     pub must_have_uppercase: bool,
@@ -43,6 +61,8 @@ pub struct PasswordRules {
     pub must_have_digit: bool,
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
+    pub length_unit: LengthUnit,
+    pub max_errors: Option<usize>,
 }
 
 impl Default for PasswordRules {
@@ -55,6 +75,8 @@ impl Default for PasswordRules {
             must_have_digit: true,
             min_length: Some(8),
             max_length: Some(64),
+            length_unit: LengthUnit::Graphemes,
+            max_errors: None,
         }
     }
 }
@@ -76,10 +98,12 @@ impl
         (
             StringMandatoryRules {
                 is_mandatory: self.is_mandatory,
+                treat_whitespace_as_empty: false,
             },
             StringLengthRules {
                 min_length: self.min_length,
                 max_length: self.max_length,
+                unit: self.length_unit,
             },
             StringSpecialCharRules {
                 must_have_uppercase: self.must_have_uppercase,
@@ -118,6 +142,16 @@ impl PasswordRules {
         }
         length_rule.check(messages, subject);
         special_char_rule.check(messages, subject);
+        messages.truncate_by_priority(self.max_errors);
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, length_rule, special_char_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(length_rule.describe());
+        constraints.extend(special_char_rule.describe());
+        constraints
     }
 }
 
@@ -192,7 +226,11 @@ pub struct PasswordDoesNotMatchLocale;
 
 impl LocaleMessage for PasswordDoesNotMatchLocale {
     fn get_locale_data(&self) -> Arc<LocaleData> {
-        LocaleData::new("validate-password-does-not-match")
+        LocaleData::new(locale_keys::VALIDATE_PASSWORD_DOES_NOT_MATCH)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::DoesNotMatch
     }
 }
 
@@ -238,13 +276,15 @@ impl Password {
     /// * The function uses a `ValidateErrorCollector` to collect and report multiple validation errors simultaneously.
     /// * If `s` is `None`, it will default to an empty string (`""`) for validation.
     pub fn parse_custom(s: Option<&str>, rules: PasswordRules) -> Result<Self, PasswordError> {
-        let is_none = s.is_none();
-        let s = s.unwrap_or_default();
-        let subject = s.as_string_validator();
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, &subject, is_none);
-        PasswordError::validate_check(messages)?;
-        Ok(Self(s.to_string(), is_none))
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            PasswordError::validate_check(messages)?;
+            Ok(Self(s.to_string(), is_none))
+        })
     }
 
     /// Parses the provided input string (`Option<&str>`) and attempts to create an instance of the type implementing this function.
@@ -295,6 +335,22 @@ impl Password {
         Self::parse_custom(s, PasswordRules::default())
     }
 
+    /// Checks the password against every rule, e.g. for a checklist of the requirements shown
+    /// while the password is typed, returning the outcome of each rule and the errors.
+    ///
+    /// Unlike `parse_custom`, the length and character rules are checked even when the password
+    /// is empty, and `max_errors` is not applied.
+    pub fn evaluate(s: Option<&str>, rules: PasswordRules) -> RuleOutcomes {
+        let s = s.unwrap_or_default();
+        let subject = s.as_string_validator();
+        let (mandatory_rule, length_rule, special_char_rule) = rules.rules();
+        RuleOutcomes::record(|messages| {
+            mandatory_rule.check(messages, &subject);
+            length_rule.check(messages, &subject);
+            special_char_rule.check(messages, &subject);
+        })
+    }
+
     /// Validates that the provided password confirmation matches the original password.
     ///
     /// # Parameters
@@ -314,17 +370,21 @@ impl Password {
     /// If any error is detected (e.g., mismatched passwords), it is wrapped and returned as part
     /// of the `PasswordError`.
     pub fn parse_confirm(&self, password_confirm: &str) -> Result<Self, PasswordError> {
-        let mut msgs = ValidateErrorCollector::new();
+        observe_parse(|| {
+            let mut msgs = ValidateErrorCollector::new();
 
-        (password_confirm != self.as_str()).then(|| {
-            msgs.push((
-                "Password does not match".to_string(),
-                Box::new(PasswordDoesNotMatchLocale),
-            ));
-        });
+            let is_match = password_confirm == self.as_str();
+            rule_result(locale_keys::VALIDATE_PASSWORD_DOES_NOT_MATCH, is_match);
+            (!is_match).then(|| {
+                msgs.push((
+                    "Password does not match".to_string(),
+                    Box::new(PasswordDoesNotMatchLocale),
+                ));
+            });
 
-        PasswordError::validate_check(msgs)?;
-        Ok(self.clone())
+            PasswordError::validate_check(msgs)?;
+            Ok(self.clone())
+        })
     }
 
     /// Provides a string slice reference to the inner value.
@@ -351,12 +411,117 @@ impl Password {
     }
 }
 
+impl OptionalValue for Password {
+    fn is_absent(&self) -> bool {
+        self.1
+    }
+}
+
 impl Into<String> for &Password {
     fn into(self) -> String {
         self.as_str().to_string()
     }
 }
 
+impl TryFrom<Option<&str>> for Password {
+    type Error = PasswordError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Password {
+    type Error = PasswordError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for Password {
+    type Error = PasswordError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl TightenRules for PasswordRules {
+    fn tighten(&self, other: &Self) -> Self {
+        Self {
+            is_mandatory: self.is_mandatory || other.is_mandatory,
+            min_length: tighter_min(self.min_length, other.min_length),
+            max_length: tighter_max(self.max_length, other.max_length),
+            length_unit: self.length_unit,
+            must_have_uppercase: self.must_have_uppercase || other.must_have_uppercase,
+            must_have_lowercase: self.must_have_lowercase || other.must_have_lowercase,
+            must_have_special_chars: self.must_have_special_chars || other.must_have_special_chars,
+            must_have_digit: self.must_have_digit || other.must_have_digit,
+            max_errors: self.max_errors,
+        }
+    }
+}
+
+impl Validator<Option<&str>, Password, PasswordError> for PasswordRules {
+    fn validate(&self, input: Option<&str>) -> Result<Password, PasswordError> {
+        Password::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for PasswordRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl PasswordRules {
+    /// Returns an input satisfying the rules, `Aa1!sample` repeated or truncated to fit the length
+    /// constraints. It starts with an uppercase letter, a lowercase letter, a digit and a special
+    /// character, so the character rules are met from four characters.
+    pub fn arbitrary_valid_input(&self) -> String {
+        crate::common::fixtures::sample_string("Aa1!sample", self.min_length, self.max_length)
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl Password {
+    /// Returns a valid `Password` for the default `PasswordRules`.
+    pub fn valid_sample() -> Self {
+        Self::sample_matching(PasswordRules::default())
+    }
+
+    /// Returns a valid `Password` for the given rules, parsed from `arbitrary_valid_input`.
+    ///
+    /// # Panics
+    /// Panics if no input can satisfy the rules, e.g. a `max_length` too short for the required characters.
+    pub fn sample_matching(rules: PasswordRules) -> Self {
+        let input = rules.arbitrary_valid_input();
+        Self::parse_custom(Some(&input), rules).expect("Expected sample to be valid")
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Password {
+    /// Creates an instance of `Password` without validation, e.g. when loading values which were
+    /// validated before being stored. The string is kept as is, `None` gives an empty value.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(s.to_string(), is_none)
+    }
+
+    /// Creates an instance of `Password` from a stored string without validation, so stored values
+    /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -374,4 +539,50 @@ mod tests {
         let password = password.parse_confirm("match");
         assert!(password.is_ok());
     }
+
+    #[test]
+    fn test_password_evaluate() {
+        let outcomes = Password::evaluate(None, PasswordRules::default());
+        assert_eq!(outcomes.passed("validate-cannot-be-empty"), Some(false));
+        assert_eq!(outcomes.passed("validate-min-length"), Some(false));
+        assert_eq!(outcomes.passed("validate-max-length"), Some(true));
+        assert_eq!(outcomes.passed("validate-must-have-digit"), Some(false));
+
+        let outcomes = Password::evaluate(Some("Password1!"), PasswordRules::default());
+        assert!(outcomes.is_valid());
+        assert_eq!(outcomes.passed_count(), outcomes.outcomes.len());
+        assert!(outcomes.errors.0.is_empty());
+    }
+
+    #[test]
+    fn test_password_max_errors() {
+        let error = Password::parse(Some("abc"))
+            .err()
+            .expect("Expected password to be invalid");
+        assert_eq!(error.0.0.len(), 4);
+
+        let rules = PasswordRules {
+            max_errors: Some(1),
+            ..PasswordRules::default()
+        };
+        let error = Password::parse_custom(Some("abc"), rules)
+            .err()
+            .expect("Expected password to be invalid");
+        assert_eq!(
+            error.0.violations(),
+            vec![crate::common::rule_violation::RuleViolation::MinLength { min: 8 }]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "test-fixtures")]
+    fn test_password_sample_matching() {
+        let rules = PasswordRules {
+            min_length: Some(4),
+            max_length: Some(4),
+            ..PasswordRules::default()
+        };
+        assert_eq!(rules.arbitrary_valid_input(), "Aa1!");
+        assert!(Password::parse(Some(Password::valid_sample().as_str())).is_ok());
+    }
 }
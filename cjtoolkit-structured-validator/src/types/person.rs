@@ -0,0 +1,235 @@
+//! This module contains structures for working with a person's full name.
+//!
+//! `PersonName` validates a first, middle and last name together, reusing the name aliases,
+//! and keeps the result of each part in `PersonNameError`, so every invalid part can be
+//! reported at once.
+
+use crate::common::constraint::Constraint;
+use crate::common::flag_error::FlagCounter;
+use crate::common::validator::Validator;
+use crate::types::name::name_alias::{
+    FirstName, FirstNameError, FirstNameRules, LastName, LastNameError, LastNameRules, MiddleName,
+    MiddleNameError, MiddleNameRules,
+};
+use thiserror::Error;
+
+/// The order in which the parts of a name are written by `PersonName::full_name`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum NameOrder {
+    /// First name, middle name, then last name, e.g. `John Ronald Tolkien`.
+    #[default]
+    GivenFirst,
+    /// Last name, first name, then middle name, e.g. `Mao Ze Dong`.
+    FamilyFirst,
+}
+
+/// A structure representing the rules associated with each part of a person's name.
+///
+/// # Fields
+///
+/// * `first` - The rules of the first name, mandatory and 1 to 50 characters by default.
+/// * `middle` - The rules of the middle name, optional and up to 50 characters by default.
+/// * `last` - The rules of the last name, mandatory and 1 to 50 characters by default.
+/// * `order` - The order used by `PersonName::full_name`.
+#[derive(Clone, Default)]
+pub struct PersonNameRules {
+    pub first: FirstNameRules,
+    pub middle: MiddleNameRules,
+    pub last: LastNameRules,
+    pub order: NameOrder,
+}
+
+/// Represents an error that occurs during person name validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Person Name Validation Error"`.
+///
+/// # Fields
+/// - `first`: The result of the first name validation.
+/// - `middle`: The result of the middle name validation.
+/// - `last`: The result of the last name validation.
+impl PersonNameRules {
+    /// Returns the constraints enforced by the rules of each part, keyed by `first`, `middle`
+    /// and `last`.
+    pub fn describe(&self) -> Vec<(&'static str, Vec<Constraint>)> {
+        vec![
+            ("first", self.first.describe()),
+            ("middle", self.middle.describe()),
+            ("last", self.last.describe()),
+        ]
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Clone)]
+#[error("Person Name Validation Error")]
+pub struct PersonNameError {
+    pub first: Result<FirstName, FirstNameError>,
+    pub middle: Result<MiddleName, MiddleNameError>,
+    pub last: Result<LastName, LastNameError>,
+}
+
+/// A structure representing a validated person's name.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PersonName {
+    first: FirstName,
+    middle: MiddleName,
+    last: LastName,
+    order: NameOrder,
+}
+
+impl PersonName {
+    /// Parses the parts of a person's name based on the provided validation rules.
+    ///
+    /// # Errors
+    /// Returns a `PersonNameError` holding the result of every part if any of them is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::person::{PersonName, PersonNameRules};
+    ///
+    /// let name = PersonName::parse(Some("Ada"), None, Some("Lovelace"), PersonNameRules::default());
+    /// assert_eq!(name.map(|n| n.full_name()).ok().as_deref(), Some("Ada Lovelace"));
+    ///
+    /// let err = PersonName::parse(Some(""), None, Some(""), PersonNameRules::default());
+    /// let err = err.expect_err("Expected error");
+    /// assert!(err.first.is_err() && err.middle.is_ok() && err.last.is_err());
+    /// ```
+    pub fn parse(
+        first: Option<&str>,
+        middle: Option<&str>,
+        last: Option<&str>,
+        rules: PersonNameRules,
+    ) -> Result<Self, PersonNameError> {
+        let mut flag = FlagCounter::new();
+
+        let first = flag.check(FirstName::parse_custom(first, rules.first));
+        let middle = flag.check(MiddleName::parse_custom(middle, rules.middle));
+        let last = flag.check(LastName::parse_custom(last, rules.last));
+
+        if flag.is_flagged() {
+            return Err(PersonNameError {
+                first,
+                middle,
+                last,
+            });
+        }
+        Ok(Self {
+            first: first.expect("Expected first name to be valid"),
+            middle: middle.expect("Expected middle name to be valid"),
+            last: last.expect("Expected last name to be valid"),
+            order: rules.order,
+        })
+    }
+
+    /// Returns the first name.
+    pub fn first(&self) -> &FirstName {
+        &self.first
+    }
+
+    /// Returns the middle name, `None` if it was not provided or is empty.
+    pub fn middle(&self) -> Option<&MiddleName> {
+        if self.middle.as_str().is_empty() {
+            None
+        } else {
+            Some(&self.middle)
+        }
+    }
+
+    /// Returns the last name.
+    pub fn last(&self) -> &LastName {
+        &self.last
+    }
+
+    /// Returns the parts of the name joined with a space, following the `NameOrder` of the
+    /// rules. Empty parts are skipped.
+    pub fn full_name(&self) -> String {
+        self.full_name_with_order(self.order)
+    }
+
+    /// Returns the parts of the name joined with a space, following the given `NameOrder`.
+    /// Empty parts are skipped.
+    pub fn full_name_with_order(&self, order: NameOrder) -> String {
+        let parts = match order {
+            NameOrder::GivenFirst => [
+                self.first.as_str(),
+                self.middle.as_str(),
+                self.last.as_str(),
+            ],
+            NameOrder::FamilyFirst => [
+                self.last.as_str(),
+                self.first.as_str(),
+                self.middle.as_str(),
+            ],
+        };
+        parts
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl From<&PersonName> for String {
+    fn from(person_name: &PersonName) -> Self {
+        person_name.full_name()
+    }
+}
+
+impl Validator<(Option<&str>, Option<&str>, Option<&str>), PersonName, PersonNameError>
+    for PersonNameRules
+{
+    fn validate(
+        &self,
+        (first, middle, last): (Option<&str>, Option<&str>, Option<&str>),
+    ) -> Result<PersonName, PersonNameError> {
+        PersonName::parse(first, middle, last, self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_name() {
+        let name = PersonName::parse(
+            Some("John"),
+            Some("Ronald"),
+            Some("Tolkien"),
+            PersonNameRules::default(),
+        )
+        .expect("Expected valid name");
+        assert_eq!(name.full_name(), "John Ronald Tolkien");
+        assert_eq!(
+            name.full_name_with_order(NameOrder::FamilyFirst),
+            "Tolkien John Ronald"
+        );
+        assert_eq!(name.middle().map(|m| m.as_str()), Some("Ronald"));
+    }
+
+    #[test]
+    fn test_family_first_without_middle() {
+        let rules = PersonNameRules {
+            order: NameOrder::FamilyFirst,
+            ..PersonNameRules::default()
+        };
+        let name =
+            PersonName::parse(Some("Ze"), None, Some("Mao"), rules).expect("Expected valid name");
+        assert_eq!(name.full_name(), "Mao Ze");
+        assert!(name.middle().is_none());
+    }
+
+    #[test]
+    fn test_person_name_error() {
+        let err = PersonName::parse(
+            None,
+            Some(&"a".repeat(51)),
+            Some("Tolkien"),
+            PersonNameRules::default(),
+        )
+        .expect_err("Expected error");
+        assert!(err.first.is_err());
+        assert!(err.middle.is_err());
+        assert!(err.last.is_ok());
+    }
+}
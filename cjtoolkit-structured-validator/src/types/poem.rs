@@ -0,0 +1,312 @@
+//! This module contains the `poem` support of the value types.
+//!
+//! `ValidatedForm<T>` and `ValidatedJson<T>` extract the request body as the input of `T`, then
+//! validate it with `ValidateRequest`. When the validation fails, the request is rejected with
+//! the status suggested by the errors, `422 Unprocessable Entity` unless e.g. a username is
+//! already taken, and a JSON body mapping each field to its messages.
+//! The messages are translated with `poem::i18n::Locale` when `I18NResources` are attached to
+//! the endpoint, falling back to the original English messages.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use cjtoolkit_structured_validator::types::poem::{FieldErrors, ValidateRequest, ValidatedForm};
+//! use poem::{handler, post, Route};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct SignupInput {
+//!     name: Option<String>,
+//! }
+//!
+//! struct Signup {
+//!     name: Name,
+//! }
+//!
+//! impl ValidateRequest for Signup {
+//!     type Input = SignupInput;
+//!
+//!     fn validate_request(input: SignupInput) -> Result<Self, FieldErrors> {
+//!         let mut errors = FieldErrors::new();
+//!         let name = errors.check("name", Name::parse(input.name.as_deref()));
+//!         errors.into_result()?;
+//!         Ok(Self {
+//!             name: name.expect("Expected name to be valid"),
+//!         })
+//!     }
+//! }
+//!
+//! #[handler]
+//! fn signup(ValidatedForm(signup): ValidatedForm<Signup>) -> String {
+//!     format!("Hello {}", signup.name)
+//! }
+//!
+//! let app = Route::new().at("/", post(signup));
+//! ```
+
+use crate::common::locale::{LocaleData, LocaleValue, ValidateErrorStore};
+use crate::common::status_hint::StatusHint;
+use crate::common::translator::{TranslateStore, Translator};
+use ::poem::error::ResponseError;
+use ::poem::http::StatusCode;
+use ::poem::i18n::{I18NArgs, I18NResources, Locale};
+use ::poem::web::{Form, Json};
+use ::poem::{FromRequest, IntoResponse, Request, RequestBody, Response};
+use ::serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// The validation errors of a request, as a list of field names and error stores.
+///
+/// Fields without errors are not kept.
+#[derive(Debug, Clone, Default)]
+pub struct FieldErrors(pub Vec<(String, ValidateErrorStore)>);
+
+impl FieldErrors {
+    /// Creates an empty list of field errors.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Records the error of `result` under `field`, then returns `result` unchanged.
+    pub fn check<T, E>(&mut self, field: &str, result: Result<T, E>) -> Result<T, E>
+    where
+        for<'a> &'a E: Into<ValidateErrorStore>,
+    {
+        if let Err(e) = &result {
+            self.0.push((field.to_string(), e.into()));
+        }
+        result
+    }
+
+    /// Returns `true` if no field has errors.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `Ok(())` if no field has errors, otherwise `Err(self)`.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+
+    /// Returns the HTTP status suggested by the errors of every field, `None` if no field has
+    /// errors.
+    pub fn suggested_status(&self) -> Option<StatusHint> {
+        StatusHint::combine(self.0.iter().filter_map(|(_, store)| store.status_hint()))
+    }
+
+    /// Returns the messages of each field, translated with `locale` when provided,
+    /// otherwise the original messages.
+    pub fn as_messages(&self, locale: Option<&Locale>) -> BTreeMap<String, Vec<String>> {
+        let mut messages: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (field, store) in &self.0 {
+            let field_messages = messages.entry(field.clone()).or_default();
+            field_messages.extend(match locale {
+                Some(locale) => store.as_translated_messages(locale),
+                None => store.as_original_message_vec(),
+            });
+        }
+        messages
+    }
+}
+
+/// Translates with the messages of the `I18NResources` attached to the endpoint.
+impl Translator for Locale {
+    fn translate(&self, data: &LocaleData, original: &str) -> String {
+        if data.args.is_empty() {
+            return self
+                .text(&data.name)
+                .unwrap_or_else(|_| original.to_string());
+        }
+        let mut args = I18NArgs::default();
+        for (key, value) in data.args.iter() {
+            args = match value {
+                LocaleValue::String(string) => args.set(key.clone(), string.clone()),
+                LocaleValue::Uint(uint) => args.set(key.clone(), *uint),
+                LocaleValue::Int(int) => args.set(key.clone(), *int),
+                LocaleValue::Float(float) => args.set(key.clone(), *float),
+            };
+        }
+        self.text_with_args(&data.name, args)
+            .unwrap_or_else(|_| original.to_string())
+    }
+}
+
+/// A trait for request bodies which are validated after being deserialized.
+///
+/// # Associated Types
+/// - `Input`: The raw, deserialized body of the request.
+pub trait ValidateRequest: Sized {
+    type Input: DeserializeOwned;
+
+    fn validate_request(input: Self::Input) -> Result<Self, FieldErrors>;
+}
+
+/// The rejection of a request failing validation, responding with the status suggested by
+/// the errors and the messages of each field as JSON.
+///
+/// # Fields
+/// - `0`: The messages of each field.
+/// - `1`: The status suggested by the errors.
+#[derive(Debug, Error, Clone, PartialEq)]
+#[error("Validation failed")]
+pub struct ValidationRejection(pub BTreeMap<String, Vec<String>>, pub StatusHint);
+
+impl ValidationRejection {
+    /// Builds the rejection of `errors`, translated with `locale` when provided.
+    pub fn new(errors: &FieldErrors, locale: Option<&Locale>) -> Self {
+        Self(
+            errors.as_messages(locale),
+            errors
+                .suggested_status()
+                .unwrap_or(StatusHint::UnprocessableEntity),
+        )
+    }
+
+    /// Builds the rejection of `errors`, translated with the `Locale` of the request when
+    /// `I18NResources` are attached to the endpoint.
+    pub async fn from_request(req: &Request, errors: &FieldErrors) -> Self {
+        let locale = match req.extensions().get::<I18NResources>() {
+            Some(_) => Locale::from_request_without_body(req).await.ok(),
+            None => None,
+        };
+        Self::new(errors, locale.as_ref())
+    }
+}
+
+impl ResponseError for ValidationRejection {
+    fn status(&self) -> StatusCode {
+        StatusCode::from_u16(self.1.status_code()).unwrap_or(StatusCode::UNPROCESSABLE_ENTITY)
+    }
+
+    fn as_response(&self) -> Response {
+        let mut response = Json(&self.0).into_response();
+        response.set_status(self.status());
+        response
+    }
+}
+
+/// An extractor validating a `application/x-www-form-urlencoded` request body,
+/// or the query string of a `GET` request.
+pub struct ValidatedForm<T>(pub T);
+
+impl<'a, T: ValidateRequest + Send> FromRequest<'a> for ValidatedForm<T> {
+    async fn from_request(req: &'a Request, body: &mut RequestBody) -> ::poem::Result<Self> {
+        let Form(input) = Form::<T::Input>::from_request(req, body).await?;
+        match T::validate_request(input) {
+            Ok(value) => Ok(Self(value)),
+            Err(errors) => Err(ValidationRejection::from_request(req, &errors).await.into()),
+        }
+    }
+}
+
+/// An extractor validating a JSON request body.
+pub struct ValidatedJson<T>(pub T);
+
+impl<'a, T: ValidateRequest + Send> FromRequest<'a> for ValidatedJson<T> {
+    async fn from_request(req: &'a Request, body: &mut RequestBody) -> ::poem::Result<Self> {
+        let Json(input) = Json::<T::Input>::from_request(req, body).await?;
+        match T::validate_request(input) {
+            Ok(value) => Ok(Self(value)),
+            Err(errors) => Err(ValidationRejection::from_request(req, &errors).await.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::Name;
+    use ::poem::http::header;
+    use ::poem::test::TestClient;
+    use ::poem::{EndpointExt, Route, handler, post};
+    use ::serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct SignupInput {
+        name: Option<String>,
+    }
+
+    struct Signup {
+        name: Name,
+    }
+
+    impl ValidateRequest for Signup {
+        type Input = SignupInput;
+
+        fn validate_request(input: SignupInput) -> Result<Self, FieldErrors> {
+            let mut errors = FieldErrors::new();
+            let name = errors.check("name", Name::parse(input.name.as_deref()));
+            errors.into_result()?;
+            Ok(Self {
+                name: name.expect("Expected name to be valid"),
+            })
+        }
+    }
+
+    #[handler]
+    fn signup(ValidatedJson(signup): ValidatedJson<Signup>) -> String {
+        signup.name.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_validated_json_ok() {
+        let cli = TestClient::new(Route::new().at("/", post(signup)));
+        let resp = cli
+            .post("/")
+            .body_json(&serde_json::json!({"name": "ValidName"}))
+            .send()
+            .await;
+        resp.assert_status_is_ok();
+        resp.assert_text("ValidName").await;
+    }
+
+    #[tokio::test]
+    async fn test_validated_json_rejected_with_original_messages() {
+        let cli = TestClient::new(Route::new().at("/", post(signup)));
+        let resp = cli
+            .post("/")
+            .body_json(&serde_json::json!({"name": "No"}))
+            .send()
+            .await;
+        resp.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        resp.assert_json(serde_json::json!({"name": ["Must be at least 5 characters"]}))
+            .await;
+    }
+
+    #[test]
+    fn test_rejection_status_of_taken_username() {
+        use crate::types::username::UsernameTakenLocale;
+
+        let mut collector = crate::common::locale::ValidateErrorCollector::new();
+        collector.push(("Username is taken", Box::new(UsernameTakenLocale)));
+        let mut errors = FieldErrors::new();
+        errors.0.push(("username".to_string(), collector.into()));
+
+        let rejection = ValidationRejection::new(&errors, None);
+        assert_eq!(rejection.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_validated_json_rejected_with_translated_messages() {
+        let resources = I18NResources::builder()
+            .add_ftl(
+                "fr-FR",
+                "validate-min-length = Doit contenir au moins { $min } caractères",
+            )
+            .build()
+            .expect("Expected resources");
+        let cli = TestClient::new(Route::new().at("/", post(signup)).data(resources));
+        let resp = cli
+            .post("/")
+            .header(header::ACCEPT_LANGUAGE, "fr-FR")
+            .body_json(&serde_json::json!({"name": "No"}))
+            .send()
+            .await;
+        resp.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        resp.assert_json(serde_json::json!({
+            "name": ["Doit contenir au moins \u{2068}5\u{2069} caractères"]
+        }))
+        .await;
+    }
+}
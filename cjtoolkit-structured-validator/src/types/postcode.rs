@@ -0,0 +1,414 @@
+//! This module contains structures and traits for working with postcodes.
+//!
+//! Postcodes are trimmed and converted to uppercase, then matched against a regular
+//! expression. Patterns are provided for the UK, US ZIP codes and Canada, any other
+//! pattern can be used with `Postcode::parse_with_pattern`.
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, RuleViolation, ValidateErrorCollector, ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::rule_result;
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use regex::Regex;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, OnceLock};
+use thiserror::Error;
+
+static UK_REGEX_CACHE: OnceLock<Regex> = OnceLock::new();
+static US_ZIP_REGEX_CACHE: OnceLock<Regex> = OnceLock::new();
+static CA_REGEX_CACHE: OnceLock<Regex> = OnceLock::new();
+
+fn cached_regex(cache: &'static OnceLock<Regex>, pattern: &str) -> &'static Regex {
+    cache.get_or_init(|| Regex::new(pattern).expect("Invalid Regex"))
+}
+
+/// A structure to define rules or constraints associated with a postcode.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean field indicating whether the postcode is mandatory or optional.
+#[derive(Clone)]
+pub struct PostcodeRules {
+    pub is_mandatory: bool,
+}
+
+impl Default for PostcodeRules {
+    fn default() -> Self {
+        Self { is_mandatory: true }
+    }
+}
+
+impl OptionalRules for PostcodeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+        }
+    }
+}
+
+impl From<&PostcodeRules> for StringMandatoryRules {
+    fn from(rules: &PostcodeRules) -> Self {
+        StringMandatoryRules {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: false,
+        }
+    }
+}
+
+impl PostcodeRules {
+    fn rule(&self) -> StringMandatoryRules {
+        self.into()
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let rule = self.rule();
+        rule.check(messages, subject);
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = self.rule().describe();
+        constraints.push(Constraint::from_locale(&PostcodeLocale));
+        constraints
+    }
+}
+
+/// Represents an error that occurs during postcode validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Postcode Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Postcode Validation Error")]
+pub struct PostcodeError(pub ValidateErrorStore);
+
+impl ValidationCheck for PostcodeError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&PostcodeError> for ValidateErrorStore {
+    fn from(error: &PostcodeError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A locale message for a postcode that does not match the expected pattern.
+///
+/// # Key
+/// `validate-postcode`
+pub struct PostcodeLocale;
+
+impl LocaleMessage for PostcodeLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new(locale_keys::VALIDATE_POSTCODE)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::pattern("postcode")
+    }
+}
+
+/// A structure representing a validated postcode.
+///
+/// # Fields
+/// - `0: String` - The trimmed, uppercase postcode.
+/// - `1: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+pub struct Postcode(String, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for Postcode {
+    fn default() -> Self {
+        Self(String::new(), true)
+    }
+}
+
+impl Postcode {
+    /// The pattern of a UK postcode, e.g. `SW1A 1AA`.
+    pub const UK_PATTERN: &'static str = r"^[A-Z]{1,2}[0-9R][0-9A-Z]? [0-9][ABD-HJLNP-UW-Z]{2}$";
+
+    /// The pattern of a US ZIP code, e.g. `12345` or `12345-6789`.
+    pub const US_ZIP_PATTERN: &'static str = r"^[0-9]{5}(-[0-9]{4})?$";
+
+    /// The pattern of a Canadian postal code, e.g. `K1A 0B1`.
+    pub const CA_PATTERN: &'static str =
+        r"^[ABCEGHJ-NPRSTVXY][0-9][ABCEGHJ-NPRSTV-Z] ?[0-9][ABCEGHJ-NPRSTV-Z][0-9]$";
+
+    /// Parses a postcode based on the provided validation rules, matching it against `pattern`.
+    ///
+    /// # Errors
+    /// Returns a `PostcodeError` if the postcode is empty while mandatory,
+    /// or if it does not match the pattern.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::postcode::{Postcode, PostcodeRules};
+    /// use regex::Regex;
+    ///
+    /// let pattern = Regex::new(r"^[0-9]{4}$").unwrap();
+    /// let postcode = Postcode::parse_with_pattern(Some("2000"), PostcodeRules::default(), &pattern);
+    /// assert!(postcode.is_ok());
+    /// ```
+    pub fn parse_with_pattern(
+        s: Option<&str>,
+        rules: PostcodeRules,
+        pattern: &Regex,
+    ) -> Result<Self, PostcodeError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default().trim().to_uppercase();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            PostcodeError::validate_check(messages)?;
+            if is_none && !rules.is_mandatory {
+                return Ok(Self(s, is_none));
+            }
+            let mut messages = ValidateErrorCollector::new();
+            let is_match = pattern.is_match(&s);
+            rule_result(locale_keys::VALIDATE_POSTCODE, is_match);
+            if !is_match {
+                messages.push(("Invalid Postcode".to_string(), Box::new(PostcodeLocale)));
+            }
+            PostcodeError::validate_check(messages)?;
+            Ok(Self(s, is_none))
+        })
+    }
+
+    /// Parses a UK postcode based on the provided validation rules.
+    pub fn parse_uk_custom(s: Option<&str>, rules: PostcodeRules) -> Result<Self, PostcodeError> {
+        Self::parse_with_pattern(s, rules, cached_regex(&UK_REGEX_CACHE, Self::UK_PATTERN))
+    }
+
+    /// Parses a UK postcode using the default `PostcodeRules`.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::postcode::Postcode;
+    ///
+    /// assert!(Postcode::parse_uk(Some("SW1A 1AA")).is_ok());
+    /// assert!(Postcode::parse_uk(Some("SW1A 1A")).is_err());
+    /// ```
+    pub fn parse_uk(s: Option<&str>) -> Result<Self, PostcodeError> {
+        Self::parse_uk_custom(s, PostcodeRules::default())
+    }
+
+    /// Parses a US ZIP code based on the provided validation rules.
+    pub fn parse_us_zip_custom(
+        s: Option<&str>,
+        rules: PostcodeRules,
+    ) -> Result<Self, PostcodeError> {
+        Self::parse_with_pattern(
+            s,
+            rules,
+            cached_regex(&US_ZIP_REGEX_CACHE, Self::US_ZIP_PATTERN),
+        )
+    }
+
+    /// Parses a US ZIP code using the default `PostcodeRules`.
+    pub fn parse_us_zip(s: Option<&str>) -> Result<Self, PostcodeError> {
+        Self::parse_us_zip_custom(s, PostcodeRules::default())
+    }
+
+    /// Parses a Canadian postal code based on the provided validation rules.
+    pub fn parse_ca_custom(s: Option<&str>, rules: PostcodeRules) -> Result<Self, PostcodeError> {
+        Self::parse_with_pattern(s, rules, cached_regex(&CA_REGEX_CACHE, Self::CA_PATTERN))
+    }
+
+    /// Parses a Canadian postal code using the default `PostcodeRules`.
+    pub fn parse_ca(s: Option<&str>) -> Result<Self, PostcodeError> {
+        Self::parse_ca_custom(s, PostcodeRules::default())
+    }
+
+    /// Returns the postcode as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the postcode was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
+    /// Converts the current instance into an `Option<Postcode>`.
+    ///
+    /// # Returns
+    /// - `None` if the postcode was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<Postcode> {
+        if self.1 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for Postcode {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&Postcode> for String {
+    fn from(postcode: &Postcode) -> Self {
+        postcode.as_str().to_string()
+    }
+}
+
+impl Display for Postcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Postcode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Postcode {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `Postcode` key can be found by
+/// a `&str`.
+impl PartialEq for Postcode {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Postcode {}
+
+impl Hash for Postcode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for Postcode {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Postcode {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Postcode {
+    /// Creates an instance of `Postcode` without validation, e.g. when loading values which were
+    /// validated before being stored. The string is kept as is, `None` gives an empty value.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(s.to_string(), is_none)
+    }
+
+    /// Creates an instance of `Postcode` from a stored string without validation, so stored values
+    /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
+
+/// The rules alone do not say which pattern to match, so the validator is the pair of the rules
+/// and the pattern.
+///
+/// # Example
+/// ```
+/// use cjtoolkit_structured_validator::common::validator::Validator;
+/// use cjtoolkit_structured_validator::types::postcode::{Postcode, PostcodeRules};
+/// use regex::Regex;
+///
+/// let validator = (
+///     PostcodeRules::default(),
+///     Regex::new(Postcode::UK_PATTERN).unwrap(),
+/// );
+/// assert!(validator.validate(Some("SW1A 1AA")).is_ok());
+/// assert!(validator.validate(Some("SW1A 1A")).is_err());
+/// ```
+impl Validator<Option<&str>, Postcode, PostcodeError> for (PostcodeRules, Regex) {
+    fn validate(&self, input: Option<&str>) -> Result<Postcode, PostcodeError> {
+        Postcode::parse_with_pattern(input, self.0.clone(), &self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uk_postcode() {
+        assert!(Postcode::parse_uk(Some("SW1A 1AA")).is_ok());
+        assert!(Postcode::parse_uk(Some(" m1 1ae ")).is_ok());
+        assert!(Postcode::parse_uk(Some("SW1A 1A")).is_err());
+        assert!(Postcode::parse_uk(None).is_err());
+    }
+
+    #[test]
+    fn test_us_zip() {
+        assert!(Postcode::parse_us_zip(Some("90210")).is_ok());
+        assert!(Postcode::parse_us_zip(Some("90210-1234")).is_ok());
+        assert!(Postcode::parse_us_zip(Some("9021")).is_err());
+    }
+
+    #[test]
+    fn test_ca_postcode() {
+        assert!(Postcode::parse_ca(Some("K1A 0B1")).is_ok());
+        assert!(Postcode::parse_ca(Some("k1a0b1")).is_ok());
+        assert!(Postcode::parse_ca(Some("D1A 0B1")).is_err());
+    }
+
+    #[test]
+    fn test_optional_postcode() {
+        let rules = PostcodeRules {
+            is_mandatory: false,
+        };
+        let postcode = Postcode::parse_uk_custom(None, rules);
+        assert_eq!(postcode.map(|p| p.into_option()), Ok(None));
+    }
+
+    #[test]
+    fn test_display_and_as_ref() {
+        let postcode = Postcode::parse_uk(Some("sw1a 1aa")).unwrap_or_default();
+        assert_eq!(postcode.to_string(), "SW1A 1AA");
+        assert_eq!(postcode.as_ref(), "SW1A 1AA");
+        assert_eq!(postcode, "SW1A 1AA");
+    }
+
+    #[test]
+    fn test_validator() {
+        let validator = (
+            PostcodeRules::default(),
+            Regex::new(Postcode::US_ZIP_PATTERN).expect("Invalid Regex"),
+        );
+        assert!(validator.validate(Some("90210")).is_ok());
+        assert!(validator.validate(Some("9021")).is_err());
+        assert!(Postcode::parse_optional(None, validator).is_ok_and(|p| p.is_absent()));
+    }
+}
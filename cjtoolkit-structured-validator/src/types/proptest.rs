@@ -0,0 +1,312 @@
+//! This module contains the `proptest` strategies of the value types.
+//!
+//! Each strategy generates inputs around the constraints of the given rules, e.g. strings just
+//! shorter and longer than the length limits, numbers just outside the range, or nothing at all,
+//! then classifies each input by parsing it with the same rules. So a `RuleSample::Valid` input
+//! is guaranteed to parse, and a `RuleSample::Invalid` input is guaranteed to fail, following the
+//! definitions of validity of this crate.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::name::NameRules;
+//! use cjtoolkit_structured_validator::types::proptest::name_rules_strategy;
+//! use proptest::prelude::*;
+//! use proptest::test_runner::TestRunner;
+//!
+//! // The form logic under test, which should agree with `NameRules::default()`.
+//! fn is_valid_name(name: Option<&str>) -> bool {
+//!     name.is_some_and(|name| (5..=20).contains(&name.chars().count()) && !name.trim().is_empty())
+//! }
+//!
+//! let rules = NameRules::default();
+//! let mut runner = TestRunner::default();
+//! runner
+//!     .run(&name_rules_strategy(&rules), |sample| {
+//!         prop_assert_eq!(is_valid_name(sample.input().as_deref()), sample.is_valid());
+//!         Ok(())
+//!     })
+//!     .expect("Expected form logic to agree with the rules");
+//! ```
+
+use crate::types::description::{Description, DescriptionRules};
+#[cfg(feature = "email")]
+use crate::types::email::{Email, EmailRules};
+use crate::types::name::{Name, NameRules};
+use crate::types::numbers::float::{Float, FloatRules};
+use crate::types::numbers::integer::{Integer, IntegerRules};
+use crate::types::numbers::unsigned::{Unsigned, UnsignedRules};
+use crate::types::password::{Password, PasswordRules};
+#[cfg(feature = "url")]
+use crate::types::url::{Url, UrlRules};
+use crate::types::username::{Username, UsernameRules};
+use ::proptest::prelude::*;
+use ::proptest::sample::select;
+
+/// An input generated by a strategy, classified against the rules of the strategy.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleSample<T> {
+    /// The input satisfies the rules.
+    Valid(T),
+    /// The input violates at least one of the rules.
+    Invalid(T),
+}
+
+impl<T> RuleSample<T> {
+    /// Returns `true` if the input satisfies the rules.
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid(_))
+    }
+
+    /// Returns a reference to the input.
+    pub fn input(&self) -> &T {
+        match self {
+            Self::Valid(input) | Self::Invalid(input) => input,
+        }
+    }
+
+    /// Converts the sample into its input.
+    pub fn into_input(self) -> T {
+        match self {
+            Self::Valid(input) | Self::Invalid(input) => input,
+        }
+    }
+}
+
+fn classify<T: Clone + std::fmt::Debug + 'static>(
+    candidates: BoxedStrategy<T>,
+    is_valid: impl Fn(&T) -> bool + 'static,
+) -> BoxedStrategy<RuleSample<T>> {
+    candidates
+        .prop_map(move |input| {
+            if is_valid(&input) {
+                RuleSample::Valid(input)
+            } else {
+                RuleSample::Invalid(input)
+            }
+        })
+        .boxed()
+}
+
+/// Generates lengths anywhere up to past the limits, and exactly on either side of them. The
+/// lengths saturate at `usize::MAX` rather than overflow.
+fn length_candidates(min: Option<usize>, max: Option<usize>) -> BoxedStrategy<usize> {
+    let lower = min.unwrap_or_default();
+    let upper = max.unwrap_or(lower.saturating_add(32));
+    prop_oneof![
+        4 => 0..=upper.saturating_add(8),
+        1 => Just(lower),
+        1 => Just(lower.saturating_sub(1)),
+        1 => Just(upper),
+        1 => Just(upper.saturating_add(1)),
+    ]
+    .boxed()
+}
+
+/// Generates ASCII strings, so their length is the same in every `LengthUnit`, along with
+/// blank strings and no string at all.
+fn string_candidates(
+    min: Option<usize>,
+    max: Option<usize>,
+    charset: &'static str,
+) -> BoxedStrategy<Option<String>> {
+    let lengths = length_candidates(min, max);
+    prop_oneof![
+        1 => Just(None),
+        1 => lengths.clone().prop_map(|length| Some(" ".repeat(length))),
+        8 => lengths.prop_flat_map(move |length| {
+            ::proptest::string::string_regex(&format!("{charset}{{{length}}}"))
+                .expect("Expected valid regex")
+                .prop_map(Some)
+        }),
+    ]
+    .boxed()
+}
+
+fn number_candidates<T>(
+    any: BoxedStrategy<T>,
+    bounds: Vec<T>,
+    allowed_values: Option<Vec<T>>,
+) -> BoxedStrategy<Option<T>>
+where
+    T: Clone + std::fmt::Debug + 'static,
+{
+    let mut candidates = vec![(1, Just(None).boxed()), (4, any.prop_map(Some).boxed())];
+    if !bounds.is_empty() {
+        candidates.push((2, select(bounds).prop_map(Some).boxed()));
+    }
+    if let Some(allowed_values) = allowed_values.filter(|values| !values.is_empty()) {
+        candidates.push((2, select(allowed_values).prop_map(Some).boxed()));
+    }
+    ::proptest::strategy::Union::new_weighted(candidates).boxed()
+}
+
+/// Returns a strategy generating valid and invalid `Name` inputs for `rules`.
+pub fn name_rules_strategy(rules: &NameRules) -> BoxedStrategy<RuleSample<Option<String>>> {
+    let rules = rules.clone();
+    classify(
+        string_candidates(rules.min_length, rules.max_length, "[a-zA-Z0-9]"),
+        move |input| Name::parse_custom(input.as_deref(), rules.clone()).is_ok(),
+    )
+}
+
+/// Returns a strategy generating valid and invalid `Username` inputs for `rules`, including
+/// usernames with invisible characters.
+pub fn username_rules_strategy(rules: &UsernameRules) -> BoxedStrategy<RuleSample<Option<String>>> {
+    let rules = rules.clone();
+    let candidates = string_candidates(rules.min_length, rules.max_length, "[a-z0-9_]");
+    let candidates = prop_oneof![
+        4 => candidates.clone(),
+        1 => candidates.prop_map(|input| input.map(|input| format!("{input}\u{200B}"))),
+    ]
+    .boxed();
+    classify(candidates, move |input| {
+        Username::parse_custom(input.as_deref(), rules.clone()).is_ok()
+    })
+}
+
+/// Returns a strategy generating valid and invalid `Description` inputs for `rules`.
+pub fn description_rules_strategy(
+    rules: &DescriptionRules,
+) -> BoxedStrategy<RuleSample<Option<String>>> {
+    let rules = rules.clone();
+    classify(
+        string_candidates(rules.min_length, rules.max_length, "[a-zA-Z0-9 ]"),
+        move |input| Description::parse_custom(input.as_deref(), rules.clone()).is_ok(),
+    )
+}
+
+/// Returns a strategy generating valid and invalid `Password` inputs for `rules`, including
+/// passwords made of lowercase letters only.
+pub fn password_rules_strategy(rules: &PasswordRules) -> BoxedStrategy<RuleSample<Option<String>>> {
+    let rules = rules.clone();
+    let candidates = prop_oneof![
+        3 => string_candidates(rules.min_length, rules.max_length, "[a-zA-Z0-9!@#$%]"),
+        1 => string_candidates(rules.min_length, rules.max_length, "[a-z]"),
+    ]
+    .boxed();
+    classify(candidates, move |input| {
+        Password::parse_custom(input.as_deref(), rules.clone()).is_ok()
+    })
+}
+
+/// Returns a strategy generating valid and invalid `Email` inputs for `rules`.
+#[cfg(feature = "email")]
+pub fn email_rules_strategy(rules: &EmailRules) -> BoxedStrategy<RuleSample<Option<String>>> {
+    let rules = rules.clone();
+    let candidates = prop_oneof![
+        1 => Just(None),
+        3 => "[a-z]{1,10}@[a-z]{1,10}\\.com".prop_map(Some),
+        2 => "[a-z@.]{0,12}".prop_map(Some),
+    ]
+    .boxed();
+    classify(candidates, move |input| {
+        Email::parse_custom(input.as_deref(), rules.clone()).is_ok()
+    })
+}
+
+/// Returns a strategy generating valid and invalid `Url` inputs for `rules`.
+#[cfg(feature = "url")]
+pub fn url_rules_strategy(rules: &UrlRules) -> BoxedStrategy<RuleSample<Option<String>>> {
+    let rules = rules.clone();
+    let candidates = prop_oneof![
+        1 => Just(None),
+        3 => "https://[a-z]{1,10}\\.com/[a-z]{0,5}".prop_map(Some),
+        2 => "[a-z:/.]{0,12}".prop_map(Some),
+    ]
+    .boxed();
+    classify(candidates, move |input| {
+        Url::parse_custom(input.as_deref(), rules.clone()).is_ok()
+    })
+}
+
+/// Returns a strategy generating valid and invalid `Integer` inputs for `rules`.
+pub fn integer_rules_strategy(rules: &IntegerRules) -> BoxedStrategy<RuleSample<Option<isize>>> {
+    let rules = rules.clone();
+    let bounds = [rules.min, rules.max]
+        .into_iter()
+        .flatten()
+        .flat_map(|bound| [bound.saturating_sub(1), bound, bound.saturating_add(1)])
+        .collect();
+    classify(
+        number_candidates(
+            (-1000isize..1000).boxed(),
+            bounds,
+            rules.allowed_values.clone(),
+        ),
+        move |input| Integer::parse_custom(*input, rules.clone()).is_ok(),
+    )
+}
+
+/// Returns a strategy generating valid and invalid `Unsigned` inputs for `rules`.
+pub fn unsigned_rules_strategy(rules: &UnsignedRules) -> BoxedStrategy<RuleSample<Option<usize>>> {
+    let rules = rules.clone();
+    let bounds = [rules.min, rules.max]
+        .into_iter()
+        .flatten()
+        .flat_map(|bound| [bound.saturating_sub(1), bound, bound.saturating_add(1)])
+        .collect();
+    classify(
+        number_candidates((0usize..1000).boxed(), bounds, rules.allowed_values.clone()),
+        move |input| Unsigned::parse_custom(*input, rules.clone()).is_ok(),
+    )
+}
+
+/// Returns a strategy generating valid and invalid `Float` inputs for `rules`.
+pub fn float_rules_strategy(rules: &FloatRules) -> BoxedStrategy<RuleSample<Option<f64>>> {
+    let rules = rules.clone();
+    let bounds = [rules.min, rules.max]
+        .into_iter()
+        .flatten()
+        .flat_map(|bound| [bound - 1.0, bound, bound + 1.0])
+        .collect();
+    classify(
+        number_candidates((-1000.0f64..1000.0).boxed(), bounds, None),
+        move |input| Float::parse_custom(*input, rules.clone()).is_ok(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::proptest::test_runner::TestRunner;
+
+    #[test]
+    fn test_strategy_generates_valid_and_invalid_samples() {
+        let strategy = integer_rules_strategy(&IntegerRules {
+            min: Some(10),
+            max: Some(20),
+            must_be_even: true,
+            ..IntegerRules::default()
+        });
+        let mut runner = TestRunner::deterministic();
+        let samples: Vec<_> = (0..200)
+            .map(|_| {
+                strategy
+                    .new_tree(&mut runner)
+                    .expect("Expected value tree")
+                    .current()
+            })
+            .collect();
+        assert!(samples.iter().any(|sample| sample.is_valid()));
+        assert!(samples.iter().any(|sample| !sample.is_valid()));
+        for sample in samples {
+            let expected = sample
+                .input()
+                .is_some_and(|input| (10..=20).contains(&input) && input % 2 == 0);
+            assert_eq!(sample.is_valid(), expected);
+        }
+    }
+
+    #[test]
+    fn test_length_candidates_saturate() {
+        let strategy = length_candidates(Some(usize::MAX), None);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..50 {
+            strategy.new_tree(&mut runner).expect("Expected value tree");
+        }
+        let strategy = length_candidates(None, Some(usize::MAX - 1));
+        for _ in 0..50 {
+            strategy.new_tree(&mut runner).expect("Expected value tree");
+        }
+    }
+}
@@ -0,0 +1,376 @@
+//! This module contains structures for working with ratings and survey scores.
+//!
+//! `StarRating` is a review rating, 1 to 5 stars by default, `Nps` is a Net Promoter Score,
+//! 0 to 10, and `Likert` is the answer to a Likert scale question, 1 to 5 by default, see
+//! `LikertRules::points`. Each is a whole number checked against its own bounds, with its own
+//! locale key, so the message can name what is rated.
+
+use crate::base::number_rules::{NumberMandatoryRules, number_from_str};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+};
+use crate::common::locale_keys;
+use crate::common::observer::rule_result;
+use std::sync::Arc;
+
+/// The locale of a rating outside of its bounds, with the key of the rating type.
+///
+/// # Keys
+/// - `validate-star-rating-range` for `StarRating`.
+/// - `validate-nps-range` for `Nps`.
+/// - `validate-likert-range` for `Likert`.
+pub struct RatingRangeLocale {
+    pub key: &'static str,
+    pub min: isize,
+    pub max: isize,
+}
+
+impl LocaleMessage for RatingRangeLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            self.key,
+            vec![
+                ("min".to_string(), LocaleValue::from(self.min)),
+                ("max".to_string(), LocaleValue::from(self.max)),
+            ],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Range {
+            min: Some(LocaleValue::from(self.min)),
+            max: Some(LocaleValue::from(self.max)),
+        }
+    }
+}
+
+fn check_rating(
+    messages: &mut ValidateErrorCollector,
+    subject: Option<isize>,
+    (is_mandatory, min, max): (bool, isize, isize),
+    key: &'static str,
+) {
+    if !is_mandatory && subject.is_none() {
+        return;
+    }
+    NumberMandatoryRules { is_mandatory }.check(messages, subject);
+    let Some(subject) = subject else {
+        return;
+    };
+    let is_in_range = (min..=max).contains(&subject);
+    rule_result(key, is_in_range);
+    if !is_in_range {
+        messages.push((
+            format!("Must be between {} and {}", min, max),
+            Box::new(RatingRangeLocale { key, min, max }),
+        ));
+    }
+}
+
+macro_rules! define_rating_type {
+    (
+        $(#[$meta:meta])*
+        $name:ident,
+        $rules:ident,
+        $error:ident,
+        key = $key:expr,
+        min = $min:expr,
+        max = $max:expr $(,)?
+    ) => {
+        #[doc = concat!("The validation rules of `", stringify!($name), "`.")]
+        ///
+        /// # Fields
+        /// * `is_mandatory` - Whether the rating must be provided.
+        /// * `min` - The lowest rating, inclusive.
+        /// * `max` - The highest rating, inclusive.
+        #[derive(Clone)]
+        pub struct $rules {
+            pub is_mandatory: bool,
+            pub min: isize,
+            pub max: isize,
+        }
+
+        impl Default for $rules {
+            fn default() -> Self {
+                Self {
+                    is_mandatory: true,
+                    min: $min,
+                    max: $max,
+                }
+            }
+        }
+
+        impl $rules {
+            /// Returns the constraints enforced by the rules.
+            pub fn describe(&self) -> Vec<Constraint> {
+                let mut constraints = NumberMandatoryRules {
+                    is_mandatory: self.is_mandatory,
+                }
+                .describe();
+                constraints.push(Constraint::from_locale(&RatingRangeLocale {
+                    key: $key,
+                    min: self.min,
+                    max: self.max,
+                }));
+                constraints
+            }
+        }
+
+        #[doc = concat!("An error of `", stringify!($name), "` validation.")]
+        #[derive(Debug, PartialEq, Clone, Default)]
+        pub struct $error(pub $crate::common::locale::ValidateErrorStore);
+
+        impl std::fmt::Display for $error {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(concat!(stringify!($name), " Validation Error"))
+            }
+        }
+
+        impl std::error::Error for $error {}
+
+        impl $crate::common::validation_check::ValidationCheck for $error {
+            fn validate_new(messages: $crate::common::locale::ValidateErrorStore) -> Self {
+                Self(messages)
+            }
+        }
+
+        impl From<&$error> for $crate::common::locale::ValidateErrorStore {
+            fn from(error: &$error) -> Self {
+                error.0.clone()
+            }
+        }
+
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        pub struct $name(isize, bool);
+
+        impl $name {
+            /// Parses the rating based on the provided validation rules.
+            ///
+            /// # Errors
+            /// Returns an error if the rating is missing while mandatory, or is out of bounds.
+            pub fn parse_custom(s: Option<isize>, rules: $rules) -> Result<Self, $error> {
+                use $crate::common::validation_check::ValidationCheck;
+                $crate::common::observer::observe_parse(|| {
+                    let mut messages = ValidateErrorCollector::new();
+                    check_rating(
+                        &mut messages,
+                        s,
+                        (rules.is_mandatory, rules.min, rules.max),
+                        $key,
+                    );
+                    <$error>::validate_check(messages)?;
+                    Ok(Self(s.unwrap_or_default(), s.is_none()))
+                })
+            }
+
+            /// Parses the rating based on the default rules.
+            ///
+            /// # Errors
+            /// Returns an error if the rating fails validation based on the default rules.
+            pub fn parse(s: Option<isize>) -> Result<Self, $error> {
+                Self::parse_custom(s, <$rules>::default())
+            }
+
+            /// Parses the text of a rating, e.g. a form field, based on the provided validation
+            /// rules. A missing or blank text is parsed as `None`.
+            ///
+            /// # Errors
+            /// Returns an error if the text is not a whole number, or if the rating fails
+            /// validation based on the rules.
+            pub fn parse_str_custom(s: Option<&str>, rules: $rules) -> Result<Self, $error> {
+                use $crate::common::validation_check::ValidationCheck;
+                match number_from_str(s, "integer") {
+                    Ok(number) => Self::parse_custom(number, rules),
+                    Err(messages) => $crate::common::observer::observe_parse(|| {
+                        Err(<$error>::validate_new(messages.into()))
+                    }),
+                }
+            }
+
+            /// Parses the text of a rating based on the default rules.
+            pub fn parse_str(s: Option<&str>) -> Result<Self, $error> {
+                Self::parse_str_custom(s, <$rules>::default())
+            }
+
+            /// Returns the rating.
+            pub fn as_isize(&self) -> isize {
+                self.0
+            }
+
+            /// Returns `true` if the rating was not provided.
+            pub fn is_none(&self) -> bool {
+                self.1
+            }
+
+            /// Converts the rating into an `Option`, `None` if it was not provided.
+            pub fn into_option(self) -> Option<Self> {
+                if self.1 { None } else { Some(self) }
+            }
+        }
+
+        impl $crate::common::maybe_validated::OptionalValue for $name {
+            fn is_absent(&self) -> bool {
+                self.is_none()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl TryFrom<Option<isize>> for $name {
+            type Error = $error;
+
+            fn try_from(s: Option<isize>) -> Result<Self, Self::Error> {
+                Self::parse(s)
+            }
+        }
+
+        impl TryFrom<isize> for $name {
+            type Error = $error;
+
+            fn try_from(s: isize) -> Result<Self, Self::Error> {
+                Self::parse(Some(s))
+            }
+        }
+
+        impl $crate::common::validator::Validator<Option<isize>, $name, $error> for $rules {
+            fn validate(&self, input: Option<isize>) -> Result<$name, $error> {
+                $name::parse_custom(input, self.clone())
+            }
+        }
+
+        impl $crate::common::maybe_validated::OptionalRules for $rules {
+            fn optional(self) -> Self {
+                Self {
+                    is_mandatory: false,
+                    ..self
+                }
+            }
+        }
+
+        impl $crate::common::schema::SchemaField for $name {
+            type Input = isize;
+            type Rules = $rules;
+            type Error = $error;
+
+            fn parse_field(input: Option<&isize>, rules: $rules) -> Result<Self, $error> {
+                Self::parse_custom(input.copied(), rules)
+            }
+        }
+    };
+}
+
+define_rating_type!(
+    /// A review rating, 1 to 5 stars by default.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::rating::{StarRating, StarRatingRules};
+    ///
+    /// assert!(StarRating::parse(Some(4)).is_ok());
+    /// assert!(StarRating::parse(Some(0)).is_err());
+    /// let rules = StarRatingRules {
+    ///     max: 10,
+    ///     ..StarRatingRules::default()
+    /// };
+    /// assert!(StarRating::parse_custom(Some(9), rules).is_ok());
+    /// ```
+    StarRating,
+    StarRatingRules,
+    StarRatingError,
+    key = locale_keys::VALIDATE_STAR_RATING_RANGE,
+    min = 1,
+    max = 5,
+);
+
+define_rating_type!(
+    /// A Net Promoter Score, 0 to 10.
+    Nps,
+    NpsRules,
+    NpsError,
+    key = locale_keys::VALIDATE_NPS_RANGE,
+    min = 0,
+    max = 10,
+);
+
+define_rating_type!(
+    /// The answer to a Likert scale question, 1 to 5 by default.
+    Likert,
+    LikertRules,
+    LikertError,
+    key = locale_keys::VALIDATE_LIKERT_RANGE,
+    min = 1,
+    max = 5,
+);
+
+/// The category of a Net Promoter Score.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NpsCategory {
+    /// A score of 0 to 6.
+    Detractor,
+    /// A score of 7 or 8.
+    Passive,
+    /// A score of 9 or 10.
+    Promoter,
+}
+
+impl Nps {
+    /// Returns the category of the score.
+    pub fn category(&self) -> NpsCategory {
+        match self.0 {
+            9.. => NpsCategory::Promoter,
+            7..=8 => NpsCategory::Passive,
+            _ => NpsCategory::Detractor,
+        }
+    }
+}
+
+impl LikertRules {
+    /// Rules for a Likert scale of `points` answers, from 1 to `points`, e.g. 7.
+    pub fn points(points: isize) -> Self {
+        Self {
+            max: points,
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rating_range() {
+        let error = StarRating::parse(Some(6)).expect_err("Expected out of range");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be between 1 and 5"]
+        );
+        assert_eq!(
+            error.0.0[0].1.get_locale_data().name,
+            locale_keys::VALIDATE_STAR_RATING_RANGE
+        );
+        assert!(Likert::parse_custom(Some(7), LikertRules::points(7)).is_ok());
+        assert!(Likert::parse(Some(7)).is_err());
+        assert!(Nps::parse_str(Some("abc")).is_err());
+        assert!(Nps::parse(None).is_err());
+        let rules = NpsRules {
+            is_mandatory: false,
+            ..NpsRules::default()
+        };
+        assert!(Nps::parse_custom(None, rules).is_ok_and(|nps| nps.is_none()));
+    }
+
+    #[test]
+    fn test_nps_category() {
+        let category = |score| Nps::parse(Some(score)).map(|nps| nps.category()).ok();
+        assert_eq!(category(0), Some(NpsCategory::Detractor));
+        assert_eq!(category(8), Some(NpsCategory::Passive));
+        assert_eq!(category(10), Some(NpsCategory::Promoter));
+    }
+}
@@ -0,0 +1,404 @@
+//! This module contains the `serde` support of the value types.
+//!
+//! Deserializing a value type runs the default rules of the type, the first validation error
+//! is surfaced as a custom `serde` error in the format `"{locale key}: {message}"`. A missing
+//! or `null` value is validated as `None`, so mandatory fields report the mandatory error.
+//!
+//! Serializing a value type writes the inner canonical string or number, or `null` when the
+//! value was not provided, so validated structs can be persisted or echoed back directly.
+//! `Password` is deliberately not serializable.
+//!
+//! When the default rules do not fit, `Raw<T>` deserializes the input of `T` without
+//! validating it, so it can be validated later with custom rules.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use cjtoolkit_structured_validator::types::serde::Raw;
+//! use cjtoolkit_structured_validator::types::username::{Username, UsernameRules};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Form {
+//!     name: Name,
+//!     username: Raw<Username>,
+//! }
+//!
+//! let form: Form = serde_json::from_str(r#"{"name": "ValidName", "username": "abc"}"#)
+//!     .expect("Expected valid form");
+//! assert_eq!(form.name.as_str(), "ValidName");
+//! let rules = UsernameRules { min_length: Some(3), ..UsernameRules::default() };
+//! assert!(Username::parse_custom(form.username.as_input().as_deref(), rules).is_ok());
+//!
+//! let form = serde_json::from_str::<Form>(r#"{"name": "No", "username": "abc"}"#);
+//! assert!(form.is_err());
+//! ```
+
+use crate::common::locale::ValidateErrorStore;
+use ::serde::de::DeserializeOwned;
+use ::serde::ser::SerializeStruct;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Debug, Formatter};
+
+/// A trait for value types that can be validated with their default rules from a
+/// deserialized input.
+///
+/// # Associated Types
+/// - `Input`: The raw input of the type, e.g. `Option<String>` for `Name`.
+/// - `Error`: The validation error of the type.
+pub trait ValidatedDeserialize: Sized {
+    type Input: DeserializeOwned;
+    type Error;
+
+    fn validate_input(input: Self::Input) -> Result<Self, Self::Error>;
+}
+
+/// Converts the first message of an error store into a `serde` error.
+fn first_message_error<E: ::serde::de::Error>(store: ValidateErrorStore) -> E {
+    match store.0.first() {
+        Some((message, locale)) => {
+            E::custom(format!("{}: {}", locale.get_locale_data().name, message))
+        }
+        None => E::custom("Validation Error"),
+    }
+}
+
+/// Deserializes and validates a value type with its default rules.
+pub fn deserialize_validated<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: ValidatedDeserialize,
+    for<'a> &'a T::Error: Into<ValidateErrorStore>,
+    D: Deserializer<'de>,
+{
+    let input = T::Input::deserialize(deserializer)?;
+    T::validate_input(input).map_err(|e| first_message_error((&e).into()))
+}
+
+/// An unvalidated input of a value type, as an escape hatch from the default rules.
+///
+/// # Fields
+/// - `0`: The raw input of the type.
+pub struct Raw<T: ValidatedDeserialize>(pub T::Input);
+
+impl<T: ValidatedDeserialize> Raw<T> {
+    /// Returns a reference to the raw input.
+    pub fn as_input(&self) -> &T::Input {
+        &self.0
+    }
+
+    /// Returns the raw input.
+    pub fn into_input(self) -> T::Input {
+        self.0
+    }
+
+    /// Validates the raw input with the default rules of the type.
+    pub fn validate(self) -> Result<T, T::Error> {
+        T::validate_input(self.0)
+    }
+}
+
+impl<T: ValidatedDeserialize> Debug for Raw<T>
+where
+    T::Input: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Raw").field(&self.0).finish()
+    }
+}
+
+impl<T: ValidatedDeserialize> Clone for Raw<T>
+where
+    T::Input: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'de, T: ValidatedDeserialize> Deserialize<'de> for Raw<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::Input::deserialize(deserializer).map(Self)
+    }
+}
+
+macro_rules! validated_deserialize {
+    ($type:ty, $input:ty, $error:ty, $parse:expr) => {
+        impl ValidatedDeserialize for $type {
+            type Input = $input;
+            type Error = $error;
+
+            fn validate_input(input: Self::Input) -> Result<Self, Self::Error> {
+                $parse(input)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_validated(deserializer)
+            }
+        }
+    };
+}
+
+macro_rules! validated_deserialize_str {
+    ($type:ty, $error:ty) => {
+        validated_deserialize!($type, Option<String>, $error, |s: Option<String>| {
+            <$type>::parse(s.as_deref())
+        });
+    };
+}
+
+macro_rules! transparent_serialize {
+    ($type:ty, |$value:ident| $inner:expr) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let $value = self;
+                if $value.is_none() {
+                    serializer.serialize_none()
+                } else {
+                    serializer.serialize_some(&$inner)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! transparent_serialize_str {
+    ($type:ty) => {
+        transparent_serialize!($type, |value| value.as_str());
+    };
+}
+
+mod string_types {
+    use super::*;
+    use crate::types::description::{Description, DescriptionError};
+    use crate::types::name::name_alias::{
+        AddressLine, AddressLineError, Field, FieldError, FirstName, FirstNameError, ForeName,
+        ForeNameError, LastName, LastNameError, MiddleName, MiddleNameError, Title, TitleError,
+    };
+    use crate::types::name::{Name, NameError};
+    use crate::types::net::domain::{Domain, DomainError};
+    use crate::types::net::hostname::{Hostname, HostnameError};
+    use crate::types::net::mac_address::{MacAddress, MacAddressError};
+    use crate::types::password::{Password, PasswordError};
+    use crate::types::social::{Handle, HandleError};
+    use crate::types::sorting::SortExpr;
+    use crate::types::username::{Username, UsernameError};
+
+    validated_deserialize_str!(Name, NameError);
+    validated_deserialize_str!(Title, TitleError);
+    validated_deserialize_str!(FirstName, FirstNameError);
+    validated_deserialize_str!(ForeName, ForeNameError);
+    validated_deserialize_str!(MiddleName, MiddleNameError);
+    validated_deserialize_str!(LastName, LastNameError);
+    validated_deserialize_str!(AddressLine, AddressLineError);
+    validated_deserialize_str!(Field, FieldError);
+    validated_deserialize_str!(Description, DescriptionError);
+    validated_deserialize_str!(Username, UsernameError);
+    validated_deserialize_str!(Password, PasswordError);
+    validated_deserialize_str!(Handle, HandleError);
+    validated_deserialize_str!(MacAddress, MacAddressError);
+    validated_deserialize_str!(Hostname, HostnameError);
+    validated_deserialize_str!(Domain, DomainError);
+
+    transparent_serialize_str!(Name);
+    transparent_serialize_str!(Title);
+    transparent_serialize_str!(FirstName);
+    transparent_serialize_str!(ForeName);
+    transparent_serialize_str!(MiddleName);
+    transparent_serialize_str!(LastName);
+    transparent_serialize_str!(AddressLine);
+    transparent_serialize_str!(Field);
+    transparent_serialize_str!(Description);
+    transparent_serialize_str!(Username);
+    transparent_serialize_str!(Handle);
+    transparent_serialize_str!(SortExpr);
+    transparent_serialize!(MacAddress, |value| value.to_canonical());
+    transparent_serialize_str!(Hostname);
+    transparent_serialize_str!(Domain);
+    transparent_serialize_str!(crate::types::national_id::NationalId);
+
+    #[cfg(feature = "email")]
+    validated_deserialize_str!(crate::types::email::Email, crate::types::email::EmailError);
+    #[cfg(feature = "email")]
+    transparent_serialize_str!(crate::types::email::Email);
+
+    #[cfg(feature = "url")]
+    validated_deserialize_str!(crate::types::url::Url, crate::types::url::UrlError);
+    #[cfg(feature = "url")]
+    transparent_serialize_str!(crate::types::url::Url);
+
+    #[cfg(feature = "postcode")]
+    transparent_serialize_str!(crate::types::postcode::Postcode);
+
+    impl Serialize for crate::types::person::PersonName {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("PersonName", 3)?;
+            state.serialize_field("first", self.first())?;
+            state.serialize_field("middle", &self.middle())?;
+            state.serialize_field("last", self.last())?;
+            state.end()
+        }
+    }
+}
+
+mod number_types {
+    use super::*;
+    use crate::types::numbers::float::{Float, FloatError};
+    use crate::types::numbers::integer::{Integer, IntegerError};
+    use crate::types::numbers::unsigned::{
+        NonZeroUnsigned, U32, U32Error, U64, U64Error, Unsigned, UnsignedError,
+    };
+
+    validated_deserialize!(Float, Option<f64>, FloatError, Float::parse);
+    validated_deserialize!(Integer, Option<isize>, IntegerError, Integer::parse);
+    validated_deserialize!(Unsigned, Option<usize>, UnsignedError, Unsigned::parse);
+    validated_deserialize!(U64, Option<u64>, U64Error, U64::parse);
+    validated_deserialize!(U32, Option<u32>, U32Error, U32::parse);
+    validated_deserialize!(
+        NonZeroUnsigned,
+        Option<u64>,
+        U64Error,
+        NonZeroUnsigned::parse
+    );
+
+    transparent_serialize!(Float, |value| value.as_f64());
+    transparent_serialize!(Integer, |value| value.as_isize());
+    transparent_serialize!(Unsigned, |value| value.as_usize());
+    transparent_serialize!(U64, |value| value.as_u64());
+    transparent_serialize!(U32, |value| value.as_u32());
+    transparent_serialize!(NonZeroUnsigned, |value| value.as_u64().unwrap_or_default());
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_types {
+    use super::*;
+    use crate::types::times_chrono::date::{DateError, DateValue};
+    use crate::types::times_chrono::date_time::{DateTimeError, DateTimeValue};
+    use crate::types::times_chrono::naive_date_time::{NaiveDateTimeError, NaiveDateTimeValue};
+    use crate::types::times_chrono::time::{TimeError, TimeValue};
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+    validated_deserialize!(DateValue, Option<NaiveDate>, DateError, DateValue::parse);
+    validated_deserialize!(TimeValue, Option<NaiveTime>, TimeError, TimeValue::parse);
+    validated_deserialize!(
+        NaiveDateTimeValue,
+        Option<NaiveDateTime>,
+        NaiveDateTimeError,
+        NaiveDateTimeValue::parse
+    );
+    validated_deserialize!(
+        DateTimeValue<Utc>,
+        Option<DateTime<Utc>>,
+        DateTimeError,
+        DateTimeValue::<Utc>::parse
+    );
+    validated_deserialize!(
+        DateTimeValue<FixedOffset>,
+        Option<DateTime<FixedOffset>>,
+        DateTimeError,
+        DateTimeValue::<FixedOffset>::parse
+    );
+
+    impl Serialize for DateValue {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.as_naive_date().serialize(serializer)
+        }
+    }
+
+    impl Serialize for TimeValue {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.as_time().serialize(serializer)
+        }
+    }
+
+    impl Serialize for NaiveDateTimeValue {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.as_naive_date_time().serialize(serializer)
+        }
+    }
+
+    impl<Tz: TimeZone> Serialize for DateTimeValue<Tz> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.as_date_time().serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "humantime")]
+mod humantime_types {
+    use super::*;
+    use crate::types::times_humantime::DateTimeValue;
+
+    impl Serialize for DateTimeValue {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.as_timestamp() {
+                Some(timestamp) => serializer.collect_str(&timestamp),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::name::Name;
+    use crate::types::numbers::unsigned::Unsigned;
+
+    #[derive(Deserialize)]
+    struct Form {
+        name: Name,
+        age: Option<Unsigned>,
+    }
+
+    #[test]
+    fn test_deserialize_valid() {
+        let form: Form = serde_json::from_str(r#"{"name": "ValidName", "age": 30}"#)
+            .expect("Expected valid form");
+        assert_eq!(form.name.as_str(), "ValidName");
+        assert!(form.age.is_some());
+    }
+
+    #[test]
+    fn test_deserialize_invalid_surfaces_locale_key() {
+        let err = serde_json::from_str::<Form>(r#"{"name": "No"}"#)
+            .err()
+            .map(|e| e.to_string())
+            .unwrap_or_default();
+        assert!(err.starts_with("validate-min-length: "), "{}", err);
+    }
+
+    #[test]
+    fn test_deserialize_missing_mandatory() {
+        let err = serde_json::from_str::<Form>(r#"{}"#)
+            .err()
+            .map(|e| e.to_string())
+            .unwrap_or_default();
+        assert!(err.starts_with("validate-cannot-be-empty: "), "{}", err);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let name = Name::parse(Some("ValidName")).expect("Expected valid name");
+        let age = Unsigned::parse_custom(
+            None,
+            crate::types::numbers::unsigned::UnsignedRules {
+                is_mandatory: false,
+                ..Default::default()
+            },
+        )
+        .expect("Expected valid age");
+        assert_eq!(
+            serde_json::to_string(&(name, age)).unwrap_or_default(),
+            r#"["ValidName",null]"#
+        );
+    }
+
+    #[test]
+    fn test_raw() {
+        let raw: Raw<Name> = serde_json::from_str(r#""No""#).expect("Expected raw input");
+        assert_eq!(raw.as_input().as_deref(), Some("No"));
+        assert!(raw.validate().is_err());
+    }
+}
@@ -0,0 +1,447 @@
+//! This module contains structures and traits for working with the bodies of SMS messages.
+//!
+//! A body is sent with the GSM-7 encoding if all of its characters are in the GSM 03.38
+//! alphabet, otherwise with UCS-2. A single SMS holds 160 GSM-7 characters or 70 UCS-2
+//! characters, and a longer body is split in segments of 153 GSM-7 or 67 UCS-2 characters. The
+//! characters of the GSM-7 extension table, e.g. `€` or `{`, count twice, as do the characters
+//! outside of the Basic Multilingual Plane in UCS-2, e.g. emojis.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::sms::{SmsBody, SmsBodyRules, SmsEncoding};
+//!
+//! let body = SmsBody::parse(Some("Your code is 1234")).expect("Expected body to be valid");
+//! assert_eq!(body.encoding(), SmsEncoding::Gsm7);
+//! assert_eq!(body.segments(), 1);
+//!
+//! let greeting = "Olá 👋 ".repeat(12);
+//! let error = SmsBody::parse(Some(&greeting)).expect_err("Expected too many segments");
+//! assert_eq!(error.0.as_original_message_vec(), vec!["Must fit in 1 SMS, not 2"]);
+//! ```
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::rule_result;
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+    ¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+const GSM7_EXTENSION: &str = "\u{c}^{}\\[~]|€";
+
+/// The encoding an SMS body is sent with.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SmsEncoding {
+    /// The 7-bit GSM 03.38 alphabet, 160 characters per SMS.
+    Gsm7,
+    /// The 16-bit UCS-2 encoding, 70 characters per SMS.
+    Ucs2,
+}
+
+impl SmsEncoding {
+    /// Returns the encoding `body` is sent with, `Gsm7` if all of its characters are in the
+    /// GSM 03.38 alphabet, otherwise `Ucs2`.
+    pub fn detect(body: &str) -> Self {
+        if body
+            .chars()
+            .all(|c| GSM7_BASIC.contains(c) || GSM7_EXTENSION.contains(c))
+        {
+            Self::Gsm7
+        } else {
+            Self::Ucs2
+        }
+    }
+
+    /// Returns the number of units of `c`, septets in GSM-7 and code units in UCS-2.
+    fn units(&self, c: char) -> usize {
+        match self {
+            Self::Gsm7 if GSM7_EXTENSION.contains(c) => 2,
+            Self::Gsm7 => 1,
+            Self::Ucs2 => c.len_utf16(),
+        }
+    }
+
+    /// Returns the number of units of a single SMS, and of a segment of a longer body.
+    fn capacity(&self) -> (usize, usize) {
+        match self {
+            Self::Gsm7 => (160, 153),
+            Self::Ucs2 => (70, 67),
+        }
+    }
+
+    /// Returns the number of segments `body` is split in, `0` if it is empty. A character is
+    /// never split between two segments.
+    pub fn count_segments(&self, body: &str) -> usize {
+        let (single, segment) = self.capacity();
+        let total: usize = body.chars().map(|c| self.units(c)).sum();
+        if total == 0 {
+            return 0;
+        }
+        if total <= single {
+            return 1;
+        }
+        let mut segments = 1;
+        let mut used = 0;
+        for units in body.chars().map(|c| self.units(c)) {
+            if used + units > segment {
+                segments += 1;
+                used = 0;
+            }
+            used += units;
+        }
+        segments
+    }
+}
+
+/// A structure to define the rules of an SMS body.
+///
+/// # Fields
+///
+/// * `is_mandatory` - Whether the body is mandatory.
+/// * `max_segments` - The maximum number of segments the body may be split in, `None` for no
+///   maximum. A single SMS by default.
+#[derive(Clone)]
+pub struct SmsBodyRules {
+    pub is_mandatory: bool,
+    pub max_segments: Option<usize>,
+}
+
+impl Default for SmsBodyRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            max_segments: Some(1),
+        }
+    }
+}
+
+impl From<&SmsBodyRules> for StringMandatoryRules {
+    fn from(rules: &SmsBodyRules) -> Self {
+        Self {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: true,
+        }
+    }
+}
+
+impl SmsBodyRules {
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        segments: usize,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        StringMandatoryRules::from(self).check(messages, subject);
+        let Some(max) = self.max_segments else {
+            return;
+        };
+        let is_within_max = segments <= max;
+        rule_result(locale_keys::VALIDATE_SMS_MAX_SEGMENTS, is_within_max);
+        if !is_within_max {
+            let message = match max {
+                1 => format!("Must fit in 1 SMS, not {}", segments),
+                _ => format!("Must fit in {} SMS, not {}", max, segments),
+            };
+            messages.push((message, Box::new(SmsSegmentsLocale { max, segments })));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = StringMandatoryRules::from(self).describe();
+        if let Some(max) = self.max_segments {
+            constraints.push(Constraint::from_locale(&SmsSegmentsLocale {
+                max,
+                segments: 0,
+            }));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during SMS body validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"SMS Body Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("SMS Body Validation Error")]
+pub struct SmsBodyError(pub ValidateErrorStore);
+
+impl ValidationCheck for SmsBodyError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&SmsBodyError> for ValidateErrorStore {
+    fn from(error: &SmsBodyError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// The locale of an SMS body split in more segments than allowed, with the number of segments.
+///
+/// # Key
+/// `validate-sms-max-segments`
+pub struct SmsSegmentsLocale {
+    pub max: usize,
+    pub segments: usize,
+}
+
+impl LocaleMessage for SmsSegmentsLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_SMS_MAX_SEGMENTS,
+            vec![
+                ("max".to_string(), LocaleValue::from(self.max)),
+                ("segments".to_string(), LocaleValue::from(self.segments)),
+            ],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::MaxItems { max: self.max }
+    }
+}
+
+/// A structure representing a validated SMS body.
+///
+/// # Fields
+/// - `0: String` - The body.
+/// - `1: SmsEncoding` - The encoding the body is sent with.
+/// - `2: usize` - The number of segments the body is split in.
+/// - `3: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+pub struct SmsBody(String, SmsEncoding, usize, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for SmsBody {
+    fn default() -> Self {
+        Self(String::new(), SmsEncoding::Gsm7, 0, true)
+    }
+}
+
+impl SmsBody {
+    /// Parses an SMS body based on the provided validation rules.
+    ///
+    /// # Errors
+    /// Returns an `SmsBodyError` if the body is empty while mandatory, or is split in more
+    /// segments than `max_segments`.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::sms::{SmsBody, SmsBodyRules};
+    ///
+    /// let rules = SmsBodyRules {
+    ///     max_segments: Some(2),
+    ///     ..SmsBodyRules::default()
+    /// };
+    /// let body = SmsBody::parse_custom(Some(&"a".repeat(161)), rules);
+    /// assert_eq!(body.map(|b| b.segments()).ok(), Some(2));
+    /// ```
+    pub fn parse_custom(s: Option<&str>, rules: SmsBodyRules) -> Result<Self, SmsBodyError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let encoding = SmsEncoding::detect(s);
+            let segments = encoding.count_segments(s);
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &s.as_string_validator(), segments, is_none);
+            SmsBodyError::validate_check(messages)?;
+            Ok(Self(s.to_string(), encoding, segments, is_none))
+        })
+    }
+
+    /// Parses an SMS body using the default `SmsBodyRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, SmsBodyError> {
+        Self::parse_custom(s, SmsBodyRules::default())
+    }
+
+    /// Returns the body.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the encoding the body is sent with.
+    pub fn encoding(&self) -> SmsEncoding {
+        self.1
+    }
+
+    /// Returns the number of segments the body is split in.
+    pub fn segments(&self) -> usize {
+        self.2
+    }
+
+    /// Returns `true` if the body was not provided.
+    pub fn is_none(&self) -> bool {
+        self.3
+    }
+
+    /// Converts the current instance into an `Option<SmsBody>`.
+    ///
+    /// # Returns
+    /// - `None` if the body was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<SmsBody> {
+        if self.3 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for SmsBody {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&SmsBody> for String {
+    fn from(body: &SmsBody) -> Self {
+        body.as_str().to_string()
+    }
+}
+
+impl Display for SmsBody {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for SmsBody {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for SmsBody {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `SmsBody` key can be found by
+/// a `&str`.
+impl PartialEq for SmsBody {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SmsBody {}
+
+impl Hash for SmsBody {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for SmsBody {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SmsBody {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for SmsBody {
+    type Error = SmsBodyError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for SmsBody {
+    type Error = SmsBodyError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for SmsBody {
+    type Error = SmsBodyError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, SmsBody, SmsBodyError> for SmsBodyRules {
+    fn validate(&self, input: Option<&str>) -> Result<SmsBody, SmsBodyError> {
+        SmsBody::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for SmsBodyRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_segments() {
+        let gsm7 = SmsEncoding::Gsm7;
+        assert_eq!(gsm7.count_segments(""), 0);
+        assert_eq!(gsm7.count_segments(&"a".repeat(160)), 1);
+        assert_eq!(gsm7.count_segments(&"a".repeat(161)), 2);
+        assert_eq!(gsm7.count_segments(&"€".repeat(80)), 1);
+        let a = "a".repeat(152);
+        assert_eq!(gsm7.count_segments(&format!("{a}€{a}")), 3);
+        let ucs2 = SmsEncoding::Ucs2;
+        assert_eq!(ucs2.count_segments(&"ж".repeat(70)), 1);
+        assert_eq!(ucs2.count_segments(&"ж".repeat(134)), 2);
+        assert_eq!(ucs2.count_segments(&"ж".repeat(135)), 3);
+        assert_eq!(ucs2.count_segments(&"👋".repeat(35)), 1);
+    }
+
+    #[test]
+    fn test_detect_encoding() {
+        assert_eq!(SmsEncoding::detect("Hello {name}, 5€!"), SmsEncoding::Gsm7);
+        assert_eq!(SmsEncoding::detect("Привет"), SmsEncoding::Ucs2);
+        assert_eq!(SmsEncoding::detect("Hi 👋"), SmsEncoding::Ucs2);
+    }
+
+    #[test]
+    fn test_max_segments() {
+        let error = SmsBody::parse(Some(&"ж".repeat(71))).expect_err("Expected too many segments");
+        let data = error.0.0[0].1.get_locale_data();
+        assert_eq!(data.name, locale_keys::VALIDATE_SMS_MAX_SEGMENTS);
+        assert_eq!(data.args.get("segments"), Some(&LocaleValue::from(2usize)));
+        assert!(SmsBody::parse(Some("   ")).is_err());
+        let rules = SmsBodyRules {
+            is_mandatory: false,
+            max_segments: None,
+        };
+        assert!(SmsBody::parse_custom(None, rules).is_ok_and(|body| body.is_none()));
+    }
+}
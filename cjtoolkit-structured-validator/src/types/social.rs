@@ -0,0 +1,460 @@
+//! This module contains structures and traits for working with social media handles.
+//!
+//! A `Handle` is validated with the same mandatory and length rules as a `Name`, with
+//! an optional leading prefix (e.g. `@`) stripped before validation, plus a character
+//! set check. Presets are provided for Twitter, Instagram and GitHub.
+
+use crate::base::string_rules::{LengthUnit, StringLengthRules, StringMandatoryRules};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, RuleViolation, ValidateErrorCollector, ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// The platform a handle belongs to, used to pick the locale key of the character set error.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum HandlePlatform {
+    #[default]
+    Generic,
+    Twitter,
+    Instagram,
+    GitHub,
+}
+
+/// A structure representing the rules and constraints associated with a handle.
+///
+/// # Fields
+///
+/// * `is_mandatory` - Whether the handle is required.
+/// * `min_length` - The optional minimum length of the handle, excluding the prefix.
+/// * `max_length` - The optional maximum length of the handle, excluding the prefix.
+/// * `prefix` - An optional prefix that is stripped from the handle when present, e.g. `@`.
+/// * `allowed_chars` - A function deciding whether a character is allowed in the handle.
+/// * `separator_chars` - Characters that are allowed, but not at the start or the end of the
+///   handle, nor twice in a row, e.g. `-` for GitHub.
+/// * `platform` - The platform of the handle, used for the locale key.
+#[derive(Clone)]
+pub struct HandleRules {
+    pub is_mandatory: bool,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub prefix: Option<char>,
+    pub allowed_chars: fn(char) -> bool,
+    pub separator_chars: &'static [char],
+    pub platform: HandlePlatform,
+}
+
+impl Default for HandleRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            min_length: Some(1),
+            max_length: Some(30),
+            prefix: Some('@'),
+            allowed_chars: |c| c.is_ascii_alphanumeric() || c == '_',
+            separator_chars: &[],
+            platform: HandlePlatform::Generic,
+        }
+    }
+}
+
+impl HandleRules {
+    /// Rules for a Twitter/X handle: 1 to 15 letters, digits or underscores.
+    pub fn twitter() -> Self {
+        Self {
+            max_length: Some(15),
+            platform: HandlePlatform::Twitter,
+            ..Self::default()
+        }
+    }
+
+    /// Rules for an Instagram handle: 1 to 30 letters, digits, underscores or periods,
+    /// not starting or ending with a period, nor with two periods in a row.
+    pub fn instagram() -> Self {
+        Self {
+            allowed_chars: |c| c.is_ascii_alphanumeric() || c == '_' || c == '.',
+            separator_chars: &['.'],
+            platform: HandlePlatform::Instagram,
+            ..Self::default()
+        }
+    }
+
+    /// Rules for a GitHub handle: 1 to 39 letters, digits or hyphens,
+    /// not starting or ending with a hyphen, nor with two hyphens in a row.
+    pub fn github() -> Self {
+        Self {
+            max_length: Some(39),
+            allowed_chars: |c| c.is_ascii_alphanumeric() || c == '-',
+            separator_chars: &['-'],
+            platform: HandlePlatform::GitHub,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<&HandleRules> for (StringMandatoryRules, StringLengthRules) {
+    fn from(rules: &HandleRules) -> Self {
+        (
+            StringMandatoryRules {
+                is_mandatory: rules.is_mandatory,
+                treat_whitespace_as_empty: false,
+            },
+            StringLengthRules {
+                min_length: rules.min_length,
+                max_length: rules.max_length,
+                unit: LengthUnit::Graphemes,
+            },
+        )
+    }
+}
+
+impl HandleRules {
+    fn rules(&self) -> (StringMandatoryRules, StringLengthRules) {
+        self.into()
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let (mandatory_rule, length_rule) = self.rules();
+        mandatory_rule.check(messages, subject);
+        if !messages.is_empty() {
+            return;
+        }
+        length_rule.check(messages, subject);
+    }
+
+    fn check_chars(&self, messages: &mut ValidateErrorCollector, handle: &str) {
+        if handle.is_empty() {
+            return;
+        }
+        let is_separator = |c: char| self.separator_chars.contains(&c);
+        let is_valid = handle.chars().all(self.allowed_chars)
+            && !handle.starts_with(is_separator)
+            && !handle.ends_with(is_separator)
+            && !handle
+                .chars()
+                .zip(handle.chars().skip(1))
+                .any(|(a, b)| is_separator(a) && is_separator(b));
+        if !is_valid {
+            messages.push((
+                "Contains invalid characters".to_string(),
+                Box::new(HandleLocale(self.platform)),
+            ));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, length_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(length_rule.describe());
+        constraints.push(Constraint::from_locale(&HandleLocale(self.platform)));
+        constraints
+    }
+}
+
+/// A locale message for a handle that does not follow the character set of its platform.
+///
+/// # Key
+/// * `validate-handle-invalid` for `HandlePlatform::Generic`
+/// * `validate-handle-twitter-invalid` for `HandlePlatform::Twitter`
+/// * `validate-handle-instagram-invalid` for `HandlePlatform::Instagram`
+/// * `validate-handle-github-invalid` for `HandlePlatform::GitHub`
+pub struct HandleLocale(pub HandlePlatform);
+
+impl LocaleMessage for HandleLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        match self.0 {
+            HandlePlatform::Generic => ld::new(locale_keys::VALIDATE_HANDLE_INVALID),
+            HandlePlatform::Twitter => ld::new(locale_keys::VALIDATE_HANDLE_TWITTER_INVALID),
+            HandlePlatform::Instagram => ld::new(locale_keys::VALIDATE_HANDLE_INSTAGRAM_INVALID),
+            HandlePlatform::GitHub => ld::new(locale_keys::VALIDATE_HANDLE_GITHUB_INVALID),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self.0 {
+            HandlePlatform::Generic => RuleViolation::pattern("handle"),
+            HandlePlatform::Twitter => RuleViolation::pattern("handle-twitter"),
+            HandlePlatform::Instagram => RuleViolation::pattern("handle-instagram"),
+            HandlePlatform::GitHub => RuleViolation::pattern("handle-github"),
+        }
+    }
+}
+
+/// Represents an error that occurs during handle validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Handle Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Handle Validation Error")]
+pub struct HandleError(pub ValidateErrorStore);
+
+impl ValidationCheck for HandleError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&HandleError> for ValidateErrorStore {
+    fn from(error: &HandleError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A structure representing a validated social media handle.
+///
+/// # Fields
+/// - `0: String` - The handle, without the prefix.
+/// - `1: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct Handle(String, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for Handle {
+    fn default() -> Self {
+        Self(String::new(), true)
+    }
+}
+
+impl Handle {
+    /// Parses a handle based on the provided validation rules.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::social::{Handle, HandleRules};
+    ///
+    /// let handle = Handle::parse_custom(Some("@rustlang"), HandleRules::twitter());
+    /// assert_eq!(handle.map(|h| h.as_str().to_string()).ok().as_deref(), Some("rustlang"));
+    /// ```
+    pub fn parse_custom(s: Option<&str>, rules: HandleRules) -> Result<Self, HandleError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let s = match rules.prefix {
+                Some(prefix) => s.strip_prefix(prefix).unwrap_or(s),
+                None => s,
+            };
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            rules.check_chars(&mut messages, s);
+            HandleError::validate_check(messages)?;
+            Ok(Self(s.to_string(), is_none))
+        })
+    }
+
+    /// Parses a handle using the default `HandleRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, HandleError> {
+        Self::parse_custom(s, HandleRules::default())
+    }
+
+    /// Parses a Twitter/X handle using `HandleRules::twitter`.
+    pub fn parse_twitter(s: Option<&str>) -> Result<Self, HandleError> {
+        Self::parse_custom(s, HandleRules::twitter())
+    }
+
+    /// Parses an Instagram handle using `HandleRules::instagram`.
+    pub fn parse_instagram(s: Option<&str>) -> Result<Self, HandleError> {
+        Self::parse_custom(s, HandleRules::instagram())
+    }
+
+    /// Parses a GitHub handle using `HandleRules::github`.
+    pub fn parse_github(s: Option<&str>) -> Result<Self, HandleError> {
+        Self::parse_custom(s, HandleRules::github())
+    }
+
+    /// Returns the handle, without the prefix, as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the handle was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
+    /// Converts the current instance into an `Option<Handle>`.
+    ///
+    /// # Returns
+    /// - `None` if the handle was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<Handle> {
+        if self.1 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for Handle {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&Handle> for String {
+    fn from(handle: &Handle) -> Self {
+        handle.as_str().to_string()
+    }
+}
+
+impl Display for Handle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Handle {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Handle {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `Handle` key can be found by
+/// a `&str`.
+impl PartialEq for Handle {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Handle {}
+
+impl Hash for Handle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for Handle {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Handle {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for Handle {
+    type Error = HandleError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Handle {
+    type Error = HandleError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for Handle {
+    type Error = HandleError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, Handle, HandleError> for HandleRules {
+    fn validate(&self, input: Option<&str>) -> Result<Handle, HandleError> {
+        Handle::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for HandleRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Handle {
+    /// Creates an instance of `Handle` without validation, e.g. when loading values which were
+    /// validated before being stored. The string is kept as is, `None` gives an empty value.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(s.to_string(), is_none)
+    }
+
+    /// Creates an instance of `Handle` from a stored string without validation, so stored values
+    /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_twitter_handle() {
+        assert!(Handle::parse_twitter(Some("@jack")).is_ok());
+        assert!(Handle::parse_twitter(Some("jack_dorsey")).is_ok());
+        assert!(Handle::parse_twitter(Some("@this_is_way_too_long")).is_err());
+        assert!(Handle::parse_twitter(Some("jack.dorsey")).is_err());
+        assert!(Handle::parse_twitter(Some("@")).is_err());
+    }
+
+    #[test]
+    fn test_instagram_handle() {
+        assert!(Handle::parse_instagram(Some("some.user_1")).is_ok());
+        assert!(Handle::parse_instagram(Some(".someuser")).is_err());
+        assert!(Handle::parse_instagram(Some("some..user")).is_err());
+    }
+
+    #[test]
+    fn test_github_handle() {
+        assert!(Handle::parse_github(Some("rust-lang")).is_ok());
+        assert!(Handle::parse_github(Some("rust-")).is_err());
+        assert!(Handle::parse_github(Some("rust--lang")).is_err());
+        let err = Handle::parse_github(Some("rust_lang"))
+            .err()
+            .unwrap_or_default();
+        assert_eq!(
+            err.0.0[0].1.get_locale_data().name,
+            "validate-handle-github-invalid"
+        );
+    }
+}
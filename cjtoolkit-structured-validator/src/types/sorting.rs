@@ -0,0 +1,443 @@
+//! This module contains structures and traits for working with sort/order-by expressions.
+//!
+//! A sort expression is a comma separated list of field names, where each field can be
+//! prefixed with `-` for descending order or `+` for ascending order, for example
+//! `-created_at,name`.
+//!
+//! No field is allowed by default, so a `SortExpr` is only parsed with explicit `SortRules`,
+//! through `SortExpr::parse_custom` or the `Validator` implementation of `SortRules`.
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// The direction of a sorted field.
+///
+/// # Variants
+/// - `Ascending`: The field has no prefix or is prefixed with `+`.
+/// - `Descending`: The field is prefixed with `-`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Ascending,
+    Descending,
+}
+
+/// A structure representing the rules for a sort expression.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean field indicating whether the sort expression is required.
+/// * `allowed_fields` - The list of field names that are allowed to be sorted on.
+///
+/// # Defaults
+/// By default, the sort expression is optional and no field is allowed.
+#[derive(Clone, Default)]
+pub struct SortRules {
+    pub is_mandatory: bool,
+    pub allowed_fields: Vec<String>,
+}
+
+impl From<&SortRules> for StringMandatoryRules {
+    fn from(rules: &SortRules) -> Self {
+        StringMandatoryRules {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: true,
+        }
+    }
+}
+
+impl SortRules {
+    fn rule(&self) -> StringMandatoryRules {
+        self.into()
+    }
+
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        let rule = self.rule();
+        rule.check(messages, subject);
+    }
+
+    fn check_format(&self, messages: &mut ValidateErrorCollector, fields: &[(String, Direction)]) {
+        if fields.iter().any(|(field, _)| field.is_empty()) {
+            messages.push((
+                "Must not have an empty field".to_string(),
+                Box::new(SortFormatLocale::EmptyField),
+            ));
+        }
+        for (index, (field, _)) in fields.iter().enumerate() {
+            let is_first_duplicate = !field.is_empty()
+                && fields[..index].iter().all(|(other, _)| other != field)
+                && fields[index + 1..].iter().any(|(other, _)| other == field);
+            if is_first_duplicate {
+                messages.push((
+                    format!("Cannot sort by '{}' more than once", field),
+                    Box::new(SortFormatLocale::DuplicateField(field.clone())),
+                ));
+            }
+        }
+    }
+
+    fn check_fields(&self, messages: &mut ValidateErrorCollector, fields: &[(String, Direction)]) {
+        for (field, _) in fields {
+            if !self.allowed_fields.iter().any(|allowed| allowed == field) {
+                messages.push((
+                    format!("Cannot sort by '{}'", field),
+                    Box::new(SortFieldNotAllowedLocale(field.clone())),
+                ));
+            }
+        }
+    }
+
+    /// Returns the constraints enforced by the rules, the fields allowed being listed in
+    /// `allowed_fields`.
+    pub fn describe(&self) -> Vec<Constraint> {
+        self.rule().describe()
+    }
+}
+
+/// A locale message for a field that is not in the allow-list of `SortRules`.
+///
+/// # Fields
+/// - `0`: The offending field name.
+///
+/// # Key
+/// `validate-sort-field-not-allowed`
+pub struct SortFieldNotAllowedLocale(pub String);
+
+impl LocaleMessage for SortFieldNotAllowedLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_SORT_FIELD_NOT_ALLOWED,
+            vec![("field".to_string(), LocaleValue::from(self.0.clone()))],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::FieldNotAllowed {
+            field: self.0.clone(),
+        }
+    }
+}
+
+/// A locale message for a sort expression which is not a list of distinct fields.
+///
+/// # Variants
+/// - `EmptyField`: A field is empty, e.g. in `name,` or `-`.
+/// - `DuplicateField(String)`: The field is sorted on more than once, e.g. in `name,-name`.
+pub enum SortFormatLocale {
+    /// # Key
+    /// `validate-sort-empty-field`
+    EmptyField,
+    /// # Key
+    /// `validate-sort-duplicate-field`
+    DuplicateField(String),
+}
+
+impl LocaleMessage for SortFormatLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        match self {
+            Self::EmptyField => LocaleData::new(locale_keys::VALIDATE_SORT_EMPTY_FIELD),
+            Self::DuplicateField(field) => LocaleData::new_with_vec(
+                locale_keys::VALIDATE_SORT_DUPLICATE_FIELD,
+                vec![("field".to_string(), LocaleValue::from(field.clone()))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::EmptyField => RuleViolation::pattern("sort"),
+            Self::DuplicateField(_) => {
+                RuleViolation::Custom(locale_keys::VALIDATE_SORT_DUPLICATE_FIELD.to_string())
+            }
+        }
+    }
+}
+
+/// Represents an error that occurs during sort expression validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Sort Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Sort Validation Error")]
+pub struct SortError(pub ValidateErrorStore);
+
+impl ValidationCheck for SortError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&SortError> for ValidateErrorStore {
+    fn from(error: &SortError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A structure representing a validated sort expression.
+///
+/// # Fields
+/// - `0: String` - The original sort expression.
+/// - `1: Vec<(String, Direction)>` - The parsed fields with their direction.
+/// - `2: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+pub struct SortExpr(String, Vec<(String, Direction)>, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for SortExpr {
+    fn default() -> Self {
+        Self(String::new(), vec![], true)
+    }
+}
+
+impl SortExpr {
+    fn parse_fields(s: &str) -> Vec<(String, Direction)> {
+        if s.trim().is_empty() {
+            return vec![];
+        }
+        s.split(',')
+            .map(|field| {
+                let field = field.trim();
+                if let Some(field) = field.strip_prefix('-') {
+                    (field.to_string(), Direction::Descending)
+                } else {
+                    let field = field.strip_prefix('+').unwrap_or(field);
+                    (field.to_string(), Direction::Ascending)
+                }
+            })
+            .collect()
+    }
+
+    /// Parses a sort expression and checks every field against the allow-list in `rules`.
+    ///
+    /// # Errors
+    /// Returns a `SortError` when the expression is blank while mandatory, has an empty field or
+    /// a field more than once, or with one `validate-sort-field-not-allowed` message per field
+    /// that is not allowed.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::sorting::{Direction, SortExpr, SortRules};
+    ///
+    /// let rules = SortRules {
+    ///     is_mandatory: true,
+    ///     allowed_fields: vec!["created_at".to_string(), "name".to_string()],
+    /// };
+    /// let sort = SortExpr::parse_custom(Some("-created_at,name"), rules).unwrap();
+    /// assert_eq!(
+    ///     sort.as_fields(),
+    ///     &[
+    ///         ("created_at".to_string(), Direction::Descending),
+    ///         ("name".to_string(), Direction::Ascending),
+    ///     ]
+    /// );
+    /// ```
+    pub fn parse_custom(s: Option<&str>, rules: SortRules) -> Result<Self, SortError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            SortError::validate_check(messages)?;
+            let fields = Self::parse_fields(s);
+            let mut messages = ValidateErrorCollector::new();
+            rules.check_format(&mut messages, &fields);
+            SortError::validate_check(messages)?;
+            let mut messages = ValidateErrorCollector::new();
+            rules.check_fields(&mut messages, &fields);
+            SortError::validate_check(messages)?;
+            Ok(Self(s.to_string(), fields, is_none))
+        })
+    }
+
+    /// Returns the parsed fields with their direction.
+    pub fn as_fields(&self) -> &[(String, Direction)] {
+        &self.1
+    }
+
+    /// Returns the original sort expression as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the sort expression was not provided.
+    pub fn is_none(&self) -> bool {
+        self.2
+    }
+
+    /// Converts the current instance into an `Option<SortExpr>`.
+    ///
+    /// # Returns
+    /// - `None` if the sort expression was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<SortExpr> {
+        if self.2 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for SortExpr {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&SortExpr> for String {
+    fn from(sort_expr: &SortExpr) -> Self {
+        sort_expr.as_str().to_string()
+    }
+}
+
+impl Display for SortExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for SortExpr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for SortExpr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `SortExpr` key can be found by
+/// a `&str`.
+impl PartialEq for SortExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SortExpr {}
+
+impl Hash for SortExpr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for SortExpr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SortExpr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Validator<Option<&str>, SortExpr, SortError> for SortRules {
+    fn validate(&self, input: Option<&str>) -> Result<SortExpr, SortError> {
+        SortExpr::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for SortRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> SortRules {
+        SortRules {
+            is_mandatory: true,
+            allowed_fields: vec!["created_at".to_string(), "name".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_valid_sort() {
+        let sort = SortExpr::parse_custom(Some("-created_at, +name"), rules());
+        assert!(sort.is_ok());
+        let sort = sort.unwrap_or_default();
+        assert_eq!(sort.as_fields()[0].1, Direction::Descending);
+        assert_eq!(sort.as_fields()[1].1, Direction::Ascending);
+    }
+
+    #[test]
+    fn test_field_not_allowed() {
+        let sort = SortExpr::parse_custom(Some("name,-password,email"), rules());
+        let err = sort.err().unwrap_or_default();
+        assert_eq!(
+            err.0.as_original_message_vec(),
+            vec!["Cannot sort by 'password'", "Cannot sort by 'email'"]
+        );
+        let data = err.0.0[0].1.get_locale_data();
+        assert_eq!(data.name, "validate-sort-field-not-allowed");
+        assert!(data.args.contains_key("field"));
+    }
+
+    #[test]
+    fn test_mandatory_sort() {
+        assert!(SortExpr::parse_custom(None, rules()).is_err());
+        let err = SortExpr::parse_custom(Some(" "), rules()).expect_err("Expected blank sort");
+        assert_eq!(err.0.as_original_message_vec(), vec!["Cannot be empty"]);
+        assert!(SortExpr::parse_custom(None, SortRules::default()).is_ok());
+        assert!(SortExpr::parse_custom(Some(" "), SortRules::default()).is_ok());
+    }
+
+    #[test]
+    fn test_empty_field() {
+        for s in ["name,", ",name", "name,,created_at", "-"] {
+            let err = SortExpr::parse_custom(Some(s), rules()).expect_err("Expected empty field");
+            assert_eq!(
+                err.0.as_original_message_vec(),
+                vec!["Must not have an empty field"],
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_duplicate_field() {
+        let err = SortExpr::parse_custom(Some("name,-name,created_at,+name"), rules())
+            .expect_err("Expected duplicate field");
+        assert_eq!(
+            err.0.as_original_message_vec(),
+            vec!["Cannot sort by 'name' more than once"]
+        );
+        let data = err.0.0[0].1.get_locale_data();
+        assert_eq!(data.name, "validate-sort-duplicate-field");
+        assert!(data.args.contains_key("field"));
+    }
+}
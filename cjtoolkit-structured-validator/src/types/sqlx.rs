@@ -0,0 +1,186 @@
+//! This module contains the `sqlx` support of the value types.
+//!
+//! The string types are stored as text and the numeric types as `BIGINT` or `DOUBLE`,
+//! for every database where `String`, `i64` and `f64` are supported. A value that was not
+//! provided is encoded as `NULL`, use `Option<T>` for nullable columns.
+//!
+//! Decoding does not validate, the stored values are loaded with `from_stored` and
+//! `new_unchecked`, as they were validated with the rules of the application before being
+//! stored, and may no longer satisfy the rules, or the default rules of the type. Only the
+//! numbers out of the range of the type are reported as decode errors.
+
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Decode, Encode, Type};
+
+macro_rules! sqlx_value {
+    ($type:ty, $inner:ty, |$value:ident| $encode:expr, |$decoded:ident| $decode:expr) => {
+        impl<DB: Database> Type<DB> for $type
+        where
+            $inner: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$inner as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$inner as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: Database> Encode<'q, DB> for $type
+        where
+            $inner: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                let $value = self;
+                if $value.is_none() {
+                    return Ok(IsNull::Yes);
+                }
+                let inner: $inner = $encode;
+                inner.encode_by_ref(buf)
+            }
+        }
+
+        impl<'r, DB: Database> Decode<'r, DB> for $type
+        where
+            $inner: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                let $decoded = <$inner as Decode<'r, DB>>::decode(value)?;
+                Ok($decode)
+            }
+        }
+    };
+}
+
+macro_rules! sqlx_str {
+    ($type:ty) => {
+        sqlx_value!(
+            $type,
+            String,
+            |value| value.as_str().to_string(),
+            |decoded| <$type>::from_stored(&decoded)
+        );
+    };
+}
+
+sqlx_str!(crate::types::name::Name);
+sqlx_str!(crate::types::name::name_alias::Title);
+sqlx_str!(crate::types::name::name_alias::FirstName);
+sqlx_str!(crate::types::name::name_alias::ForeName);
+sqlx_str!(crate::types::name::name_alias::MiddleName);
+sqlx_str!(crate::types::name::name_alias::LastName);
+sqlx_str!(crate::types::name::name_alias::AddressLine);
+sqlx_str!(crate::types::name::name_alias::Field);
+sqlx_str!(crate::types::description::Description);
+sqlx_str!(crate::types::username::Username);
+sqlx_str!(crate::types::social::Handle);
+sqlx_str!(crate::types::net::domain::Domain);
+sqlx_str!(crate::types::net::hostname::Hostname);
+sqlx_str!(crate::types::net::mac_address::MacAddress);
+
+#[cfg(feature = "email")]
+sqlx_str!(crate::types::email::Email);
+
+#[cfg(feature = "url")]
+sqlx_str!(crate::types::url::Url);
+
+sqlx_value!(
+    crate::types::numbers::float::Float,
+    f64,
+    |value| value.as_f64(),
+    |decoded| crate::types::numbers::float::Float::new_unchecked(Some(decoded))
+);
+
+sqlx_value!(
+    crate::types::numbers::integer::Integer,
+    i64,
+    |value| i64::try_from(value.as_isize())?,
+    |decoded| crate::types::numbers::integer::Integer::new_unchecked(Some(isize::try_from(
+        decoded
+    )?))
+);
+
+sqlx_value!(
+    crate::types::numbers::unsigned::Unsigned,
+    i64,
+    |value| i64::try_from(value.as_usize())?,
+    |decoded| {
+        crate::types::numbers::unsigned::Unsigned::new_unchecked(Some(usize::try_from(decoded)?))
+    }
+);
+
+sqlx_value!(
+    crate::types::numbers::unsigned::U64,
+    i64,
+    |value| i64::try_from(value.as_u64())?,
+    |decoded| crate::types::numbers::unsigned::U64::new_unchecked(Some(u64::try_from(decoded)?))
+);
+
+sqlx_value!(
+    crate::types::numbers::unsigned::U32,
+    i64,
+    |value| i64::from(value.as_u32()),
+    |decoded| crate::types::numbers::unsigned::U32::new_unchecked(Some(u32::try_from(decoded)?))
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::types::name::Name;
+    use crate::types::name::name_alias::FirstName;
+    use crate::types::numbers::float::Float;
+    use crate::types::numbers::integer::{Integer, IntegerRules};
+    use ::sqlx::sqlite::SqliteConnection;
+    use ::sqlx::{Connection, Row};
+
+    async fn connect() -> SqliteConnection {
+        SqliteConnection::connect("sqlite::memory:")
+            .await
+            .expect("Expected in-memory database")
+    }
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let mut connection = connect().await;
+        let name = Name::parse(Some("Valid Name")).expect("Expected valid name");
+        let count = Integer::parse(Some(42)).expect("Expected valid integer");
+        let ratio = Float::parse(Some(1.5)).expect("Expected valid float");
+        let optional = IntegerRules {
+            is_mandatory: false,
+            ..IntegerRules::default()
+        };
+        let absent = Integer::parse_custom(None, optional).expect("Expected absent integer");
+        let row = ::sqlx::query("SELECT ?, ?, ?, ?")
+            .bind(name.clone())
+            .bind(count.clone())
+            .bind(ratio.clone())
+            .bind(absent)
+            .fetch_one(&mut connection)
+            .await
+            .expect("Expected row");
+        assert_eq!(row.try_get::<Name, _>(0).ok(), Some(name));
+        assert_eq!(row.try_get::<Integer, _>(1).ok(), Some(count));
+        assert_eq!(row.try_get::<Float, _>(2).ok(), Some(ratio));
+        assert_eq!(row.try_get::<Option<Integer>, _>(3).ok(), Some(None));
+    }
+
+    #[tokio::test]
+    async fn test_decode_does_not_validate() {
+        let mut connection = connect().await;
+        let row = ::sqlx::query("SELECT 'Al', 'Al', 1000")
+            .fetch_one(&mut connection)
+            .await
+            .expect("Expected row");
+        assert!(row.try_get::<Name, _>(0).is_ok_and(|name| name == "Al"));
+        assert!(
+            row.try_get::<FirstName, _>(1)
+                .is_ok_and(|name| name == "Al")
+        );
+        let count = row.try_get::<Integer, _>(2).map(|count| count.as_isize());
+        assert_eq!(count.ok(), Some(1000));
+    }
+}
@@ -0,0 +1,486 @@
+//! This module contains structures and traits for working with template strings, e.g. the
+//! subject of a notification email, `Hello {name}`, edited by the users.
+//!
+//! The placeholders are delimited by `{` and `}` by default, or by `{{` and `}}`, see
+//! `PlaceholderSyntax`. A template is valid if its placeholders are balanced and named, and,
+//! if `allowed_placeholders` is set, only use the allowed names, so a typo like `{nmae}` is
+//! caught when the template is saved rather than when it is rendered.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::template::{TemplateString, TemplateStringRules};
+//!
+//! let rules = TemplateStringRules {
+//!     allowed_placeholders: Some(vec!["name".to_string(), "order".to_string()]),
+//!     ..TemplateStringRules::default()
+//! };
+//! let subject = "Hi {name}, order {order} shipped";
+//! let template = TemplateString::parse_custom(Some(subject), rules.clone())
+//!     .expect("Expected template to be valid");
+//! assert_eq!(template.placeholders(), ["name", "order"]);
+//!
+//! let error = TemplateString::parse_custom(Some("Hi {nmae}"), rules.clone())
+//!     .expect_err("Expected unknown placeholder");
+//! assert_eq!(error.0.as_original_message_vec(), vec!["Unknown placeholder: nmae"]);
+//! assert!(TemplateString::parse_custom(Some("Hi {name"), rules).is_err());
+//! ```
+
+use crate::base::string_rules::StringMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::rule_result;
+use crate::common::rejected_input::observe_parse_input;
+use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// The delimiters of the placeholders of a template.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum PlaceholderSyntax {
+    /// `{name}`, with `{{` and `}}` for literal braces.
+    #[default]
+    Brace,
+    /// `{{name}}`, as in Mustache or Handlebars, single braces being literal.
+    DoubleBrace,
+}
+
+impl PlaceholderSyntax {
+    /// Returns the opening and closing delimiters.
+    pub fn delimiters(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Brace => ("{", "}"),
+            Self::DoubleBrace => ("{{", "}}"),
+        }
+    }
+
+    /// Returns the names of the placeholders of `template`, in order, or `None` if a placeholder
+    /// is unbalanced or has no name.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::template::PlaceholderSyntax;
+    ///
+    /// let syntax = PlaceholderSyntax::DoubleBrace;
+    /// assert_eq!(syntax.placeholders("{{ a }} {b} {{c}}"), Some(vec!["a", "c"]));
+    /// assert_eq!(syntax.placeholders("{{a}} }}"), None);
+    /// assert_eq!(PlaceholderSyntax::Brace.placeholders("{{a}} {b}"), Some(vec!["b"]));
+    /// ```
+    pub fn placeholders<'a>(&self, template: &'a str) -> Option<Vec<&'a str>> {
+        let (open, close) = self.delimiters();
+        let mut placeholders = Vec::new();
+        let mut rest = template;
+        while let Some(start) = rest.find(['{', '}']) {
+            rest = &rest[start..];
+            if *self == Self::Brace && (rest.starts_with("{{") || rest.starts_with("}}")) {
+                rest = &rest[2..];
+                continue;
+            }
+            if rest.starts_with(close) {
+                return None;
+            }
+            let Some(inner) = rest.strip_prefix(open) else {
+                rest = &rest[1..];
+                continue;
+            };
+            let end = inner.find(close)?;
+            let name = inner[..end].trim();
+            if name.is_empty() || name.contains(open) {
+                return None;
+            }
+            placeholders.push(name);
+            rest = &inner[end + close.len()..];
+        }
+        Some(placeholders)
+    }
+}
+
+/// A structure to define the rules of a template string.
+///
+/// # Fields
+///
+/// * `is_mandatory` - Whether the template is mandatory.
+/// * `syntax` - The delimiters of the placeholders, `{name}` by default.
+/// * `allowed_placeholders` - The names the placeholders may use, `None` for any name.
+#[derive(Clone)]
+pub struct TemplateStringRules {
+    pub is_mandatory: bool,
+    pub syntax: PlaceholderSyntax,
+    pub allowed_placeholders: Option<Vec<String>>,
+}
+
+impl Default for TemplateStringRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            syntax: PlaceholderSyntax::Brace,
+            allowed_placeholders: None,
+        }
+    }
+}
+
+impl From<&TemplateStringRules> for StringMandatoryRules {
+    fn from(rules: &TemplateStringRules) -> Self {
+        Self {
+            is_mandatory: rules.is_mandatory,
+            treat_whitespace_as_empty: true,
+        }
+    }
+}
+
+impl TemplateStringRules {
+    fn check(
+        &self,
+        messages: &mut ValidateErrorCollector,
+        subject: &StringValidator,
+        placeholders: Option<&[&str]>,
+        is_none: bool,
+    ) {
+        if !self.is_mandatory && is_none {
+            return;
+        }
+        StringMandatoryRules::from(self).check(messages, subject);
+        rule_result(
+            locale_keys::VALIDATE_TEMPLATE_UNBALANCED,
+            placeholders.is_some(),
+        );
+        let Some(placeholders) = placeholders else {
+            messages.push((
+                "Has an unbalanced or empty placeholder".to_string(),
+                Box::new(TemplateLocale::Unbalanced),
+            ));
+            return;
+        };
+        let Some(allowed) = &self.allowed_placeholders else {
+            return;
+        };
+        let mut unknown: Vec<&str> = Vec::new();
+        for placeholder in placeholders {
+            if !allowed.iter().any(|name| name == placeholder) && !unknown.contains(placeholder) {
+                unknown.push(placeholder);
+            }
+        }
+        rule_result(
+            locale_keys::VALIDATE_TEMPLATE_UNKNOWN_PLACEHOLDER,
+            unknown.is_empty(),
+        );
+        for placeholder in unknown {
+            messages.push((
+                format!("Unknown placeholder: {}", placeholder),
+                Box::new(TemplateLocale::unknown_placeholder(placeholder, allowed)),
+            ));
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = StringMandatoryRules::from(self).describe();
+        constraints.push(Constraint::from_locale(&TemplateLocale::Unbalanced));
+        if let Some(allowed) = &self.allowed_placeholders {
+            constraints.push(Constraint::from_locale(
+                &TemplateLocale::unknown_placeholder("", allowed),
+            ));
+        }
+        constraints
+    }
+}
+
+/// Represents an error that occurs during template string validation.
+///
+/// # Display
+/// The `Display` implementation for this error will output: `"Template Validation Error"`.
+///
+/// # Fields
+/// - `0`: A `ValidateErrorStore` instance, which contains details about the validation errors encountered.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Template Validation Error")]
+pub struct TemplateStringError(pub ValidateErrorStore);
+
+impl ValidationCheck for TemplateStringError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&TemplateStringError> for ValidateErrorStore {
+    fn from(error: &TemplateStringError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// An enumeration of the template specific validation failures.
+pub enum TemplateLocale {
+    /// A placeholder is not closed, not opened, or has no name.
+    /// # Key
+    /// `validate-template-unbalanced`
+    Unbalanced,
+    /// A placeholder uses a name which is not allowed, with the comma separated `allowed` names.
+    /// # Key
+    /// `validate-template-unknown-placeholder`
+    UnknownPlaceholder {
+        placeholder: String,
+        allowed: String,
+    },
+}
+
+impl TemplateLocale {
+    fn unknown_placeholder(placeholder: &str, allowed: &[String]) -> Self {
+        Self::UnknownPlaceholder {
+            placeholder: placeholder.to_string(),
+            allowed: allowed.join(", "),
+        }
+    }
+}
+
+impl LocaleMessage for TemplateLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::Unbalanced => ld::new(locale_keys::VALIDATE_TEMPLATE_UNBALANCED),
+            Self::UnknownPlaceholder {
+                placeholder,
+                allowed,
+            } => ld::new_with_vec(
+                locale_keys::VALIDATE_TEMPLATE_UNKNOWN_PLACEHOLDER,
+                vec![
+                    ("placeholder".to_string(), lv::from(placeholder.clone())),
+                    ("allowed".to_string(), lv::from(allowed.clone())),
+                ],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::Unbalanced => RuleViolation::pattern("template"),
+            Self::UnknownPlaceholder { allowed, .. } => RuleViolation::NotAllowed {
+                allowed: allowed.clone(),
+            },
+        }
+    }
+}
+
+/// A structure representing a validated template string.
+///
+/// # Fields
+/// - `0: String` - The template.
+/// - `1: Vec<String>` - The names of the placeholders, in order.
+/// - `2: bool` - A boolean flag, none if `true`, otherwise `false`.
+#[derive(Debug, Clone)]
+pub struct TemplateString(String, Vec<String>, bool);
+
+#[cfg(any(feature = "allow-default-value", test))]
+impl Default for TemplateString {
+    fn default() -> Self {
+        Self(String::new(), Vec::new(), true)
+    }
+}
+
+impl TemplateString {
+    /// Parses a template string based on the provided validation rules.
+    ///
+    /// # Errors
+    /// Returns a `TemplateStringError` if the template is empty while mandatory, has an
+    /// unbalanced or empty placeholder, or uses a placeholder which is not allowed.
+    pub fn parse_custom(
+        s: Option<&str>,
+        rules: TemplateStringRules,
+    ) -> Result<Self, TemplateStringError> {
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let placeholders = rules.syntax.placeholders(s);
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(
+                &mut messages,
+                &s.as_string_validator(),
+                placeholders.as_deref(),
+                is_none,
+            );
+            TemplateStringError::validate_check(messages)?;
+            let placeholders = placeholders
+                .unwrap_or_default()
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+            Ok(Self(s.to_string(), placeholders, is_none))
+        })
+    }
+
+    /// Parses a template string using the default `TemplateStringRules`.
+    pub fn parse(s: Option<&str>) -> Result<Self, TemplateStringError> {
+        Self::parse_custom(s, TemplateStringRules::default())
+    }
+
+    /// Returns the template.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the names of the placeholders, in order, with duplicates.
+    pub fn placeholders(&self) -> &[String] {
+        &self.1
+    }
+
+    /// Returns `true` if the template was not provided.
+    pub fn is_none(&self) -> bool {
+        self.2
+    }
+
+    /// Converts the current instance into an `Option<TemplateString>`.
+    ///
+    /// # Returns
+    /// - `None` if the template was not provided.
+    /// - `Some(self)` otherwise.
+    pub fn into_option(self) -> Option<TemplateString> {
+        if self.2 { None } else { Some(self) }
+    }
+}
+
+impl OptionalValue for TemplateString {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl From<&TemplateString> for String {
+    fn from(template: &TemplateString) -> Self {
+        template.as_str().to_string()
+    }
+}
+
+impl Display for TemplateString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for TemplateString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for TemplateString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `TemplateString` key can be found by
+/// a `&str`.
+impl PartialEq for TemplateString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for TemplateString {}
+
+impl Hash for TemplateString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for TemplateString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for TemplateString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for TemplateString {
+    type Error = TemplateStringError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for TemplateString {
+    type Error = TemplateStringError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for TemplateString {
+    type Error = TemplateStringError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl Validator<Option<&str>, TemplateString, TemplateStringError> for TemplateStringRules {
+    fn validate(&self, input: Option<&str>) -> Result<TemplateString, TemplateStringError> {
+        TemplateString::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for TemplateStringRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brace_placeholders() {
+        let syntax = PlaceholderSyntax::Brace;
+        assert_eq!(
+            syntax.placeholders("{a} {{literal}} { b }"),
+            Some(vec!["a", "b"])
+        );
+        for template in ["{a", "a}", "{}", "{a{b}", "{ }"] {
+            assert_eq!(syntax.placeholders(template), None, "{}", template);
+        }
+    }
+
+    #[test]
+    fn test_unknown_placeholders() {
+        let rules = TemplateStringRules {
+            syntax: PlaceholderSyntax::DoubleBrace,
+            allowed_placeholders: Some(vec!["name".to_string()]),
+            ..TemplateStringRules::default()
+        };
+        let error = TemplateString::parse_custom(Some("{{x}} {{name}} {{y}} {{x}}"), rules)
+            .expect_err("Expected unknown placeholders");
+        let placeholders: Vec<_> = error
+            .0
+            .0
+            .iter()
+            .map(|(_, locale)| locale.get_locale_data().args["placeholder"].clone())
+            .collect();
+        assert_eq!(
+            placeholders,
+            vec![
+                LocaleValue::from("x".to_string()),
+                LocaleValue::from("y".to_string())
+            ]
+        );
+    }
+}
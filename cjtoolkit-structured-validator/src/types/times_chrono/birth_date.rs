@@ -0,0 +1,363 @@
+use crate::base::date_time::clock::{Clock, SystemClock};
+use crate::base::date_time::data::AsDateTimeData;
+use crate::base::date_time::rules::DateTimeMandatoryRules;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::{observe_parse, rule_result};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// A struct representing the age requirements of a birth date.
+///
+/// The age is computed on the current date in UTC, as given by `clock`, a person born on the
+/// 29th of February turning a year older on the 1st of March in common years.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean flag that indicates whether the birth date is mandatory.
+/// * `min_age` - The minimum age in years, `None` for no minimum, birth dates in the future
+///   being rejected regardless.
+/// * `max_age` - The maximum age in years, `None` for no maximum.
+/// * `clock` - The source of the current date.
+///
+/// # Default
+///
+/// The birth date is mandatory, for an adult of at least 18 years, see `AgeRules::adult`.
+#[derive(Clone)]
+pub struct AgeRules {
+    pub is_mandatory: bool,
+    pub min_age: Option<u32>,
+    pub max_age: Option<u32>,
+    pub clock: Arc<dyn Clock>,
+}
+
+impl Default for AgeRules {
+    fn default() -> Self {
+        Self::adult()
+    }
+}
+
+impl AgeRules {
+    fn with_min_age(min_age: u32) -> Self {
+        Self {
+            is_mandatory: true,
+            min_age: Some(min_age),
+            max_age: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Rules requiring an age of at least 13 years, the threshold of parental consent of the
+    /// Children's Online Privacy Protection Act (COPPA).
+    pub fn coppa() -> Self {
+        Self::with_min_age(13)
+    }
+
+    /// Rules requiring an age of at least 16 years, the default threshold of parental consent of
+    /// the General Data Protection Regulation (GDPR).
+    pub fn gdpr() -> Self {
+        Self::with_min_age(16)
+    }
+
+    /// Rules requiring an age of at least 18 years.
+    pub fn adult() -> Self {
+        Self::with_min_age(18)
+    }
+
+    fn today(&self) -> NaiveDate {
+        DateTime::<Utc>::from(self.clock.now()).date_naive()
+    }
+
+    fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<&NaiveDate>) -> i64 {
+        let mandatory_rule = DateTimeMandatoryRules {
+            is_mandatory: self.is_mandatory,
+        };
+        mandatory_rule.check(messages, subject.map(|s| s.as_date_time_data()).as_ref());
+        let Some(subject) = subject else {
+            return 0;
+        };
+        let age = BirthDate::age_on(subject, &self.today());
+        let min_age = self.min_age.unwrap_or_default();
+        rule_result(locale_keys::VALIDATE_AGE_MIN, age >= i64::from(min_age));
+        if age < i64::from(min_age) {
+            messages.push((
+                format!("Must be at least {} years old", min_age),
+                Box::new(AgeLocale::MinAge { min: min_age, age }),
+            ));
+        }
+        if let Some(max_age) = self.max_age {
+            rule_result(locale_keys::VALIDATE_AGE_MAX, age <= i64::from(max_age));
+            if age > i64::from(max_age) {
+                messages.push((
+                    format!("Must be at most {} years old", max_age),
+                    Box::new(AgeLocale::MaxAge { max: max_age, age }),
+                ));
+            }
+        }
+        age
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mandatory_rule = DateTimeMandatoryRules {
+            is_mandatory: self.is_mandatory,
+        };
+        let mut constraints = mandatory_rule.describe();
+        if let Some(min) = self.min_age {
+            constraints.push(Constraint::from_locale(&AgeLocale::MinAge { min, age: 0 }));
+        }
+        if let Some(max) = self.max_age {
+            constraints.push(Constraint::from_locale(&AgeLocale::MaxAge { max, age: 0 }));
+        }
+        constraints
+    }
+}
+
+/// An enumeration of the age specific validation failures, with the computed `age`.
+pub enum AgeLocale {
+    /// The person is younger than `min` years.
+    /// # Key
+    /// `validate-age-min`
+    MinAge { min: u32, age: i64 },
+    /// The person is older than `max` years.
+    /// # Key
+    /// `validate-age-max`
+    MaxAge { max: u32, age: i64 },
+}
+
+impl LocaleMessage for AgeLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::MinAge { min, age } => ld::new_with_vec(
+                locale_keys::VALIDATE_AGE_MIN,
+                vec![
+                    ("min".to_string(), lv::from(*min as usize)),
+                    ("age".to_string(), lv::from(*age as isize)),
+                ],
+            ),
+            Self::MaxAge { max, age } => ld::new_with_vec(
+                locale_keys::VALIDATE_AGE_MAX,
+                vec![
+                    ("max".to_string(), lv::from(*max as usize)),
+                    ("age".to_string(), lv::from(*age as isize)),
+                ],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::MinAge { min, .. } => RuleViolation::Range {
+                min: Some(LocaleValue::from(*min as usize)),
+                max: None,
+            },
+            Self::MaxAge { max, .. } => RuleViolation::Range {
+                min: None,
+                max: Some(LocaleValue::from(*max as usize)),
+            },
+        }
+    }
+}
+
+/// Represents an error encountered during birth date validation.
+///
+/// # Fields
+/// - `0: ValidateErrorStore` - A field that stores validation errors for further analysis.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Birth Date Validation Error")]
+pub struct BirthDateError(pub ValidateErrorStore);
+
+impl ValidationCheck for BirthDateError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&BirthDateError> for ValidateErrorStore {
+    fn from(error: &BirthDateError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A validated, optional birth date, with the age computed when it was validated.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "allow-default-value", test), derive(Default))]
+pub struct BirthDate(Option<NaiveDate>, Option<u32>);
+
+impl BirthDate {
+    /// Returns the age in years on `today` of a person born on `birth_date`, negative for birth
+    /// dates after `today`.
+    pub fn age_on(birth_date: &NaiveDate, today: &NaiveDate) -> i64 {
+        let age = i64::from(today.year() - birth_date.year());
+        if (today.month(), today.day()) < (birth_date.month(), birth_date.day()) {
+            age - 1
+        } else {
+            age
+        }
+    }
+
+    /// Validates the age of a person born on `subject` against `rules`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BirthDateError` if `subject` is missing while mandatory, or the age is out of
+    /// the bounds of the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use cjtoolkit_structured_validator::base::date_time::clock::FixedClock;
+    /// use cjtoolkit_structured_validator::types::times_chrono::birth_date::{AgeRules, BirthDate};
+    /// use std::sync::Arc;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// // 2023-11-14
+    /// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let rules = AgeRules {
+    ///     clock: Arc::new(FixedClock(now)),
+    ///     ..AgeRules::gdpr()
+    /// };
+    ///
+    /// let birth_date = BirthDate::parse_custom(NaiveDate::from_ymd_opt(2007, 11, 14), rules.clone());
+    /// assert_eq!(birth_date.map(|b| b.age()).ok(), Some(Some(16)));
+    ///
+    /// let error = BirthDate::parse_custom(NaiveDate::from_ymd_opt(2007, 11, 15), rules)
+    ///     .expect_err("Expected to be too young");
+    /// assert_eq!(error.0.as_original_message_vec(), vec!["Must be at least 16 years old"]);
+    /// ```
+    pub fn parse_custom(
+        subject: Option<NaiveDate>,
+        rules: AgeRules,
+    ) -> Result<Self, BirthDateError> {
+        observe_parse(|| {
+            if !rules.is_mandatory && subject.is_none() {
+                return Ok(Self(None, None));
+            }
+            let mut messages = ValidateErrorCollector::new();
+            let age = rules.check(&mut messages, subject.as_ref());
+            BirthDateError::validate_check(messages)?;
+            Ok(Self(subject, subject.map(|_| age as u32)))
+        })
+    }
+
+    /// Validates the age of a person born on `subject` against the default rules.
+    pub fn parse(subject: Option<NaiveDate>) -> Result<Self, BirthDateError> {
+        Self::parse_custom(subject, AgeRules::default())
+    }
+
+    /// Returns the birth date, if any.
+    pub fn as_naive_date(&self) -> Option<NaiveDate> {
+        self.0
+    }
+
+    /// Returns the age in years when the birth date was validated, if any.
+    pub fn age(&self) -> Option<u32> {
+        self.1
+    }
+}
+
+impl OptionalValue for BirthDate {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl Validator<Option<NaiveDate>, BirthDate, BirthDateError> for AgeRules {
+    fn validate(&self, input: Option<NaiveDate>) -> Result<BirthDate, BirthDateError> {
+        BirthDate::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for AgeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::date_time::clock::FixedClock;
+    use std::time::{Duration, SystemTime};
+
+    fn rules_on(today: NaiveDate, rules: AgeRules) -> AgeRules {
+        let seconds = today
+            .and_hms_opt(12, 0, 0)
+            .unwrap_or_default()
+            .and_utc()
+            .timestamp();
+        AgeRules {
+            clock: Arc::new(FixedClock(
+                SystemTime::UNIX_EPOCH + Duration::from_secs(seconds as u64),
+            )),
+            ..rules
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(y, m, d)
+    }
+
+    #[test]
+    fn test_age_on() {
+        let birth_date = date(2008, 2, 29).unwrap_or_default();
+        let age_on = |y, m, d| BirthDate::age_on(&birth_date, &date(y, m, d).unwrap_or_default());
+        assert_eq!(age_on(2026, 2, 28), 17);
+        assert_eq!(age_on(2026, 3, 1), 18);
+        assert_eq!(age_on(2028, 2, 29), 20);
+        assert_eq!(age_on(2008, 2, 28), -1);
+    }
+
+    #[test]
+    fn test_presets() {
+        let today = date(2025, 6, 15).unwrap_or_default();
+        for (rules, min_age) in [
+            (AgeRules::coppa(), 13),
+            (AgeRules::gdpr(), 16),
+            (AgeRules::adult(), 18),
+        ] {
+            let rules = rules_on(today, rules);
+            let old_enough = date(2025 - min_age, 6, 15);
+            assert!(BirthDate::parse_custom(old_enough, rules.clone()).is_ok());
+            let too_young = date(2025 - min_age, 6, 16);
+            let error =
+                BirthDate::parse_custom(too_young, rules).expect_err("Expected to be too young");
+            let locale = error.0.0[0].1.get_locale_data();
+            assert_eq!(locale.name, locale_keys::VALIDATE_AGE_MIN);
+            assert_eq!(
+                locale.args.get("age"),
+                Some(&LocaleValue::from(min_age as isize - 1))
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_age_and_future() {
+        let rules = rules_on(
+            date(2025, 6, 15).unwrap_or_default(),
+            AgeRules {
+                min_age: None,
+                max_age: Some(120),
+                ..AgeRules::default()
+            },
+        );
+        assert!(BirthDate::parse_custom(date(1905, 6, 15), rules.clone()).is_ok());
+        assert!(BirthDate::parse_custom(date(1904, 6, 15), rules.clone()).is_err());
+        assert!(BirthDate::parse_custom(date(2025, 6, 16), rules.clone()).is_err());
+        assert!(BirthDate::parse_custom(None, rules).is_err());
+    }
+}
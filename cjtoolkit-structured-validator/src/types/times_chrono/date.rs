@@ -1,9 +1,21 @@
-use crate::base::date_time::data::AsDateTimeData;
-use crate::base::date_time::rules::{DateTimeMandatoryRules, DateTimeRangeRules};
-use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::base::date_time::clock::{Clock, SystemClock};
+use crate::base::date_time::data::{AsDateTimeData, DateTimeKind};
+use crate::base::date_time::rules::{
+    BusinessDayRules, DateTimeMandatoryRules, DateTimeParseRules, DateTimeRangeRules,
+};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::{observe_parse, rule_result};
 use crate::common::validation_check::ValidationCheck;
-use chrono::{NaiveDate, TimeDelta, Utc};
+use crate::common::validator::Validator;
+use chrono::{DateTime, Datelike, Month, NaiveDate, TimeDelta, Utc, WeekdaySet};
 use std::ops::Add;
+use std::sync::Arc;
 use thiserror::Error;
 
 /// A struct representing validation rules for a date field, specifying its mandatory
@@ -20,27 +32,46 @@ use thiserror::Error;
 /// * `max` - An `Option<NaiveDate>` representing the maximum allowable date. If set to `None`,
 ///   there is no upper-bound constraint on the date.
 ///
+/// * `business_days` - The rules requiring the date to be a business day, `None` to allow any
+///   day.
+///
+/// * `allowed_weekdays` - The days of the week the date may fall on, `None` to allow any day.
+///
+/// * `allowed_months` - The months of the year the date may fall in, `None` to allow any month.
+///
 /// # Note
 /// This struct uses `NaiveDate` from the `chrono` crate, which represents dates without time zones.
 /// Ensure that the `chrono` crate is added as a dependency in your project to use this struct.
+#[derive(Clone)]
 pub struct DateRules {
     pub is_mandatory: bool,
     pub min: Option<NaiveDate>,
     pub max: Option<NaiveDate>,
+    pub business_days: Option<BusinessDayRules>,
+    pub allowed_weekdays: Option<WeekdaySet>,
+    pub allowed_months: Option<MonthSet>,
 }
 
 impl Default for DateRules {
     fn default() -> Self {
-        let now = Utc::now();
+        Self::default_at(&SystemClock)
+    }
+}
+
+impl DateRules {
+    /// Returns the default rules, from the date of `clock` in UTC to 30 days later.
+    pub fn default_at(clock: &dyn Clock) -> Self {
+        let now = DateTime::<Utc>::from(clock.now());
         Self {
             is_mandatory: true,
             min: Some(now.clone().date_naive()),
             max: Some(now.clone().add(TimeDelta::days(30)).date_naive()),
+            business_days: None,
+            allowed_weekdays: None,
+            allowed_months: None,
         }
     }
-}
 
-impl DateRules {
     fn rules(&self, date_format: Option<&str>) -> (DateTimeMandatoryRules, DateTimeRangeRules) {
         (
             DateTimeMandatoryRules {
@@ -68,6 +99,7 @@ impl DateRules {
         if !self.is_mandatory && subject.is_none() {
             return;
         }
+        let date = subject;
         let subject = subject.map(|s| (date_format.clone(), s).as_date_time_data());
         let (mandatory_rule, range_rule) = self.rules(date_format);
         mandatory_rule.check(messages, subject.as_ref());
@@ -75,6 +107,192 @@ impl DateRules {
             return;
         }
         range_rule.check(messages, subject.as_ref());
+        if let Some(business_days) = &self.business_days {
+            business_days.check(messages, subject.as_ref());
+        }
+        if let Some(date) = date {
+            self.check_calendar(messages, date);
+        }
+    }
+
+    fn check_calendar(&self, messages: &mut ValidateErrorCollector, subject: &NaiveDate) {
+        if let Some(allowed_weekdays) = self.allowed_weekdays {
+            let passed = allowed_weekdays.contains(subject.weekday());
+            rule_result(locale_keys::VALIDATE_DATE_WEEKDAY_NOT_ALLOWED, passed);
+            if !passed {
+                let locale = DateCalendarLocale::weekdays(allowed_weekdays);
+                messages.push((locale.message(), Box::new(locale)));
+            }
+        }
+        if let Some(allowed_months) = self.allowed_months {
+            let passed = Month::try_from(subject.month() as u8)
+                .map(|month| allowed_months.contains(month))
+                .unwrap_or_default();
+            rule_result(locale_keys::VALIDATE_DATE_MONTH_NOT_ALLOWED, passed);
+            if !passed {
+                let locale = DateCalendarLocale::months(allowed_months);
+                messages.push((locale.message(), Box::new(locale)));
+            }
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules(None);
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        if let Some(business_days) = &self.business_days {
+            constraints.extend(business_days.describe());
+        }
+        if let Some(allowed_weekdays) = self.allowed_weekdays {
+            constraints.push(Constraint::from_locale(&DateCalendarLocale::weekdays(
+                allowed_weekdays,
+            )));
+        }
+        if let Some(allowed_months) = self.allowed_months {
+            constraints.push(Constraint::from_locale(&DateCalendarLocale::months(
+                allowed_months,
+            )));
+        }
+        constraints
+    }
+}
+
+/// A set of months of the year, e.g. for dates only allowed in the summer months.
+///
+/// # Example
+/// ```rust
+/// use chrono::Month;
+/// use cjtoolkit_structured_validator::types::times_chrono::date::MonthSet;
+///
+/// let summer = MonthSet::from_array([Month::June, Month::July, Month::August]);
+/// assert!(summer.contains(Month::July));
+/// assert!(!summer.contains(Month::December));
+/// assert_eq!(summer.len(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MonthSet(u16);
+
+impl MonthSet {
+    /// The set containing no month.
+    pub const EMPTY: Self = Self(0);
+    /// The set containing every month.
+    pub const ALL: Self = Self(0b1111_1111_1111);
+
+    /// Returns the set containing the given months.
+    pub fn from_array<const N: usize>(months: [Month; N]) -> Self {
+        months.into_iter().fold(Self::EMPTY, |mut set, month| {
+            set.insert(month);
+            set
+        })
+    }
+
+    /// Returns the set containing only `month`.
+    pub fn single(month: Month) -> Self {
+        Self::from_array([month])
+    }
+
+    /// Adds `month` to the set, returning `false` if it was already present.
+    pub fn insert(&mut self, month: Month) -> bool {
+        let contained = self.contains(month);
+        self.0 |= Self::bit(month);
+        !contained
+    }
+
+    /// Returns `true` if the set contains `month`.
+    pub fn contains(&self, month: Month) -> bool {
+        self.0 & Self::bit(month) != 0
+    }
+
+    /// Returns the number of months in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns `true` if the set contains no month.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the months in the set, from January to December.
+    pub fn iter(&self) -> impl Iterator<Item = Month> + '_ {
+        (1..=12u8)
+            .filter_map(|month| Month::try_from(month).ok())
+            .filter(|month| self.contains(*month))
+    }
+
+    fn bit(month: Month) -> u16 {
+        1 << month.number_from_month().saturating_sub(1)
+    }
+}
+
+/// An enumeration of the failures of the weekday and month rules of a date, holding the allowed
+/// values joined by a comma.
+#[derive(Clone)]
+pub enum DateCalendarLocale {
+    /// The date falls on a day of the week which is not allowed.
+    /// # Key
+    /// `validate-date-weekday-not-allowed`
+    WeekdayNotAllowed(String),
+    /// The date falls in a month which is not allowed.
+    /// # Key
+    /// `validate-date-month-not-allowed`
+    MonthNotAllowed(String),
+}
+
+impl DateCalendarLocale {
+    fn weekdays(allowed: WeekdaySet) -> Self {
+        Self::WeekdayNotAllowed(
+            allowed
+                .iter(chrono::Weekday::Mon)
+                .map(|weekday| weekday.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+
+    fn months(allowed: MonthSet) -> Self {
+        Self::MonthNotAllowed(
+            allowed
+                .iter()
+                .map(|month| month.name()[..3].to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::WeekdayNotAllowed(allowed) => format!("Must be on one of {}", allowed),
+            Self::MonthNotAllowed(allowed) => format!("Must be in one of {}", allowed),
+        }
+    }
+}
+
+impl LocaleMessage for DateCalendarLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::WeekdayNotAllowed(allowed) => ld::new_with_vec(
+                locale_keys::VALIDATE_DATE_WEEKDAY_NOT_ALLOWED,
+                vec![("allowed".to_string(), lv::from(allowed.clone()))],
+            ),
+            Self::MonthNotAllowed(allowed) => ld::new_with_vec(
+                locale_keys::VALIDATE_DATE_MONTH_NOT_ALLOWED,
+                vec![("allowed".to_string(), lv::from(allowed.clone()))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::WeekdayNotAllowed(allowed) | Self::MonthNotAllowed(allowed) => {
+                RuleViolation::NotAllowed {
+                    allowed: allowed.clone(),
+                }
+            }
+        }
     }
 }
 
@@ -173,10 +391,12 @@ impl DateValue {
         rules: DateRules,
         format: Option<&str>,
     ) -> Result<Self, DateError> {
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(subject.as_ref(), &mut messages, format);
-        DateError::validate_check(messages)?;
-        Ok(Self(subject))
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(subject.as_ref(), &mut messages, format);
+            DateError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
     }
 
     /// Parses a date based on the provided `subject` and `rules`.
@@ -260,6 +480,12 @@ impl DateValue {
         Self::parse_custom(subject, DateRules::default())
     }
 
+    /// Parses the given value using the default rules, with the current time given by `clock`
+    /// instead of the system time, see `DateRules::default_at`.
+    pub fn parse_at(subject: Option<NaiveDate>, clock: &dyn Clock) -> Result<Self, DateError> {
+        Self::parse_custom(subject, DateRules::default_at(clock))
+    }
+
     /// Parses a `NaiveDate` with a custom format and returns a `Self` type or a `DateError`.
     ///
     /// This function enables parsing of a `NaiveDate` (wrapped inside an `Option`) using a specified
@@ -303,6 +529,70 @@ impl DateValue {
         Self::parse_custom_with_format(subject, DateRules::default(), format)
     }
 
+    /// Parses `subject` with the first of the `formats` it matches, then validates the parsed
+    /// date against `rules`, with the dates of the error messages formatted with the matching
+    /// format.
+    ///
+    /// A missing or blank `subject` is treated as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateError` with a `validate-date-unparseable` message, having the accepted
+    /// `formats` as argument, if `subject` matches none of the `formats`, otherwise if the
+    /// parsed date does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use cjtoolkit_structured_validator::types::times_chrono::date::{
+    ///     AsNaiveDateOnResult, DateRules, DateValue,
+    /// };
+    ///
+    /// let rules = DateRules {
+    ///     is_mandatory: true,
+    ///     min: None,
+    ///     max: None,
+    ///     business_days: None,
+    ///     allowed_weekdays: None,
+    ///     allowed_months: None,
+    /// };
+    /// let formats = ["%Y-%m-%d", "%d/%m/%Y"];
+    ///
+    /// let date = DateValue::parse_str(Some("25/12/2024"), rules.clone(), &formats);
+    /// assert_eq!(date.as_naive_date(), NaiveDate::from_ymd_opt(2024, 12, 25));
+    ///
+    /// let error = DateValue::parse_str(Some("Christmas"), rules, &formats)
+    ///     .expect_err("Expected date to be unparseable");
+    /// assert_eq!(
+    ///     error.0.as_original_message_vec(),
+    ///     vec!["Must be in the format '%Y-%m-%d' or '%d/%m/%Y'"]
+    /// );
+    /// ```
+    pub fn parse_str(
+        subject: Option<&str>,
+        rules: DateRules,
+        formats: &[&str],
+    ) -> Result<Self, DateError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            let parse_rules = DateTimeParseRules {
+                kind: DateTimeKind::Date,
+                formats,
+            };
+            let (subject, format) = parse_rules
+                .parse(&mut messages, subject, |s, f| {
+                    NaiveDate::parse_from_str(s, f).ok()
+                })
+                .unzip();
+            if messages.is_empty() {
+                rules.check(subject.as_ref(), &mut messages, format);
+            }
+            DateError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
     /// Converts the `CustomDate` object into an `Option<NaiveDate>`.
     ///
     /// # Returns
@@ -316,6 +606,12 @@ impl DateValue {
     }
 }
 
+impl OptionalValue for DateValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
 pub trait AsNaiveDateOnResult {
     fn as_naive_date(&self) -> Option<NaiveDate>;
 }
@@ -329,9 +625,26 @@ impl<E> AsNaiveDateOnResult for Result<DateValue, E> {
     }
 }
 
+impl Validator<Option<NaiveDate>, DateValue, DateError> for DateRules {
+    fn validate(&self, input: Option<NaiveDate>) -> Result<DateValue, DateError> {
+        DateValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for DateRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::base::date_time::clock::FixedClock;
+    use std::time::{Duration, SystemTime};
 
     #[test]
     fn test_parse_custom() {
@@ -367,4 +680,98 @@ mod tests {
         let result = DateValue::parse(subject);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_business_day() {
+        use crate::base::date_time::data::CalendarDate;
+        use std::sync::Arc;
+
+        let new_year = |date: &CalendarDate| date.month == 1 && date.day == 1;
+        let rules = DateRules {
+            is_mandatory: true,
+            min: None,
+            max: None,
+            business_days: Some(BusinessDayRules {
+                exclude_weekends: true,
+                holidays: vec![Arc::new(new_year)],
+            }),
+            allowed_weekdays: None,
+            allowed_months: None,
+        };
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        assert!(DateValue::parse_custom(date(2025, 1, 2), rules.clone()).is_ok());
+        let error = DateValue::parse_custom(date(2025, 1, 1), rules.clone())
+            .expect_err("Expected new year to be a holiday");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be a business day"]
+        );
+        assert!(DateValue::parse_custom(date(2025, 1, 4), rules).is_err());
+    }
+
+    #[test]
+    fn test_allowed_weekdays_and_months() {
+        use chrono::Weekday;
+
+        let rules = DateRules {
+            is_mandatory: true,
+            min: None,
+            max: None,
+            business_days: None,
+            allowed_weekdays: Some(WeekdaySet::from_array([Weekday::Mon, Weekday::Wed])),
+            allowed_months: Some(MonthSet::from_array([Month::June, Month::July])),
+        };
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        assert!(DateValue::parse_custom(date(2025, 6, 2), rules.clone()).is_ok());
+        let error = DateValue::parse_custom(date(2025, 6, 3), rules.clone())
+            .expect_err("Expected Tuesday not to be allowed");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be on one of Mon, Wed"]
+        );
+        let error = DateValue::parse_custom(date(2025, 8, 4), rules.clone())
+            .expect_err("Expected August not to be allowed");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be in one of Jun, Jul"]
+        );
+        let constraints = rules.describe();
+        for constraint in &constraints {
+            assert!(locale_keys::all_keys().contains(&constraint.locale_key.as_str()));
+        }
+        assert_eq!(
+            constraints.last().map(|c| c.kind.clone()),
+            Some(RuleViolation::NotAllowed {
+                allowed: "Jun, Jul".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_str() {
+        let formats = ["%Y-%m-%d", "%d/%m/%Y"];
+        let subject = Utc::now().date_naive().format("%d/%m/%Y").to_string();
+        let result = DateValue::parse_str(Some(&subject), DateRules::default(), &formats);
+        assert_eq!(result.as_naive_date(), Some(Utc::now().date_naive()));
+
+        let error = DateValue::parse_str(Some(" "), DateRules::default(), &formats)
+            .expect_err("Expected date to be empty");
+        assert_eq!(error.0.as_original_message_vec(), vec!["Cannot be empty"]);
+
+        let error = DateValue::parse_str(Some("today"), DateRules::default(), &formats)
+            .expect_err("Expected date to be unparseable");
+        let locale = error.0.0[0].1.get_locale_data();
+        assert_eq!(locale.name, "validate-date-unparseable");
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let clock = FixedClock(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        let today = NaiveDate::from_ymd_opt(2023, 11, 14);
+        assert!(DateValue::parse_at(today, &clock).is_ok());
+        let yesterday = NaiveDate::from_ymd_opt(2023, 11, 13);
+        assert!(DateValue::parse_at(yesterday, &clock).is_err());
+        let later = NaiveDate::from_ymd_opt(2023, 12, 15);
+        assert!(DateValue::parse_at(later, &clock).is_err());
+    }
 }
@@ -1,9 +1,21 @@
-use crate::base::date_time::data::AsDateTimeData;
-use crate::base::date_time::rules::{DateTimeMandatoryRules, DateTimeRangeRules};
-use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::base::date_time::clock::{Clock, SystemClock};
+use crate::base::date_time::data::{AsDateTimeData, DateTimeKind};
+use crate::base::date_time::rules::{
+    DateTimeMandatoryRules, DateTimeParseRules, DateTimeRangeRules,
+};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::{observe_parse, rule_result};
 use crate::common::validation_check::ValidationCheck;
-use chrono::{DateTime, NaiveDateTime, TimeDelta, TimeZone, Utc};
+use crate::common::validator::Validator;
+use chrono::{DateTime, FixedOffset, LocalResult, NaiveDateTime, TimeDelta, TimeZone, Utc};
 use std::ops::Add;
+use std::sync::Arc;
 use thiserror::Error;
 
 /// Represents a set of rules or constraints for a date-time value.
@@ -22,21 +34,34 @@ use thiserror::Error;
 ///   - `Some(DateTime<Utc>)`: The maximum allowed date-time.
 ///   - `None`: No maximum constraint is applied.
 ///
+/// * `ambiguity` - The policy for a naive date-time occurring twice in the time zone, when the
+///   clocks go back, see `DateTimeValue::parse_custom_naive_with_tz`.
+///
 /// This struct is useful for validating date-time inputs against specified bounds
 /// and determining whether such an input is required.
+#[derive(Clone)]
 pub struct DateTimeRules {
     pub is_mandatory: bool,
     pub min: Option<DateTime<Utc>>,
     pub max: Option<DateTime<Utc>>,
+    pub ambiguity: Ambiguity,
 }
 
 impl Default for DateTimeRules {
     fn default() -> Self {
-        let now = Utc::now();
+        Self::default_at(&SystemClock)
+    }
+}
+
+impl DateTimeRules {
+    /// Returns the default rules, from the time of `clock` to 30 days later.
+    pub fn default_at(clock: &dyn Clock) -> Self {
+        let now = DateTime::<Utc>::from(clock.now());
         Self {
             is_mandatory: true,
             min: Some(now.clone()),
             max: Some(now.clone().add(TimeDelta::days(30))),
+            ambiguity: Ambiguity::default(),
         }
     }
 }
@@ -76,6 +101,65 @@ impl DateTimeRules {
         }
         range_rule.check(messages, subject.as_ref());
     }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        constraints
+    }
+}
+
+/// The policy for a naive date-time occurring twice in a time zone, e.g. 01:30 on the night the
+/// clocks go back from 02:00 to 01:00.
+///
+/// # Variants
+/// - `Earliest`: The first occurrence, before the clocks go back.
+/// - `Latest`: The second occurrence, after the clocks go back.
+/// - `Reject`: The date-time is not valid, this is the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ambiguity {
+    Earliest,
+    Latest,
+    #[default]
+    Reject,
+}
+
+/// A locale message for a naive date-time that does not map to exactly one date-time in the time
+/// zone, with the argument `reason`, either `nonexistent` for a date-time skipped when the clocks
+/// go forward, or `ambiguous` for a date-time occurring twice, rejected by `Ambiguity::Reject`.
+///
+/// # Key
+/// `validate-datetime-invalid-local-time`
+#[derive(Clone)]
+pub enum DateTimeLocalTimeLocale {
+    Nonexistent,
+    Ambiguous,
+}
+
+impl DateTimeLocalTimeLocale {
+    fn reason(&self) -> &'static str {
+        match self {
+            Self::Nonexistent => "nonexistent",
+            Self::Ambiguous => "ambiguous",
+        }
+    }
+}
+
+impl LocaleMessage for DateTimeLocalTimeLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_DATETIME_INVALID_LOCAL_TIME,
+            vec![("reason".to_string(), LocaleValue::from(self.reason()))],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::InvalidLocalTime {
+            reason: self.reason().to_string(),
+        }
+    }
 }
 
 /// A custom error type for handling DateTime validation errors.
@@ -170,10 +254,12 @@ impl<Tz: TimeZone> DateTimeValue<Tz> {
         subject: Option<DateTime<Tz>>,
         rules: DateTimeRules,
     ) -> Result<Self, DateTimeError> {
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, subject.as_ref());
-        DateTimeError::validate_check(messages)?;
-        Ok(Self(subject))
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, subject.as_ref());
+            DateTimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
     }
 
     /// Parses a `NaiveDateTime` with a given timezone and applies custom date-time rules.
@@ -195,17 +281,45 @@ impl<Tz: TimeZone> DateTimeValue<Tz> {
     /// * `Err(DateTimeError)` - If there is an issue during timezone conversion or parsing,
     ///   it returns a `DateTimeError`.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function may panic if the naive date-time does not map to a valid timezone-aware
-    /// `DateTime` when used with the provided timezone (`tz`).
+    /// Returns a `DateTimeError` with a `validate-datetime-invalid-local-time` message if the
+    /// naive date-time is skipped in the time zone, when the clocks go forward, or occurs twice,
+    /// when the clocks go back, with the `ambiguity` of the `rules` set to `Ambiguity::Reject`.
     pub fn parse_custom_naive_with_tz(
         subject: Option<NaiveDateTime>,
         rules: DateTimeRules,
         tz: Tz,
     ) -> Result<Self, DateTimeError> {
-        let subject = subject.map(|s| s.and_local_timezone(tz).unwrap());
-        Self::parse_custom(subject, rules)
+        let Some(subject) = subject else {
+            return Self::parse_custom(None, rules);
+        };
+        let local = match (subject.and_local_timezone(tz), rules.ambiguity) {
+            (LocalResult::Single(local), _)
+            | (LocalResult::Ambiguous(local, _), Ambiguity::Earliest)
+            | (LocalResult::Ambiguous(_, local), Ambiguity::Latest) => Ok(local),
+            (LocalResult::Ambiguous(_, _), Ambiguity::Reject) => {
+                Err(DateTimeLocalTimeLocale::Ambiguous)
+            }
+            (LocalResult::None, _) => Err(DateTimeLocalTimeLocale::Nonexistent),
+        };
+        rule_result(
+            locale_keys::VALIDATE_DATETIME_INVALID_LOCAL_TIME,
+            local.is_ok(),
+        );
+        match local {
+            Ok(local) => Self::parse_custom(Some(local), rules),
+            Err(locale) => observe_parse(|| {
+                let message = match locale {
+                    DateTimeLocalTimeLocale::Nonexistent => "Does not exist in the time zone",
+                    DateTimeLocalTimeLocale::Ambiguous => "Is ambiguous in the time zone",
+                };
+                let mut messages = ValidateErrorCollector::new();
+                messages.push((message, Box::new(locale)));
+                DateTimeError::validate_check(messages)?;
+                Ok(Self(None))
+            }),
+        }
     }
 
     /// Parses an optional `DateTime` object using default date-time rules and returns a `Result`.
@@ -237,6 +351,15 @@ impl<Tz: TimeZone> DateTimeValue<Tz> {
         Self::parse_custom(subject, DateTimeRules::default())
     }
 
+    /// Parses the given value using the default rules, with the current time given by `clock`
+    /// instead of the system time, see `DateTimeRules::default_at`.
+    pub fn parse_at(
+        subject: Option<DateTime<Tz>>,
+        clock: &dyn Clock,
+    ) -> Result<Self, DateTimeError> {
+        Self::parse_custom(subject, DateTimeRules::default_at(clock))
+    }
+
     /// Parses a `NaiveDateTime` with the given timezone to create a `DateTime` instance.
     ///
     /// This function attempts to convert a given `NaiveDateTime` (if provided) into a
@@ -271,6 +394,76 @@ impl<Tz: TimeZone> DateTimeValue<Tz> {
     }
 }
 
+impl<Tz: TimeZone> OptionalValue for DateTimeValue<Tz> {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl DateTimeValue<FixedOffset> {
+    /// Parses `subject` with the first of the `formats` it matches, then validates the parsed
+    /// date and time against `rules`.
+    ///
+    /// The `formats` must include the offset, e.g. `%z`. A missing or blank `subject` is treated
+    /// as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` with a `validate-date-time-unparseable` message, having the
+    /// accepted `formats` as argument, if `subject` matches none of the `formats`, otherwise if
+    /// the parsed date and time does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use cjtoolkit_structured_validator::types::times_chrono::date_time::{
+    ///     Ambiguity, DateTimeRules, DateTimeValue,
+    /// };
+    ///
+    /// let rules = DateTimeRules {
+    ///     is_mandatory: true,
+    ///     min: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).single(),
+    ///     max: None,
+    ///     ambiguity: Ambiguity::Reject,
+    /// };
+    /// let formats = ["%Y-%m-%d %H:%M %z", "%d/%m/%Y %H:%M %z"];
+    ///
+    /// let result = DateTimeValue::parse_str(Some("25/12/2024 10:30 +0100"), rules.clone(), &formats);
+    /// assert!(result.is_ok());
+    ///
+    /// let error = DateTimeValue::parse_str(Some("25/12/2024 10:30"), rules, &formats)
+    ///     .expect_err("Expected date and time to be unparseable");
+    /// assert_eq!(
+    ///     error.0.as_original_message_vec(),
+    ///     vec!["Must be in the format '%Y-%m-%d %H:%M %z' or '%d/%m/%Y %H:%M %z'"]
+    /// );
+    /// ```
+    pub fn parse_str(
+        subject: Option<&str>,
+        rules: DateTimeRules,
+        formats: &[&str],
+    ) -> Result<Self, DateTimeError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            let parse_rules = DateTimeParseRules {
+                kind: DateTimeKind::DateTime,
+                formats,
+            };
+            let subject = parse_rules
+                .parse(&mut messages, subject, |s, f| {
+                    DateTime::parse_from_str(s, f).ok()
+                })
+                .map(|(subject, _)| subject);
+            if messages.is_empty() {
+                rules.check(&mut messages, subject.as_ref());
+            }
+            DateTimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+}
+
 pub trait AsDateTimeOnResult<Tz: TimeZone> {
     fn as_date_time(&self) -> Option<DateTime<Tz>>;
 }
@@ -281,9 +474,29 @@ impl<Tz: TimeZone, E> AsDateTimeOnResult<Tz> for Result<DateTimeValue<Tz>, E> {
     }
 }
 
+impl<Tz: TimeZone> Validator<Option<DateTime<Tz>>, DateTimeValue<Tz>, DateTimeError>
+    for DateTimeRules
+{
+    fn validate(&self, input: Option<DateTime<Tz>>) -> Result<DateTimeValue<Tz>, DateTimeError> {
+        DateTimeValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for DateTimeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::base::date_time::clock::FixedClock;
+    use chrono::NaiveDate;
+    use std::time::{Duration, SystemTime};
 
     #[test]
     fn test_parse_custom() {
@@ -319,4 +532,124 @@ mod tests {
         let result = DateTimeValue::parse(subject);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_at() {
+        let clock = FixedClock(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        let now = DateTime::<Utc>::from(clock.now());
+        assert!(DateTimeValue::parse_at(Some(now), &clock).is_ok());
+        let later = now.add(TimeDelta::days(30));
+        assert!(DateTimeValue::parse_at(Some(later), &clock).is_ok());
+        let before = now.add(TimeDelta::seconds(-1));
+        assert!(DateTimeValue::parse_at(Some(before), &clock).is_err());
+    }
+
+    /// A time zone one hour ahead of UTC, two hours in 2024 from the 31st of March at 01:00 UTC
+    /// to the 27th of October at 01:00 UTC, like Central European Time.
+    #[derive(Debug, Clone, Copy)]
+    struct Cet;
+
+    impl Cet {
+        fn summer(utc: &NaiveDateTime) -> bool {
+            let start = NaiveDate::from_ymd_opt(2024, 3, 31).and_then(|d| d.and_hms_opt(1, 0, 0));
+            let end = NaiveDate::from_ymd_opt(2024, 10, 27).and_then(|d| d.and_hms_opt(1, 0, 0));
+            start.is_some_and(|start| *utc >= start) && end.is_some_and(|end| *utc < end)
+        }
+
+        fn offset(summer: bool) -> FixedOffset {
+            FixedOffset::east_opt(if summer { 7200 } else { 3600 })
+                .unwrap_or_else(|| unreachable!())
+        }
+    }
+
+    impl TimeZone for Cet {
+        type Offset = FixedOffset;
+
+        fn from_offset(_: &FixedOffset) -> Self {
+            Cet
+        }
+
+        fn offset_from_local_date(&self, _: &NaiveDate) -> LocalResult<FixedOffset> {
+            LocalResult::None
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let offsets: Vec<FixedOffset> = [false, true]
+                .into_iter()
+                .map(Self::offset)
+                .filter(|offset| self.offset_from_utc_datetime(&(*local - *offset)) == *offset)
+                .collect();
+            match offsets.as_slice() {
+                [offset] => LocalResult::Single(*offset),
+                [winter, summer] => LocalResult::Ambiguous(*summer, *winter),
+                _ => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, _: &NaiveDate) -> FixedOffset {
+            Self::offset(false)
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            Self::offset(Self::summer(utc))
+        }
+    }
+
+    #[test]
+    fn test_parse_naive_with_tz_local_time() {
+        let rules = |ambiguity| DateTimeRules {
+            is_mandatory: true,
+            min: None,
+            max: None,
+            ambiguity,
+        };
+        let local = |m, d, h, min| {
+            NaiveDate::from_ymd_opt(2024, m, d).and_then(|d| d.and_hms_opt(h, min, 0))
+        };
+        let utc = |m, d, h, min| Utc.with_ymd_and_hms(2024, m, d, h, min, 0).single();
+
+        let result = DateTimeValue::parse_custom_naive_with_tz(
+            local(6, 1, 12, 0),
+            rules(Ambiguity::Reject),
+            Cet,
+        );
+        assert_eq!(result.as_date_time().map(|d| d.to_utc()), utc(6, 1, 10, 0));
+
+        let error = DateTimeValue::parse_custom_naive_with_tz(
+            local(3, 31, 2, 30),
+            rules(Ambiguity::Earliest),
+            Cet,
+        )
+        .expect_err("Expected 02:30 to be skipped");
+        let locale = error.0.0[0].1.get_locale_data();
+        assert_eq!(
+            locale.name,
+            locale_keys::VALIDATE_DATETIME_INVALID_LOCAL_TIME
+        );
+        assert_eq!(
+            locale.args.get("reason"),
+            Some(&LocaleValue::from("nonexistent"))
+        );
+
+        let ambiguous = local(10, 27, 2, 30);
+        let result =
+            DateTimeValue::parse_custom_naive_with_tz(ambiguous, rules(Ambiguity::Earliest), Cet);
+        assert_eq!(
+            result.as_date_time().map(|d| d.to_utc()),
+            utc(10, 27, 0, 30)
+        );
+        let result =
+            DateTimeValue::parse_custom_naive_with_tz(ambiguous, rules(Ambiguity::Latest), Cet);
+        assert_eq!(
+            result.as_date_time().map(|d| d.to_utc()),
+            utc(10, 27, 1, 30)
+        );
+        let error =
+            DateTimeValue::parse_custom_naive_with_tz(ambiguous, rules(Ambiguity::Reject), Cet)
+                .expect_err("Expected 02:30 to be ambiguous");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Is ambiguous in the time zone"]
+        );
+    }
 }
@@ -1,5 +1,7 @@
 //! Types for working with date and time values for chrono.
+pub mod birth_date;
 pub mod date;
 pub mod date_time;
 pub mod naive_date_time;
+pub mod period;
 pub mod time;
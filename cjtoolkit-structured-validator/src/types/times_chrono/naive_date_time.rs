@@ -1,8 +1,15 @@
-use crate::base::date_time::data::AsDateTimeData;
-use crate::base::date_time::rules::{DateTimeMandatoryRules, DateTimeRangeRules};
+use crate::base::date_time::clock::{Clock, SystemClock};
+use crate::base::date_time::data::{AsDateTimeData, DateTimeKind};
+use crate::base::date_time::rules::{
+    DateTimeMandatoryRules, DateTimeParseRules, DateTimeRangeRules,
+};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
 use crate::common::validation_check::ValidationCheck;
-use chrono::{NaiveDateTime, TimeDelta, Utc};
+use crate::common::validator::Validator;
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
 use std::ops::Add;
 use thiserror::Error;
 
@@ -27,6 +34,7 @@ use thiserror::Error;
 ///   the upper bound for the allowable datetime. If `Some`,
 ///   the given datetime must not be later than this value.
 ///   If `None`, no maximum constraint is applied.
+#[derive(Clone)]
 pub struct NaiveDateTimeRules {
     pub is_mandatory: bool,
     pub min: Option<NaiveDateTime>,
@@ -35,16 +43,21 @@ pub struct NaiveDateTimeRules {
 
 impl Default for NaiveDateTimeRules {
     fn default() -> Self {
-        let now = Utc::now();
+        Self::default_at(&SystemClock)
+    }
+}
+
+impl NaiveDateTimeRules {
+    /// Returns the default rules, from the time of `clock` in UTC to 30 days later.
+    pub fn default_at(clock: &dyn Clock) -> Self {
+        let now = DateTime::<Utc>::from(clock.now());
         Self {
             is_mandatory: true,
             min: Some(now.clone().naive_utc()),
             max: Some(now.clone().naive_utc().add(TimeDelta::days(30))),
         }
     }
-}
 
-impl NaiveDateTimeRules {
     fn rules(&self, date_format: Option<&str>) -> (DateTimeMandatoryRules, DateTimeRangeRules) {
         (
             DateTimeMandatoryRules {
@@ -80,6 +93,14 @@ impl NaiveDateTimeRules {
         }
         range_rule.check(messages, subject.as_ref());
     }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules(None);
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        constraints
+    }
 }
 
 /// A custom error type used to represent validation errors related to `NaiveDateTime`.
@@ -176,10 +197,12 @@ impl NaiveDateTimeValue {
         rules: NaiveDateTimeRules,
         format: Option<&str>,
     ) -> Result<Self, NaiveDateTimeError> {
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(subject.as_ref(), &mut messages, format);
-        NaiveDateTimeError::validate_check(messages)?;
-        Ok(Self(subject))
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(subject.as_ref(), &mut messages, format);
+            NaiveDateTimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
     }
 
     /// Parses a `NaiveDateTime` from a given `subject` using provided custom `rules`.
@@ -262,6 +285,15 @@ impl NaiveDateTimeValue {
         Self::parse_custom(subject, NaiveDateTimeRules::default())
     }
 
+    /// Parses the given value using the default rules, with the current time given by `clock`
+    /// instead of the system time, see `NaiveDateTimeRules::default_at`.
+    pub fn parse_at(
+        subject: Option<NaiveDateTime>,
+        clock: &dyn Clock,
+    ) -> Result<Self, NaiveDateTimeError> {
+        Self::parse_custom(subject, NaiveDateTimeRules::default_at(clock))
+    }
+
     /// Parses a given `NaiveDateTime` value using an optional custom format and returns the parsed result.
     ///
     /// # Parameters
@@ -310,6 +342,67 @@ impl NaiveDateTimeValue {
         Self::parse_custom_with_format(subject, NaiveDateTimeRules::default(), format)
     }
 
+    /// Parses `subject` with the first of the `formats` it matches, then validates the parsed
+    /// date and time against `rules`, with the dates of the error messages formatted with the
+    /// matching format.
+    ///
+    /// A missing or blank `subject` is treated as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NaiveDateTimeError` with a `validate-date-time-naive-unparseable` message,
+    /// having the accepted `formats` as argument, if `subject` matches none of the `formats`,
+    /// otherwise if the parsed date and time does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use cjtoolkit_structured_validator::types::times_chrono::naive_date_time::{
+    ///     NaiveDateTimeRules, NaiveDateTimeValue,
+    /// };
+    ///
+    /// let rules = NaiveDateTimeRules {
+    ///     is_mandatory: true,
+    ///     min: None,
+    ///     max: NaiveDate::from_ymd_opt(2024, 12, 31).and_then(|d| d.and_hms_opt(0, 0, 0)),
+    /// };
+    /// let formats = ["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M"];
+    ///
+    /// let result = NaiveDateTimeValue::parse_str(Some("25/12/2024 10:30"), rules.clone(), &formats);
+    /// assert!(result.is_ok());
+    ///
+    /// let error = NaiveDateTimeValue::parse_str(Some("01/01/2025 10:30"), rules, &formats)
+    ///     .expect_err("Expected date and time to be too late");
+    /// assert_eq!(
+    ///     error.0.as_original_message_vec(),
+    ///     vec!["Must be before '01/01/2025 10:30'"]
+    /// );
+    /// ```
+    pub fn parse_str(
+        subject: Option<&str>,
+        rules: NaiveDateTimeRules,
+        formats: &[&str],
+    ) -> Result<Self, NaiveDateTimeError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            let parse_rules = DateTimeParseRules {
+                kind: DateTimeKind::DateTimeNaive,
+                formats,
+            };
+            let (subject, format) = parse_rules
+                .parse(&mut messages, subject, |s, f| {
+                    NaiveDateTime::parse_from_str(s, f).ok()
+                })
+                .unzip();
+            if messages.is_empty() {
+                rules.check(subject.as_ref(), &mut messages, format);
+            }
+            NaiveDateTimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
     /// Converts the current value into an `Option<NaiveDateTime>` if applicable.
     ///
     /// # Returns
@@ -327,6 +420,12 @@ impl NaiveDateTimeValue {
     }
 }
 
+impl OptionalValue for NaiveDateTimeValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
 pub trait AsNaiveDateTimeOnResult {
     fn as_naive_date_time(&self) -> Option<NaiveDateTime>;
 }
@@ -340,6 +439,26 @@ impl<E> AsNaiveDateTimeOnResult for Result<NaiveDateTimeValue, E> {
     }
 }
 
+impl Validator<Option<NaiveDateTime>, NaiveDateTimeValue, NaiveDateTimeError>
+    for NaiveDateTimeRules
+{
+    fn validate(
+        &self,
+        input: Option<NaiveDateTime>,
+    ) -> Result<NaiveDateTimeValue, NaiveDateTimeError> {
+        NaiveDateTimeValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for NaiveDateTimeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,516 @@
+use crate::base::date_time::rules::DateTimeMandatoryLocale;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::{observe_parse, rule_result};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// A reporting period spanning a range of whole days, such as an ISO week or a quarter.
+pub trait Period: Copy + Ord + Display {
+    /// The format of the string representation, e.g. `YYYY-Www`.
+    const FORMAT: &'static str;
+
+    /// Parses the string representation of the period, `None` if it is not valid.
+    fn parse_period(s: &str) -> Option<Self>;
+
+    /// Returns the period containing `date`.
+    fn from_date(date: NaiveDate) -> Self;
+
+    /// Returns the first day of the period.
+    fn first_day(&self) -> NaiveDate;
+
+    /// Returns the last day of the period.
+    fn last_day(&self) -> NaiveDate;
+
+    /// Returns the days of the period.
+    fn date_range(&self) -> RangeInclusive<NaiveDate> {
+        self.first_day()..=self.last_day()
+    }
+}
+
+/// Splits `s` into the year and the number following `separator`, e.g. `2024` and `15` for
+/// `2024-W15`.
+fn split_period(s: &str, separator: &str) -> Option<(i32, u32)> {
+    let (year, number) = s.split_once(separator)?;
+    if year.len() != 4 || !year.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if !(1..=2).contains(&number.len()) || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((year.parse().ok()?, number.parse().ok()?))
+}
+
+/// An ISO 8601 week, from Monday to Sunday, written as `2024-W15`.
+///
+/// The year is the ISO week-numbering year, which may differ from the calendar year of the days
+/// near the new year.
+///
+/// # Example
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cjtoolkit_structured_validator::types::times_chrono::period::{IsoWeek, Period};
+///
+/// let week = IsoWeek::parse_period("2025-W01").unwrap_or_else(|| unreachable!());
+/// assert_eq!(week.first_day(), NaiveDate::from_ymd_opt(2024, 12, 30).unwrap_or_default());
+/// assert_eq!(week.last_day(), NaiveDate::from_ymd_opt(2025, 1, 5).unwrap_or_default());
+/// assert_eq!(week.to_string(), "2025-W01");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoWeek(NaiveDate);
+
+impl IsoWeek {
+    /// Returns the given week of the ISO week-numbering `year`, `None` if the year does not
+    /// have such a week.
+    pub fn new(year: i32, week: u32) -> Option<Self> {
+        NaiveDate::from_isoywd_opt(year, week, Weekday::Mon).map(Self)
+    }
+
+    /// Returns the ISO week-numbering year.
+    pub fn year(&self) -> i32 {
+        self.0.iso_week().year()
+    }
+
+    /// Returns the week number, from 1 to 53.
+    pub fn week(&self) -> u32 {
+        self.0.iso_week().week()
+    }
+}
+
+impl Period for IsoWeek {
+    const FORMAT: &'static str = "YYYY-Www";
+
+    fn parse_period(s: &str) -> Option<Self> {
+        let (year, week) = split_period(s, "-W")?;
+        Self::new(year, week)
+    }
+
+    fn from_date(date: NaiveDate) -> Self {
+        let week = date.iso_week();
+        Self::new(week.year(), week.week()).unwrap_or(Self(date))
+    }
+
+    fn first_day(&self) -> NaiveDate {
+        self.0
+    }
+
+    fn last_day(&self) -> NaiveDate {
+        self.0
+            .checked_add_days(Days::new(6))
+            .unwrap_or(NaiveDate::MAX)
+    }
+}
+
+impl Display for IsoWeek {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-W{:02}", self.year(), self.week())
+    }
+}
+
+/// A quarter of a calendar year, written as `2024-Q2`.
+///
+/// # Example
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cjtoolkit_structured_validator::types::times_chrono::period::{Period, Quarter};
+///
+/// let quarter = Quarter::parse_period("2024-Q1").unwrap_or_else(|| unreachable!());
+/// assert_eq!(quarter.first_day(), NaiveDate::from_ymd_opt(2024, 1, 1).unwrap_or_default());
+/// assert_eq!(quarter.last_day(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap_or_default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quarter(NaiveDate);
+
+impl Quarter {
+    /// Returns the given quarter of `year`, `None` if `quarter` is not between 1 and 4.
+    pub fn new(year: i32, quarter: u32) -> Option<Self> {
+        if !(1..=4).contains(&quarter) {
+            return None;
+        }
+        NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1).map(Self)
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.0.year()
+    }
+
+    /// Returns the quarter number, from 1 to 4.
+    pub fn quarter(&self) -> u32 {
+        self.0.month0() / 3 + 1
+    }
+}
+
+impl Period for Quarter {
+    const FORMAT: &'static str = "YYYY-Qq";
+
+    fn parse_period(s: &str) -> Option<Self> {
+        let (year, quarter) = split_period(s, "-Q")?;
+        Self::new(year, quarter)
+    }
+
+    fn from_date(date: NaiveDate) -> Self {
+        Self::new(date.year(), date.month0() / 3 + 1).unwrap_or(Self(date))
+    }
+
+    fn first_day(&self) -> NaiveDate {
+        self.0
+    }
+
+    fn last_day(&self) -> NaiveDate {
+        self.0
+            .checked_add_months(Months::new(3))
+            .and_then(|next| next.pred_opt())
+            .unwrap_or(NaiveDate::MAX)
+    }
+}
+
+impl Display for Quarter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-Q{}", self.year(), self.quarter())
+    }
+}
+
+/// A struct representing validation rules for a period, specifying its mandatory status and
+/// optional bounds.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean flag that indicates whether the period is mandatory.
+/// * `min` - The earliest allowable period, `None` for no lower bound.
+/// * `max` - The latest allowable period, `None` for no upper bound.
+///
+/// # Default
+///
+/// The period is mandatory, without bounds.
+#[derive(Clone)]
+pub struct PeriodRules<P: Period> {
+    pub is_mandatory: bool,
+    pub min: Option<P>,
+    pub max: Option<P>,
+}
+
+impl<P: Period> Default for PeriodRules<P> {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl<P: Period> PeriodRules<P> {
+    fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<&P>) {
+        if self.is_mandatory {
+            rule_result(locale_keys::VALIDATE_CANNOT_BE_EMPTY, subject.is_some());
+            if subject.is_none() {
+                messages.push(("Cannot be empty", DateTimeMandatoryLocale));
+            }
+        }
+        let Some(subject) = subject else {
+            return;
+        };
+        if let Some(min) = &self.min {
+            rule_result(locale_keys::VALIDATE_PERIOD_MIN, subject >= min);
+            if subject < min {
+                messages.push((
+                    format!("Must not be before {}", min),
+                    Box::new(PeriodLocale::Min(min.to_string())),
+                ));
+            }
+        }
+        if let Some(max) = &self.max {
+            rule_result(locale_keys::VALIDATE_PERIOD_MAX, subject <= max);
+            if subject > max {
+                messages.push((
+                    format!("Must not be after {}", max),
+                    Box::new(PeriodLocale::Max(max.to_string())),
+                ));
+            }
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.is_mandatory {
+            constraints.push(Constraint::from_locale(&DateTimeMandatoryLocale));
+        }
+        if let Some(min) = &self.min {
+            constraints.push(Constraint::from_locale(&PeriodLocale::Min(min.to_string())));
+        }
+        if let Some(max) = &self.max {
+            constraints.push(Constraint::from_locale(&PeriodLocale::Max(max.to_string())));
+        }
+        constraints
+    }
+}
+
+/// An enumeration of the period specific validation failures, the periods being passed in their
+/// string representation.
+pub enum PeriodLocale {
+    /// The string is not in the given format.
+    /// # Key
+    /// `validate-period-unparseable`
+    Unparseable(&'static str),
+    /// The period is before the given minimum.
+    /// # Key
+    /// `validate-period-min`
+    Min(String),
+    /// The period is after the given maximum.
+    /// # Key
+    /// `validate-period-max`
+    Max(String),
+}
+
+impl LocaleMessage for PeriodLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::Unparseable(format) => ld::new_with_vec(
+                locale_keys::VALIDATE_PERIOD_UNPARSEABLE,
+                vec![("format".to_string(), lv::from(*format))],
+            ),
+            Self::Min(min) => ld::new_with_vec(
+                locale_keys::VALIDATE_PERIOD_MIN,
+                vec![("min".to_string(), lv::from(min.clone()))],
+            ),
+            Self::Max(max) => ld::new_with_vec(
+                locale_keys::VALIDATE_PERIOD_MAX,
+                vec![("max".to_string(), lv::from(max.clone()))],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::Unparseable(_) => RuleViolation::pattern("period"),
+            Self::Min(min) => RuleViolation::Range {
+                min: Some(LocaleValue::from(min.clone())),
+                max: None,
+            },
+            Self::Max(max) => RuleViolation::Range {
+                min: None,
+                max: Some(LocaleValue::from(max.clone())),
+            },
+        }
+    }
+}
+
+/// Represents an error encountered during period validation.
+///
+/// # Fields
+/// - `0: ValidateErrorStore` - A field that stores validation errors for further analysis.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Period Validation Error")]
+pub struct PeriodError(pub ValidateErrorStore);
+
+impl ValidationCheck for PeriodError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&PeriodError> for ValidateErrorStore {
+    fn from(error: &PeriodError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A validated, optional period.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "allow-default-value", test), derive(Default))]
+pub struct PeriodValue<P: Period>(Option<P>);
+
+/// A validated, optional ISO 8601 week.
+pub type IsoWeekValue = PeriodValue<IsoWeek>;
+
+/// A validated, optional quarter.
+pub type QuarterValue = PeriodValue<Quarter>;
+
+impl<P: Period> PeriodValue<P> {
+    /// Validates `subject` against `rules`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PeriodError` if `subject` is missing while mandatory, or out of the bounds of
+    /// the `rules`.
+    pub fn parse_custom(subject: Option<P>, rules: PeriodRules<P>) -> Result<Self, PeriodError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, subject.as_ref());
+            PeriodError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
+    /// Validates `subject` against the default rules.
+    pub fn parse(subject: Option<P>) -> Result<Self, PeriodError> {
+        Self::parse_custom(subject, PeriodRules::default())
+    }
+
+    /// Parses `subject` in the format of the period, e.g. `2024-W15` or `2024-Q2`, then
+    /// validates the parsed period against `rules`.
+    ///
+    /// A missing or blank `subject` is treated as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PeriodError` with a `validate-period-unparseable` message, having the
+    /// `format` as argument, if `subject` is not a period, otherwise if the parsed period does
+    /// not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cjtoolkit_structured_validator::types::times_chrono::period::{
+    ///     PeriodRules, Quarter, QuarterValue,
+    /// };
+    ///
+    /// let rules = PeriodRules {
+    ///     min: Quarter::new(2024, 1),
+    ///     ..PeriodRules::default()
+    /// };
+    ///
+    /// let quarter = QuarterValue::parse_str(Some("2024-Q2"), rules.clone());
+    /// assert_eq!(quarter.ok().and_then(|q| q.as_period()), Quarter::new(2024, 2));
+    ///
+    /// let error = QuarterValue::parse_str(Some("2023-Q4"), rules.clone())
+    ///     .expect_err("Expected the quarter to be too early");
+    /// assert_eq!(error.0.as_original_message_vec(), vec!["Must not be before 2024-Q1"]);
+    ///
+    /// let error = QuarterValue::parse_str(Some("2024-Q5"), rules)
+    ///     .expect_err("Expected the quarter to be unparseable");
+    /// assert_eq!(error.0.as_original_message_vec(), vec!["Must be in the format YYYY-Qq"]);
+    /// ```
+    pub fn parse_str(subject: Option<&str>, rules: PeriodRules<P>) -> Result<Self, PeriodError> {
+        let subject = subject.map(str::trim).filter(|s| !s.is_empty());
+        let Some(subject) = subject else {
+            return Self::parse_custom(None, rules);
+        };
+        match P::parse_period(subject) {
+            Some(period) => Self::parse_custom(Some(period), rules),
+            None => observe_parse(|| {
+                rule_result(locale_keys::VALIDATE_PERIOD_UNPARSEABLE, false);
+                let mut messages = ValidateErrorCollector::new();
+                messages.push((
+                    format!("Must be in the format {}", P::FORMAT),
+                    Box::new(PeriodLocale::Unparseable(P::FORMAT)),
+                ));
+                PeriodError::validate_check(messages)?;
+                Ok(Self(None))
+            }),
+        }
+    }
+
+    /// Returns the period, if any.
+    pub fn as_period(&self) -> Option<P> {
+        self.0
+    }
+
+    /// Returns the days of the period, if any.
+    pub fn date_range(&self) -> Option<RangeInclusive<NaiveDate>> {
+        self.0.map(|period| period.date_range())
+    }
+}
+
+impl<P: Period> OptionalValue for PeriodValue<P> {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<P: Period> Validator<Option<P>, PeriodValue<P>, PeriodError> for PeriodRules<P> {
+    fn validate(&self, input: Option<P>) -> Result<PeriodValue<P>, PeriodError> {
+        PeriodValue::parse_custom(input, self.clone())
+    }
+}
+
+impl<P: Period> OptionalRules for PeriodRules<P> {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap_or_default()
+    }
+
+    #[test]
+    fn test_iso_week() {
+        let week = IsoWeek::parse_period("2020-W53");
+        assert_eq!(
+            week.map(|w| w.date_range()),
+            Some(date(2020, 12, 28)..=date(2021, 1, 3))
+        );
+        assert_eq!(
+            IsoWeek::from_date(date(2021, 1, 3)),
+            week.unwrap_or_else(|| unreachable!())
+        );
+        assert_eq!(IsoWeek::parse_period("2021-W53"), None);
+        assert_eq!(IsoWeek::parse_period("2021-W00"), None);
+        assert_eq!(
+            IsoWeek::parse_period("2021-W1").map(|w| w.to_string()),
+            Some("2021-W01".to_string())
+        );
+        assert_eq!(IsoWeek::parse_period("21-W01"), None);
+    }
+
+    #[test]
+    fn test_quarter() {
+        let quarter = Quarter::from_date(date(2024, 8, 15));
+        assert_eq!(quarter.to_string(), "2024-Q3");
+        assert_eq!(quarter.date_range(), date(2024, 7, 1)..=date(2024, 9, 30));
+        assert_eq!(
+            Quarter::parse_period("2024-Q4").map(|q| q.last_day()),
+            Some(date(2024, 12, 31))
+        );
+        assert_eq!(Quarter::parse_period("2024-Q0"), None);
+    }
+
+    #[test]
+    fn test_parse_str() {
+        let rules = PeriodRules {
+            is_mandatory: true,
+            min: IsoWeek::new(2024, 10),
+            max: IsoWeek::new(2024, 20),
+        };
+        let parse = |s| IsoWeekValue::parse_str(s, rules.clone());
+        assert_eq!(
+            parse(Some(" 2024-W15 ")).ok().and_then(|w| w.date_range()),
+            Some(date(2024, 4, 8)..=date(2024, 4, 14))
+        );
+        let error = parse(Some("2024-W21")).expect_err("Expected the week to be too late");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must not be after 2024-W20"]
+        );
+        let error = parse(Some("2024-15")).expect_err("Expected the week to be unparseable");
+        let locale = error.0.0[0].1.get_locale_data();
+        assert_eq!(locale.name, locale_keys::VALIDATE_PERIOD_UNPARSEABLE);
+        assert_eq!(
+            locale.args.get("format"),
+            Some(&LocaleValue::from("YYYY-Www"))
+        );
+        assert!(parse(None).is_err());
+    }
+}
@@ -1,7 +1,13 @@
-use crate::base::date_time::data::AsDateTimeData;
-use crate::base::date_time::rules::{DateTimeMandatoryRules, DateTimeRangeRules};
+use crate::base::date_time::data::{AsDateTimeData, DateTimeKind};
+use crate::base::date_time::rules::{
+    DateTimeMandatoryRules, DateTimeParseRules, DateTimeRangeRules, TimeGranularityRules,
+};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
 use chrono::NaiveTime;
 use thiserror::Error;
 
@@ -23,6 +29,12 @@ use thiserror::Error;
 ///   - If `Some(NaiveTime)`, it represents the latest valid time.
 ///   - If `None`, there is no maximum time constraint.
 ///
+/// * `granularity_minutes`
+///   - An `Option<u32>` specifying the length of the time slots in minutes, counted from
+///     midnight.
+///   - If `Some(15)`, the time must fall on :00, :15, :30 or :45.
+///   - If `None`, there is no granularity constraint.
+///
 /// # Example
 ///
 /// ```
@@ -33,15 +45,18 @@ use thiserror::Error;
 ///     is_mandatory: true,
 ///     min: Some(NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default()), // The earliest allowed time is 9:00 AM
 ///     max: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap_or_default()), // The latest allowed time is 5:00 PM
+///     granularity_minutes: Some(30), // The time must fall on the hour or the half hour
 /// };
 /// ```
 ///
 /// This struct can be used to enforce time range policies for various contexts, such as
 /// scheduling tasks or validating user input within a specific time interval.
+#[derive(Clone)]
 pub struct TimeRules {
     pub is_mandatory: bool,
     pub min: Option<NaiveTime>,
     pub max: Option<NaiveTime>,
+    pub granularity_minutes: Option<u32>,
 }
 
 impl Default for TimeRules {
@@ -50,6 +65,7 @@ impl Default for TimeRules {
             is_mandatory: true,
             min: Some(NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default()),
             max: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap_or_default()),
+            granularity_minutes: None,
         }
     }
 }
@@ -89,6 +105,28 @@ impl TimeRules {
             return;
         }
         range_rule.check_time(messages, subject.as_ref());
+        if let Some(granularity_minutes) = self.granularity_minutes {
+            TimeGranularityRules {
+                granularity_minutes,
+            }
+            .check(messages, subject.as_ref());
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules(None);
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        if let Some(granularity_minutes) = self.granularity_minutes {
+            constraints.extend(
+                TimeGranularityRules {
+                    granularity_minutes,
+                }
+                .describe(),
+            );
+        }
+        constraints
     }
 }
 
@@ -196,10 +234,12 @@ impl TimeValue {
         rules: TimeRules,
         format: Option<&str>,
     ) -> Result<Self, TimeError> {
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(subject.as_ref(), &mut messages, format);
-        TimeError::validate_check(messages)?;
-        Ok(Self(subject))
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(subject.as_ref(), &mut messages, format);
+            TimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
     }
 
     /// Parses a custom time based on the provided `subject` and `rules`.
@@ -313,6 +353,66 @@ impl TimeValue {
         Self::parse_custom_with_format(subject, TimeRules::default(), format)
     }
 
+    /// Parses `subject` with the first of the `formats` it matches, then validates the parsed
+    /// time against `rules`, with the times of the error messages formatted with the matching
+    /// format.
+    ///
+    /// A missing or blank `subject` is treated as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TimeError` with a `validate-time-unparseable` message, having the accepted
+    /// `formats` as argument, if `subject` matches none of the `formats`, otherwise if the
+    /// parsed time does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveTime;
+    /// use cjtoolkit_structured_validator::types::times_chrono::time::{
+    ///     AsNaiveTimeOnResult, TimeRules, TimeValue,
+    /// };
+    ///
+    /// let formats = ["%H:%M", "%I:%M %p"];
+    ///
+    /// let time = TimeValue::parse_str(Some("2:30 PM"), TimeRules::default(), &formats);
+    /// assert_eq!(time.as_naive_time(), NaiveTime::from_hms_opt(14, 30, 0));
+    ///
+    /// let error = TimeValue::parse_str(Some("6:00 PM"), TimeRules::default(), &formats)
+    ///     .expect_err("Expected time to be too late");
+    /// assert_eq!(error.0.as_original_message_vec(), vec!["Must be before '06:00 PM'"]);
+    ///
+    /// let error = TimeValue::parse_str(Some("noon"), TimeRules::default(), &formats)
+    ///     .expect_err("Expected time to be unparseable");
+    /// assert_eq!(
+    ///     error.0.as_original_message_vec(),
+    ///     vec!["Must be in the format '%H:%M' or '%I:%M %p'"]
+    /// );
+    /// ```
+    pub fn parse_str(
+        subject: Option<&str>,
+        rules: TimeRules,
+        formats: &[&str],
+    ) -> Result<Self, TimeError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            let parse_rules = DateTimeParseRules {
+                kind: DateTimeKind::Time,
+                formats,
+            };
+            let (subject, format) = parse_rules
+                .parse(&mut messages, subject, |s, f| {
+                    NaiveTime::parse_from_str(s, f).ok()
+                })
+                .unzip();
+            if messages.is_empty() {
+                rules.check(subject.as_ref(), &mut messages, format);
+            }
+            TimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
     /// Converts the current object into a `NaiveTime` instance.
     ///
     /// # Returns
@@ -326,6 +426,12 @@ impl TimeValue {
     }
 }
 
+impl OptionalValue for TimeValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
 pub trait AsNaiveTimeOnResult {
     fn as_naive_time(&self) -> Option<NaiveTime>;
 }
@@ -336,6 +442,21 @@ impl<E> AsNaiveTimeOnResult for Result<TimeValue, E> {
     }
 }
 
+impl Validator<Option<NaiveTime>, TimeValue, TimeError> for TimeRules {
+    fn validate(&self, input: Option<NaiveTime>) -> Result<TimeValue, TimeError> {
+        TimeValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for TimeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -373,4 +494,23 @@ mod tests {
         let result = TimeValue::parse(subject);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_granularity() {
+        let rules = TimeRules {
+            granularity_minutes: Some(15),
+            ..TimeRules::default()
+        };
+        let subject = NaiveTime::from_hms_opt(10, 45, 0);
+        assert!(TimeValue::parse_custom(subject, rules.clone()).is_ok());
+        let subject = NaiveTime::from_hms_opt(10, 50, 0);
+        let error = TimeValue::parse_custom(subject, rules.clone())
+            .expect_err("Expected 10:50 not to be on a slot");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be on a 15 minute interval"]
+        );
+        let subject = NaiveTime::from_hms_opt(10, 45, 30);
+        assert!(TimeValue::parse_custom(subject, rules).is_err());
+    }
 }
@@ -0,0 +1,326 @@
+use crate::base::date_time::rules::DateTimeMandatoryLocale;
+use crate::common::constraint::Constraint;
+use crate::common::locale::{
+    LocaleData, LocaleMessage, LocaleValue, RuleViolation, ValidateErrorCollector,
+    ValidateErrorStore,
+};
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::{observe_parse, rule_result};
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use humantime::format_duration;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+/// A struct representing validation rules for a duration, specifying its mandatory status and
+/// optional bounds.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean flag that indicates whether the duration is mandatory.
+/// * `min` - The minimum allowable duration, `None` for no lower bound.
+/// * `max` - The maximum allowable duration, `None` for no upper bound.
+///
+/// # Default
+///
+/// The duration is mandatory, without bounds.
+#[derive(Clone)]
+pub struct DurationRules {
+    pub is_mandatory: bool,
+    pub min: Option<Duration>,
+    pub max: Option<Duration>,
+}
+
+impl Default for DurationRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl DurationRules {
+    fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<&Duration>) {
+        if self.is_mandatory {
+            rule_result(locale_keys::VALIDATE_CANNOT_BE_EMPTY, subject.is_some());
+            if subject.is_none() {
+                messages.push(("Cannot be empty", DateTimeMandatoryLocale));
+            }
+        }
+        let Some(subject) = subject else {
+            return;
+        };
+        if let Some(min) = self.min {
+            rule_result(locale_keys::VALIDATE_DURATION_MIN, *subject >= min);
+            if *subject < min {
+                messages.push((
+                    format!("Must be at least {}", format_duration(min)),
+                    Box::new(DurationLocale::Min(min)),
+                ));
+            }
+        }
+        if let Some(max) = self.max {
+            rule_result(locale_keys::VALIDATE_DURATION_MAX, *subject <= max);
+            if *subject > max {
+                messages.push((
+                    format!("Must be at most {}", format_duration(max)),
+                    Box::new(DurationLocale::Max(max)),
+                ));
+            }
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if self.is_mandatory {
+            constraints.push(Constraint::from_locale(&DateTimeMandatoryLocale));
+        }
+        if let Some(min) = self.min {
+            constraints.push(Constraint::from_locale(&DurationLocale::Min(min)));
+        }
+        if let Some(max) = self.max {
+            constraints.push(Constraint::from_locale(&DurationLocale::Max(max)));
+        }
+        constraints
+    }
+}
+
+/// An enumeration of the duration specific validation failures, the bounds being passed as
+/// human-readable durations, e.g. `2h 30m`.
+pub enum DurationLocale {
+    /// The string is not a human-readable duration.
+    /// # Key
+    /// `validate-duration-unparseable`
+    Unparseable,
+    /// The duration is shorter than the given minimum.
+    /// # Key
+    /// `validate-duration-min`
+    Min(Duration),
+    /// The duration is longer than the given maximum.
+    /// # Key
+    /// `validate-duration-max`
+    Max(Duration),
+}
+
+impl LocaleMessage for DurationLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        use LocaleData as ld;
+        use LocaleValue as lv;
+        match self {
+            Self::Unparseable => ld::new(locale_keys::VALIDATE_DURATION_UNPARSEABLE),
+            Self::Min(min) => ld::new_with_vec(
+                locale_keys::VALIDATE_DURATION_MIN,
+                vec![(
+                    "min".to_string(),
+                    lv::from(format_duration(*min).to_string()),
+                )],
+            ),
+            Self::Max(max) => ld::new_with_vec(
+                locale_keys::VALIDATE_DURATION_MAX,
+                vec![(
+                    "max".to_string(),
+                    lv::from(format_duration(*max).to_string()),
+                )],
+            ),
+        }
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        match self {
+            Self::Unparseable => RuleViolation::pattern("duration"),
+            Self::Min(min) => RuleViolation::Range {
+                min: Some(LocaleValue::from(format_duration(*min).to_string())),
+                max: None,
+            },
+            Self::Max(max) => RuleViolation::Range {
+                min: None,
+                max: Some(LocaleValue::from(format_duration(*max).to_string())),
+            },
+        }
+    }
+}
+
+/// Represents an error encountered during duration validation.
+///
+/// # Fields
+/// - `0: ValidateErrorStore` - A field that stores validation errors for further analysis.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Duration Validation Error")]
+pub struct DurationError(pub ValidateErrorStore);
+
+impl ValidationCheck for DurationError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&DurationError> for ValidateErrorStore {
+    fn from(error: &DurationError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A validated, optional duration.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "allow-default-value", test), derive(Default))]
+pub struct DurationValue(Option<Duration>);
+
+impl DurationValue {
+    /// Validates `subject` against `rules`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DurationError` if `subject` is missing while mandatory, or out of the bounds
+    /// of the `rules`.
+    pub fn parse_custom(
+        subject: Option<Duration>,
+        rules: DurationRules,
+    ) -> Result<Self, DurationError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, subject.as_ref());
+            DurationError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
+    /// Validates `subject` against the default rules.
+    pub fn parse(subject: Option<Duration>) -> Result<Self, DurationError> {
+        Self::parse_custom(subject, DurationRules::default())
+    }
+
+    /// Parses `subject` as a human-readable duration, such as `2h 30m` or `1day 12hours`, then
+    /// validates the parsed duration against `rules`.
+    ///
+    /// A missing or blank `subject` is treated as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DurationError` with a `validate-duration-unparseable` message if `subject` is
+    /// not a duration, otherwise if the parsed duration does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cjtoolkit_structured_validator::types::times_humantime::duration::{
+    ///     AsDurationOnResult, DurationRules, DurationValue,
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// let rules = DurationRules {
+    ///     max: Some(Duration::from_secs(4 * 60 * 60)),
+    ///     ..DurationRules::default()
+    /// };
+    ///
+    /// let duration = DurationValue::parse_str(Some("2h 30m"), rules.clone());
+    /// assert_eq!(duration.as_duration(), Some(Duration::from_secs(9000)));
+    ///
+    /// let error = DurationValue::parse_str(Some("5h"), rules.clone())
+    ///     .expect_err("Expected the duration to be too long");
+    /// assert_eq!(error.0.as_original_message_vec(), vec!["Must be at most 4h"]);
+    ///
+    /// assert!(DurationValue::parse_str(Some("soon"), rules).is_err());
+    /// ```
+    pub fn parse_str(subject: Option<&str>, rules: DurationRules) -> Result<Self, DurationError> {
+        let subject = subject.map(str::trim).filter(|s| !s.is_empty());
+        let Some(subject) = subject else {
+            return Self::parse_custom(None, rules);
+        };
+        match humantime::parse_duration(subject) {
+            Ok(duration) => Self::parse_custom(Some(duration), rules),
+            Err(_) => observe_parse(|| {
+                rule_result(locale_keys::VALIDATE_DURATION_UNPARSEABLE, false);
+                let mut messages = ValidateErrorCollector::new();
+                messages.push((
+                    "Must be a duration, such as 2h 30m",
+                    Box::new(DurationLocale::Unparseable),
+                ));
+                DurationError::validate_check(messages)?;
+                Ok(Self(None))
+            }),
+        }
+    }
+
+    /// Returns the duration, if any.
+    pub fn as_duration(&self) -> Option<Duration> {
+        self.0
+    }
+}
+
+impl OptionalValue for DurationValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+pub trait AsDurationOnResult {
+    fn as_duration(&self) -> Option<Duration>;
+}
+
+impl<E> AsDurationOnResult for Result<DurationValue, E> {
+    fn as_duration(&self) -> Option<Duration> {
+        self.as_ref()
+            .ok()
+            .map(|d| d.as_duration())
+            .unwrap_or_default()
+    }
+}
+
+impl Validator<Option<Duration>, DurationValue, DurationError> for DurationRules {
+    fn validate(&self, input: Option<Duration>) -> Result<DurationValue, DurationError> {
+        DurationValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for DurationRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_str() {
+        let rules = DurationRules {
+            min: Some(Duration::from_secs(15 * 60)),
+            max: Some(Duration::from_secs(24 * 60 * 60)),
+            ..DurationRules::default()
+        };
+        let parse = |s| DurationValue::parse_str(s, rules.clone());
+        assert_eq!(
+            parse(Some(" 1day ")).as_duration(),
+            Some(Duration::from_secs(24 * 60 * 60))
+        );
+        let error = parse(Some("10m")).expect_err("Expected the duration to be too short");
+        assert_eq!(
+            error.0.as_original_message_vec(),
+            vec!["Must be at least 15m"]
+        );
+        let error = parse(Some("2h 30")).expect_err("Expected the duration to be unparseable");
+        let locale = error.0.0[0].1.get_locale_data();
+        assert_eq!(locale.name, locale_keys::VALIDATE_DURATION_UNPARSEABLE);
+        assert!(parse(Some("  ")).is_err());
+        assert!(parse(None).is_err());
+    }
+
+    #[test]
+    fn test_parse_optional() {
+        let rules = DurationRules {
+            is_mandatory: false,
+            ..DurationRules::default()
+        };
+        let result = DurationValue::parse_str(Some(""), rules);
+        assert_eq!(result.as_duration(), None);
+    }
+}
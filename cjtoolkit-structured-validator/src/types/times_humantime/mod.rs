@@ -1,9 +1,17 @@
+//! Types for working with date and time values and durations for humantime.
+pub mod duration;
+
+use crate::base::date_time::clock::{Clock, SystemClock};
 use crate::base::date_time::data::AsDateTimeData;
 use crate::base::date_time::rules::{DateTimeMandatoryRules, DateTimeRangeRules};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
 use humantime::Timestamp;
-use std::time::{Duration, SystemTime};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Represents the rules or constraints applied to a date-time field.
@@ -30,6 +38,7 @@ use thiserror::Error;
 ///
 /// In the above example, the `DateTimeRules` specifies that the date-time is mandatory
 /// and must fall within the year 2023.
+#[derive(Clone)]
 pub struct DateTimeRules {
     pub is_mandatory: bool,
     pub min: Option<Timestamp>,
@@ -38,7 +47,14 @@ pub struct DateTimeRules {
 
 impl Default for DateTimeRules {
     fn default() -> Self {
-        let now: Timestamp = SystemTime::now().into();
+        Self::default_at(&SystemClock)
+    }
+}
+
+impl DateTimeRules {
+    /// Returns the default rules, from the time of `clock` to 30 days later.
+    pub fn default_at(clock: &dyn Clock) -> Self {
+        let now: Timestamp = clock.now().into();
         Self {
             is_mandatory: true,
             min: Some(now.clone()),
@@ -81,6 +97,14 @@ impl DateTimeRules {
         }
         range_rule.check(messages, subject.as_ref());
     }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        constraints
+    }
 }
 
 /// Represents an error encountered during DateTime validation.
@@ -164,10 +188,12 @@ impl DateTimeValue {
         subject: Option<Timestamp>,
         rules: DateTimeRules,
     ) -> Result<Self, DateTimeError> {
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, subject.as_ref());
-        DateTimeError::validate_check(messages)?;
-        Ok(Self(subject))
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, subject.as_ref());
+            DateTimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
     }
 
     /// Parses an optional `Timestamp` into a `Self` instance using the default `DateTimeRules`.
@@ -191,6 +217,12 @@ impl DateTimeValue {
         Self::parse_custom(subject, DateTimeRules::default())
     }
 
+    /// Parses the given value using the default rules, with the current time given by `clock`
+    /// instead of the system time, see `DateTimeRules::default_at`.
+    pub fn parse_at(subject: Option<Timestamp>, clock: &dyn Clock) -> Result<Self, DateTimeError> {
+        Self::parse_custom(subject, DateTimeRules::default_at(clock))
+    }
+
     /// Converts the current object into an `Option<Timestamp>`.
     ///
     /// This method returns a cloned version of the inner `Timestamp` wrapped in an `Option`.
@@ -204,6 +236,12 @@ impl DateTimeValue {
     }
 }
 
+impl OptionalValue for DateTimeValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
 pub trait AsTimestampOnResult {
     fn as_timestamp(&self) -> Option<Timestamp>;
 }
@@ -217,10 +255,27 @@ impl<E> AsTimestampOnResult for Result<DateTimeValue, E> {
     }
 }
 
+impl Validator<Option<Timestamp>, DateTimeValue, DateTimeError> for DateTimeRules {
+    fn validate(&self, input: Option<Timestamp>) -> Result<DateTimeValue, DateTimeError> {
+        DateTimeValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for DateTimeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::base::date_time::clock::FixedClock;
     use std::ops::{Add, Sub};
+    use std::time::{Duration, SystemTime};
 
     #[test]
     fn test_parse_custom() {
@@ -262,4 +317,13 @@ mod tests {
         let result = DateTimeValue::parse(subject);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_at() {
+        let clock = FixedClock(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        let now = clock.now();
+        assert!(DateTimeValue::parse_at(Some(now.into()), &clock).is_ok());
+        let before = now.sub(Duration::from_secs(1));
+        assert!(DateTimeValue::parse_at(Some(before.into()), &clock).is_err());
+    }
 }
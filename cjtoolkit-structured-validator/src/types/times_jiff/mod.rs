@@ -0,0 +1,4 @@
+//! Types for working with date and time values for jiff.
+pub mod date;
+pub mod time;
+pub mod zoned;
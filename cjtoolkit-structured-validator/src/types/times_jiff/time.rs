@@ -0,0 +1,262 @@
+use crate::base::date_time::data::AsDateTimeData;
+use crate::base::date_time::rules::{
+    DateTimeMandatoryRules, DateTimeRangeRules, TimeGranularityRules,
+};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use jiff::civil::{Time, time};
+use thiserror::Error;
+
+/// A struct representing validation rules for a time field, specifying its mandatory status
+/// and optional boundaries on valid times of day.
+///
+/// When `max` is before `min`, the range wraps around midnight, e.g. from 22:00 to 06:00.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean flag that indicates whether the time is mandatory.
+/// * `min` - The minimum allowable time, `None` for no lower bound.
+/// * `max` - The maximum allowable time, `None` for no upper bound.
+/// * `granularity_minutes` - The length of the time slots in minutes counted from midnight,
+///   e.g. `Some(15)` for :00, :15, :30 and :45, `None` for no granularity constraint.
+///
+/// # Default
+///
+/// The time is mandatory, from 09:00 to 17:00.
+#[derive(Clone)]
+pub struct TimeRules {
+    pub is_mandatory: bool,
+    pub min: Option<Time>,
+    pub max: Option<Time>,
+    pub granularity_minutes: Option<u32>,
+}
+
+impl Default for TimeRules {
+    fn default() -> Self {
+        Self {
+            is_mandatory: true,
+            min: Some(time(9, 0, 0, 0)),
+            max: Some(time(17, 0, 0, 0)),
+            granularity_minutes: None,
+        }
+    }
+}
+
+impl TimeRules {
+    fn rules(&self, date_format: Option<&str>) -> (DateTimeMandatoryRules, DateTimeRangeRules) {
+        (
+            DateTimeMandatoryRules {
+                is_mandatory: self.is_mandatory,
+            },
+            DateTimeRangeRules {
+                min: self
+                    .min
+                    .as_ref()
+                    .map(|min| (date_format, min).as_date_time_data()),
+                max: self
+                    .max
+                    .as_ref()
+                    .map(|max| (date_format, max).as_date_time_data()),
+            },
+        )
+    }
+
+    fn check(
+        self,
+        subject: Option<&Time>,
+        messages: &mut ValidateErrorCollector,
+        date_format: Option<&str>,
+    ) {
+        if !self.is_mandatory && subject.is_none() {
+            return;
+        }
+        let subject = subject.map(|s| (date_format, s).as_date_time_data());
+        let (mandatory_rule, range_rule) = self.rules(date_format);
+        mandatory_rule.check(messages, subject.as_ref());
+        if !messages.is_empty() {
+            return;
+        }
+        range_rule.check_time(messages, subject.as_ref());
+        if let Some(granularity_minutes) = self.granularity_minutes {
+            TimeGranularityRules {
+                granularity_minutes,
+            }
+            .check(messages, subject.as_ref());
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules(None);
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        if let Some(granularity_minutes) = self.granularity_minutes {
+            constraints.extend(
+                TimeGranularityRules {
+                    granularity_minutes,
+                }
+                .describe(),
+            );
+        }
+        constraints
+    }
+}
+
+/// Represents an error encountered during time validation.
+///
+/// # Fields
+/// - `0: ValidateErrorStore` - A field that stores validation errors for further analysis.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Time Validation Error")]
+pub struct TimeError(pub ValidateErrorStore);
+
+impl ValidationCheck for TimeError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&TimeError> for ValidateErrorStore {
+    fn from(error: &TimeError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A wrapper struct for `Option<Time>` that represents a validated, optional civil time.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "allow-default-value", test), derive(Default))]
+pub struct TimeValue(Option<Time>);
+
+impl TimeValue {
+    /// Validates `subject` against `rules`, with the times of the error messages formatted with
+    /// the `strftime` style `format` when provided.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TimeError` if `subject` does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cjtoolkit_structured_validator::types::times_jiff::time::TimeValue;
+    /// use jiff::civil::time;
+    ///
+    /// assert!(TimeValue::parse(Some(time(10, 30, 0, 0))).is_ok());
+    ///
+    /// let error = TimeValue::parse_with_format(Some(time(18, 0, 0, 0)), Some("%H:%M"))
+    ///     .expect_err("Expected time to be too late");
+    /// assert_eq!(error.0.as_original_message_vec(), vec!["Must be before '18:00'"]);
+    /// ```
+    pub fn parse_custom_with_format(
+        subject: Option<Time>,
+        rules: TimeRules,
+        format: Option<&str>,
+    ) -> Result<Self, TimeError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(subject.as_ref(), &mut messages, format);
+            TimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
+    /// Validates `subject` against `rules`.
+    pub fn parse_custom(subject: Option<Time>, rules: TimeRules) -> Result<Self, TimeError> {
+        Self::parse_custom_with_format(subject, rules, None)
+    }
+
+    /// Validates `subject` against the default rules.
+    pub fn parse(subject: Option<Time>) -> Result<Self, TimeError> {
+        Self::parse_custom(subject, TimeRules::default())
+    }
+
+    /// Validates `subject` against the default rules, with the times of the error messages
+    /// formatted with the `strftime` style `format` when provided.
+    pub fn parse_with_format(
+        subject: Option<Time>,
+        format: Option<&str>,
+    ) -> Result<Self, TimeError> {
+        Self::parse_custom_with_format(subject, TimeRules::default(), format)
+    }
+
+    /// Returns the time, if any.
+    pub fn as_time(&self) -> Option<Time> {
+        self.0
+    }
+}
+
+impl OptionalValue for TimeValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+pub trait AsTimeOnResult {
+    fn as_time(&self) -> Option<Time>;
+}
+
+impl<E> AsTimeOnResult for Result<TimeValue, E> {
+    fn as_time(&self) -> Option<Time> {
+        self.as_ref().ok().and_then(|t| t.as_time())
+    }
+}
+
+impl Validator<Option<Time>, TimeValue, TimeError> for TimeRules {
+    fn validate(&self, input: Option<Time>) -> Result<TimeValue, TimeError> {
+        TimeValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for TimeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_default_err() {
+        let result = TimeValue::parse(None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_max_min_ok() {
+        let result = TimeValue::parse(Some(time(10, 0, 0, 0)));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_default_max_err() {
+        let result = TimeValue::parse(Some(time(18, 0, 0, 0)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_min_err() {
+        let result = TimeValue::parse(Some(time(8, 0, 0, 0)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_overnight() {
+        let rules = TimeRules {
+            is_mandatory: true,
+            min: Some(time(22, 0, 0, 0)),
+            max: Some(time(6, 0, 0, 0)),
+            granularity_minutes: None,
+        };
+        let result = TimeValue::parse_custom(Some(time(23, 0, 0, 0)), rules);
+        assert!(result.is_ok());
+    }
+}
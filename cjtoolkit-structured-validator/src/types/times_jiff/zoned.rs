@@ -0,0 +1,235 @@
+use crate::base::date_time::clock::{Clock, SystemClock};
+use crate::base::date_time::data::AsDateTimeData;
+use crate::base::date_time::rules::{DateTimeMandatoryRules, DateTimeRangeRules};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use jiff::{ToSpan, Zoned};
+use thiserror::Error;
+
+/// A struct representing validation rules for a date and time field in a time zone, specifying
+/// its mandatory status and optional boundaries on valid instants.
+///
+/// The bounds are compared as instants, so values in different time zones can be compared.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean flag that indicates whether the date and time is mandatory.
+/// * `min` - The minimum allowable instant, `None` for no lower bound.
+/// * `max` - The maximum allowable instant, `None` for no upper bound.
+///
+/// # Default
+///
+/// The date and time is mandatory, from now to 30 days later, in the system time zone.
+#[derive(Clone)]
+pub struct ZonedRules {
+    pub is_mandatory: bool,
+    pub min: Option<Zoned>,
+    pub max: Option<Zoned>,
+}
+
+impl Default for ZonedRules {
+    fn default() -> Self {
+        Self::default_at(&SystemClock)
+    }
+}
+
+impl ZonedRules {
+    /// Returns the default rules, from the time of `clock` in the system time zone to 30 days later.
+    pub fn default_at(clock: &dyn Clock) -> Self {
+        let now = Zoned::try_from(clock.now()).ok();
+        Self {
+            is_mandatory: true,
+            max: now.as_ref().and_then(|now| now.checked_add(30.days()).ok()),
+            min: now,
+        }
+    }
+
+    fn rules(&self) -> (DateTimeMandatoryRules, DateTimeRangeRules) {
+        (
+            DateTimeMandatoryRules {
+                is_mandatory: self.is_mandatory,
+            },
+            DateTimeRangeRules {
+                min: self.min.as_ref().map(|min| min.as_date_time_data()),
+                max: self.max.as_ref().map(|max| max.as_date_time_data()),
+            },
+        )
+    }
+
+    fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<&Zoned>) {
+        if !self.is_mandatory && subject.is_none() {
+            return;
+        }
+        let subject = subject.map(|s| s.as_date_time_data());
+        let (mandatory_rule, range_rule) = self.rules();
+        mandatory_rule.check(messages, subject.as_ref());
+        if !messages.is_empty() {
+            return;
+        }
+        range_rule.check(messages, subject.as_ref());
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        constraints
+    }
+}
+
+/// Represents an error encountered during the validation of a zoned date and time.
+///
+/// # Fields
+/// - `0: ValidateErrorStore` - A field that stores validation errors for further analysis.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Zoned Validation Error")]
+pub struct ZonedError(pub ValidateErrorStore);
+
+impl ValidationCheck for ZonedError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&ZonedError> for ValidateErrorStore {
+    fn from(error: &ZonedError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A wrapper struct for `Option<Zoned>` that represents a validated, optional date and time in
+/// a time zone.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "allow-default-value", test), derive(Default))]
+pub struct ZonedValue(Option<Zoned>);
+
+impl ZonedValue {
+    /// Validates `subject` against `rules`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ZonedError` if `subject` does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cjtoolkit_structured_validator::types::times_jiff::zoned::{ZonedRules, ZonedValue};
+    /// use jiff::Zoned;
+    ///
+    /// let rules = ZonedRules {
+    ///     is_mandatory: true,
+    ///     min: Some("2024-03-10T09:00[America/New_York]".parse::<Zoned>().unwrap()),
+    ///     max: None,
+    /// };
+    ///
+    /// // 13:30 UTC is 09:30 in New York.
+    /// let subject = "2024-03-10T13:30[UTC]".parse::<Zoned>().unwrap();
+    /// assert!(ZonedValue::parse_custom(Some(subject), rules.clone()).is_ok());
+    ///
+    /// let subject = "2024-03-10T12:30[UTC]".parse::<Zoned>().unwrap();
+    /// assert!(ZonedValue::parse_custom(Some(subject), rules).is_err());
+    /// ```
+    pub fn parse_custom(subject: Option<Zoned>, rules: ZonedRules) -> Result<Self, ZonedError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, subject.as_ref());
+            ZonedError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
+    /// Validates `subject` against the default rules.
+    pub fn parse(subject: Option<Zoned>) -> Result<Self, ZonedError> {
+        Self::parse_custom(subject, ZonedRules::default())
+    }
+
+    /// Parses the given value using the default rules, with the current time given by `clock`
+    /// instead of the system time, see `ZonedRules::default_at`.
+    pub fn parse_at(subject: Option<Zoned>, clock: &dyn Clock) -> Result<Self, ZonedError> {
+        Self::parse_custom(subject, ZonedRules::default_at(clock))
+    }
+
+    /// Returns the date and time, if any.
+    pub fn as_zoned(&self) -> Option<Zoned> {
+        self.0.clone()
+    }
+}
+
+impl OptionalValue for ZonedValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+pub trait AsZonedOnResult {
+    fn as_zoned(&self) -> Option<Zoned>;
+}
+
+impl<E> AsZonedOnResult for Result<ZonedValue, E> {
+    fn as_zoned(&self) -> Option<Zoned> {
+        self.as_ref().ok().and_then(|t| t.as_zoned())
+    }
+}
+
+impl Validator<Option<Zoned>, ZonedValue, ZonedError> for ZonedRules {
+    fn validate(&self, input: Option<Zoned>) -> Result<ZonedValue, ZonedError> {
+        ZonedValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for ZonedRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::date_time::clock::FixedClock;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_parse_default_err() {
+        let result = ZonedValue::parse(None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_max_min_ok() {
+        let subject = Zoned::now().checked_add(1.day()).ok();
+        let result = ZonedValue::parse(subject);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_default_max_err() {
+        let subject = Zoned::now().checked_add(31.days()).ok();
+        let result = ZonedValue::parse(subject);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_min_err() {
+        let subject = Zoned::now().checked_sub(1.day()).ok();
+        let result = ZonedValue::parse(subject);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let clock = FixedClock(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        let now = Zoned::try_from(clock.now()).ok();
+        assert!(ZonedValue::parse_at(now.clone(), &clock).is_ok());
+        let later = now.and_then(|now| now.checked_add(31.days()).ok());
+        assert!(ZonedValue::parse_at(later, &clock).is_err());
+    }
+}
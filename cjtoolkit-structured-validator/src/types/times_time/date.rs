@@ -0,0 +1,283 @@
+use crate::base::date_time::clock::{Clock, SystemClock};
+use crate::base::date_time::data::AsDateTimeData;
+use crate::base::date_time::rules::{BusinessDayRules, DateTimeMandatoryRules, DateTimeRangeRules};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use thiserror::Error;
+use time::format_description::BorrowedFormatItem;
+use time::{Date, Duration, OffsetDateTime};
+
+/// A struct representing validation rules for a date field, specifying its mandatory
+/// status and optional boundaries on valid date ranges.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean flag that indicates whether the date field is mandatory.
+/// * `min` - The minimum allowable date, `None` for no lower bound.
+/// * `max` - The maximum allowable date, `None` for no upper bound.
+/// * `business_days` - The rules requiring the date to be a business day, `None` to allow any
+///   day.
+///
+/// # Default
+///
+/// The date is mandatory, from today in UTC to 30 days later.
+#[derive(Clone)]
+pub struct DateRules {
+    pub is_mandatory: bool,
+    pub min: Option<Date>,
+    pub max: Option<Date>,
+    pub business_days: Option<BusinessDayRules>,
+}
+
+impl Default for DateRules {
+    fn default() -> Self {
+        Self::default_at(&SystemClock)
+    }
+}
+
+impl DateRules {
+    /// Returns the default rules, from the date of `clock` in UTC to 30 days later.
+    pub fn default_at(clock: &dyn Clock) -> Self {
+        let today = OffsetDateTime::from(clock.now()).date();
+        Self {
+            is_mandatory: true,
+            min: Some(today),
+            max: today.checked_add(Duration::days(30)),
+            business_days: None,
+        }
+    }
+
+    fn rules(
+        &self,
+        format: Option<&[BorrowedFormatItem<'_>]>,
+    ) -> (DateTimeMandatoryRules, DateTimeRangeRules) {
+        (
+            DateTimeMandatoryRules {
+                is_mandatory: self.is_mandatory,
+            },
+            DateTimeRangeRules {
+                min: self
+                    .min
+                    .as_ref()
+                    .map(|min| (format, min).as_date_time_data()),
+                max: self
+                    .max
+                    .as_ref()
+                    .map(|max| (format, max).as_date_time_data()),
+            },
+        )
+    }
+
+    fn check(
+        self,
+        subject: Option<&Date>,
+        messages: &mut ValidateErrorCollector,
+        format: Option<&[BorrowedFormatItem<'_>]>,
+    ) {
+        if !self.is_mandatory && subject.is_none() {
+            return;
+        }
+        let subject = subject.map(|s| (format, s).as_date_time_data());
+        let (mandatory_rule, range_rule) = self.rules(format);
+        mandatory_rule.check(messages, subject.as_ref());
+        if !messages.is_empty() {
+            return;
+        }
+        range_rule.check(messages, subject.as_ref());
+        if let Some(business_days) = &self.business_days {
+            business_days.check(messages, subject.as_ref());
+        }
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules(None);
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        if let Some(business_days) = &self.business_days {
+            constraints.extend(business_days.describe());
+        }
+        constraints
+    }
+}
+
+/// Represents an error encountered during date validation.
+///
+/// # Fields
+/// - `0: ValidateErrorStore` - A field that stores validation errors for further analysis.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("Date Validation Error")]
+pub struct DateError(pub ValidateErrorStore);
+
+impl ValidationCheck for DateError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&DateError> for ValidateErrorStore {
+    fn from(error: &DateError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A wrapper struct for `Option<Date>` that represents a validated, optional date value.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "allow-default-value", test), derive(Default))]
+pub struct DateValue(Option<Date>);
+
+impl DateValue {
+    /// Validates `subject` against `rules`, with the dates of the error messages formatted with
+    /// `format` when provided.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateError` if `subject` does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cjtoolkit_structured_validator::types::times_time::date::{DateRules, DateValue};
+    /// use time::macros::{date, format_description};
+    ///
+    /// let rules = DateRules {
+    ///     is_mandatory: true,
+    ///     min: Some(date!(2024 - 01 - 01)),
+    ///     max: Some(date!(2024 - 12 - 31)),
+    ///     business_days: None,
+    /// };
+    /// let format = format_description!("[day]/[month]/[year]");
+    ///
+    /// let error = DateValue::parse_custom_with_format(
+    ///     Some(date!(2025 - 01 - 01)),
+    ///     rules,
+    ///     Some(format),
+    /// )
+    /// .expect_err("Expected date to be too late");
+    /// assert_eq!(error.0.as_original_message_vec(), vec!["Must be before '01/01/2025'"]);
+    /// ```
+    pub fn parse_custom_with_format(
+        subject: Option<Date>,
+        rules: DateRules,
+        format: Option<&[BorrowedFormatItem<'_>]>,
+    ) -> Result<Self, DateError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(subject.as_ref(), &mut messages, format);
+            DateError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
+    /// Validates `subject` against `rules`.
+    pub fn parse_custom(subject: Option<Date>, rules: DateRules) -> Result<Self, DateError> {
+        Self::parse_custom_with_format(subject, rules, None)
+    }
+
+    /// Validates `subject` against the default rules.
+    pub fn parse(subject: Option<Date>) -> Result<Self, DateError> {
+        Self::parse_custom(subject, DateRules::default())
+    }
+
+    /// Parses the given value using the default rules, with the current time given by `clock`
+    /// instead of the system time, see `DateRules::default_at`.
+    pub fn parse_at(subject: Option<Date>, clock: &dyn Clock) -> Result<Self, DateError> {
+        Self::parse_custom(subject, DateRules::default_at(clock))
+    }
+
+    /// Validates `subject` against the default rules, with the dates of the error messages
+    /// formatted with `format` when provided.
+    pub fn parse_with_format(
+        subject: Option<Date>,
+        format: Option<&[BorrowedFormatItem<'_>]>,
+    ) -> Result<Self, DateError> {
+        Self::parse_custom_with_format(subject, DateRules::default(), format)
+    }
+
+    /// Returns the date, if any.
+    pub fn as_date(&self) -> Option<Date> {
+        self.0
+    }
+}
+
+impl OptionalValue for DateValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+pub trait AsDateOnResult {
+    fn as_date(&self) -> Option<Date>;
+}
+
+impl<E> AsDateOnResult for Result<DateValue, E> {
+    fn as_date(&self) -> Option<Date> {
+        self.as_ref().ok().and_then(|t| t.as_date())
+    }
+}
+
+impl Validator<Option<Date>, DateValue, DateError> for DateRules {
+    fn validate(&self, input: Option<Date>) -> Result<DateValue, DateError> {
+        DateValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for DateRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::date_time::clock::FixedClock;
+    use std::time::SystemTime;
+
+    fn today() -> Date {
+        OffsetDateTime::now_utc().date()
+    }
+
+    #[test]
+    fn test_parse_default_err() {
+        let result = DateValue::parse(None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_max_min_ok() {
+        let result = DateValue::parse(Some(today()));
+        assert!(result.is_ok());
+        let result = DateValue::parse(Some(today() + Duration::days(30)));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_default_max_err() {
+        let result = DateValue::parse(Some(today() + Duration::days(31)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_min_err() {
+        let result = DateValue::parse(Some(today() - Duration::days(1)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let clock =
+            FixedClock(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000));
+        let today = Date::from_calendar_date(2023, time::Month::November, 14).ok();
+        assert!(DateValue::parse_at(today, &clock).is_ok());
+        let later = today.and_then(|today| today.checked_add(Duration::days(31)));
+        assert!(DateValue::parse_at(later, &clock).is_err());
+    }
+}
@@ -0,0 +1,249 @@
+use crate::base::date_time::clock::{Clock, SystemClock};
+use crate::base::date_time::data::AsDateTimeData;
+use crate::base::date_time::rules::{DateTimeMandatoryRules, DateTimeRangeRules};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use thiserror::Error;
+use time::{Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// A struct representing validation rules for a date and time field with an offset, specifying
+/// its mandatory status and optional boundaries on valid instants.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean flag that indicates whether the date and time is mandatory.
+/// * `min` - The minimum allowable instant, `None` for no lower bound.
+/// * `max` - The maximum allowable instant, `None` for no upper bound.
+///
+/// # Default
+///
+/// The date and time is mandatory, from now to 30 days later.
+#[derive(Clone)]
+pub struct DateTimeRules {
+    pub is_mandatory: bool,
+    pub min: Option<OffsetDateTime>,
+    pub max: Option<OffsetDateTime>,
+}
+
+impl Default for DateTimeRules {
+    fn default() -> Self {
+        Self::default_at(&SystemClock)
+    }
+}
+
+impl DateTimeRules {
+    /// Returns the default rules, from the time of `clock` to 30 days later.
+    pub fn default_at(clock: &dyn Clock) -> Self {
+        let now = OffsetDateTime::from(clock.now());
+        Self {
+            is_mandatory: true,
+            min: Some(now),
+            max: now.checked_add(Duration::days(30)),
+        }
+    }
+
+    fn rules(&self) -> (DateTimeMandatoryRules, DateTimeRangeRules) {
+        (
+            DateTimeMandatoryRules {
+                is_mandatory: self.is_mandatory,
+            },
+            DateTimeRangeRules {
+                min: self.min.as_ref().map(|min| min.as_date_time_data()),
+                max: self.max.as_ref().map(|max| max.as_date_time_data()),
+            },
+        )
+    }
+
+    fn check(&self, messages: &mut ValidateErrorCollector, subject: Option<&OffsetDateTime>) {
+        if !self.is_mandatory && subject.is_none() {
+            return;
+        }
+        let subject = subject.map(|s| s.as_date_time_data());
+        let (mandatory_rule, range_rule) = self.rules();
+        mandatory_rule.check(messages, subject.as_ref());
+        if !messages.is_empty() {
+            return;
+        }
+        range_rule.check(messages, subject.as_ref());
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        constraints
+    }
+}
+
+/// Represents an error encountered during date and time validation.
+///
+/// # Fields
+/// - `0: ValidateErrorStore` - A field that stores validation errors for further analysis.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("DateTime Validation Error")]
+pub struct DateTimeError(pub ValidateErrorStore);
+
+impl ValidationCheck for DateTimeError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&DateTimeError> for ValidateErrorStore {
+    fn from(error: &DateTimeError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A wrapper struct for `Option<OffsetDateTime>` that represents a validated, optional date and
+/// time with an offset.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "allow-default-value", test), derive(Default))]
+pub struct DateTimeValue(Option<OffsetDateTime>);
+
+impl DateTimeValue {
+    /// Validates `subject` against `rules`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DateTimeError` if `subject` does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cjtoolkit_structured_validator::types::times_time::date_time::DateTimeValue;
+    /// use time::{Duration, OffsetDateTime};
+    ///
+    /// let tomorrow = OffsetDateTime::now_utc() + Duration::days(1);
+    /// assert!(DateTimeValue::parse(Some(tomorrow)).is_ok());
+    /// assert!(DateTimeValue::parse(None).is_err());
+    /// ```
+    pub fn parse_custom(
+        subject: Option<OffsetDateTime>,
+        rules: DateTimeRules,
+    ) -> Result<Self, DateTimeError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, subject.as_ref());
+            DateTimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
+    /// Validates the date and time `subject` at `offset` against `rules`.
+    pub fn parse_custom_primitive_with_offset(
+        subject: Option<PrimitiveDateTime>,
+        rules: DateTimeRules,
+        offset: UtcOffset,
+    ) -> Result<Self, DateTimeError> {
+        Self::parse_custom(subject.map(|s| s.assume_offset(offset)), rules)
+    }
+
+    /// Validates `subject` against the default rules.
+    pub fn parse(subject: Option<OffsetDateTime>) -> Result<Self, DateTimeError> {
+        Self::parse_custom(subject, DateTimeRules::default())
+    }
+
+    /// Parses the given value using the default rules, with the current time given by `clock`
+    /// instead of the system time, see `DateTimeRules::default_at`.
+    pub fn parse_at(
+        subject: Option<OffsetDateTime>,
+        clock: &dyn Clock,
+    ) -> Result<Self, DateTimeError> {
+        Self::parse_custom(subject, DateTimeRules::default_at(clock))
+    }
+
+    /// Validates the date and time `subject` at `offset` against the default rules.
+    pub fn parse_primitive_with_offset(
+        subject: Option<PrimitiveDateTime>,
+        offset: UtcOffset,
+    ) -> Result<Self, DateTimeError> {
+        Self::parse_custom_primitive_with_offset(subject, DateTimeRules::default(), offset)
+    }
+
+    /// Returns the date and time, if any.
+    pub fn as_date_time(&self) -> Option<OffsetDateTime> {
+        self.0
+    }
+}
+
+impl OptionalValue for DateTimeValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+pub trait AsDateTimeOnResult {
+    fn as_date_time(&self) -> Option<OffsetDateTime>;
+}
+
+impl<E> AsDateTimeOnResult for Result<DateTimeValue, E> {
+    fn as_date_time(&self) -> Option<OffsetDateTime> {
+        self.as_ref().ok().and_then(|t| t.as_date_time())
+    }
+}
+
+impl Validator<Option<OffsetDateTime>, DateTimeValue, DateTimeError> for DateTimeRules {
+    fn validate(&self, input: Option<OffsetDateTime>) -> Result<DateTimeValue, DateTimeError> {
+        DateTimeValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for DateTimeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_default_err() {
+        let result = DateTimeValue::parse(None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_max_min_ok() {
+        let subject = Some(OffsetDateTime::now_utc() + Duration::days(1));
+        let result = DateTimeValue::parse(subject);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_default_max_err() {
+        let subject = Some(OffsetDateTime::now_utc() + Duration::days(31));
+        let result = DateTimeValue::parse(subject);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_min_err() {
+        let subject = Some(OffsetDateTime::now_utc() - Duration::days(1));
+        let result = DateTimeValue::parse(subject);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_primitive_with_offset() {
+        let now = OffsetDateTime::now_utc() + Duration::hours(1);
+        let local = PrimitiveDateTime::new(now.date(), now.time());
+        let offset = UtcOffset::from_hms(2, 0, 0).expect("Expected valid offset");
+
+        // Two hours ahead of UTC, so an hour before now.
+        let result = DateTimeValue::parse_primitive_with_offset(Some(local), offset);
+        assert!(result.is_err());
+        let result = DateTimeValue::parse_primitive_with_offset(Some(local), UtcOffset::UTC);
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,5 @@
+//! Types for working with date and time values for the `time` crate.
+pub mod date;
+pub mod date_time;
+pub mod primitive_date_time;
+pub mod time;
@@ -0,0 +1,274 @@
+use crate::base::date_time::clock::{Clock, SystemClock};
+use crate::base::date_time::data::AsDateTimeData;
+use crate::base::date_time::rules::{DateTimeMandatoryRules, DateTimeRangeRules};
+use crate::common::constraint::Constraint;
+use crate::common::locale::{ValidateErrorCollector, ValidateErrorStore};
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::observer::observe_parse;
+use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use thiserror::Error;
+use time::format_description::BorrowedFormatItem;
+use time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+/// A struct representing validation rules for a date and time field without offset, specifying
+/// its mandatory status and optional boundaries on valid values.
+///
+/// # Fields
+///
+/// * `is_mandatory` - A boolean flag that indicates whether the date and time is mandatory.
+/// * `min` - The minimum allowable date and time, `None` for no lower bound.
+/// * `max` - The maximum allowable date and time, `None` for no upper bound.
+///
+/// # Default
+///
+/// The date and time is mandatory, from now in UTC to 30 days later.
+#[derive(Clone)]
+pub struct PrimitiveDateTimeRules {
+    pub is_mandatory: bool,
+    pub min: Option<PrimitiveDateTime>,
+    pub max: Option<PrimitiveDateTime>,
+}
+
+impl Default for PrimitiveDateTimeRules {
+    fn default() -> Self {
+        Self::default_at(&SystemClock)
+    }
+}
+
+impl PrimitiveDateTimeRules {
+    /// Returns the default rules, from the time of `clock` in UTC to 30 days later.
+    pub fn default_at(clock: &dyn Clock) -> Self {
+        let now = OffsetDateTime::from(clock.now());
+        let now = PrimitiveDateTime::new(now.date(), now.time());
+        Self {
+            is_mandatory: true,
+            min: Some(now),
+            max: now.checked_add(Duration::days(30)),
+        }
+    }
+
+    fn rules(
+        &self,
+        format: Option<&[BorrowedFormatItem<'_>]>,
+    ) -> (DateTimeMandatoryRules, DateTimeRangeRules) {
+        (
+            DateTimeMandatoryRules {
+                is_mandatory: self.is_mandatory,
+            },
+            DateTimeRangeRules {
+                min: self
+                    .min
+                    .as_ref()
+                    .map(|min| (format, min).as_date_time_data()),
+                max: self
+                    .max
+                    .as_ref()
+                    .map(|max| (format, max).as_date_time_data()),
+            },
+        )
+    }
+
+    fn check(
+        self,
+        subject: Option<&PrimitiveDateTime>,
+        messages: &mut ValidateErrorCollector,
+        format: Option<&[BorrowedFormatItem<'_>]>,
+    ) {
+        if !self.is_mandatory && subject.is_none() {
+            return;
+        }
+        let subject = subject.map(|s| (format, s).as_date_time_data());
+        let (mandatory_rule, range_rule) = self.rules(format);
+        mandatory_rule.check(messages, subject.as_ref());
+        if !messages.is_empty() {
+            return;
+        }
+        range_rule.check(messages, subject.as_ref());
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, range_rule) = self.rules(None);
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(range_rule.describe());
+        constraints
+    }
+}
+
+/// Represents an error encountered during the validation of a date and time without offset.
+///
+/// # Fields
+/// - `0: ValidateErrorStore` - A field that stores validation errors for further analysis.
+#[derive(Debug, Error, PartialEq, Clone, Default)]
+#[error("PrimitiveDateTime Validation Error")]
+pub struct PrimitiveDateTimeError(pub ValidateErrorStore);
+
+impl ValidationCheck for PrimitiveDateTimeError {
+    fn validate_new(messages: ValidateErrorStore) -> Self {
+        Self(messages)
+    }
+}
+
+impl From<&PrimitiveDateTimeError> for ValidateErrorStore {
+    fn from(error: &PrimitiveDateTimeError) -> Self {
+        error.0.clone()
+    }
+}
+
+/// A wrapper struct for `Option<PrimitiveDateTime>` that represents a validated, optional date
+/// and time without offset.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "allow-default-value", test), derive(Default))]
+pub struct PrimitiveDateTimeValue(Option<PrimitiveDateTime>);
+
+impl PrimitiveDateTimeValue {
+    /// Validates `subject` against `rules`, with the dates of the error messages formatted with
+    /// `format` when provided.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PrimitiveDateTimeError` if `subject` does not satisfy the `rules`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cjtoolkit_structured_validator::types::times_time::primitive_date_time::{
+    ///     PrimitiveDateTimeRules, PrimitiveDateTimeValue,
+    /// };
+    /// use time::macros::{datetime, format_description};
+    ///
+    /// let rules = PrimitiveDateTimeRules {
+    ///     is_mandatory: true,
+    ///     min: Some(datetime!(2024-01-01 09:00)),
+    ///     max: None,
+    /// };
+    /// let format = format_description!("[day]/[month]/[year] [hour]:[minute]");
+    ///
+    /// let error = PrimitiveDateTimeValue::parse_custom_with_format(
+    ///     Some(datetime!(2023-12-31 23:30)),
+    ///     rules,
+    ///     Some(format),
+    /// )
+    /// .expect_err("Expected date and time to be too early");
+    /// assert_eq!(error.0.as_original_message_vec(), vec!["Must be after '31/12/2023 23:30'"]);
+    /// ```
+    pub fn parse_custom_with_format(
+        subject: Option<PrimitiveDateTime>,
+        rules: PrimitiveDateTimeRules,
+        format: Option<&[BorrowedFormatItem<'_>]>,
+    ) -> Result<Self, PrimitiveDateTimeError> {
+        observe_parse(|| {
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(subject.as_ref(), &mut messages, format);
+            PrimitiveDateTimeError::validate_check(messages)?;
+            Ok(Self(subject))
+        })
+    }
+
+    /// Validates `subject` against `rules`.
+    pub fn parse_custom(
+        subject: Option<PrimitiveDateTime>,
+        rules: PrimitiveDateTimeRules,
+    ) -> Result<Self, PrimitiveDateTimeError> {
+        Self::parse_custom_with_format(subject, rules, None)
+    }
+
+    /// Validates `subject` against the default rules.
+    pub fn parse(subject: Option<PrimitiveDateTime>) -> Result<Self, PrimitiveDateTimeError> {
+        Self::parse_custom(subject, PrimitiveDateTimeRules::default())
+    }
+
+    /// Parses the given value using the default rules, with the current time given by `clock`
+    /// instead of the system time, see `PrimitiveDateTimeRules::default_at`.
+    pub fn parse_at(
+        subject: Option<PrimitiveDateTime>,
+        clock: &dyn Clock,
+    ) -> Result<Self, PrimitiveDateTimeError> {
+        Self::parse_custom(subject, PrimitiveDateTimeRules::default_at(clock))
+    }
+
+    /// Validates `subject` against the default rules, with the dates of the error messages
+    /// formatted with `format` when provided.
+    pub fn parse_with_format(
+        subject: Option<PrimitiveDateTime>,
+        format: Option<&[BorrowedFormatItem<'_>]>,
+    ) -> Result<Self, PrimitiveDateTimeError> {
+        Self::parse_custom_with_format(subject, PrimitiveDateTimeRules::default(), format)
+    }
+
+    /// Returns the date and time, if any.
+    pub fn as_primitive_date_time(&self) -> Option<PrimitiveDateTime> {
+        self.0
+    }
+}
+
+impl OptionalValue for PrimitiveDateTimeValue {
+    fn is_absent(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+pub trait AsPrimitiveDateTimeOnResult {
+    fn as_primitive_date_time(&self) -> Option<PrimitiveDateTime>;
+}
+
+impl<E> AsPrimitiveDateTimeOnResult for Result<PrimitiveDateTimeValue, E> {
+    fn as_primitive_date_time(&self) -> Option<PrimitiveDateTime> {
+        self.as_ref().ok().and_then(|t| t.as_primitive_date_time())
+    }
+}
+
+impl Validator<Option<PrimitiveDateTime>, PrimitiveDateTimeValue, PrimitiveDateTimeError>
+    for PrimitiveDateTimeRules
+{
+    fn validate(
+        &self,
+        input: Option<PrimitiveDateTime>,
+    ) -> Result<PrimitiveDateTimeValue, PrimitiveDateTimeError> {
+        PrimitiveDateTimeValue::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for PrimitiveDateTimeRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> PrimitiveDateTime {
+        let now = OffsetDateTime::now_utc();
+        PrimitiveDateTime::new(now.date(), now.time())
+    }
+
+    #[test]
+    fn test_parse_default_err() {
+        let result = PrimitiveDateTimeValue::parse(None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_max_min_ok() {
+        let result = PrimitiveDateTimeValue::parse(Some(now() + Duration::days(1)));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_default_max_err() {
+        let result = PrimitiveDateTimeValue::parse(Some(now() + Duration::days(31)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_default_min_err() {
+        let result = PrimitiveDateTimeValue::parse(Some(now() - Duration::days(1)));
+        assert!(result.is_err());
+    }
+}
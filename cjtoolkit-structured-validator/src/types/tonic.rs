@@ -0,0 +1,142 @@
+//! This module contains the `tonic` support of the value types.
+//!
+//! `validate_request` validates the message of a `tonic::Request` with `ValidateRequest`, e.g.
+//! at the start of a service method. When the validation fails, the request is rejected with a
+//! `tonic::Status` carrying a `google.rpc.BadRequest` detail, with a field violation per error,
+//! the code being `ALREADY_EXISTS` when e.g. a username is already taken, and
+//! `INVALID_ARGUMENT` otherwise. The descriptions are the original English messages.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::common::validation_report::ValidationReport;
+//! use cjtoolkit_structured_validator::types::name::Name;
+//! use cjtoolkit_structured_validator::types::tonic::{ValidateRequest, validate_request};
+//! use tonic::Code;
+//! use tonic_types::StatusExt;
+//!
+//! struct SignupMessage {
+//!     name: String,
+//! }
+//!
+//! #[derive(Debug)]
+//! struct Signup {
+//!     name: Name,
+//! }
+//!
+//! impl ValidateRequest for Signup {
+//!     type Input = SignupMessage;
+//!
+//!     fn validate_request(input: SignupMessage) -> Result<Self, ValidationReport> {
+//!         let mut report = ValidationReport::new();
+//!         let name = report.check("name", Name::parse(Some(&input.name)));
+//!         report.into_result()?;
+//!         Ok(Self {
+//!             name: name.expect("Expected name to be valid"),
+//!         })
+//!     }
+//! }
+//!
+//! let request = tonic::Request::new(SignupMessage { name: "No".to_string() });
+//! let status = validate_request::<Signup>(request).expect_err("Expected name to be too short");
+//! assert_eq!(status.code(), Code::InvalidArgument);
+//! let bad_request = status.get_details_bad_request().expect("Expected bad request details");
+//! assert_eq!(bad_request.field_violations[0].field, "name");
+//! ```
+
+use crate::common::status_hint::StatusHint;
+use crate::common::validation_report::ValidationReport;
+use ::tonic::{Code, Request, Status};
+use ::tonic_types::{ErrorDetails, FieldViolation, StatusExt};
+
+/// A trait for request messages which are validated after being decoded.
+///
+/// # Associated Types
+/// - `Input`: The raw, decoded message of the request.
+pub trait ValidateRequest: Sized {
+    type Input;
+
+    fn validate_request(input: Self::Input) -> Result<Self, ValidationReport>;
+}
+
+/// Validates the message of `request` as `T`, keeping the metadata and extensions of the
+/// request, or returns the `Status` of the validation errors.
+pub fn validate_request<T: ValidateRequest>(
+    request: Request<T::Input>,
+) -> Result<Request<T>, Status> {
+    let (metadata, extensions, input) = request.into_parts();
+    let validated = T::validate_request(input)?;
+    Ok(Request::from_parts(metadata, extensions, validated))
+}
+
+/// The report is converted into a status with a `google.rpc.BadRequest` detail, with a field
+/// violation per error, the code following the status suggested by the errors.
+impl From<ValidationReport> for Status {
+    fn from(report: ValidationReport) -> Self {
+        let code = match report.suggested_status() {
+            Some(StatusHint::Conflict) => Code::AlreadyExists,
+            _ => Code::InvalidArgument,
+        };
+        let violations: Vec<FieldViolation> = report
+            .0
+            .iter()
+            .flat_map(|(field, store)| {
+                store
+                    .as_original_message_vec()
+                    .into_iter()
+                    .map(move |message| FieldViolation::new(field.as_str(), message))
+            })
+            .collect();
+        Status::with_error_details(
+            code,
+            "Validation failed",
+            ErrorDetails::with_bad_request(violations),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::locale::ValidateErrorCollector;
+    use crate::types::numbers::integer::{Integer, IntegerRules};
+    use crate::types::username::{UsernameError, UsernameTakenLocale};
+
+    #[test]
+    fn test_report_into_status() {
+        let mut report = ValidationReport::new();
+        let _ = report.check(
+            "age",
+            Integer::parse_custom(
+                Some(200),
+                IntegerRules {
+                    min: Some(300),
+                    max: Some(150),
+                    ..IntegerRules::default()
+                },
+            ),
+        );
+
+        let status: Status = report.into();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        let bad_request = status
+            .get_details_bad_request()
+            .expect("Expected bad request details");
+        let fields: Vec<&str> = bad_request
+            .field_violations
+            .iter()
+            .map(|v| v.field.as_str())
+            .collect();
+        assert_eq!(fields, vec!["age", "age"]);
+    }
+
+    #[test]
+    fn test_taken_is_already_exists() {
+        let mut messages = ValidateErrorCollector::new();
+        messages.push(("Already taken", Box::new(UsernameTakenLocale)));
+        let mut report = ValidationReport::new();
+        let _ = report.check::<(), _>("username", Err(UsernameError(messages.into())));
+
+        let status: Status = report.into();
+        assert_eq!(status.code(), Code::AlreadyExists);
+    }
+}
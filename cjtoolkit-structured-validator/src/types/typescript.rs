@@ -0,0 +1,415 @@
+//! This module contains the TypeScript and zod export of the rules, for keeping the front-end
+//! validation in sync with the Rust definitions.
+//!
+//! `TypeScriptExport` is a registry of named rule presets, exported either as TypeScript types or
+//! as zod schemas. The zod schemas are built from the constraints listed by the `describe` method
+//! of the rules, with the locale key of each constraint as its message, so the front-end reports
+//! the same translations as the back-end. The constraints zod cannot express, e.g. the forbidden
+//! zero-width characters, are listed in a comment, and still enforced by the back-end.
+//!
+//! zod counts the length of strings in UTF-16 code units rather than grapheme clusters, so the
+//! front-end may disagree on the length of strings with combined characters.
+//!
+//! # Example
+//! ```
+//! use cjtoolkit_structured_validator::types::json::Schema;
+//! use cjtoolkit_structured_validator::types::name::NameRules;
+//! use cjtoolkit_structured_validator::types::numbers::unsigned::UnsignedRules;
+//! use cjtoolkit_structured_validator::types::typescript::TypeScriptExport;
+//!
+//! let export = TypeScriptExport::new()
+//!     .preset("Name", &NameRules::default())
+//!     .preset(
+//!         "Profile",
+//!         &Schema::object([
+//!             ("name", Schema::Name(NameRules::default())),
+//!             (
+//!                 "age",
+//!                 Schema::Unsigned(UnsignedRules {
+//!                     is_mandatory: false,
+//!                     ..UnsignedRules::default()
+//!                 }),
+//!             ),
+//!         ]),
+//!     );
+//!
+//! assert_eq!(
+//!     export.to_typescript(),
+//!     "export type Name = string;\n\
+//!      \n\
+//!      export type Profile = {\n  \"name\": string;\n  \"age\"?: number | null;\n};\n"
+//! );
+//! assert!(export.to_zod().contains(
+//!     "export const nameSchema = z.string().min(1, \"validate-cannot-be-empty\")\
+//!      .min(5, \"validate-min-length\").max(20, \"validate-max-length\");"
+//! ));
+//! ```
+
+use crate::common::constraint::Constraint;
+use crate::common::locale::{LocaleValue, RuleViolation};
+use crate::common::string_validator::StringValidator;
+use crate::types::description::DescriptionRules;
+#[cfg(feature = "email")]
+use crate::types::email::EmailRules;
+use crate::types::json::Schema;
+use crate::types::name::NameRules;
+use crate::types::net::domain::DomainRules;
+use crate::types::net::hostname::HostnameRules;
+use crate::types::numbers::float::FloatRules;
+use crate::types::numbers::integer::IntegerRules;
+use crate::types::numbers::unsigned::UnsignedRules;
+use crate::types::password::PasswordRules;
+#[cfg(feature = "url")]
+use crate::types::url::UrlRules;
+use crate::types::username::UsernameRules;
+
+/// A trait for rules that can be exported as a TypeScript type and a zod schema.
+///
+/// # Required Methods
+/// - `ts_type`: Returns the TypeScript type of the values satisfying the rules.
+/// - `zod_schema`: Returns the zod schema of the values satisfying the rules, as a TypeScript
+///   expression.
+/// - `is_required`: Whether the value is mandatory, i.e. not optional when it is a field of an
+///   object.
+pub trait ZodSchema {
+    fn ts_type(&self) -> String;
+
+    fn zod_schema(&self) -> String;
+
+    fn is_required(&self) -> bool;
+}
+
+/// Returns `s` as a TypeScript string literal.
+fn ts_string(s: &str) -> String {
+    serde_json::Value::from(s).to_string()
+}
+
+fn ts_value(value: &LocaleValue) -> String {
+    match value {
+        LocaleValue::String(s) => ts_string(s),
+        LocaleValue::Uint(n) => n.to_string(),
+        LocaleValue::Int(n) => n.to_string(),
+        LocaleValue::Float(n) => n.to_string(),
+    }
+}
+
+fn regex_class(chars: &[char]) -> String {
+    chars
+        .iter()
+        .map(|c| match c {
+            '\\' | ']' | '[' | '^' | '-' | '/' => format!("\\{}", c),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Returns the zod method enforcing `constraint`, if zod can express it.
+fn zod_method(constraint: &Constraint) -> Option<String> {
+    let message = ts_string(&constraint.locale_key);
+    let method = match &constraint.kind {
+        RuleViolation::MinLength { min } => format!(".min({}, {})", min, message),
+        RuleViolation::MaxLength { max } => format!(".max({}, {})", max, message),
+        RuleViolation::MinItems { min } => format!(".min({}, {})", min, message),
+        RuleViolation::MaxItems { max } => format!(".max({}, {})", max, message),
+        RuleViolation::Range { min, max } => {
+            let mut method = String::new();
+            if let Some(min) = min {
+                method.push_str(&format!(".gte({}, {})", ts_value(min), message));
+            }
+            if let Some(max) = max {
+                method.push_str(&format!(".lte({}, {})", ts_value(max), message));
+            }
+            method
+        }
+        RuleViolation::ForbidZero => format!(".refine((v) => v !== 0, {})", message),
+        RuleViolation::MustBeEven => format!(".multipleOf(2, {})", message),
+        RuleViolation::MustBeOdd => format!(".refine((v) => Math.abs(v % 2) === 1, {})", message),
+        RuleViolation::NotAllowed { allowed } => {
+            format!(".refine((v) => [{}].includes(v), {})", allowed, message)
+        }
+        RuleViolation::Pattern { name } => match name.as_str() {
+            "email" => format!(".email({})", message),
+            "url" => format!(".url({})", message),
+            "special-chars" => format!(
+                ".regex(/[{}]/, {})",
+                regex_class(&StringValidator::SPECIAL_CHARS),
+                message
+            ),
+            "uppercase-and-lowercase" => {
+                format!(".regex(/[A-Z]/, {}).regex(/[a-z]/, {})", message, message)
+            }
+            "uppercase" => format!(".regex(/[A-Z]/, {})", message),
+            "lowercase" => format!(".regex(/[a-z]/, {})", message),
+            "digit" => format!(".regex(/[0-9]/, {})", message),
+            _ => return None,
+        },
+        RuleViolation::ForbiddenChars { name } if name == "control" => {
+            format!(".regex(/^\\P{{Cc}}*$/u, {})", message)
+        }
+        _ => return None,
+    };
+    Some(method)
+}
+
+/// Builds the zod schema of `base`, with the `constraints` of the rules, nullish unless
+/// mandatory.
+fn zod_chain(base: &str, is_mandatory: bool, constraints: &[Constraint]) -> String {
+    let mut zod = base.to_string();
+    let mut back_end_only = Vec::new();
+    for constraint in constraints {
+        if constraint.kind == RuleViolation::Mandatory {
+            if base == "z.string()" {
+                zod.push_str(&format!(".min(1, {})", ts_string(&constraint.locale_key)));
+            }
+            continue;
+        }
+        match zod_method(constraint) {
+            Some(method) => zod.push_str(&method),
+            None => back_end_only.push(constraint.locale_key.as_str()),
+        }
+    }
+    if !is_mandatory {
+        zod.push_str(".nullish()");
+    }
+    if !back_end_only.is_empty() {
+        zod.push_str(&format!(
+            " /* back-end only: {} */",
+            back_end_only.join(", ")
+        ));
+    }
+    zod
+}
+
+fn nullable(ts_type: &str, is_mandatory: bool) -> String {
+    if is_mandatory {
+        ts_type.to_string()
+    } else {
+        format!("{} | null", ts_type)
+    }
+}
+
+macro_rules! zod_rules {
+    ($rules:ty, $ts_type:literal, $zod:literal) => {
+        impl ZodSchema for $rules {
+            fn ts_type(&self) -> String {
+                nullable($ts_type, self.is_mandatory)
+            }
+
+            fn zod_schema(&self) -> String {
+                zod_chain($zod, self.is_mandatory, &self.describe())
+            }
+
+            fn is_required(&self) -> bool {
+                self.is_mandatory
+            }
+        }
+    };
+}
+
+zod_rules!(NameRules, "string", "z.string()");
+zod_rules!(UsernameRules, "string", "z.string()");
+zod_rules!(DescriptionRules, "string", "z.string()");
+zod_rules!(PasswordRules, "string", "z.string()");
+#[cfg(feature = "email")]
+zod_rules!(EmailRules, "string", "z.string()");
+#[cfg(feature = "url")]
+zod_rules!(UrlRules, "string", "z.string()");
+zod_rules!(HostnameRules, "string", "z.string()");
+zod_rules!(DomainRules, "string", "z.string()");
+zod_rules!(IntegerRules, "number", "z.number().int()");
+zod_rules!(UnsignedRules, "number", "z.number().int()");
+zod_rules!(FloatRules, "number", "z.number()");
+
+impl Schema {
+    fn as_zod_schema(&self) -> Option<&dyn ZodSchema> {
+        match self {
+            Self::Name(rules) => Some(rules),
+            Self::Username(rules) => Some(rules),
+            Self::Description(rules) => Some(rules),
+            #[cfg(feature = "email")]
+            Self::Email(rules) => Some(rules),
+            #[cfg(feature = "url")]
+            Self::Url(rules) => Some(rules),
+            Self::Integer(rules) => Some(rules),
+            Self::Unsigned(rules) => Some(rules),
+            Self::Float(rules) => Some(rules),
+            Self::Object(_) | Self::Array(..) => None,
+        }
+    }
+
+    fn ts_type_indented(&self, indent: usize) -> String {
+        match self {
+            Self::Object(fields) => {
+                let padding = "  ".repeat(indent + 1);
+                let mut ts = "{\n".to_string();
+                for (name, schema) in fields {
+                    let optional = if schema.is_required() { "" } else { "?" };
+                    ts.push_str(&format!(
+                        "{}{}{}: {};\n",
+                        padding,
+                        ts_string(name),
+                        optional,
+                        schema.ts_type_indented(indent + 1)
+                    ));
+                }
+                ts.push_str(&format!("{}}}", "  ".repeat(indent)));
+                ts
+            }
+            Self::Array(items, rules) => nullable(
+                &format!("Array<{}>", items.ts_type_indented(indent)),
+                rules.is_mandatory,
+            ),
+            _ => self
+                .as_zod_schema()
+                .map(|rules| rules.ts_type())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn zod_schema_indented(&self, indent: usize) -> String {
+        match self {
+            Self::Object(fields) => {
+                let padding = "  ".repeat(indent + 1);
+                let mut zod = "z.object({\n".to_string();
+                for (name, schema) in fields {
+                    zod.push_str(&format!(
+                        "{}{}: {},\n",
+                        padding,
+                        ts_string(name),
+                        schema.zod_schema_indented(indent + 1)
+                    ));
+                }
+                zod.push_str(&format!("{}}})", "  ".repeat(indent)));
+                zod
+            }
+            Self::Array(items, rules) => zod_chain(
+                &format!("z.array({})", items.zod_schema_indented(indent)),
+                rules.is_mandatory,
+                &rules.describe(),
+            ),
+            _ => self
+                .as_zod_schema()
+                .map(|rules| rules.zod_schema())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl ZodSchema for Schema {
+    fn ts_type(&self) -> String {
+        self.ts_type_indented(0)
+    }
+
+    fn zod_schema(&self) -> String {
+        self.zod_schema_indented(0)
+    }
+
+    fn is_required(&self) -> bool {
+        match self {
+            // A missing object is validated field by field, so it is required if any field is.
+            Self::Object(fields) => fields.iter().any(|(_, schema)| schema.is_required()),
+            Self::Array(_, rules) => rules.is_mandatory,
+            _ => self
+                .as_zod_schema()
+                .is_some_and(|rules| rules.is_required()),
+        }
+    }
+}
+
+/// A registry of named rule presets, exported as TypeScript types or zod schemas.
+#[derive(Debug, Clone, Default)]
+pub struct TypeScriptExport {
+    presets: Vec<(String, String, String)>,
+}
+
+impl TypeScriptExport {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the preset `rules` under the type `name`, e.g. `Username`.
+    pub fn preset(mut self, name: &str, rules: &dyn ZodSchema) -> Self {
+        self.presets
+            .push((name.to_string(), rules.ts_type(), rules.zod_schema()));
+        self
+    }
+
+    /// Returns the TypeScript module declaring the type of each preset.
+    pub fn to_typescript(&self) -> String {
+        self.presets
+            .iter()
+            .map(|(name, ts_type, _)| format!("export type {} = {};\n", name, ts_type))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the TypeScript module declaring the zod schema of each preset, named after the
+    /// preset, e.g. `usernameSchema`, and the type inferred from it.
+    pub fn to_zod(&self) -> String {
+        let mut module = "import { z } from \"zod\";\n".to_string();
+        for (name, _, zod) in &self.presets {
+            let schema = schema_name(name);
+            module.push_str(&format!(
+                "\nexport const {} = {};\nexport type {} = z.infer<typeof {}>;\n",
+                schema, zod, name, schema
+            ));
+        }
+        module
+    }
+}
+
+fn schema_name(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}Schema", first.to_lowercase(), chars.as_str()),
+        None => "schema".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::collection_rules::CollectionRules;
+
+    #[test]
+    fn test_zod_schema() {
+        let password = PasswordRules::default().zod_schema();
+        assert!(password.starts_with(
+            "z.string().min(1, \"validate-cannot-be-empty\").min(8, \"validate-min-length\")"
+        ));
+        assert!(
+            password.contains(".regex(/[A-Z]/, \"validate-must-have-uppercase-and-lowercase\")")
+        );
+
+        let rules = IntegerRules {
+            is_mandatory: false,
+            must_be_even: true,
+            allowed_values: Some(vec![2, 4]),
+            ..IntegerRules::default()
+        };
+        assert_eq!(
+            rules.zod_schema(),
+            "z.number().int().gte(0, \"validate-number-min-value\")\
+             .lte(255, \"validate-number-max-value\")\
+             .multipleOf(2, \"validate-number-must-be-even\")\
+             .refine((v) => [2, 4].includes(v), \"validate-number-not-allowed\").nullish()"
+        );
+
+        let names = Schema::array(
+            Schema::Name(NameRules::default()),
+            CollectionRules {
+                is_mandatory: false,
+                max_items: Some(3),
+                ..CollectionRules::default()
+            },
+        );
+        assert_eq!(names.ts_type(), "Array<string> | null");
+        assert!(
+            names
+                .zod_schema()
+                .ends_with(".max(3, \"validate-max-items\").nullish()")
+        );
+        assert_eq!(schema_name("Username"), "usernameSchema");
+    }
+}
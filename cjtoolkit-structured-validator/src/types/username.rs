@@ -1,11 +1,24 @@
 //! This module contains structures and traits for working with usernames.
 
-use crate::base::string_rules::{StringLengthRules, StringMandatoryRules};
+use crate::base::string_rules::{
+    LengthUnit, StringLengthRules, StringMandatoryRules, StringSafetyRules,
+};
+use crate::common::cached_rule::{CacheBackend, CachedAsyncRule};
+use crate::common::check_budget::{CheckBudget, CheckOutcome, CheckSkippedLocale};
+use crate::common::constraint::Constraint;
 use crate::common::locale::{
-    LocaleData, LocaleMessage, ValidateErrorCollector, ValidateErrorStore,
+    LocaleData, LocaleMessage, RuleViolation, ValidateErrorCollector, ValidateErrorStore,
 };
+use crate::common::locale_keys;
+use crate::common::maybe_validated::{OptionalRules, OptionalValue};
+use crate::common::rejected_input::observe_parse_input;
 use crate::common::string_validator::{StrValidationExtension, StringValidator};
+use crate::common::tenant_policy::{TightenRules, tighter_max, tighter_min};
 use crate::common::validation_check::ValidationCheck;
+use crate::common::validator::Validator;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -29,12 +42,33 @@ use thiserror::Error;
 ///   If `Some(value)`, the username must be at most `value` characters long.
 ///   If `None`, there is no maximum length restriction.
 ///
+/// - `length_unit`
+///   The unit the length of the username is counted in, graphemes by default.
+///
+/// - `treat_whitespace_as_empty`
+///   Whether a username made only of whitespace is treated as empty, `true` by default.
+///
+/// - `forbid_control_chars`, `forbid_zero_width` and `forbid_bidi_overrides`
+///   Whether control, zero-width and bidirectional override characters are rejected,
+///   `true` by default.
+///
+/// - `max_errors`
+///   The maximum number of errors reported, the most relevant being kept. If `None`, every
+///   error is reported.
+///
 /// This example specifies a username requirement that is mandatory, with a
 /// minimum of 3 characters and a maximum of 16 characters.
+#[derive(Clone)]
 pub struct UsernameRules {
     pub is_mandatory: bool,
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
+    pub length_unit: LengthUnit,
+    pub treat_whitespace_as_empty: bool,
+    pub forbid_control_chars: bool,
+    pub forbid_zero_width: bool,
+    pub forbid_bidi_overrides: bool,
+    pub max_errors: Option<usize>,
 }
 
 impl Default for UsernameRules {
@@ -43,26 +77,39 @@ impl Default for UsernameRules {
             is_mandatory: true,
             min_length: Some(5),
             max_length: Some(30),
+            length_unit: LengthUnit::Graphemes,
+            treat_whitespace_as_empty: true,
+            forbid_control_chars: true,
+            forbid_zero_width: true,
+            forbid_bidi_overrides: true,
+            max_errors: None,
         }
     }
 }
 
-impl Into<(StringMandatoryRules, StringLengthRules)> for &UsernameRules {
-    fn into(self) -> (StringMandatoryRules, StringLengthRules) {
+impl From<&UsernameRules> for (StringMandatoryRules, StringLengthRules, StringSafetyRules) {
+    fn from(rules: &UsernameRules) -> Self {
         (
             StringMandatoryRules {
-                is_mandatory: self.is_mandatory,
+                is_mandatory: rules.is_mandatory,
+                treat_whitespace_as_empty: rules.treat_whitespace_as_empty,
             },
             StringLengthRules {
-                min_length: self.min_length,
-                max_length: self.max_length,
+                min_length: rules.min_length,
+                max_length: rules.max_length,
+                unit: rules.length_unit,
+            },
+            StringSafetyRules {
+                forbid_control_chars: rules.forbid_control_chars,
+                forbid_zero_width: rules.forbid_zero_width,
+                forbid_bidi_overrides: rules.forbid_bidi_overrides,
             },
         )
     }
 }
 
 impl UsernameRules {
-    fn rules(&self) -> (StringMandatoryRules, StringLengthRules) {
+    fn rules(&self) -> (StringMandatoryRules, StringLengthRules, StringSafetyRules) {
         self.into()
     }
 
@@ -75,12 +122,23 @@ impl UsernameRules {
         if !self.is_mandatory && is_none {
             return;
         }
-        let (mandatory_rule, length_rule) = self.rules();
+        let (mandatory_rule, length_rule, safety_rule) = self.rules();
         mandatory_rule.check(messages, subject);
         if !messages.is_empty() {
             return;
         }
         length_rule.check(messages, subject);
+        safety_rule.check(messages, subject);
+        messages.truncate_by_priority(self.max_errors);
+    }
+
+    /// Returns the constraints enforced by the rules.
+    pub fn describe(&self) -> Vec<Constraint> {
+        let (mandatory_rule, length_rule, safety_rule) = self.rules();
+        let mut constraints = mandatory_rule.describe();
+        constraints.extend(length_rule.describe());
+        constraints.extend(safety_rule.describe());
+        constraints
     }
 }
 
@@ -133,7 +191,12 @@ impl Into<ValidateErrorStore> for &UsernameError {
 /// - `Debug`: Enables formatting the `Username` struct for debugging purposes.
 /// - `PartialEq`: Allows for equality comparison between `Username` instances.
 /// - `Clone`: Provides the ability to create duplicate instances of `Username`.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
 pub struct Username(String, bool);
 
 #[cfg(any(feature = "allow-default-value", test))]
@@ -173,6 +236,65 @@ pub trait IsUsernameTakenAsync {
     fn is_username_taken_async(&self, username: &str) -> impl Future<Output = bool>;
 }
 
+/// A trait for an asynchronous check of whether a username is taken, with a request-scoped
+/// context `Ctx`, e.g. the database transaction of the sign-up, so the check sees the rows it
+/// inserted and needs no service per request.
+///
+/// Every `IsUsernameTakenAsync` implements it, ignoring the context.
+pub trait IsUsernameTakenAsyncWithContext<Ctx: ?Sized> {
+    fn is_username_taken_async_with_context(
+        &self,
+        username: &str,
+        ctx: &Ctx,
+    ) -> impl Future<Output = bool>;
+}
+
+impl<T: IsUsernameTakenAsync, Ctx: ?Sized> IsUsernameTakenAsyncWithContext<Ctx> for T {
+    async fn is_username_taken_async_with_context(&self, username: &str, _ctx: &Ctx) -> bool {
+        self.is_username_taken_async(username).await
+    }
+}
+
+/// Caches whether usernames are taken, e.g. during the retries of a sign-up form.
+impl<R, C> IsUsernameTakenAsync for CachedAsyncRule<R, C>
+where
+    R: IsUsernameTakenAsync,
+    C: CacheBackend<bool>,
+{
+    async fn is_username_taken_async(&self, username: &str) -> bool {
+        self.get_or_check(username, |username| async move {
+            self.rule.is_username_taken_async(&username).await
+        })
+        .await
+    }
+}
+
+/// A trait for an asynchronous check of whether a username is taken, consulting a `CheckBudget`.
+///
+/// Implementations decrement the budget by the cost of the check, and resolve to `None` when the
+/// budget is exhausted and the check is skipped. Every `IsUsernameTakenAsync` implements it,
+/// at a cost of one unit per check.
+pub trait IsUsernameTakenAsyncWithBudget {
+    fn is_username_taken_async_with_budget(
+        &self,
+        username: &str,
+        budget: &CheckBudget,
+    ) -> impl Future<Output = Option<bool>>;
+}
+
+impl<T: IsUsernameTakenAsync> IsUsernameTakenAsyncWithBudget for T {
+    async fn is_username_taken_async_with_budget(
+        &self,
+        username: &str,
+        budget: &CheckBudget,
+    ) -> Option<bool> {
+        if !budget.try_consume(1) {
+            return None;
+        }
+        Some(self.is_username_taken_async(username).await)
+    }
+}
+
 /// A struct representing the locale or message type for the "username taken" error.
 ///
 /// This struct can be used as part of an error handling system or localization framework
@@ -184,7 +306,35 @@ pub struct UsernameTakenLocale;
 
 impl LocaleMessage for UsernameTakenLocale {
     fn get_locale_data(&self) -> Arc<LocaleData> {
-        LocaleData::new("validate-username-taken")
+        LocaleData::new(locale_keys::VALIDATE_USERNAME_TAKEN)
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Taken
+    }
+}
+
+/// A struct representing the locale for the "username confusable" error, holding the existing
+/// username it looks like.
+///
+/// # Key
+/// `validate-username-confusable`
+#[cfg(feature = "confusables")]
+pub struct UsernameConfusableLocale(pub String);
+
+#[cfg(feature = "confusables")]
+impl LocaleMessage for UsernameConfusableLocale {
+    fn get_locale_data(&self) -> Arc<LocaleData> {
+        LocaleData::new_with_vec(
+            locale_keys::VALIDATE_USERNAME_CONFUSABLE,
+            vec![("username".to_string(), self.0.clone().into())],
+        )
+    }
+
+    fn rule_violation(&self) -> RuleViolation {
+        RuleViolation::Confusable {
+            with: self.0.clone(),
+        }
     }
 }
 
@@ -226,13 +376,15 @@ impl Username {
     /// assert!(result.is_err());
     /// ```
     pub fn parse_custom(s: Option<&str>, rules: UsernameRules) -> Result<Self, UsernameError> {
-        let is_none = s.is_none();
-        let s = s.unwrap_or_default();
-        let subject = s.as_string_validator();
-        let mut messages = ValidateErrorCollector::new();
-        rules.check(&mut messages, &subject, is_none);
-        UsernameError::validate_check(messages)?;
-        Ok(Self(s.to_string(), is_none))
+        observe_parse_input(s, || {
+            let is_none = s.is_none();
+            let s = s.unwrap_or_default();
+            let subject = s.as_string_validator();
+            let mut messages = ValidateErrorCollector::new();
+            rules.check(&mut messages, &subject, is_none);
+            UsernameError::validate_check(messages)?;
+            Ok(Self(s.to_string(), is_none))
+        })
     }
 
     /// Parses a given string slice (`Option<&str>`) into a `Self` instance using the default username rules.
@@ -355,10 +507,57 @@ impl Username {
         &self,
         service: &T,
     ) -> Result<Self, UsernameError> {
+        self.check_username_taken_async_with_context(service, &())
+            .await
+    }
+
+    /// Like `check_username_taken_async`, but the service is given the request-scoped `ctx`,
+    /// e.g. a database transaction.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::username::{
+    ///     IsUsernameTakenAsyncWithContext, Username,
+    /// };
+    /// use std::sync::Mutex;
+    ///
+    /// struct Transaction {
+    ///     inserted: Mutex<Vec<String>>,
+    /// }
+    ///
+    /// struct Users;
+    ///
+    /// impl IsUsernameTakenAsyncWithContext<Transaction> for Users {
+    ///     async fn is_username_taken_async_with_context(
+    ///         &self,
+    ///         username: &str,
+    ///         tx: &Transaction,
+    ///     ) -> bool {
+    ///         tx.inserted.lock().expect("Expected lock").iter().any(|u| u == username)
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().expect("Expected runtime").block_on(async {
+    /// let tx = Transaction {
+    ///     inserted: Mutex::new(vec!["alice".to_string()]),
+    /// };
+    /// let username = Username::parse(Some("alice")).expect("Expected valid username");
+    /// assert!(username.check_username_taken_async_with_context(&Users, &tx).await.is_err());
+    /// # });
+    /// ```
+    pub async fn check_username_taken_async_with_context<Ctx, T>(
+        &self,
+        service: &T,
+        ctx: &Ctx,
+    ) -> Result<Self, UsernameError>
+    where
+        Ctx: ?Sized,
+        T: IsUsernameTakenAsyncWithContext<Ctx>,
+    {
         let mut messages = ValidateErrorCollector::new();
 
         service
-            .is_username_taken_async(self.as_str())
+            .is_username_taken_async_with_context(self.as_str(), ctx)
             .await
             .then(|| {
                 messages.push(("Already taken".to_string(), Box::new(UsernameTakenLocale)));
@@ -368,6 +567,119 @@ impl Username {
         Ok(self.clone())
     }
 
+    /// Like `check_username_taken_async`, but the check consults `budget` and is skipped when
+    /// the budget is exhausted.
+    ///
+    /// A skipped check is not an error, the username is returned with a `validate-check-skipped`
+    /// warning in `CheckOutcome::warnings`, so callers may accept it and re-check it later.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::common::check_budget::CheckBudget;
+    /// use cjtoolkit_structured_validator::types::username::{IsUsernameTakenAsync, Username};
+    ///
+    /// struct Users;
+    ///
+    /// impl IsUsernameTakenAsync for Users {
+    ///     async fn is_username_taken_async(&self, username: &str) -> bool {
+    ///         username == "admin"
+    ///     }
+    /// }
+    ///
+    /// # tokio_test(async {
+    /// let budget = CheckBudget::new(1);
+    /// let username = Username::parse(Some("alice")).expect("Expected valid username");
+    /// let outcome = username
+    ///     .check_username_taken_async_with_budget(&Users, &budget)
+    ///     .await
+    ///     .expect("Expected username to be available");
+    /// assert!(!outcome.is_degraded());
+    ///
+    /// let outcome = username
+    ///     .check_username_taken_async_with_budget(&Users, &budget)
+    ///     .await
+    ///     .expect("Expected skipped check to pass");
+    /// assert!(outcome.is_degraded());
+    /// # });
+    /// # fn tokio_test(f: impl std::future::Future<Output = ()>) {
+    /// #     tokio::runtime::Runtime::new().expect("Expected runtime").block_on(f)
+    /// # }
+    /// ```
+    pub async fn check_username_taken_async_with_budget<T: IsUsernameTakenAsyncWithBudget>(
+        &self,
+        service: &T,
+        budget: &CheckBudget,
+    ) -> Result<CheckOutcome<Self>, UsernameError> {
+        let mut messages = ValidateErrorCollector::new();
+        let mut warnings = ValidateErrorCollector::new();
+
+        match service
+            .is_username_taken_async_with_budget(self.as_str(), budget)
+            .await
+        {
+            Some(true) => {
+                messages.push(("Already taken".to_string(), Box::new(UsernameTakenLocale)));
+            }
+            Some(false) => {}
+            None => {
+                warnings.push((
+                    "Could not be checked, try again later".to_string(),
+                    Box::new(CheckSkippedLocale),
+                ));
+            }
+        }
+
+        UsernameError::validate_check(messages)?;
+        Ok(CheckOutcome {
+            value: self.clone(),
+            warnings: warnings.into(),
+        })
+    }
+
+    /// Returns the confusable skeleton of the username, as defined by Unicode Technical Standard
+    /// #39, after lowercasing it. Two usernames with the same skeleton look alike, e.g. "аdmin"
+    /// with a Cyrillic "а" and "admin".
+    #[cfg(feature = "confusables")]
+    pub fn skeleton(&self) -> String {
+        unicode_security::skeleton(&self.0.to_lowercase()).collect()
+    }
+
+    /// Checks the username does not look like any of the `existing` usernames, comparing their
+    /// confusable skeletons.
+    ///
+    /// # Returns
+    /// * `Ok(Self)` - If the username does not look like any existing username.
+    /// * `Err(UsernameError)` - If it does, holding the first existing username it looks like.
+    ///
+    /// # Example
+    /// ```
+    /// use cjtoolkit_structured_validator::types::username::Username;
+    ///
+    /// let username = Username::parse(Some("\u{430}dmin1")).expect("Expected valid username");
+    /// assert!(username.check_confusable_with(["admin1", "guest"]).is_err());
+    /// assert!(username.check_confusable_with(["guest"]).is_ok());
+    /// ```
+    #[cfg(feature = "confusables")]
+    pub fn check_confusable_with<'a, I: IntoIterator<Item = &'a str>>(
+        &self,
+        existing: I,
+    ) -> Result<Self, UsernameError> {
+        let mut messages = ValidateErrorCollector::new();
+        let skeleton = self.skeleton();
+
+        if let Some(existing) = existing.into_iter().find(|existing| {
+            unicode_security::skeleton(&existing.to_lowercase()).eq(skeleton.chars())
+        }) {
+            messages.push((
+                "Too similar to an existing username".to_string(),
+                Box::new(UsernameConfusableLocale(existing.to_string())),
+            ));
+        }
+
+        UsernameError::validate_check(messages)?;
+        Ok(self.clone())
+    }
+
     /// Returns the string slice representation of the current object.
     ///
     /// # Returns
@@ -380,6 +692,11 @@ impl Username {
         &self.0
     }
 
+    /// Returns `true` if the username was not provided.
+    pub fn is_none(&self) -> bool {
+        self.1
+    }
+
     /// Converts the `Username` wrapper into an `Option<Username>` type.
     ///
     /// If the internal boolean flag (`self.1`) is `true`, it returns `None`.
@@ -394,12 +711,163 @@ impl Username {
     }
 }
 
+impl OptionalValue for Username {
+    fn is_absent(&self) -> bool {
+        self.is_none()
+    }
+}
+
 impl Into<String> for &Username {
     fn into(self) -> String {
         self.as_str().to_string()
     }
 }
 
+impl Display for Username {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Username {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Username {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Compares the strings, as `Borrow<str>` requires, so a `Username` key can be found by
+/// a `&str`.
+impl PartialEq for Username {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Username {}
+
+impl Hash for Username {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for Username {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Username {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<Option<&str>> for Username {
+    type Error = UsernameError;
+
+    fn try_from(s: Option<&str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Username {
+    type Error = UsernameError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(Some(s))
+    }
+}
+
+impl TryFrom<String> for Username {
+    type Error = UsernameError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(Some(&s))
+    }
+}
+
+impl TightenRules for UsernameRules {
+    fn tighten(&self, other: &Self) -> Self {
+        Self {
+            is_mandatory: self.is_mandatory || other.is_mandatory,
+            min_length: tighter_min(self.min_length, other.min_length),
+            max_length: tighter_max(self.max_length, other.max_length),
+            length_unit: self.length_unit,
+            treat_whitespace_as_empty: self.treat_whitespace_as_empty
+                || other.treat_whitespace_as_empty,
+            forbid_control_chars: self.forbid_control_chars || other.forbid_control_chars,
+            forbid_zero_width: self.forbid_zero_width || other.forbid_zero_width,
+            forbid_bidi_overrides: self.forbid_bidi_overrides || other.forbid_bidi_overrides,
+            max_errors: self.max_errors,
+        }
+    }
+}
+
+impl Validator<Option<&str>, Username, UsernameError> for UsernameRules {
+    fn validate(&self, input: Option<&str>) -> Result<Username, UsernameError> {
+        Username::parse_custom(input, self.clone())
+    }
+}
+
+impl OptionalRules for UsernameRules {
+    fn optional(self) -> Self {
+        Self {
+            is_mandatory: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl UsernameRules {
+    /// Returns an input satisfying the rules, `sampleuser` repeated or truncated to fit the length
+    /// constraints.
+    pub fn arbitrary_valid_input(&self) -> String {
+        crate::common::fixtures::sample_string("sampleuser", self.min_length, self.max_length)
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl Username {
+    /// Returns a valid `Username` for the default `UsernameRules`.
+    pub fn valid_sample() -> Self {
+        Self::sample_matching(UsernameRules::default())
+    }
+
+    /// Returns a valid `Username` for the given rules, parsed from `arbitrary_valid_input`.
+    ///
+    /// # Panics
+    /// Panics if no input can satisfy the rules, e.g. `min_length` above `max_length`.
+    pub fn sample_matching(rules: UsernameRules) -> Self {
+        let input = rules.arbitrary_valid_input();
+        Self::parse_custom(Some(&input), rules).expect("Expected sample to be valid")
+    }
+}
+
+#[cfg(feature = "trusted-input")]
+impl Username {
+    /// Creates an instance of `Username` without validation, e.g. when loading values which were
+    /// validated before being stored. The string is kept as is, `None` gives an empty value.
+    pub fn new_unchecked(s: Option<&str>) -> Self {
+        let is_none = s.is_none();
+        let s = s.unwrap_or_default();
+        Self(s.to_string(), is_none)
+    }
+
+    /// Creates an instance of `Username` from a stored string without validation, so stored values
+    /// which no longer satisfy the rules can still be loaded. See `new_unchecked`.
+    pub fn from_stored(s: &str) -> Self {
+        Self::new_unchecked(Some(s))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -463,4 +931,96 @@ mod tests {
                 .is_ok()
         )
     }
+
+    struct ExpensiveUsernameCheckService;
+
+    impl IsUsernameTakenAsyncWithBudget for ExpensiveUsernameCheckService {
+        async fn is_username_taken_async_with_budget(
+            &self,
+            username: &str,
+            budget: &CheckBudget,
+        ) -> Option<bool> {
+            budget.try_consume(5).then(|| username == "taken")
+        }
+    }
+
+    #[tokio::test]
+    async fn username_is_taken_async_with_budget() {
+        let budget = CheckBudget::new(1);
+        let error = Username("taken".to_string(), false)
+            .check_username_taken_async_with_budget(
+                &FakeUsernameCheckService("taken".to_string()),
+                &budget,
+            )
+            .await
+            .expect_err("Expected username to be taken");
+        assert_eq!(error.0.violations(), vec![RuleViolation::Taken]);
+        assert!(budget.is_exhausted());
+    }
+
+    #[tokio::test]
+    async fn username_check_skipped_when_budget_exhausted() {
+        let budget = CheckBudget::new(4);
+        let outcome = Username("taken".to_string(), false)
+            .check_username_taken_async_with_budget(&ExpensiveUsernameCheckService, &budget)
+            .await
+            .expect("Expected skipped check to pass");
+        assert!(outcome.is_degraded());
+        assert_eq!(
+            outcome.warnings.violations(),
+            vec![RuleViolation::Unverified {
+                name: "budget".to_string()
+            }]
+        );
+        assert_eq!(budget.remaining(), 4);
+    }
+
+    #[test]
+    fn username_display_and_as_ref() {
+        let username = Username::parse(Some("ValidUser")).unwrap_or_default();
+        assert_eq!(format!("@{}", username), "@ValidUser");
+        assert_eq!(username.as_ref(), "ValidUser");
+        assert!(username == *"ValidUser");
+    }
+
+    #[test]
+    fn username_with_bidi_override_is_rejected() {
+        let err = Username::parse(Some("admin\u{202E}nimda"))
+            .expect_err("Expected bidi override to be rejected");
+        assert_eq!(
+            err.0.as_original_message_vec(),
+            vec!["Must not contain text direction characters"]
+        );
+    }
+
+    #[cfg(feature = "confusables")]
+    #[test]
+    fn username_confusable_with_existing() {
+        let username = Username::parse(Some("p\u{430}ypal")).unwrap_or_default();
+        let err = username
+            .check_confusable_with(["PayPal"])
+            .expect_err("Expected username to be confusable");
+        assert_eq!(
+            err.0.violations(),
+            vec![RuleViolation::Confusable {
+                with: "PayPal".to_string()
+            }]
+        );
+        assert!(username.check_confusable_with(["paypal2"]).is_ok());
+    }
+
+    #[test]
+    fn username_blank_is_empty() {
+        let err = Username::parse(Some("      ")).expect_err("Expected blank username to fail");
+        assert_eq!(err.0.as_original_message_vec(), vec!["Cannot be empty"]);
+    }
+
+    #[test]
+    #[cfg(feature = "trusted-input")]
+    fn username_from_stored_skips_validation() {
+        assert!(Username::parse(Some("ab")).is_err());
+        let username = Username::from_stored("ab");
+        assert_eq!(username.as_str(), "ab");
+        assert!(Username::new_unchecked(None).is_none());
+    }
 }
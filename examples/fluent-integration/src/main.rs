@@ -1,6 +1,6 @@
 use cjtoolkit_structured_validator::common::flag_error::FlagCounter;
-use cjtoolkit_structured_validator::common::locale::{LocaleData, LocaleValue, ValidateErrorStore};
-use cjtoolkit_structured_validator::common::validation_collector::AsValidateErrorStore;
+use cjtoolkit_structured_validator::common::locale::{LocaleData, LocaleValue};
+use cjtoolkit_structured_validator::common::translator::{TranslateStore, Translator};
 use cjtoolkit_structured_validator::types::description::{Description, DescriptionError};
 use cjtoolkit_structured_validator::types::name::name_alias::{Title, TitleError};
 use fluent::{FluentArgs, FluentBundle, FluentResource};
@@ -8,24 +8,15 @@ use std::borrow::Borrow;
 use std::sync::Arc;
 use unic_langid::LanguageIdentifier;
 
-trait FluentBundleForLocaleData {
-    fn get_translation<R: Borrow<FluentResource>>(
-        &self,
-        bundle: &FluentBundle<R>,
-        original: String,
-    ) -> String;
-}
+struct FluentTranslator<'a, R>(&'a FluentBundle<R>);
 
-impl FluentBundleForLocaleData for LocaleData {
-    fn get_translation<R: Borrow<FluentResource>>(
-        &self,
-        bundle: &FluentBundle<R>,
-        original: String,
-    ) -> String {
+impl<R: Borrow<FluentResource>> Translator for FluentTranslator<'_, R> {
+    fn translate(&self, data: &LocaleData, original: &str) -> String {
+        let bundle = self.0;
         let mut args: Option<FluentArgs> = None;
-        if !self.args.is_empty() {
+        if !data.args.is_empty() {
             let mut values = FluentArgs::new();
-            for (key, value) in self.args.iter() {
+            for (key, value) in data.args.iter() {
                 match value {
                     LocaleValue::String(string) => {
                         values.set::<String, String>(key.clone(), string.clone());
@@ -46,74 +37,14 @@ impl FluentBundleForLocaleData for LocaleData {
 
         let mut errors = vec![];
         bundle
-            .get_message(self.name.as_str())
-            .map(|f| {
-                let pattern = f.value();
-                match pattern {
-                    None => original.clone(),
-                    Some(pattern) => {
-                        let value = bundle.format_pattern(pattern, args.as_ref(), &mut errors);
-                        value.to_string()
-                    }
-                }
+            .get_message(data.name.as_str())
+            .and_then(|f| f.value())
+            .map(|pattern| {
+                bundle
+                    .format_pattern(pattern, args.as_ref(), &mut errors)
+                    .to_string()
             })
-            .unwrap_or(original)
-    }
-}
-
-pub trait FluentBundleForStore {
-    fn as_translated_messages<R: Borrow<FluentResource>>(
-        &self,
-        bundle: &FluentBundle<R>,
-    ) -> Vec<String>;
-
-    fn as_translated_messages_arc<R: Borrow<FluentResource>>(
-        &self,
-        bundle: &FluentBundle<R>,
-    ) -> Arc<[String]> {
-        self.as_translated_messages(bundle).into()
-    }
-}
-
-impl FluentBundleForStore for ValidateErrorStore {
-    fn as_translated_messages<R: Borrow<FluentResource>>(
-        &self,
-        bundle: &FluentBundle<R>,
-    ) -> Vec<String> {
-        self.0
-            .iter()
-            .map(|e| e.1.get_locale_data().get_translation(bundle, e.0.clone()))
-            .collect()
-    }
-}
-
-trait AsTranslatedMessageFromResult: AsValidateErrorStore {
-    fn as_translated_messages<R: Borrow<FluentResource>>(
-        &self,
-        bundle: &FluentBundle<R>,
-    ) -> Vec<String>;
-    fn as_translated_messages_arc<R: Borrow<FluentResource>>(
-        &self,
-        bundle: &FluentBundle<R>,
-    ) -> Arc<[String]>;
-}
-
-impl<T, E> AsTranslatedMessageFromResult for Result<T, E>
-where
-    for<'a> &'a E: Into<ValidateErrorStore>,
-{
-    fn as_translated_messages<R: Borrow<FluentResource>>(
-        &self,
-        bundle: &FluentBundle<R>,
-    ) -> Vec<String> {
-        self.as_validate_store().as_translated_messages(bundle)
-    }
-
-    fn as_translated_messages_arc<R: Borrow<FluentResource>>(
-        &self,
-        bundle: &FluentBundle<R>,
-    ) -> Arc<[String]> {
-        self.as_validate_store().as_translated_messages_arc(bundle)
+            .unwrap_or_else(|| original.to_string())
     }
 }
 
@@ -185,8 +116,12 @@ struct SubjectMessage {
 impl<R: Borrow<FluentResource>> From<(&SubjectError, &FluentBundle<R>)> for SubjectMessage {
     fn from((error, bundle): (&SubjectError, &FluentBundle<R>)) -> Self {
         Self {
-            title: error.title.as_translated_messages_arc(bundle),
-            description: error.description.as_translated_messages_arc(bundle),
+            title: error
+                .title
+                .as_translated_messages_arc(&FluentTranslator(bundle)),
+            description: error
+                .description
+                .as_translated_messages_arc(&FluentTranslator(bundle)),
         }
     }
 }
@@ -1,3 +1,4 @@
+use cjtoolkit_structured_validator::base::string_rules::{LengthUnit, SpamHeuristicsRules};
 use cjtoolkit_structured_validator::common::locale::{LocaleData, LocaleMessage};
 use cjtoolkit_structured_validator::common::validation_check::ValidationCheck;
 use cjtoolkit_structured_validator::common::validation_collector::AsValidateErrorStore;
@@ -31,6 +32,9 @@ impl FruitChoice for Name {
                 is_mandatory: true,
                 min_length: None,
                 max_length: None,
+                length_unit: LengthUnit::Graphemes,
+                treat_whitespace_as_empty: true,
+                spam_heuristics: SpamHeuristicsRules::default(),
             },
         );
         let mut messages = subject.as_validate_error_collector();